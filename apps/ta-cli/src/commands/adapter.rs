@@ -3,6 +3,8 @@
 // Adapters are config templates that generate agent-specific integration
 // files. `ta adapter install claude-code` generates `.mcp.json` and
 // `.ta/config.toml` so Claude Code can connect to the TA MCP server.
+// `ta adapter install --agent codex` registers the same server in Codex's
+// project-scoped `.codex/config.toml`.
 //
 // Messaging adapters (v0.15.9):
 //   `ta adapter setup messaging/<plugin>` — one-time credential wizard
@@ -25,9 +27,17 @@ pub enum AdapterCommands {
     /// List available agent adapters.
     List,
     /// Install an adapter for a specific agent system.
+    ///
+    /// Examples:
+    ///   ta adapter install claude-code
+    ///   ta adapter install --agent codex
     Install {
-        /// Adapter name (e.g., "claude-code").
-        name: String,
+        /// Adapter name (e.g., "claude-code", "codex").
+        #[arg(required_unless_present = "agent", conflicts_with = "agent")]
+        name: Option<String>,
+        /// Agent to wire up (alternative to the positional name).
+        #[arg(long)]
+        agent: Option<String>,
     },
     /// One-time setup wizard for a messaging or social media provider plugin.
     ///
@@ -87,7 +97,10 @@ pub enum CredentialsCommands {
 pub fn execute(cmd: &AdapterCommands, project_root: &Path) -> anyhow::Result<()> {
     match cmd {
         AdapterCommands::List => list_adapters(project_root),
-        AdapterCommands::Install { name } => install_adapter(name, project_root),
+        AdapterCommands::Install { name, agent } => {
+            let name = name.as_deref().or(agent.as_deref()).unwrap_or_default();
+            install_adapter(name, project_root)
+        }
         AdapterCommands::Setup { plugin } => setup_plugin(plugin, project_root),
         AdapterCommands::Health { adapter_type } => health_check(adapter_type, project_root),
         AdapterCommands::Credentials { cmd } => credentials_cmd(cmd),
@@ -101,7 +114,15 @@ pub fn execute(cmd: &AdapterCommands, project_root: &Path) -> anyhow::Result<()>
 fn list_adapters(project_root: &Path) -> anyhow::Result<()> {
     println!("Available adapters:");
     println!();
-    println!("  claude-code    Claude Code (MCP stdio server)");
+    let status = |configured: bool| if configured { "[configured]" } else { "" };
+    println!(
+        "  claude-code    Claude Code (MCP stdio server)   {}",
+        status(claude_code_configured(project_root))
+    );
+    println!(
+        "  codex          OpenAI Codex CLI (MCP stdio server)   {}",
+        status(codex_configured(project_root))
+    );
     println!("  generic-mcp    Generic MCP client");
     println!();
     println!("Install with: ta adapter install <name>");
@@ -155,6 +176,7 @@ fn list_adapters(project_root: &Path) -> anyhow::Result<()> {
 fn install_adapter(name: &str, project_root: &Path) -> anyhow::Result<()> {
     match name {
         "claude-code" => install_claude_code(project_root),
+        "codex" => install_codex(project_root),
         "generic-mcp" => install_generic_mcp(project_root),
         _ => {
             anyhow::bail!(
@@ -870,6 +892,169 @@ fn install_claude_code(project_root: &Path) -> anyhow::Result<()> {
     Ok(())
 }
 
+/// MCP server name registered in Codex's config.
+const CODEX_SERVER_NAME: &str = "trusted-autonomy";
+/// Project-scoped Codex config, relative to the project root.
+const CODEX_CONFIG_REL_PATH: &str = ".codex/config.toml";
+
+/// Codex profile holding the settings TA runs Codex with. It only applies
+/// when selected (`codex --profile trusted-autonomy`), so plain `codex` runs
+/// in the project keep the user's own approval and sandbox settings.
+const CODEX_PROFILE_NAME: &str = "trusted-autonomy";
+
+/// Settings equivalent to the Claude Code permissions TA injects into
+/// staging: the agent works in a staging copy and every change is reviewed
+/// as a draft, so Codex should not stop to ask before each tool call.
+const CODEX_PERMISSION_DEFAULTS: &[(&str, &str)] = &[
+    ("approval_policy", "never"),
+    ("sandbox_mode", "workspace-write"),
+];
+
+/// Header of the top-level block earlier versions of `install_codex` wrote.
+const CODEX_LEGACY_MARKER: &str = "# Added by `ta adapter install --agent codex`.";
+
+/// Drop the top-level `approval_policy`/`sandbox_mode` block an earlier
+/// install prepended, which applied to every Codex run in the project.
+/// Returns `None` when the file doesn't start with that block.
+fn strip_legacy_codex_permissions(existing: &str) -> Option<String> {
+    let mut rest = existing
+        .strip_prefix(CODEX_LEGACY_MARKER)?
+        .strip_prefix('\n')?;
+    for (key, value) in CODEX_PERMISSION_DEFAULTS {
+        if let Some(after) = rest.strip_prefix(&format!("{} = \"{}\"\n", key, value)) {
+            rest = after;
+        }
+    }
+    Some(rest.strip_prefix('\n').unwrap_or(rest).to_string())
+}
+
+fn install_codex(project_root: &Path) -> anyhow::Result<()> {
+    let config_path = project_root.join(CODEX_CONFIG_REL_PATH);
+    let mut existing = if config_path.exists() {
+        fs::read_to_string(&config_path)?
+    } else {
+        String::new()
+    };
+    let legacy_removed = match strip_legacy_codex_permissions(&existing) {
+        Some(stripped) => {
+            existing = stripped;
+            println!(
+                "  Removed the top-level approval_policy/sandbox_mode an earlier install \
+                 added to {} (now in [profiles.{}])",
+                CODEX_CONFIG_REL_PATH, CODEX_PROFILE_NAME
+            );
+            true
+        }
+        None => false,
+    };
+    let table: toml::Table = existing.parse().map_err(|e| {
+        anyhow::anyhow!(
+            "{} is not valid TOML ({}). Fix it and re-run `ta adapter install --agent codex`.",
+            config_path.display(),
+            e
+        )
+    })?;
+
+    // Edit the file textually rather than re-serializing the parsed table so
+    // the user's comments and layout survive.
+    let mut blocks = Vec::new();
+    if table
+        .get("mcp_servers")
+        .and_then(|v| v.get(CODEX_SERVER_NAME))
+        .is_some()
+    {
+        println!(
+            "  {} already registers [mcp_servers.{}] — skipping (check manually)",
+            CODEX_CONFIG_REL_PATH, CODEX_SERVER_NAME
+        );
+    } else {
+        blocks.push(format!(
+            r#"[mcp_servers.{name}]
+command = "cargo"
+args = ["run", "-p", "ta-daemon", "--"]
+startup_timeout_sec = 60
+
+[mcp_servers.{name}.env]
+TA_LOG_LEVEL = "info"
+"#,
+            name = CODEX_SERVER_NAME
+        ));
+    }
+    if table
+        .get("profiles")
+        .and_then(|v| v.get(CODEX_PROFILE_NAME))
+        .is_some()
+    {
+        println!(
+            "  {} already defines [profiles.{}] — leaving it",
+            CODEX_CONFIG_REL_PATH, CODEX_PROFILE_NAME
+        );
+    } else {
+        let mut profile = format!("[profiles.{}]\n", CODEX_PROFILE_NAME);
+        for (key, value) in CODEX_PERMISSION_DEFAULTS {
+            profile.push_str(&format!("{} = \"{}\"\n", key, value));
+        }
+        blocks.push(profile);
+    }
+
+    if !blocks.is_empty() || legacy_removed {
+        let mut content = existing.clone();
+        for block in &blocks {
+            if !content.is_empty() && !content.ends_with('\n') {
+                content.push('\n');
+            }
+            if !content.is_empty() {
+                content.push('\n');
+            }
+            content.push_str(block);
+        }
+        if let Some(parent) = config_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let verb = if config_path.exists() {
+            "Updated"
+        } else {
+            "Created"
+        };
+        fs::write(&config_path, content)?;
+        println!("  {} {}", verb, CODEX_CONFIG_REL_PATH);
+    }
+
+    install_ta_config(project_root)?;
+
+    println!();
+    println!("Codex adapter installed!");
+    println!();
+    println!("Next steps:");
+    println!("  1. Build TA:  cargo build --workspace");
+    println!("  2. Trust this project in Codex so it loads .codex/config.toml");
+    println!("  3. Start Codex in this directory — TA tools appear under the");
+    println!("     '{}' MCP server", CODEX_SERVER_NAME);
+    println!("  4. Review with: ta draft list / ta draft view <id>");
+    println!("  5. Approve:    ta draft approve <id>");
+    println!("  6. Apply:      ta draft apply <id>");
+
+    Ok(())
+}
+
+/// True when `.mcp.json` registers the TA server for Claude Code.
+fn claude_code_configured(project_root: &Path) -> bool {
+    fs::read_to_string(project_root.join(".mcp.json"))
+        .ok()
+        .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+        .map(|json| json["mcpServers"]["ta"].is_object())
+        .unwrap_or(false)
+}
+
+/// True when `.codex/config.toml` registers the TA server for Codex.
+fn codex_configured(project_root: &Path) -> bool {
+    fs::read_to_string(project_root.join(CODEX_CONFIG_REL_PATH))
+        .ok()
+        .and_then(|s| s.parse::<toml::Table>().ok())
+        .and_then(|t| t.get("mcp_servers")?.get(CODEX_SERVER_NAME).cloned())
+        .is_some()
+}
+
 fn install_generic_mcp(project_root: &Path) -> anyhow::Result<()> {
     install_ta_config(project_root)?;
 
@@ -957,6 +1142,86 @@ mod tests {
         assert_eq!(content, "existing");
     }
 
+    #[test]
+    fn install_codex_registers_server_and_permissions() {
+        let dir = TempDir::new().unwrap();
+        assert!(!codex_configured(dir.path()));
+        install_adapter("codex", dir.path()).unwrap();
+
+        let config: toml::Table = fs::read_to_string(dir.path().join(".codex/config.toml"))
+            .unwrap()
+            .parse()
+            .unwrap();
+        let server = &config["mcp_servers"]["trusted-autonomy"];
+        assert_eq!(server["command"].as_str(), Some("cargo"));
+        assert_eq!(server["env"]["TA_LOG_LEVEL"].as_str(), Some("info"));
+        // Permissions live in TA's profile, never at the top level.
+        let profile = &config["profiles"]["trusted-autonomy"];
+        assert_eq!(profile["approval_policy"].as_str(), Some("never"));
+        assert_eq!(profile["sandbox_mode"].as_str(), Some("workspace-write"));
+        assert!(config.get("approval_policy").is_none());
+        assert!(config.get("sandbox_mode").is_none());
+        assert!(dir.path().join(".ta/config.toml").exists());
+        assert!(codex_configured(dir.path()));
+        assert!(!claude_code_configured(dir.path()));
+
+        // Re-running is a no-op.
+        let before = fs::read_to_string(dir.path().join(".codex/config.toml")).unwrap();
+        install_codex(dir.path()).unwrap();
+        let after = fs::read_to_string(dir.path().join(".codex/config.toml")).unwrap();
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn install_codex_preserves_existing_config() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join(".codex")).unwrap();
+        fs::write(
+            dir.path().join(".codex/config.toml"),
+            "# my settings\napproval_policy = \"on-request\"\n\n[mcp_servers.other]\ncommand = \"other\"",
+        )
+        .unwrap();
+
+        install_codex(dir.path()).unwrap();
+
+        let content = fs::read_to_string(dir.path().join(".codex/config.toml")).unwrap();
+        assert!(content.contains("# my settings"));
+        let config: toml::Table = content.parse().unwrap();
+        // The user's own settings are left exactly as they were.
+        assert_eq!(config["approval_policy"].as_str(), Some("on-request"));
+        assert!(config.get("sandbox_mode").is_none());
+        assert!(config["profiles"]["trusted-autonomy"].is_table());
+        assert!(config["mcp_servers"]["other"].is_table());
+        assert!(config["mcp_servers"]["trusted-autonomy"].is_table());
+    }
+
+    #[test]
+    fn install_codex_moves_legacy_top_level_permissions_into_profile() {
+        let dir = TempDir::new().unwrap();
+        fs::create_dir_all(dir.path().join(".codex")).unwrap();
+        fs::write(
+            dir.path().join(".codex/config.toml"),
+            "# Added by `ta adapter install --agent codex`.\n\
+             approval_policy = \"never\"\n\
+             sandbox_mode = \"workspace-write\"\n\
+             \n\
+             model = \"o4-mini\"\n",
+        )
+        .unwrap();
+
+        install_codex(dir.path()).unwrap();
+
+        let content = fs::read_to_string(dir.path().join(".codex/config.toml")).unwrap();
+        assert!(content.starts_with("model = \"o4-mini\"\n"), "{}", content);
+        let config: toml::Table = content.parse().unwrap();
+        assert!(config.get("approval_policy").is_none());
+        assert!(config.get("sandbox_mode").is_none());
+        assert_eq!(
+            config["profiles"]["trusted-autonomy"]["approval_policy"].as_str(),
+            Some("never")
+        );
+    }
+
     #[test]
     fn install_generic_mcp_creates_config() {
         let dir = TempDir::new().unwrap();
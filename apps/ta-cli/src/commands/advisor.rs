@@ -178,8 +178,8 @@ pub fn advise(
     }
 
     // Load goal store.
-    let goals_dir = config.workspace_root.join(".ta/goals");
-    let store = GoalRunStore::new(&goals_dir).map_err(|e| {
+    let goals_dir = &config.goals_dir;
+    let store = GoalRunStore::new(goals_dir).map_err(|e| {
         anyhow::anyhow!(
             "Failed to load goal store from {:?}: {}. \
              Run `ta goal list` to verify the workspace.",
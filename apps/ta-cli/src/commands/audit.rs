@@ -1,10 +1,10 @@
-// audit.rs — Audit subcommands: verify, tail, show, export, drift, baseline,
-//             verify-attestation (v0.14.1), ledger (v0.14.6).
+// audit.rs — Audit subcommands: verify, checkpoint, tail, query, show, from-commit,
+//             export, drift, baseline, verify-attestation (v0.14.1), ledger (v0.14.6).
 
 use clap::Subcommand;
 use ta_audit::{
-    AttestationBackend, AuditDisposition, AuditEvent, AuditLog, BaselineStore, DraftSummary,
-    DriftSeverity, GoalAuditLedger, LedgerFilter, SoftwareAttestationBackend,
+    AttestationBackend, AuditCheckpoint, AuditDisposition, AuditEvent, AuditLog, BaselineStore,
+    DraftSummary, DriftSeverity, GoalAuditLedger, LedgerFilter, SoftwareAttestationBackend,
 };
 use ta_goal::{MessagingAuditLog, SocialAuditLog};
 use ta_mcp_gateway::GatewayConfig;
@@ -16,6 +16,26 @@ pub enum AuditCommands {
         /// Path to audit log (defaults to .ta/audit.jsonl).
         #[arg(long)]
         log: Option<String>,
+        /// Verify only the events appended since the last trusted checkpoint
+        /// (see `ta audit checkpoint`).
+        #[arg(long)]
+        from_checkpoint: bool,
+        /// Path to keys directory used to check the checkpoint signature
+        /// (defaults to .ta/keys).
+        #[arg(long)]
+        keys: Option<String>,
+    },
+    /// Verify the full hash chain and record a signed checkpoint at its head.
+    ///
+    /// Later `ta audit verify --from-checkpoint` runs only check events
+    /// appended after the checkpoint.
+    Checkpoint {
+        /// Path to audit log (defaults to .ta/audit.jsonl).
+        #[arg(long)]
+        log: Option<String>,
+        /// Path to keys directory (defaults to .ta/keys).
+        #[arg(long)]
+        keys: Option<String>,
     },
     /// Show recent audit events.
     Tail {
@@ -26,6 +46,35 @@ pub enum AuditCommands {
         #[arg(short, default_value = "10")]
         n: usize,
     },
+    /// List every event of one logical operation: all events a single `ta`
+    /// command recorded share its correlation ID (the OP column of `ta audit tail`).
+    ///
+    /// Example:
+    ///   ta audit query --correlation 3f2a9c1e
+    Query {
+        /// Path to audit log (defaults to .ta/audit.jsonl).
+        #[arg(long)]
+        log: Option<String>,
+        /// Correlation ID, or a prefix of at least 8 characters.
+        #[arg(long, value_name = "ID")]
+        correlation: String,
+        /// Print the matching events as JSON lines.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Summarize audit activity: events per action and agent, policy
+    /// decisions, applies per day, and the most frequently written targets.
+    Stats {
+        /// Path to audit log (defaults to .ta/audit.jsonl).
+        #[arg(long)]
+        log: Option<String>,
+        /// Only count events recorded after this date (ISO 8601, e.g. 2026-01-01).
+        #[arg(long)]
+        since: Option<String>,
+        /// Number of write targets to list.
+        #[arg(long, default_value = "10")]
+        top: usize,
+    },
     /// Display the decision trail for a goal with reasoning (v0.3.3).
     Show {
         /// Goal ID to display decision trail for.
@@ -33,6 +82,26 @@ pub enum AuditCommands {
         /// Path to audit log (defaults to .ta/audit.jsonl).
         #[arg(long)]
         log: Option<String>,
+        /// Show event text verbatim instead of redacting secrets
+        /// (`.ta/redact.toml`).
+        #[arg(long)]
+        no_redact: bool,
+    },
+    /// Show where a commit came from: the goal, draft, review record, and
+    /// audit events named by its `TA-Goal`/`TA-Draft` trailers.
+    ///
+    /// Example:
+    ///   ta audit from-commit HEAD~2
+    FromCommit {
+        /// Commit SHA (or any revision git accepts).
+        sha: String,
+        /// Path to audit log (defaults to .ta/audit.jsonl).
+        #[arg(long)]
+        log: Option<String>,
+        /// Show event text verbatim instead of redacting secrets
+        /// (`.ta/redact.toml`).
+        #[arg(long)]
+        no_redact: bool,
     },
     /// Export structured audit data for compliance reporting (v0.3.3 / v0.14.8.2).
     ///
@@ -54,6 +123,10 @@ pub enum AuditCommands {
         /// Path to audit log (defaults to .ta/audit.jsonl).
         #[arg(long)]
         log: Option<String>,
+        /// Export event data verbatim instead of redacting secrets
+        /// (`.ta/redact.toml`).
+        #[arg(long)]
+        no_redact: bool,
     },
     /// Show behavioral drift report for an agent (v0.4.2).
     Drift {
@@ -214,32 +287,58 @@ pub enum ExportFormat {
 
 pub fn execute(cmd: &AuditCommands, config: &GatewayConfig) -> anyhow::Result<()> {
     match cmd {
-        AuditCommands::Verify { log } => {
+        AuditCommands::Verify {
+            log,
+            from_checkpoint,
+            keys,
+        } => {
             let path = log
                 .as_ref()
                 .map(std::path::PathBuf::from)
                 .unwrap_or_else(|| config.audit_log.clone());
 
-            if !path.exists() {
+            if AuditLog::segments(&path)?.is_empty() {
                 println!("No audit log found at {}", path.display());
                 return Ok(());
             }
 
-            // Verify using the real hash-chain verification (recomputes hashes).
-            match AuditLog::verify_chain(&path) {
-                Ok(_) => {
-                    let events = AuditLog::read_all(&path)?;
+            if *from_checkpoint {
+                if let Some(checkpoint) = AuditCheckpoint::latest(&path)? {
+                    return verify_from_checkpoint(config, &path, &checkpoint, keys.as_deref());
+                }
+                println!(
+                    "No audit checkpoint recorded — verifying the full chain. \
+                     Run `ta audit checkpoint` to enable incremental verification."
+                );
+            }
+
+            // Verify using the real hash-chain verification (recomputes hashes),
+            // walking rotated segments oldest-first into the active file.
+            match AuditLog::verify_segments(&path) {
+                Ok(report) => {
                     println!(
-                        "Audit log verified: {} event(s), hash chain intact.",
-                        events.len()
+                        "Audit log verified: {} event(s) across {} segment(s), hash chain intact.",
+                        report.events,
+                        report.segments.len()
                     );
+                    if report.anchored_at_pruned_segment {
+                        println!(
+                            "  Note: older segments were removed by retention; chain verified from {}.",
+                            report.segments[0].display()
+                        );
+                    }
                 }
-                Err(ta_audit::AuditError::IntegrityViolation {
+                Err(ta_audit::AuditError::SegmentIntegrityViolation {
+                    path: segment,
                     line,
                     expected,
                     actual,
                 }) => {
-                    println!("INTEGRITY VIOLATION at line {}:", line);
+                    println!(
+                        "INTEGRITY VIOLATION in {} at line {}:",
+                        segment.display(),
+                        line
+                    );
                     println!("  Expected previous_hash: {}", expected);
                     println!("  Actual previous_hash:   {}", actual);
                     println!();
@@ -250,45 +349,116 @@ pub fn execute(cmd: &AuditCommands, config: &GatewayConfig) -> anyhow::Result<()
             }
         }
 
+        AuditCommands::Checkpoint { log, keys } => {
+            execute_checkpoint(config, log.as_deref(), keys.as_deref())?;
+        }
+
         AuditCommands::Tail { log, n } => {
             let path = log
                 .as_ref()
                 .map(std::path::PathBuf::from)
                 .unwrap_or_else(|| config.audit_log.clone());
 
-            if !path.exists() {
+            if AuditLog::segments(&path)?.is_empty() {
                 println!("No audit log found at {}", path.display());
                 return Ok(());
             }
 
-            let events = AuditLog::read_all(&path)?;
-            let start = events.len().saturating_sub(*n);
-            let recent = &events[start..];
+            // Stream the log and keep only the last `n` events in memory.
+            let mut recent = std::collections::VecDeque::with_capacity(*n);
+            for event in AuditLog::iter_segments(&path)? {
+                if *n == 0 {
+                    break;
+                }
+                if recent.len() == *n {
+                    recent.pop_front();
+                }
+                recent.push_back(event?);
+            }
 
             if recent.is_empty() {
                 println!("No audit events.");
                 return Ok(());
             }
+            print_event_table(recent.iter());
+        }
 
-            println!(
-                "{:<26} {:<12} {:<14} TARGET",
-                "TIMESTAMP", "AGENT", "ACTION"
-            );
-            println!("{}", "-".repeat(80));
+        AuditCommands::Query {
+            log,
+            correlation,
+            json,
+        } => {
+            let path = log
+                .as_ref()
+                .map(std::path::PathBuf::from)
+                .unwrap_or_else(|| config.audit_log.clone());
 
-            for event in recent {
-                println!(
-                    "{:<26} {:<12} {:<14} {}",
-                    event.timestamp.format("%Y-%m-%d %H:%M:%S"),
-                    event.agent_id,
-                    format!("{:?}", event.action),
-                    event.target_uri.as_deref().unwrap_or("-"),
-                );
+            if AuditLog::segments(&path)?.is_empty() {
+                println!("No audit log found at {}", path.display());
+                return Ok(());
+            }
+
+            let events = events_for_correlation(&path, correlation)?;
+            if *json {
+                for event in &events {
+                    println!("{}", serde_json::to_string(event)?);
+                }
+            } else if events.is_empty() {
+                println!("No audit events with correlation ID {}.", correlation);
+            } else {
+                print_event_table(events.iter());
             }
         }
 
-        AuditCommands::Show { goal_id, log } => {
-            show_decision_trail(config, goal_id, log.as_deref())?;
+        AuditCommands::Stats { log, since, top } => {
+            let path = log
+                .as_ref()
+                .map(std::path::PathBuf::from)
+                .unwrap_or_else(|| config.audit_log.clone());
+
+            if AuditLog::segments(&path)?.is_empty() {
+                println!("No audit log found at {}", path.display());
+                return Ok(());
+            }
+
+            let since = since
+                .as_deref()
+                .map(|s| {
+                    chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                        .map(|d| d.and_hms_opt(0, 0, 0).unwrap().and_utc())
+                        .or_else(|_| s.parse::<chrono::DateTime<chrono::Utc>>())
+                        .map_err(|e| anyhow::anyhow!("Invalid --since date '{}': {}", s, e))
+                })
+                .transpose()?;
+
+            let mut stats = AuditStats::default();
+            for event in AuditLog::iter_segments(&path)? {
+                let event = event?;
+                if since.is_some_and(|since| event.timestamp <= since) {
+                    continue;
+                }
+                stats.record(&event);
+            }
+            print!("{}", stats.render(*top));
+        }
+
+        AuditCommands::Show {
+            goal_id,
+            log,
+            no_redact,
+        } => {
+            show_decision_trail(config, goal_id, log.as_deref(), *no_redact)?;
+        }
+
+        AuditCommands::FromCommit {
+            sha,
+            log,
+            no_redact,
+        } => {
+            print!(
+                "{}",
+                commit_provenance(config, sha, log.as_deref(), *no_redact)?
+            );
         }
 
         AuditCommands::Export {
@@ -296,6 +466,7 @@ pub fn execute(cmd: &AuditCommands, config: &GatewayConfig) -> anyhow::Result<()
             workflow_run,
             format,
             log,
+            no_redact,
         } => {
             if let Some(run_id) = workflow_run {
                 let runs_dir = config.workspace_root.join(".ta").join("workflow-runs");
@@ -309,7 +480,7 @@ pub fn execute(cmd: &AuditCommands, config: &GatewayConfig) -> anyhow::Result<()
                            ta audit export --workflow-run <run-id>"
                     )
                 })?;
-                export_audit(config, gid, format, log.as_deref())?;
+                export_audit(config, gid, format, log.as_deref(), *no_redact)?;
             }
         }
 
@@ -814,11 +985,8 @@ fn execute_drift(
     let audit_path = log_path
         .map(std::path::PathBuf::from)
         .unwrap_or_else(|| config.audit_log.clone());
-    let events = if audit_path.exists() {
-        AuditLog::read_all(&audit_path)?
-    } else {
-        Vec::new()
-    };
+    // Baselines and drift cover the whole history, rotated segments included.
+    let events = AuditLog::iter_segments(&audit_path)?.collect::<Result<Vec<_>, _>>()?;
 
     // Load draft summaries.
     let drafts = load_draft_summaries(config)?;
@@ -885,11 +1053,7 @@ fn execute_baseline(
         .map(std::path::PathBuf::from)
         .unwrap_or_else(|| config.audit_log.clone());
 
-    let events = if audit_path.exists() {
-        AuditLog::read_all(&audit_path)?
-    } else {
-        Vec::new()
-    };
+    let events = AuditLog::iter_segments(&audit_path)?.collect::<Result<Vec<_>, _>>()?;
     let drafts = load_draft_summaries(config)?;
 
     let baseline = ta_audit::drift::compute_baseline(agent_id, &events, &drafts);
@@ -919,25 +1083,86 @@ fn execute_baseline(
 
 // ── Existing subcommands ──
 
-/// Filter events related to a specific goal.
-fn events_for_goal(events: &[AuditEvent], goal_id: &str) -> Vec<AuditEvent> {
-    events
-        .iter()
-        .filter(|e| {
-            // Check agent_id or metadata for goal reference.
-            e.agent_id.contains(goal_id)
-                || e.target_uri
-                    .as_deref()
-                    .map(|u| u.contains(goal_id))
-                    .unwrap_or(false)
-                || {
-                    // Check metadata for goal_id reference.
-                    let meta = e.metadata.to_string();
-                    meta.contains(goal_id)
-                }
-        })
-        .cloned()
-        .collect()
+/// Whether an event relates to a specific goal.
+fn event_matches_goal(e: &AuditEvent, goal_id: &str) -> bool {
+    // Events stamped with a goal run ID (v0.10.15) match on it or its prefix.
+    if e.goal_run_id
+        .is_some_and(|id| id.to_string().starts_with(goal_id))
+    {
+        return true;
+    }
+    // Otherwise check agent_id or metadata for goal reference.
+    e.agent_id.contains(goal_id)
+        || e.target_uri
+            .as_deref()
+            .map(|u| u.contains(goal_id))
+            .unwrap_or(false)
+        || {
+            // Check metadata for goal_id reference.
+            let meta = e.metadata.to_string();
+            meta.contains(goal_id)
+        }
+}
+
+/// Stream the audit log (all segments) and collect events related to a goal.
+pub(crate) fn events_for_goal(
+    path: &std::path::Path,
+    goal_id: &str,
+) -> anyhow::Result<Vec<AuditEvent>> {
+    let mut events = Vec::new();
+    for event in AuditLog::iter_segments(path)? {
+        let event = event?;
+        if event_matches_goal(&event, goal_id) {
+            events.push(event);
+        }
+    }
+    Ok(events)
+}
+
+/// Stream the audit log (all segments) and collect the events of one
+/// operation. `id` is a full correlation ID or a prefix of at least 8 characters.
+fn events_for_correlation(path: &std::path::Path, id: &str) -> anyhow::Result<Vec<AuditEvent>> {
+    let id = id.trim().to_lowercase();
+    if uuid::Uuid::parse_str(&id).is_err() && id.len() < 8 {
+        anyhow::bail!(
+            "Correlation ID '{}' is too short — use at least 8 characters.",
+            id
+        );
+    }
+    let mut events = Vec::new();
+    for event in AuditLog::iter_segments(path)? {
+        let event = event?;
+        if event
+            .correlation_id
+            .is_some_and(|c| c.to_string().starts_with(&id))
+        {
+            events.push(event);
+        }
+    }
+    Ok(events)
+}
+
+/// Print events as the `ta audit tail` table. OP is the first 8 characters of
+/// the event's correlation ID, the argument `ta audit query --correlation` takes.
+fn print_event_table<'a>(events: impl Iterator<Item = &'a AuditEvent>) {
+    println!(
+        "{:<26} {:<12} {:<14} {:<9} TARGET",
+        "TIMESTAMP", "AGENT", "ACTION", "OP"
+    );
+    println!("{}", "-".repeat(90));
+    for event in events {
+        println!(
+            "{:<26} {:<12} {:<14} {:<9} {}",
+            event.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            event.agent_id,
+            format!("{:?}", event.action),
+            event
+                .correlation_id
+                .map(|c| c.to_string()[..8].to_string())
+                .unwrap_or_else(|| "-".to_string()),
+            event.target_uri.as_deref().unwrap_or("-"),
+        );
+    }
 }
 
 /// Display the decision trail for a goal with reasoning (v0.3.3).
@@ -945,6 +1170,7 @@ fn show_decision_trail(
     config: &GatewayConfig,
     goal_id: &str,
     log_path: Option<&str>,
+    no_redact: bool,
 ) -> anyhow::Result<()> {
     let path = log_path
         .map(std::path::PathBuf::from)
@@ -955,23 +1181,32 @@ fn show_decision_trail(
         return Ok(());
     }
 
-    let all_events = AuditLog::read_all(&path)?;
-    let events = events_for_goal(&all_events, goal_id);
+    let redactor = (!no_redact).then(|| ta_audit::Redactor::for_project(&config.workspace_root));
+    let mut events = events_for_goal(&path, goal_id)?;
+    if let Some(redactor) = &redactor {
+        events.iter_mut().for_each(|e| redactor.redact_event(e));
+    }
 
     if events.is_empty() {
         // Show all events if no goal-specific filter matches — the goal_id
         // might be a prefix or might match the draft package instead.
+        let mut total = 0usize;
+        let mut reasoned_events = Vec::new();
+        for event in AuditLog::iter_segments(&path)? {
+            let mut event = event?;
+            total += 1;
+            if event.reasoning.is_some() {
+                if let Some(redactor) = &redactor {
+                    redactor.redact_event(&mut event);
+                }
+                reasoned_events.push(event);
+            }
+        }
         println!("No events found for goal {}.", goal_id);
         println!();
-        println!(
-            "Showing all {} events with decision reasoning:",
-            all_events.len()
-        );
+        println!("Showing all {} events with decision reasoning:", total);
 
-        let reasoned: Vec<_> = all_events
-            .iter()
-            .filter(|e| e.reasoning.is_some())
-            .collect();
+        let reasoned: Vec<_> = reasoned_events.iter().collect();
         if reasoned.is_empty() {
             println!("  No events have decision reasoning attached.");
             println!("  Reasoning is captured during policy evaluation (v0.3.3).");
@@ -1001,6 +1236,236 @@ fn show_decision_trail(
     Ok(())
 }
 
+/// TA trailers read from a commit message. The pre-trailer `Goal-ID`/`PR-ID`
+/// lines written by the VCS adapters are accepted as fallbacks.
+#[derive(Debug, Default, PartialEq)]
+struct CommitTrailers {
+    goal: Option<String>,
+    draft: Option<String>,
+    agent: Option<String>,
+    audit: Option<String>,
+}
+
+fn parse_commit_trailers(message: &str) -> CommitTrailers {
+    let mut trailers = CommitTrailers::default();
+    let mut legacy_goal = None;
+    let mut legacy_draft = None;
+    for line in message.lines() {
+        let Some((key, value)) = line.split_once(": ") else {
+            continue;
+        };
+        let value = Some(value.trim().to_string());
+        match key.trim() {
+            "TA-Goal" => trailers.goal = value,
+            "TA-Draft" => trailers.draft = value,
+            "TA-Agent" => trailers.agent = value,
+            "TA-Audit" => trailers.audit = value,
+            "Goal-ID" => legacy_goal = value,
+            "PR-ID" => legacy_draft = value,
+            _ => {}
+        }
+    }
+    trailers.goal = trailers.goal.or(legacy_goal);
+    trailers.draft = trailers.draft.or(legacy_draft);
+    trailers
+}
+
+/// `ta audit from-commit`: trace a commit back to the goal, draft, review
+/// record, and audit events that produced it.
+fn commit_provenance(
+    config: &GatewayConfig,
+    sha: &str,
+    log_path: Option<&str>,
+    no_redact: bool,
+) -> anyhow::Result<String> {
+    use std::fmt::Write as _;
+
+    let output = std::process::Command::new("git")
+        .args(["show", "-s", "--format=%H%n%B", sha])
+        .current_dir(&config.workspace_root)
+        .output()
+        .map_err(|e| anyhow::anyhow!("Failed to run git: {}", e))?;
+    if !output.status.success() {
+        anyhow::bail!(
+            "Could not read commit '{}': {}",
+            sha,
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+    let text = String::from_utf8_lossy(&output.stdout);
+    let (full_sha, message) = text.split_once('\n').unwrap_or((text.trim(), ""));
+    let subject = message.lines().next().unwrap_or("");
+    let trailers = parse_commit_trailers(message);
+    let Some(goal_ref) = trailers.goal.as_deref() else {
+        anyhow::bail!(
+            "Commit {} has no TA-Goal trailer — it was not applied by `ta draft apply`, \
+             or predates commit trailers.",
+            &full_sha[..full_sha.len().min(8)]
+        );
+    };
+
+    let mut out = String::new();
+    writeln!(out, "Commit {}", full_sha)?;
+    writeln!(out, "  {}", subject)?;
+
+    // ── Goal ──
+    writeln!(out)?;
+    let goal = uuid::Uuid::parse_str(goal_ref).ok().and_then(|id| {
+        ta_goal::GoalRunStore::new(&config.goals_dir)
+            .ok()?
+            .get(id)
+            .ok()?
+    });
+    match &goal {
+        Some(goal) => {
+            writeln!(out, "Goal {}", goal.goal_run_id)?;
+            writeln!(out, "  Title:     {}", goal.title)?;
+            writeln!(out, "  Objective: {}", goal.objective)?;
+            writeln!(out, "  Agent:     {}", goal.agent_id)?;
+            writeln!(out, "  State:     {}", goal.state)?;
+            writeln!(
+                out,
+                "  Created:   {}",
+                goal.created_at.format("%Y-%m-%d %H:%M:%S UTC")
+            )?;
+        }
+        None => {
+            writeln!(
+                out,
+                "Goal {} (not found in {})",
+                goal_ref,
+                config.goals_dir.display()
+            )?;
+            if let Some(agent) = &trailers.agent {
+                writeln!(out, "  Agent:     {}", agent)?;
+            }
+        }
+    }
+
+    // ── Draft and its review record ──
+    if let Some(draft_ref) = trailers.draft.as_deref() {
+        writeln!(out)?;
+        let pkg = uuid::Uuid::parse_str(draft_ref)
+            .ok()
+            .and_then(|id| super::draft::load_package(config, id).ok());
+        match pkg {
+            Some(pkg) => {
+                writeln!(
+                    out,
+                    "Draft {} ({})",
+                    ta_changeset::draft_canonical_id(&pkg),
+                    pkg.package_id
+                )?;
+                writeln!(out, "  Summary:   {}", pkg.summary.what_changed)?;
+                writeln!(out, "  Files:     {}", pkg.changes.artifacts.len())?;
+                let status = match &pkg.status {
+                    ta_changeset::DraftStatus::Applied {
+                        applied_at,
+                        applied_via,
+                    } => format!(
+                        "applied {} ({})",
+                        applied_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                        applied_via
+                    ),
+                    ta_changeset::DraftStatus::Approved {
+                        approved_by,
+                        approved_at,
+                        ..
+                    } => format!(
+                        "approved by {} at {}",
+                        approved_by,
+                        approved_at.format("%Y-%m-%d %H:%M:%S UTC")
+                    ),
+                    other => other.to_string(),
+                };
+                writeln!(out, "  Status:    {}", status)?;
+                for approval in &pkg.pending_approvals {
+                    writeln!(
+                        out,
+                        "  Approved:  {} at {}",
+                        approval.reviewer,
+                        approval.approved_at.format("%Y-%m-%d %H:%M:%S UTC")
+                    )?;
+                }
+            }
+            None => writeln!(out, "Draft {} (not found)", draft_ref)?,
+        }
+    }
+
+    let goal_id = goal
+        .as_ref()
+        .map(|g| g.goal_run_id.to_string())
+        .unwrap_or_else(|| goal_ref.to_string());
+    let ledger_path = GoalAuditLedger::path_for(&config.workspace_root);
+    let ledger_entry = GoalAuditLedger::read_all(&ledger_path)
+        .unwrap_or_default()
+        .into_iter()
+        .rev()
+        .find(|e| e.goal_id.to_string() == goal_id);
+    if let Some(entry) = ledger_entry {
+        writeln!(out)?;
+        writeln!(out, "Review record")?;
+        writeln!(out, "  Outcome:   {}", entry.disposition)?;
+        if let Some(reviewer) = &entry.reviewer {
+            writeln!(out, "  Reviewer:  {}", reviewer)?;
+        }
+        if let Some(policy) = &entry.policy_result {
+            writeln!(out, "  Policy:    {}", policy)?;
+        }
+        writeln!(out, "  Review:    {}s", entry.review_seconds)?;
+    }
+
+    // ── Audit events ──
+    let path = log_path
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| config.audit_log.clone());
+    writeln!(out)?;
+    if AuditLog::segments(&path)?.is_empty() {
+        writeln!(out, "No audit log found at {}", path.display())?;
+        return Ok(out);
+    }
+    let mut events = events_for_goal(&path, &goal_id)?;
+    if !no_redact {
+        let redactor = ta_audit::Redactor::for_project(&config.workspace_root);
+        events.iter_mut().for_each(|e| redactor.redact_event(e));
+    }
+    if let Some(head) = &trailers.audit {
+        let found = AuditLog::head_hash(&path)?.as_deref() == Some(head.as_str())
+            || AuditLog::iter_segments(&path)?
+                .filter_map(Result::ok)
+                .any(|e| e.previous_hash.as_deref() == Some(head.as_str()));
+        writeln!(
+            out,
+            "Audit head at commit: {} ({})",
+            head,
+            if found {
+                "present in audit log"
+            } else {
+                "NOT found in audit log"
+            }
+        )?;
+    }
+    writeln!(out, "Audit events ({})", events.len())?;
+    for event in &events {
+        writeln!(
+            out,
+            "  [{}] {:?} by {}{}",
+            event.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            event.action,
+            event.agent_id,
+            event
+                .target_uri
+                .as_deref()
+                .map(|t| format!(" → {}", t))
+                .unwrap_or_default()
+        )?;
+        if let Some(reasoning) = &event.reasoning {
+            writeln!(out, "      Rationale: {}", reasoning.rationale)?;
+        }
+    }
+    Ok(out)
+}
+
 /// Print events with their reasoning details.
 fn print_events_with_reasoning(events: &[&AuditEvent]) {
     for event in events {
@@ -1034,64 +1499,164 @@ fn print_events_with_reasoning(events: &[&AuditEvent]) {
     }
 }
 
-// ── VerifyAttestation subcommand (v0.14.1) ──
+// ── Checkpoint subcommand ──
 
-fn execute_verify_attestation(
+fn attestation_backend(
+    config: &GatewayConfig,
+    keys_path: Option<&str>,
+) -> anyhow::Result<SoftwareAttestationBackend> {
+    let keys_dir = keys_path
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| config.workspace_root.join(".ta").join("keys"));
+    SoftwareAttestationBackend::load_or_generate(&keys_dir).map_err(|e| {
+        anyhow::anyhow!(
+            "Failed to load attestation key from {}: {}",
+            keys_dir.display(),
+            e
+        )
+    })
+}
+
+fn execute_checkpoint(
     config: &GatewayConfig,
     log_path: Option<&str>,
-    event_id: Option<&str>,
     keys_path: Option<&str>,
 ) -> anyhow::Result<()> {
     let path = log_path
         .map(std::path::PathBuf::from)
         .unwrap_or_else(|| config.audit_log.clone());
 
-    if !path.exists() {
+    if AuditLog::segments(&path)?.is_empty() {
         println!("No audit log found at {}", path.display());
         return Ok(());
     }
 
-    let keys_dir = keys_path
-        .map(std::path::PathBuf::from)
-        .unwrap_or_else(|| config.workspace_root.join(".ta").join("keys"));
-
-    // Load or generate the software backend.
-    let backend = SoftwareAttestationBackend::load_or_generate(&keys_dir).map_err(|e| {
+    // Capturing walks the whole chain first: a checkpoint only ever vouches
+    // for a log that verified end to end.
+    let Some(mut checkpoint) = AuditCheckpoint::capture(&path).map_err(|e| {
         anyhow::anyhow!(
-            "Failed to load attestation key from {}: {}",
-            keys_dir.display(),
+            "Audit log failed verification — refusing to record a checkpoint: {}",
             e
         )
-    })?;
-
-    let events = AuditLog::read_all(&path)?;
-    let events_to_check: Vec<&AuditEvent> = if let Some(id) = event_id {
-        events
-            .iter()
-            .filter(|e| e.event_id.to_string().starts_with(id))
-            .collect()
-    } else {
-        events.iter().collect()
+    })?
+    else {
+        println!("Audit log is empty — nothing to checkpoint.");
+        return Ok(());
     };
 
-    if events_to_check.is_empty() {
-        println!("No events found.");
+    let backend = attestation_backend(config, keys_path)?;
+    checkpoint.sign(&backend)?;
+    checkpoint.append(&path)?;
+
+    println!(
+        "Checkpoint recorded: {} event(s), head {} ({} line {}).",
+        checkpoint.events,
+        &checkpoint.head_hash[..checkpoint.head_hash.len().min(16)],
+        checkpoint.segment,
+        checkpoint.line
+    );
+    println!("  Signed with key {}.", backend.public_key_fingerprint());
+    println!("  Stored in {}", AuditCheckpoint::path_for(&path).display());
+    Ok(())
+}
+
+fn verify_from_checkpoint(
+    config: &GatewayConfig,
+    path: &std::path::Path,
+    checkpoint: &AuditCheckpoint,
+    keys_path: Option<&str>,
+) -> anyhow::Result<()> {
+    let backend = attestation_backend(config, keys_path)?;
+    if !checkpoint.verify_signature(&backend) {
+        anyhow::bail!(
+            "Audit checkpoint in {} is unsigned or its signature does not match key {}. \
+             Run a full `ta audit verify` before trusting this log.",
+            AuditCheckpoint::path_for(path).display(),
+            backend.public_key_fingerprint()
+        );
+    }
+
+    match checkpoint.verify_since(path) {
+        Ok(report) => {
+            println!(
+                "Audit log verified from checkpoint ({}): {} new event(s) after {} trusted, \
+                 hash chain intact.",
+                checkpoint.created_at.format("%Y-%m-%d %H:%M:%S UTC"),
+                report.new_events,
+                report.checkpoint_events
+            );
+            Ok(())
+        }
+        Err(ta_audit::AuditError::SegmentIntegrityViolation {
+            path: segment,
+            line,
+            expected,
+            actual,
+        }) => {
+            println!(
+                "INTEGRITY VIOLATION in {} at line {}:",
+                segment.display(),
+                line
+            );
+            println!("  Expected previous_hash: {}", expected);
+            println!("  Actual previous_hash:   {}", actual);
+            println!();
+            println!("The audit log may have been tampered with.");
+            anyhow::bail!("Audit log integrity check failed");
+        }
+        Err(e @ ta_audit::AuditError::CheckpointNotFound { .. }) => {
+            println!("{}", e);
+            println!();
+            println!("The audit log was rewritten or truncated at or before the checkpoint.");
+            anyhow::bail!("Audit log integrity check failed");
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+// ── VerifyAttestation subcommand (v0.14.1) ──
+
+fn execute_verify_attestation(
+    config: &GatewayConfig,
+    log_path: Option<&str>,
+    event_id: Option<&str>,
+    keys_path: Option<&str>,
+) -> anyhow::Result<()> {
+    let path = log_path
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(|| config.audit_log.clone());
+
+    if AuditLog::segments(&path)?.is_empty() {
+        println!("No audit log found at {}", path.display());
         return Ok(());
     }
 
+    // Load or generate the software backend.
+    let backend = attestation_backend(config, keys_path)?;
+
+    let mut checked = 0usize;
     let mut signed = 0usize;
     let mut valid = 0usize;
     let mut invalid = 0usize;
     let mut unsigned = 0usize;
 
-    println!("{:<36}  {:<16}  STATUS", "EVENT ID", "BACKEND");
-    println!("{}", "-".repeat(70));
-
-    for event in &events_to_check {
+    // Stream events so memory stays flat on large logs.
+    for event in AuditLog::iter_segments(&path)? {
+        let event = event?;
+        if let Some(id) = event_id {
+            if !event.event_id.to_string().starts_with(id) {
+                continue;
+            }
+        }
+        if checked == 0 {
+            println!("{:<36}  {:<16}  STATUS", "EVENT ID", "BACKEND");
+            println!("{}", "-".repeat(70));
+        }
+        checked += 1;
         if let Some(record) = &event.attestation {
             signed += 1;
             // Reconstruct canonical payload: event JSON with attestation = None.
-            let mut canonical_event = (*event).clone();
+            let mut canonical_event = event.clone();
             canonical_event.attestation = None;
             let canonical = serde_json::to_string(&canonical_event)?;
 
@@ -1127,6 +1692,11 @@ fn execute_verify_attestation(
         }
     }
 
+    if checked == 0 {
+        println!("No events found.");
+        return Ok(());
+    }
+
     println!();
     println!(
         "Summary: {} signed ({} valid, {} invalid), {} unsigned",
@@ -1149,6 +1719,7 @@ fn export_audit(
     goal_id: &str,
     _format: &ExportFormat,
     log_path: Option<&str>,
+    no_redact: bool,
 ) -> anyhow::Result<()> {
     let path = log_path
         .map(std::path::PathBuf::from)
@@ -1158,8 +1729,11 @@ fn export_audit(
         anyhow::bail!("No audit log found at {}", path.display());
     }
 
-    let all_events = AuditLog::read_all(&path)?;
-    let events = events_for_goal(&all_events, goal_id);
+    let mut events = events_for_goal(&path, goal_id)?;
+    if !no_redact {
+        let redactor = ta_audit::Redactor::for_project(&config.workspace_root);
+        events.iter_mut().for_each(|e| redactor.redact_event(e));
+    }
 
     // Build structured export.
     let export = serde_json::json!({
@@ -1352,3 +1926,355 @@ fn social_audit(
 
     Ok(())
 }
+
+/// Running counts for `ta audit stats`, built in one pass over the log.
+#[derive(Debug, Default)]
+struct AuditStats {
+    total: usize,
+    first: Option<chrono::DateTime<chrono::Utc>>,
+    last: Option<chrono::DateTime<chrono::Utc>>,
+    by_action: std::collections::BTreeMap<String, usize>,
+    by_agent: std::collections::BTreeMap<String, usize>,
+    /// Policy decisions keyed by outcome: allow, deny, require_approval.
+    decisions: std::collections::BTreeMap<String, usize>,
+    applies_per_day: std::collections::BTreeMap<chrono::NaiveDate, usize>,
+    writes_by_target: std::collections::HashMap<String, usize>,
+}
+
+impl AuditStats {
+    fn record(&mut self, event: &AuditEvent) {
+        self.total += 1;
+        self.first = Some(
+            self.first
+                .map_or(event.timestamp, |t| t.min(event.timestamp)),
+        );
+        self.last = Some(
+            self.last
+                .map_or(event.timestamp, |t| t.max(event.timestamp)),
+        );
+        *self
+            .by_action
+            .entry(format!("{:?}", event.action))
+            .or_default() += 1;
+        *self.by_agent.entry(event.agent_id.clone()).or_default() += 1;
+
+        match event.action {
+            ta_audit::AuditAction::PolicyDecision => {
+                if let Some(decision) = event.metadata["decision"]["decision"].as_str() {
+                    *self.decisions.entry(decision.to_string()).or_default() += 1;
+                }
+            }
+            ta_audit::AuditAction::Apply => {
+                *self
+                    .applies_per_day
+                    .entry(event.timestamp.date_naive())
+                    .or_default() += 1;
+            }
+            _ => {}
+        }
+
+        // A write is a tool call or decision on a write verb, or an apply.
+        let is_write = event.action == ta_audit::AuditAction::Apply
+            || event.metadata["verb"]
+                .as_str()
+                .is_some_and(|verb| verb.starts_with("write"));
+        if is_write {
+            if let Some(target) = &event.target_uri {
+                *self.writes_by_target.entry(target.clone()).or_default() += 1;
+            }
+        }
+    }
+
+    fn render(&self, top: usize) -> String {
+        use std::fmt::Write;
+
+        let mut out = String::new();
+        if self.total == 0 {
+            out.push_str("No audit events in range.\n");
+            return out;
+        }
+        let _ = writeln!(
+            out,
+            "{} event(s) from {} to {}",
+            self.total,
+            self.first.unwrap().format("%Y-%m-%d %H:%M"),
+            self.last.unwrap().format("%Y-%m-%d %H:%M"),
+        );
+
+        let mut section = |title: &str, rows: Vec<(String, usize)>| {
+            if rows.is_empty() {
+                return;
+            }
+            let _ = writeln!(out, "\n{}", title);
+            for (key, count) in rows {
+                let _ = writeln!(out, "  {:<40} {:>7}", key, count);
+            }
+        };
+        let by_count = |map: &std::collections::BTreeMap<String, usize>| {
+            let mut rows: Vec<_> = map.iter().map(|(k, v)| (k.clone(), *v)).collect();
+            rows.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+            rows
+        };
+        section("By action:", by_count(&self.by_action));
+        section("By agent:", by_count(&self.by_agent));
+        section("Policy decisions:", by_count(&self.decisions));
+        section(
+            "Applies per day:",
+            self.applies_per_day
+                .iter()
+                .map(|(day, n)| (day.to_string(), *n))
+                .collect(),
+        );
+        let mut writes: Vec<_> = self
+            .writes_by_target
+            .iter()
+            .map(|(k, v)| (k.clone(), *v))
+            .collect();
+        writes.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        writes.truncate(top);
+        section("Top write targets:", writes);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ta_audit::AuditAction;
+
+    #[test]
+    fn query_by_correlation_returns_one_operations_events() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("audit.jsonl");
+        let apply_op = uuid::Uuid::new_v4();
+        let other_op = uuid::Uuid::new_v4();
+        {
+            let mut log = AuditLog::open(&path).unwrap();
+            for (action, op) in [
+                (AuditAction::Apply, Some(apply_op)),
+                (AuditAction::ToolCall, Some(other_op)),
+                (AuditAction::Approval, Some(apply_op)),
+                (AuditAction::Error, None),
+            ] {
+                let mut event = AuditEvent::new("cli", action);
+                if let Some(op) = op {
+                    event = event.with_correlation_id(op);
+                }
+                log.append(&mut event).unwrap();
+            }
+        }
+
+        let full = events_for_correlation(&path, &apply_op.to_string()).unwrap();
+        let actions: Vec<_> = full.iter().map(|e| e.action.clone()).collect();
+        assert_eq!(actions, vec![AuditAction::Apply, AuditAction::Approval]);
+
+        let prefix = apply_op.to_string()[..8].to_uppercase();
+        assert_eq!(events_for_correlation(&path, &prefix).unwrap().len(), 2);
+        assert!(events_for_correlation(&path, "abc").is_err());
+    }
+
+    #[test]
+    fn from_commit_traces_trailers_to_goal_draft_and_events() {
+        let project = tempfile::TempDir::new().unwrap();
+        let root = project.path();
+        let git = |args: &[&str]| {
+            let status = std::process::Command::new("git")
+                .args(["-c", "user.name=Test", "-c", "user.email=test@test.com"])
+                .args(args)
+                .current_dir(root)
+                .output()
+                .unwrap()
+                .status;
+            assert!(status.success(), "git {:?} failed", args);
+        };
+        git(&["init", "-q"]);
+        std::fs::write(root.join("README.md"), "# Original\n").unwrap();
+        let config = GatewayConfig::for_project(root);
+        super::super::goal::execute(
+            &super::super::goal::GoalCommands::Start {
+                title: "Provenance".to_string(),
+                source: Some(root.to_path_buf()),
+                objective: "Trace commits".to_string(),
+                agent: "test-agent".to_string(),
+                phase: None,
+                follow_up: None,
+                objective_file: None,
+                timeout: None,
+            },
+            &config,
+        )
+        .unwrap();
+        let goal = ta_goal::GoalRunStore::new(&config.goals_dir)
+            .unwrap()
+            .list()
+            .unwrap()[0]
+            .clone();
+        std::fs::write(goal.workspace_path.join("README.md"), "# Changed\n").unwrap();
+        super::super::draft::build_package(
+            &config,
+            &goal.goal_run_id.to_string(),
+            "Provenance",
+            false,
+        )
+        .unwrap();
+        let pkg = super::super::draft::load_all_packages(&config).unwrap()[0].clone();
+        let mut event = AuditEvent::new("test-agent", AuditAction::Approval)
+            .with_target("fs://workspace/README.md");
+        event.goal_run_id = Some(goal.goal_run_id);
+        AuditLog::open(&config.audit_log)
+            .unwrap()
+            .append(&mut event)
+            .unwrap();
+        let head = AuditLog::head_hash(&config.audit_log).unwrap().unwrap();
+
+        git(&["add", "README.md"]);
+        let message = format!(
+            "Provenance\n\nTA-Goal: {}\nTA-Draft: {}\nTA-Agent: test-agent\nTA-Audit: {}",
+            goal.goal_run_id, pkg.package_id, head
+        );
+        git(&["commit", "-q", "-m", &message]);
+
+        let out = commit_provenance(&config, "HEAD", None, false).unwrap();
+        assert!(out.contains(&format!("Goal {}", goal.goal_run_id)), "{out}");
+        assert!(out.contains("Objective: Trace commits"));
+        assert!(out.contains("Agent:     test-agent"));
+        assert!(out.contains(&format!("({})", pkg.package_id)));
+        assert!(out.contains("present in audit log"));
+        assert!(out.contains("Audit events (1)"));
+        assert!(out.contains("Approval by test-agent → fs://workspace/README.md"));
+
+        // A commit without trailers has no provenance to show.
+        git(&["commit", "-q", "--allow-empty", "-m", "Manual change"]);
+        let err = commit_provenance(&config, "HEAD", None, false).unwrap_err();
+        assert!(err.to_string().contains("no TA-Goal trailer"));
+    }
+
+    #[test]
+    fn audit_stats_aggregates_actions_decisions_and_writes() {
+        let mut stats = AuditStats::default();
+        let decision = |agent: &str, verb: &str, decision: &str, target: &str| {
+            AuditEvent::new(agent, AuditAction::PolicyDecision)
+                .with_target(target)
+                .with_metadata(serde_json::json!({
+                    "tool": "fs",
+                    "verb": verb,
+                    "decision": { "decision": decision },
+                }))
+        };
+        stats.record(&decision(
+            "claude",
+            "write_patch",
+            "allow",
+            "fs://workspace/a.rs",
+        ));
+        stats.record(&decision(
+            "claude",
+            "write_patch",
+            "allow",
+            "fs://workspace/a.rs",
+        ));
+        stats.record(&decision("codex", "read", "deny", "fs://workspace/.env"));
+        stats.record(
+            &AuditEvent::new("claude", AuditAction::Apply).with_target("fs://workspace/b.rs"),
+        );
+
+        assert_eq!(stats.total, 4);
+        assert_eq!(stats.by_agent["claude"], 3);
+        assert_eq!(stats.by_action["PolicyDecision"], 3);
+        assert_eq!(stats.decisions["allow"], 2);
+        assert_eq!(stats.decisions["deny"], 1);
+        assert_eq!(stats.applies_per_day.values().sum::<usize>(), 1);
+        assert_eq!(stats.writes_by_target["fs://workspace/a.rs"], 2);
+        assert!(!stats.writes_by_target.contains_key("fs://workspace/.env"));
+
+        let rendered = stats.render(1);
+        assert!(rendered.starts_with("4 event(s)"));
+        let top = rendered.split("Top write targets:").nth(1).unwrap();
+        assert!(top.contains("fs://workspace/a.rs"));
+        assert!(!top.contains("b.rs"), "--top limits the list");
+    }
+
+    #[test]
+    fn checkpoint_then_incremental_verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GatewayConfig::for_project(dir.path());
+        std::fs::create_dir_all(config.audit_log.parent().unwrap()).unwrap();
+        let append = |n: usize| {
+            let mut log = AuditLog::open(&config.audit_log).unwrap();
+            for _ in 0..n {
+                log.append(&mut AuditEvent::new("agent", AuditAction::ToolCall))
+                    .unwrap();
+            }
+        };
+        let verify = |from_checkpoint| {
+            execute(
+                &AuditCommands::Verify {
+                    log: None,
+                    from_checkpoint,
+                    keys: None,
+                },
+                &config,
+            )
+        };
+
+        append(3);
+        execute(
+            &AuditCommands::Checkpoint {
+                log: None,
+                keys: None,
+            },
+            &config,
+        )
+        .unwrap();
+        append(2);
+        verify(true).unwrap();
+
+        // A forged checkpoint (re-written without a valid signature) is refused.
+        let checkpoints = AuditCheckpoint::path_for(&config.audit_log);
+        let mut forged = AuditCheckpoint::latest(&config.audit_log).unwrap().unwrap();
+        forged.events = 99;
+        std::fs::write(&checkpoints, serde_json::to_string(&forged).unwrap()).unwrap();
+        assert!(verify(true).is_err());
+        verify(false).unwrap();
+    }
+
+    #[test]
+    fn verify_attestation_checks_rotated_segments() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GatewayConfig::for_project(dir.path());
+        std::fs::create_dir_all(config.audit_log.parent().unwrap()).unwrap();
+        let keys = dir.path().join("keys");
+        {
+            let backend = SoftwareAttestationBackend::load_or_generate(&keys).unwrap();
+            // Rotate after every event, so all but the newest are in segments.
+            let mut log = AuditLog::open(&config.audit_log)
+                .unwrap()
+                .with_rotation(ta_audit::RotationPolicy {
+                    max_bytes: 1,
+                    keep_segments: 0,
+                })
+                .with_attestation(Box::new(backend));
+            for i in 0..3 {
+                log.append(&mut AuditEvent::new(
+                    format!("agent-{}", i),
+                    AuditAction::ToolCall,
+                ))
+                .unwrap();
+            }
+        }
+        let segments = AuditLog::segments(&config.audit_log).unwrap();
+        assert!(segments.len() > 2, "{:?}", segments);
+        let oldest = AuditLog::read_all(&segments[0]).unwrap().remove(0);
+        let verify = |event_id: Option<&str>| {
+            execute_verify_attestation(&config, None, event_id, keys.to_str())
+        };
+        verify(None).unwrap();
+
+        // A signed event edited after rotation fails verification, whether
+        // the whole log or just that event is checked.
+        let raw = std::fs::read_to_string(&segments[0]).unwrap();
+        std::fs::write(&segments[0], raw.replace("agent-0", "agent-X")).unwrap();
+        assert!(verify(None).is_err());
+        assert!(verify(Some(&oldest.event_id.to_string()[..8])).is_err());
+    }
+}
@@ -237,6 +237,10 @@ fn run_init(
         None,             // existing_goal_id
         None,             // workflow = default (single-agent)
         None,             // persona_name = None
+        None,             // env_file
+        None,             // agent_command
+        &[],              // context_files
+        None,             // timeout
     )?;
 
     println!();
@@ -1422,7 +1426,6 @@ fn create_constitution_amend_draft(
     };
     use ta_goal::{GoalRun, GoalRunState, GoalRunStore};
     use ta_workspace::ChangeStore;
-    use ta_workspace::JsonFileStore;
 
     let review_id = uuid::Uuid::new_v4();
     let review_id_str = review_id.to_string();
@@ -1472,7 +1475,8 @@ fn create_constitution_amend_draft(
     )
     .with_commit_intent(CommitIntent::RequestCommit);
 
-    let mut cs_store = JsonFileStore::new(&store_path)
+    let mut cs_store = config
+        .open_change_store(&store_path)
         .map_err(|e| anyhow::anyhow!("Failed to open changeset store: {}", e))?;
     cs_store
         .save(&review_id_str, &changeset)
@@ -1485,13 +1489,16 @@ fn create_constitution_amend_draft(
         title: "Constitution Amendment".to_string(),
         objective: "Amend the auto-approve constitution rules.".to_string(),
         agent_id: "ta-constitution-amend".to_string(),
+        agent_command: None,
         state: GoalRunState::Running,
         manifest_id: uuid::Uuid::new_v4(),
         workspace_path: staging_dir.clone(),
         store_path: store_path.clone(),
         source_dir: None,
+        base_ref: None,
         plan_phase: None,
         parent_goal_id: None,
+        cloned_from: None,
         source_snapshot: None,
         is_macro: false,
         parent_macro_id: None,
@@ -1508,17 +1515,21 @@ fn create_constitution_amend_draft(
             .map(|s| s.to_string()),
         agent_pid: None,
         heartbeat_required: false,
+        last_heartbeat: None,
         pr_url: None,
         pr_package_id: None,
         progress_note: None,
         vcs_isolation: None,
         initiated_by: None,
         memory_entries_created: vec![],
+        readonly: false,
+        source_roots: Vec::new(),
         created_at: now,
         updated_at: now,
         input_tokens: 0,
         output_tokens: 0,
         agent_model: String::new(),
+        timeout_secs: None,
     };
 
     let goal_store = GoalRunStore::new(&config.goals_dir)
@@ -1588,6 +1599,10 @@ fn create_constitution_amend_draft(
                 comments: None,
                 amendment: None,
                 kind: None,
+                no_commit: false,
+                renamed_from: None,
+                mode_change: None,
+                line_endings_normalized: None,
             }],
             patch_sets: vec![],
             pending_actions: vec![],
@@ -1622,8 +1637,11 @@ fn create_constitution_amend_draft(
         display_id: Some(format!("{}-01", &review_id_str[..8])),
         tag: Some(format!("constitution-amend-{}", &review_id_str[..8])),
         vcs_status: None,
+        submit_outcome: None,
+        content_hash: None,
         parent_draft_id: None,
         pending_approvals: vec![],
+        review_assignments: vec![],
         supervisor_review: None,
         ignored_artifacts: vec![],
         baseline_artifacts: vec![],
@@ -1633,6 +1651,11 @@ fn create_constitution_amend_draft(
         draft_seq: 1,
         plan_phase: None,
         plan_md_base: None,
+        split_from: None,
+        split_into: Vec::new(),
+        merged_from: Vec::new(),
+        noop_dropped: Vec::new(),
+        staged_hashes: Default::default(),
     };
 
     super::draft::save_package(config, &pkg)
@@ -2188,7 +2211,6 @@ fn create_review_draft(
     };
     use ta_goal::{GoalRun, GoalRunState, GoalRunStore};
     use ta_workspace::ChangeStore;
-    use ta_workspace::JsonFileStore;
 
     let review_id = Uuid::new_v4();
     let review_id_str = review_id.to_string();
@@ -2243,7 +2265,8 @@ fn create_review_draft(
     )
     .with_commit_intent(CommitIntent::RequestCommit);
 
-    let mut cs_store = JsonFileStore::new(&store_path)
+    let mut cs_store = config
+        .open_change_store(&store_path)
         .map_err(|e| anyhow::anyhow!("Failed to open changeset store: {}", e))?;
     cs_store
         .save(&review_id_str, &changeset)
@@ -2260,13 +2283,16 @@ fn create_review_draft(
             stats.rules_before, stats.rules_after, stats.exact_removed, stats.conflicts,
         ),
         agent_id: "ta-constitution-review".to_string(),
+        agent_command: None,
         state: GoalRunState::Running,
         manifest_id: Uuid::new_v4(),
         workspace_path: staging_dir.clone(),
         store_path: store_path.clone(),
         source_dir: None, // legacy path — no overlay diff
+        base_ref: None,
         plan_phase: None,
         parent_goal_id: None,
+        cloned_from: None,
         source_snapshot: None,
         is_macro: false,
         parent_macro_id: None,
@@ -2283,17 +2309,21 @@ fn create_review_draft(
             .map(|s| s.to_string()),
         agent_pid: None,
         heartbeat_required: false,
+        last_heartbeat: None,
         pr_url: None,
         pr_package_id: None,
         progress_note: None,
         vcs_isolation: None,
         initiated_by: None,
         memory_entries_created: vec![],
+        readonly: false,
+        source_roots: Vec::new(),
         created_at: now,
         updated_at: now,
         input_tokens: 0,
         output_tokens: 0,
         agent_model: String::new(),
+        timeout_secs: None,
     };
 
     let goal_store = GoalRunStore::new(&config.goals_dir)
@@ -2392,6 +2422,10 @@ fn create_review_draft(
                 comments: None,
                 amendment: None,
                 kind: None,
+                no_commit: false,
+                renamed_from: None,
+                mode_change: None,
+                line_endings_normalized: None,
             }],
             patch_sets: vec![],
             pending_actions: vec![],
@@ -2430,8 +2464,11 @@ fn create_review_draft(
         display_id: Some(format!("{}-01", &review_id_str[..8])),
         tag: Some(format!("constitution-review-{}", &review_id_str[..8])),
         vcs_status: None,
+        submit_outcome: None,
+        content_hash: None,
         parent_draft_id: None,
         pending_approvals: vec![],
+        review_assignments: vec![],
         supervisor_review: None,
         ignored_artifacts: vec![],
         baseline_artifacts: vec![],
@@ -2441,6 +2478,11 @@ fn create_review_draft(
         draft_seq: 1,
         plan_phase: None,
         plan_md_base: None,
+        split_from: None,
+        split_into: Vec::new(),
+        merged_from: Vec::new(),
+        noop_dropped: Vec::new(),
+        staged_hashes: Default::default(),
     };
 
     super::draft::save_package(config, &pkg)
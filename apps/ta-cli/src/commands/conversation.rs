@@ -73,31 +73,7 @@ pub fn execute(config: &GatewayConfig, goal_id: &str, json: bool) -> anyhow::Res
 }
 
 fn resolve_goal_id(prefix: &str, config: &GatewayConfig) -> anyhow::Result<Uuid> {
-    // Try parsing as a full UUID first.
-    if let Ok(id) = Uuid::parse_str(prefix) {
-        return Ok(id);
-    }
-
-    // Otherwise, match by prefix against known goals.
-    let goal_store = GoalRunStore::new(&config.goals_dir)?;
-    let all_goals = goal_store.list()?;
-    let matches: Vec<_> = all_goals
-        .iter()
-        .filter(|g| g.goal_run_id.to_string().starts_with(prefix))
-        .collect();
-
-    match matches.len() {
-        0 => anyhow::bail!(
-            "No goal found matching prefix '{}'. Use `ta goal list --all` to see all goals.",
-            prefix
-        ),
-        1 => Ok(matches[0].goal_run_id),
-        n => anyhow::bail!(
-            "Ambiguous prefix '{}' matches {} goals. Use a longer prefix.",
-            prefix,
-            n
-        ),
-    }
+    super::goal::resolve_goal_id(prefix, &GoalRunStore::new(&config.goals_dir)?)
 }
 
 #[cfg(test)]
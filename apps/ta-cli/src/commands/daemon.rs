@@ -912,7 +912,7 @@ fn cmd_health(project_root: &Path) -> anyhow::Result<()> {
 
     // 6. Goal process liveness
     print!("  Goal processes... ");
-    let goals_dir = project_root.join(".ta/goals");
+    let goals_dir = ta_mcp_gateway::GatewayConfig::for_project(project_root).goals_dir;
     if goals_dir.exists() {
         match ta_goal::GoalRunStore::new(&goals_dir) {
             Ok(store) => {
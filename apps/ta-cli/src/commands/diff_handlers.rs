@@ -0,0 +1,255 @@
+// diff_handlers.rs — Validate the external diff handlers in .ta/diff-handlers.toml.
+//
+// A misconfigured handler otherwise only shows up when `ta draft view --file`
+// tries to open a file mid-review. `ta diff-handlers test` checks every rule up
+// front and can launch the matching handler on a throwaway copy of a sample.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use clap::Subcommand;
+use ta_changeset::diff_handlers::{DiffHandlersConfig, HandlerRule};
+use ta_mcp_gateway::GatewayConfig;
+
+/// How long a launched handler must survive (or exit cleanly within) to pass.
+const OPEN_GRACE: Duration = Duration::from_secs(2);
+
+#[derive(Subcommand)]
+pub enum DiffHandlersCommands {
+    /// Check each configured handler: pattern validity and command on PATH.
+    Test {
+        /// Also launch the handler matching this sample file on a temporary
+        /// copy of it, to verify the handler actually runs.
+        #[arg(long, value_name = "SAMPLE")]
+        open: Option<PathBuf>,
+    },
+}
+
+pub fn execute(cmd: &DiffHandlersCommands, config: &GatewayConfig) -> anyhow::Result<()> {
+    match cmd {
+        DiffHandlersCommands::Test { open } => test_handlers(config, open.as_deref()),
+    }
+}
+
+/// Result of checking a single handler rule.
+struct RuleCheck {
+    /// Why the pattern never matches, if it doesn't.
+    pattern_problem: Option<String>,
+    /// Resolved location of the handler command, if found.
+    command_path: Option<PathBuf>,
+}
+
+impl RuleCheck {
+    fn passed(&self) -> bool {
+        self.pattern_problem.is_none() && self.command_path.is_some()
+    }
+}
+
+fn check_rule(rule: &HandlerRule) -> RuleCheck {
+    RuleCheck {
+        pattern_problem: rule.pattern_problem(),
+        command_path: which::which(&rule.command).ok(),
+    }
+}
+
+fn test_handlers(config: &GatewayConfig, sample: Option<&Path>) -> anyhow::Result<()> {
+    let config_path = config.workspace_root.join(".ta/diff-handlers.toml");
+    let handlers = DiffHandlersConfig::load(&config_path)
+        .map_err(|e| anyhow::anyhow!("{} ({})", e, config_path.display()))?;
+
+    if handlers.handler.is_empty() {
+        println!(
+            "No diff handlers configured ({} is missing or empty).",
+            config_path.display()
+        );
+        println!("Non-text files open with the OS default application.");
+        return Ok(());
+    }
+
+    println!(
+        "Diff handlers ({}): {} rule(s)",
+        config_path.display(),
+        handlers.handler.len()
+    );
+    let mut failures = 0usize;
+    for (i, rule) in handlers.handler.iter().enumerate() {
+        println!();
+        match &rule.description {
+            Some(desc) => println!("  [{}] {} — {}", i + 1, rule.pattern, desc),
+            None => println!("  [{}] {}", i + 1, rule.pattern),
+        }
+        println!(
+            "      command: {}",
+            format!("{} {}", rule.command, rule.args.join(" ")).trim_end()
+        );
+
+        let check = check_rule(rule);
+        if let Some(problem) = &check.pattern_problem {
+            println!("      [FAIL] pattern: {}", problem);
+        }
+        match &check.command_path {
+            Some(path) => println!("      [ok]   {} found at {}", rule.command, path.display()),
+            None => println!("      [FAIL] {} not found on PATH", rule.command),
+        }
+        if !rule.args.iter().any(|a| a.contains("{file}")) {
+            println!("      [warn] args have no {{file}} placeholder; the file path is not passed");
+        }
+        if !check.passed() {
+            failures += 1;
+        }
+    }
+
+    if let Some(sample) = sample {
+        println!();
+        match open_sample(&handlers, sample, OPEN_GRACE) {
+            Ok(msg) => println!("  [ok]   {}", msg),
+            Err(e) => {
+                println!("  [FAIL] {}", e);
+                failures += 1;
+            }
+        }
+    }
+
+    println!();
+    if failures > 0 {
+        anyhow::bail!(
+            "{} diff handler check(s) failed. Fix {} and re-run `ta diff-handlers test`.",
+            failures,
+            config_path.display()
+        );
+    }
+    println!("All diff handler checks passed.");
+    Ok(())
+}
+
+/// Launch the handler matching `sample` on a temporary copy of it.
+///
+/// The handler passes if it is still running after `grace` (typical for GUI
+/// viewers) or exits successfully within it; a non-zero exit fails.
+fn open_sample(
+    handlers: &DiffHandlersConfig,
+    sample: &Path,
+    grace: Duration,
+) -> anyhow::Result<String> {
+    let sample_str = sample.to_string_lossy();
+    let rule = handlers.find_handler(&sample_str).ok_or_else(|| {
+        anyhow::anyhow!(
+            "no handler matches {} — `ta draft view` would use the OS default",
+            sample_str
+        )
+    })?;
+
+    let file_name = sample
+        .file_name()
+        .ok_or_else(|| anyhow::anyhow!("sample {} has no file name", sample_str))?;
+    let temp_dir = std::env::temp_dir().join(format!("ta-diff-handler-{}", uuid::Uuid::new_v4()));
+    std::fs::create_dir_all(&temp_dir)?;
+    let temp_file = temp_dir.join(file_name);
+    if sample.is_file() {
+        std::fs::copy(sample, &temp_file)?;
+    } else {
+        // No real sample: an empty file still exercises command and arguments.
+        std::fs::write(&temp_file, b"")?;
+    }
+
+    let result = wait_for_handler(rule, &temp_file, grace);
+    // A handler that is still running keeps its copy; otherwise clean up.
+    if !matches!(result, Ok(HandlerOutcome::Running(_))) {
+        let _ = std::fs::remove_dir_all(&temp_dir);
+    }
+    match result? {
+        HandlerOutcome::Exited => Ok(format!(
+            "{} opened {} and exited cleanly",
+            rule.command,
+            temp_file.display()
+        )),
+        HandlerOutcome::Running(pid) => Ok(format!(
+            "{} is running (PID {}) on {} — check that the file opened",
+            rule.command,
+            pid,
+            temp_file.display()
+        )),
+    }
+}
+
+enum HandlerOutcome {
+    /// Exited successfully within the grace period.
+    Exited,
+    /// Still running after the grace period (PID).
+    Running(u32),
+}
+
+fn wait_for_handler(
+    rule: &HandlerRule,
+    file: &Path,
+    grace: Duration,
+) -> anyhow::Result<HandlerOutcome> {
+    let mut child = rule.spawn(file)?;
+    let started = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            if status.success() {
+                return Ok(HandlerOutcome::Exited);
+            }
+            anyhow::bail!(
+                "{} exited with {} on {}",
+                rule.command,
+                status,
+                file.display()
+            );
+        }
+        if started.elapsed() >= grace {
+            return Ok(HandlerOutcome::Running(child.id()));
+        }
+        std::thread::sleep(Duration::from_millis(50));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, command: &str, args: &[&str]) -> HandlerRule {
+        HandlerRule {
+            pattern: pattern.to_string(),
+            command: command.to_string(),
+            args: args.iter().map(|a| a.to_string()).collect(),
+            description: None,
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn check_rule_flags_missing_commands_and_bad_patterns() {
+        assert!(check_rule(&rule("*.png", "sh", &["{file}"])).passed());
+
+        let missing = check_rule(&rule("*.png", "definitely-not-a-viewer-12345", &[]));
+        assert!(missing.command_path.is_none());
+        assert!(!missing.passed());
+
+        let braces = check_rule(&rule("*.{png,jpg}", "sh", &[]));
+        assert!(braces.pattern_problem.is_some());
+        assert!(!braces.passed());
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn open_sample_reports_exit_status() {
+        let handlers = DiffHandlersConfig {
+            handler: vec![
+                rule("*.ok", "sh", &["-c", "test -f \"$0\"", "{file}"]),
+                rule("*.bad", "sh", &["-c", "exit 3", "{file}"]),
+            ],
+        };
+        let grace = Duration::from_secs(10);
+
+        let msg = open_sample(&handlers, Path::new("sample.ok"), grace).unwrap();
+        assert!(msg.contains("exited cleanly"), "{}", msg);
+
+        let err = open_sample(&handlers, Path::new("sample.bad"), grace).unwrap_err();
+        assert!(err.to_string().contains("exited with"), "{}", err);
+
+        let err = open_sample(&handlers, Path::new("sample.txt"), grace).unwrap_err();
+        assert!(err.to_string().contains("no handler matches"), "{}", err);
+    }
+}
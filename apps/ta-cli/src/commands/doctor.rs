@@ -512,7 +512,7 @@ fn check_gc(config: &GatewayConfig) -> Vec<CheckResult> {
     let mut results = Vec::new();
 
     // Stale staging dirs.
-    let staging_dir = config.workspace_root.join(".ta").join("staging");
+    let staging_dir = config.staging_dir.clone();
     if staging_dir.exists() {
         let seven_days_secs: u64 = 7 * 24 * 3600;
         let now_secs = std::time::SystemTime::now()
@@ -551,8 +551,13 @@ fn append_draft_context(ctx: &mut String, config: &GatewayConfig, draft_id: Uuid
             if let Some(ref comments) = artifact.comments {
                 for comment in &comments.comments {
                     ctx.push_str(&format!(
-                        "  - **{}**: {}\n",
-                        comment.commenter, comment.text
+                        "  - **{}**{}: {}\n",
+                        comment.commenter,
+                        comment
+                            .location_label()
+                            .map(|l| format!(" ({})", l))
+                            .unwrap_or_default(),
+                        comment.text
                     ));
                 }
             }
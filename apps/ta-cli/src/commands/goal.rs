@@ -5,8 +5,8 @@ use std::path::PathBuf;
 
 use clap::Subcommand;
 use ta_goal::{
-    GoalHistoryLedger, GoalOutcome, GoalRun, GoalRunState, GoalRunStore, HistoryFilter,
-    VelocityEntry, VelocityStore,
+    EventDispatcher, GoalHistoryLedger, GoalOutcome, GoalRun, GoalRunState, GoalRunStore,
+    HistoryFilter, LogSink, VelocityEntry, VelocityStore,
 };
 use ta_mcp_gateway::GatewayConfig;
 use ta_policy::constitution::{
@@ -145,6 +145,7 @@ pub fn start_goal_extending_parent(
     goal.plan_phase = phase.map(|p| p.to_string());
     // Reuse the parent's source snapshot so diffs are against the original source.
     goal.source_snapshot = parent.source_snapshot.clone();
+    goal.base_ref = parent.base_ref.clone();
 
     goal.transition(GoalRunState::Configured)?;
     goal.transition(GoalRunState::Running)?;
@@ -153,6 +154,38 @@ pub fn start_goal_extending_parent(
     Ok(goal)
 }
 
+/// Startup sweep: fail active goals that have been idle past their timeout.
+/// Called on every `ta` invocation alongside `check_stale_drafts`; prints one
+/// line per goal to stderr. Errors are logged and otherwise ignored.
+pub fn check_timed_out_goals(config: &GatewayConfig) {
+    if !config.goals_dir.exists() {
+        return;
+    }
+    let workflow = ta_submit::WorkflowConfig::load_or_default(
+        &config.workspace_root.join(".ta/workflow.toml"),
+    );
+    let Ok(store) = GoalRunStore::new(&config.goals_dir) else {
+        return;
+    };
+    let mut dispatcher = EventDispatcher::new();
+    dispatcher.add_sink(
+        Box::new(LogSink::new(&config.events_log)),
+        ta_changeset::interaction::NotificationLevel::Debug,
+    );
+
+    match ta_goal::sweep_timed_out_goals(&store, workflow.gc.goal_timeout(), &dispatcher) {
+        Ok(failed) => {
+            for id in failed {
+                eprintln!(
+                    "warning: goal {} made no progress within its timeout and was marked failed",
+                    &id.to_string()[..8]
+                );
+            }
+        }
+        Err(e) => tracing::warn!("goal timeout sweep failed: {}", e),
+    }
+}
+
 #[derive(Subcommand)]
 pub enum GoalCommands {
     /// Start a new goal run with an overlay workspace.
@@ -177,6 +210,10 @@ pub enum GoalCommands {
         /// Read objective from a file instead of --objective.
         #[arg(long)]
         objective_file: Option<PathBuf>,
+        /// Fail the goal if it makes no progress for this many seconds
+        /// (overrides `[gc] goal_timeout_secs`).
+        #[arg(long)]
+        timeout: Option<u64>,
     },
     /// List goal runs (default: active only; use --all for everything).
     List {
@@ -189,14 +226,41 @@ pub enum GoalCommands {
         /// Show all goals including terminal states.
         #[arg(long)]
         all: bool,
+        /// Output a JSON array of goal records instead of the table.
+        #[arg(long)]
+        json: bool,
     },
     /// Show details for a specific goal run.
     Status {
         /// Goal run ID.
         id: String,
-        /// Output as JSON instead of human-readable text.
+        /// Output the full goal as JSON instead of human-readable text.
         #[arg(long)]
         json: bool,
+        /// With --json, print the stable goal record (the `ta goal list --json`
+        /// shape) instead of the full goal.
+        #[arg(long, requires = "json")]
+        record: bool,
+        /// Redraw the status and the goal's recent audit events every
+        /// --interval seconds until the agent finishes (Ctrl-C to stop).
+        #[arg(long, conflicts_with = "json")]
+        watch: bool,
+        /// Seconds between redraws with --watch.
+        #[arg(long, default_value = "2", requires = "watch")]
+        interval: u64,
+        /// Also list the capability grants the goal's manifest allows, with
+        /// the approval gates and limits `.ta/policy.yaml` adds.
+        #[arg(long, conflicts_with_all = ["json", "watch"])]
+        show_policy: bool,
+    },
+    /// Write the capability manifest a goal ran under as JSON, for offline
+    /// audit with `ta policy explain --manifest <file>`.
+    ExportManifest {
+        /// Goal run ID (full UUID, 8+ character prefix, or tag).
+        id: String,
+        /// Write to this file instead of stdout.
+        #[arg(long, short)]
+        output: Option<PathBuf>,
     },
     /// Delete a goal run and its staging directory.
     Delete {
@@ -206,6 +270,22 @@ pub enum GoalCommands {
         #[arg(long)]
         reason: Option<String>,
     },
+    /// Re-run a goal from scratch with the same title, objective, source, and phase.
+    ///
+    /// Creates a new goal (new ID, manifest, and staging workspace) linked to
+    /// the original as a clone. Unlike `--follow-up`, the clone does not build
+    /// on or supersede the original's draft.
+    Clone {
+        /// Goal run ID, tag, or prefix to clone.
+        id: String,
+        /// Launch the agent on the clone (like `ta run`) instead of only
+        /// preparing the staging workspace.
+        #[arg(long)]
+        launch: bool,
+        /// Agent to use (defaults to the original goal's agent).
+        #[arg(long)]
+        agent: Option<String>,
+    },
     /// Manage access constitutions for goals (v0.4.3).
     Constitution {
         #[command(subcommand)]
@@ -363,32 +443,19 @@ fn find_parent_goal(
     config: &GatewayConfig,
 ) -> anyhow::Result<Uuid> {
     match id_prefix {
-        Some(prefix) => {
-            // Match by goal ID prefix (first N characters).
-            let all_goals = store.list()?;
-            let matches: Vec<_> = all_goals
-                .iter()
-                .filter(|g| g.goal_run_id.to_string().starts_with(prefix))
-                .collect();
-
-            match matches.len() {
-                0 => {
-                    // No goal matched — try matching as a draft ID and resolve to its goal.
-                    if let Some(goal_id) = resolve_draft_to_goal(prefix, &all_goals, config) {
-                        return Ok(goal_id);
-                    }
-                    anyhow::bail!("No goal or draft found matching prefix '{}'", prefix);
+        Some(prefix) => match resolve_goal_id(prefix, store) {
+            Ok(id) if store.get(id)?.is_some() => Ok(id),
+            resolved => {
+                // No goal matched — try matching as a draft ID and resolve to its goal.
+                if let Some(goal_id) = resolve_draft_to_goal(prefix, &store.list()?, config) {
+                    return Ok(goal_id);
                 }
-                1 => Ok(matches[0].goal_run_id),
-                _ => {
-                    anyhow::bail!(
-                        "Ambiguous prefix '{}' matches {} goals. Use a longer prefix.",
-                        prefix,
-                        matches.len()
-                    )
+                match resolved {
+                    Err(e) => Err(e),
+                    Ok(_) => anyhow::bail!("No goal or draft found matching '{}'", prefix),
                 }
             }
-        }
+        },
         None => {
             // Find the most recent goal (prefer unapplied, fall back to latest applied).
             let all_goals = store.list()?;
@@ -420,7 +487,10 @@ fn find_parent_goal(
 /// Returns true if the goal subcommand is `start` — used by the terms gate in
 /// main.rs to identify agent-spawning operations that require acceptance.
 pub fn is_start_command(cmd: &GoalCommands) -> bool {
-    matches!(cmd, GoalCommands::Start { .. })
+    matches!(
+        cmd,
+        GoalCommands::Start { .. } | GoalCommands::Clone { launch: true, .. }
+    )
 }
 
 pub fn execute(cmd: &GoalCommands, config: &GatewayConfig) -> anyhow::Result<()> {
@@ -435,6 +505,7 @@ pub fn execute(cmd: &GoalCommands, config: &GatewayConfig) -> anyhow::Result<()>
             phase,
             follow_up,
             objective_file,
+            timeout,
         } => start_goal(
             config,
             &store,
@@ -445,10 +516,15 @@ pub fn execute(cmd: &GoalCommands, config: &GatewayConfig) -> anyhow::Result<()>
             phase.as_deref(),
             follow_up.as_ref(),
             objective_file.as_deref(),
-        ),
-        GoalCommands::List { state, active, all } => {
-            list_goals(&store, config, state.as_deref(), *active, *all)
-        }
+            *timeout,
+        )
+        .map(|_| ()),
+        GoalCommands::List {
+            state,
+            active,
+            all,
+            json,
+        } => list_goals(&store, config, state.as_deref(), *active, *all, *json),
         GoalCommands::History {
             phase,
             agent,
@@ -463,8 +539,27 @@ pub fn execute(cmd: &GoalCommands, config: &GatewayConfig) -> anyhow::Result<()>
             *json,
             *limit,
         ),
-        GoalCommands::Status { id, json } => show_status(&store, config, id, *json),
+        GoalCommands::Status {
+            id,
+            json,
+            record,
+            watch,
+            interval,
+            show_policy,
+        } => {
+            if *watch {
+                watch_status(&store, config, id, *interval)
+            } else {
+                show_status(&store, config, id, *json, *record, *show_policy)
+            }
+        }
+        GoalCommands::ExportManifest { id, output } => {
+            export_manifest(&store, config, id, output.as_deref())
+        }
         GoalCommands::Delete { id, reason } => delete_goal(&store, config, id, reason.as_deref()),
+        GoalCommands::Clone { id, launch, agent } => {
+            clone_goal(config, &store, id, *launch, agent.as_deref()).map(|_| ())
+        }
         GoalCommands::Constitution { command } => execute_constitution(command, config, &store),
         GoalCommands::Inspect { id, json } => goal_inspect(config, &store, id, *json),
         GoalCommands::PostMortem { id } => goal_post_mortem(config, &store, id),
@@ -505,7 +600,8 @@ fn start_goal(
     phase: Option<&str>,
     follow_up: Option<&Option<String>>,
     objective_file: Option<&std::path::Path>,
-) -> anyhow::Result<()> {
+    timeout_secs: Option<u64>,
+) -> anyhow::Result<Uuid> {
     // Resolve objective from file if specified.
     let final_objective = if let Some(obj_file) = objective_file {
         std::fs::read_to_string(obj_file)?
@@ -539,7 +635,7 @@ fn start_goal(
     if let Some(ref parent) = extend_parent {
         // v0.4.1.2: Reuse parent's staging directory — no fresh copy needed.
         let pid = parent_goal_id.unwrap(); // safe: extend_parent is Some only when parent_goal_id is Some
-        let goal = start_goal_extending_parent(
+        let mut goal = start_goal_extending_parent(
             config,
             store,
             title,
@@ -549,6 +645,10 @@ fn start_goal(
             parent,
             pid,
         )?;
+        if timeout_secs.is_some() {
+            goal.timeout_secs = timeout_secs;
+            store.save(&goal)?;
+        }
 
         println!(
             "Goal started: {} (extending parent staging)",
@@ -560,14 +660,17 @@ fn start_goal(
         println!();
         println!("Agent workspace ready. To enter:");
         println!("  cd {}", goal.workspace_path.display());
+        Ok(goal.goal_run_id)
     } else {
         // Fresh staging copy (original behavior or standalone follow-up).
-        let mut goal = ta_goal::GoalRun::new(
+        let mut goal = new_goal_run(
+            config,
             title,
             &final_objective,
             agent,
-            PathBuf::new(), // placeholder — set after overlay creation
-            config.store_dir.join("placeholder"), // placeholder
+            &source_dir,
+            phase,
+            timeout_secs,
         );
         goal.parent_goal_id = parent_goal_id;
         let goal_id = goal.goal_run_id.to_string();
@@ -578,34 +681,12 @@ fn start_goal(
         let excludes = super::draft::load_excludes_with_adapter(&source_dir);
 
         // v0.13.13: Use configured staging strategy (default: Full).
-        let workflow = ta_submit::config::WorkflowConfig::load_or_default(&source_dir);
-        let staging_mode = match workflow.staging.strategy {
-            ta_submit::config::StagingStrategy::Full => ta_workspace::OverlayStagingMode::Full,
-            ta_submit::config::StagingStrategy::Smart => ta_workspace::OverlayStagingMode::Smart,
-            ta_submit::config::StagingStrategy::RefsCow => {
-                ta_workspace::OverlayStagingMode::RefsCow
-            }
-            ta_submit::config::StagingStrategy::ProjFs => ta_workspace::OverlayStagingMode::ProjFs,
-        };
-        let overlay = OverlayWorkspace::create_with_strategy(
-            &goal_id,
-            &source_dir,
-            &config.staging_dir,
-            excludes,
-            staging_mode,
-        )?;
+        let overlay = create_goal_overlay(&goal_id, &source_dir, &config.staging_dir, excludes)?;
 
-        // v0.2.1: Capture source snapshot for conflict detection.
-        let snapshot_json = overlay
-            .snapshot()
-            .and_then(|snap| serde_json::to_value(snap).ok());
-
-        // Update goal with actual paths.
-        goal.workspace_path = overlay.staging_dir().to_path_buf();
-        goal.store_path = config.store_dir.join(&goal_id);
-        goal.source_dir = Some(source_dir.clone());
-        goal.plan_phase = phase.map(|p| p.to_string());
-        goal.source_snapshot = snapshot_json;
+        // Update goal with actual paths and the source snapshot for
+        // conflict detection (v0.2.1).
+        record_goal_overlay(&mut goal, &overlay);
+        goal.base_ref = ta_workspace::overlay::get_git_head_sha(&source_dir);
 
         // v0.15.19.3: Snapshot source PLAN.md as three-way merge base.
         // Stored at <staging>/.ta/plan_base.md for use during `ta draft build`.
@@ -653,13 +734,217 @@ fn start_goal(
         println!();
         println!("Agent workspace ready. To enter:");
         println!("  cd {}", overlay.staging_dir().display());
+        Ok(goal.goal_run_id)
     }
+}
 
-    Ok(())
+/// A new goal record for `source_dir`, before its staging workspace exists.
+/// The workspace path is set once the overlay is created.
+fn new_goal_run(
+    config: &GatewayConfig,
+    title: &str,
+    objective: &str,
+    agent: &str,
+    source_dir: &std::path::Path,
+    phase: Option<&str>,
+    timeout_secs: Option<u64>,
+) -> ta_goal::GoalRun {
+    let mut goal = ta_goal::GoalRun::new(title, objective, agent, PathBuf::new(), PathBuf::new());
+    goal.store_path = config.store_dir.join(goal.goal_run_id.to_string());
+    goal.source_dir = Some(source_dir.to_path_buf());
+    goal.plan_phase = phase.map(|p| p.to_string());
+    goal.timeout_secs = timeout_secs;
+    goal
 }
 
-/// Resolve a goal ID from a tag, full UUID, or an 8+ character prefix.
-fn resolve_goal_id(id: &str, store: &GoalRunStore) -> anyhow::Result<Uuid> {
+/// Create a fresh goal from an existing one's title, objective, source, and phase.
+///
+/// Without `launch` this is `ta goal start` with the original's settings; with
+/// it, the goal record is created first and `ta run` builds the staging
+/// workspace and launches the agent on it. Returns the new goal's ID.
+fn clone_goal(
+    config: &GatewayConfig,
+    store: &GoalRunStore,
+    id: &str,
+    launch: bool,
+    agent: Option<&str>,
+) -> anyhow::Result<Uuid> {
+    let original_id = resolve_goal_id(id, store)?;
+    let original = store
+        .get(original_id)?
+        .ok_or_else(|| anyhow::anyhow!("Goal {} not found", original_id))?;
+    // A custom --agent-command carries over unless a different agent is named.
+    let agent_command = original.agent_command.clone().filter(|_| agent.is_none());
+    let agent = agent.unwrap_or(&original.agent_id);
+    let source_dir = original
+        .source_dir
+        .clone()
+        .unwrap_or_else(|| config.workspace_root.clone());
+
+    let clone_id = if launch {
+        // `ta run` creates the staging workspace for this record.
+        let mut goal = new_goal_run(
+            config,
+            &original.title,
+            &original.objective,
+            agent,
+            &source_dir,
+            original.plan_phase.as_deref(),
+            original.timeout_secs,
+        );
+        goal.cloned_from = Some(original_id);
+        goal.agent_command = agent_command.clone();
+        goal.transition(GoalRunState::Configured)?;
+        goal.transition(GoalRunState::Running)?;
+        store.save_with_tag(&mut goal)?;
+        goal.goal_run_id
+    } else {
+        let clone_id = start_goal(
+            config,
+            store,
+            &original.title,
+            Some(&source_dir),
+            &original.objective,
+            agent,
+            original.plan_phase.as_deref(),
+            None,
+            None,
+            original.timeout_secs,
+        )?;
+        let mut goal = store
+            .get(clone_id)?
+            .ok_or_else(|| anyhow::anyhow!("Cloned goal {} not found", clone_id))?;
+        goal.cloned_from = Some(original_id);
+        goal.agent_command = agent_command.clone();
+        store.save(&goal)?;
+        goal.goal_run_id
+    };
+
+    println!(
+        "  Cloned from: {} ({})",
+        original.shortref(),
+        original.title
+    );
+
+    if launch {
+        let clone_id_str = clone_id.to_string();
+        super::run::execute(
+            config,
+            Some(&original.title),
+            agent,
+            Some(source_dir.as_path()),
+            &original.objective,
+            original.plan_phase.as_deref(),
+            None,  // follow_up
+            None,  // follow_up_draft
+            None,  // follow_up_goal
+            None,  // objective_file
+            false, // no_launch
+            false, // interactive
+            false, // macro_goal
+            None,  // resume
+            false, // headless
+            false, // skip_verify
+            false, // quiet
+            Some(&clone_id_str),
+            None, // workflow = default (single-agent)
+            None, // persona_name
+            None, // env_file
+            agent_command.as_deref(),
+            &[],  // context_files
+            None, // timeout
+        )?;
+    }
+    Ok(clone_id)
+}
+
+/// Shortest goal ID prefix `resolve_goal_id` accepts.
+const MIN_GOAL_ID_PREFIX: usize = 4;
+
+/// Create a goal's staging workspace under `staging_root`: a copy of
+/// `source_dir`, or one `<alias>/` copy per root when `[workspace.roots]`
+/// is set (root paths are relative to `source_dir`).
+pub(crate) fn create_goal_overlay(
+    goal_id: &str,
+    source_dir: &std::path::Path,
+    staging_root: &std::path::Path,
+    excludes: ta_workspace::ExcludePatterns,
+) -> anyhow::Result<OverlayWorkspace> {
+    let (staging_mode, parallel) = staging_options(source_dir);
+    let roots =
+        ta_submit::config::WorkflowConfig::load_or_default(&source_dir.join(".ta/workflow.toml"))
+            .workspace
+            .roots;
+    if roots.is_empty() {
+        return Ok(OverlayWorkspace::create_with_options(
+            goal_id,
+            source_dir,
+            staging_root,
+            excludes,
+            staging_mode,
+            parallel,
+        )?);
+    }
+    let roots = roots
+        .into_iter()
+        .map(|(alias, path)| {
+            let path = source_dir.join(path);
+            let path = path.canonicalize().map_err(|e| {
+                anyhow::anyhow!("[workspace.roots] {} = {}: {}", alias, path.display(), e)
+            })?;
+            Ok((alias, path))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    Ok(OverlayWorkspace::create_multi(
+        goal_id,
+        roots,
+        staging_root,
+        excludes,
+        staging_mode,
+        parallel,
+    )?)
+}
+
+/// Record a freshly created overlay on its goal: the staging path and the
+/// goal-start snapshot (one per root for a multi-root workspace).
+pub(crate) fn record_goal_overlay(goal: &mut ta_goal::GoalRun, overlay: &OverlayWorkspace) {
+    let snapshot_json = |snap: &ta_workspace::SourceSnapshot| serde_json::to_value(snap).ok();
+    goal.workspace_path = overlay.staging_dir().to_path_buf();
+    goal.source_snapshot = overlay.snapshot().and_then(snapshot_json);
+    goal.source_roots = overlay
+        .roots()
+        .map(|(alias, path)| ta_goal::GoalSourceRoot {
+            alias: alias.to_string(),
+            path: path.to_path_buf(),
+            snapshot: overlay.root_snapshot(alias).and_then(snapshot_json),
+        })
+        .collect();
+}
+
+/// Staging mode and copy parallelism for a goal rooted at `source_dir`.
+///
+/// Reads `<source_dir>/.ta/workflow.toml` (plus its `workflow.local.toml`
+/// overlay). `WorkflowConfig::load_or_default` takes the file path, not the
+/// project directory; given the directory it fails to read and silently
+/// falls back to defaults, so `[staging] strategy` would never apply.
+pub(crate) fn staging_options(
+    source_dir: &std::path::Path,
+) -> (ta_workspace::OverlayStagingMode, bool) {
+    let workflow =
+        ta_submit::config::WorkflowConfig::load_or_default(&source_dir.join(".ta/workflow.toml"));
+    let mode = match workflow.staging.strategy {
+        ta_submit::config::StagingStrategy::Full => ta_workspace::OverlayStagingMode::Full,
+        ta_submit::config::StagingStrategy::Smart => ta_workspace::OverlayStagingMode::Smart,
+        ta_submit::config::StagingStrategy::RefsCow => ta_workspace::OverlayStagingMode::RefsCow,
+        ta_submit::config::StagingStrategy::ProjFs => ta_workspace::OverlayStagingMode::ProjFs,
+    };
+    (mode, workflow.workspace.parallel)
+}
+
+/// Resolve a goal ID from a tag, full UUID, or a unique prefix of at least
+/// four characters. Every command that takes a goal ID goes through here; an
+/// ambiguous prefix fails with the matching goals listed.
+pub(crate) fn resolve_goal_id(id: &str, store: &GoalRunStore) -> anyhow::Result<Uuid> {
     // Try tag resolution first (v0.11.2.3).
     if let Ok(Some(g)) = store.resolve_tag(id) {
         return Ok(g.goal_run_id);
@@ -669,26 +954,36 @@ fn resolve_goal_id(id: &str, store: &GoalRunStore) -> anyhow::Result<Uuid> {
         return Ok(uuid);
     }
 
-    if id.len() < 8 {
+    if id.len() < MIN_GOAL_ID_PREFIX {
         anyhow::bail!(
-            "No goal found matching '{}' (not a tag and too short for UUID prefix -- use at least 8 characters)",
-            id
+            "No goal found matching '{}' (not a tag and too short for an ID prefix -- use at least {} characters)",
+            id,
+            MIN_GOAL_ID_PREFIX
         );
     }
 
+    let prefix = id.to_lowercase();
     let goals = store.list()?;
     let matches: Vec<_> = goals
         .iter()
-        .filter(|g| g.goal_run_id.to_string().starts_with(id))
+        .filter(|g| g.goal_run_id.to_string().starts_with(&prefix))
         .collect();
 
     match matches.len() {
-        0 => anyhow::bail!("No goal found matching '{}'", id),
+        0 => anyhow::bail!(
+            "No goal found matching '{}'. Run `ta goal list --all` to see all goals.",
+            id
+        ),
         1 => Ok(matches[0].goal_run_id),
         n => anyhow::bail!(
-            "Ambiguous prefix '{}' matches {} goals. Use a longer prefix or a goal tag.",
+            "Ambiguous prefix '{}' matches {} goals:\n  {}\nUse a longer prefix or a goal tag.",
             id,
-            n
+            n,
+            matches
+                .iter()
+                .map(|g| format!("{}  {} ({})", g.goal_run_id, g.title, g.state))
+                .collect::<Vec<_>>()
+                .join("\n  ")
         ),
     }
 }
@@ -699,6 +994,7 @@ fn list_goals(
     state: Option<&str>,
     active: bool,
     all: bool,
+    json: bool,
 ) -> anyhow::Result<()> {
     let mut goals = if let Some(state_filter) = state {
         store.list_by_state(state_filter)?
@@ -722,6 +1018,16 @@ fn list_goals(
         });
     }
 
+    if json {
+        let packages = load_all_packages_silent(config);
+        let records: Vec<ta_goal::GoalRunRecord> = goals
+            .iter()
+            .map(|g| g.to_record(latest_draft(g, &packages)))
+            .collect();
+        println!("{}", serde_json::to_string_pretty(&records)?);
+        return Ok(());
+    }
+
     if goals.is_empty() {
         println!("No goal runs found.");
         return Ok(());
@@ -850,6 +1156,19 @@ fn load_all_packages_silent(
         .unwrap_or_default()
 }
 
+/// Latest draft (id, status) built for a goal, for [`ta_goal::GoalRunRecord`].
+fn latest_draft(
+    goal: &ta_goal::GoalRun,
+    packages: &[ta_changeset::DraftPackage],
+) -> Option<(Uuid, String)> {
+    let goal_id_str = goal.goal_run_id.to_string();
+    packages
+        .iter()
+        .filter(|p| p.goal.goal_id == goal_id_str)
+        .max_by_key(|p| p.created_at)
+        .map(|p| (p.package_id, p.status.to_string()))
+}
+
 /// Get (draft_status, vcs_status) display columns for a goal.
 fn goal_draft_vcs_columns(
     goal: &ta_goal::GoalRun,
@@ -1054,10 +1373,7 @@ fn goal_recover(
                 println!("    {}", diag);
                 if let Some(pkg_id) = goal.pr_package_id {
                     let short_pkg = &pkg_id.to_string()[..8];
-                    let pkg_path = config
-                        .workspace_root
-                        .join(".ta/pr_packages")
-                        .join(format!("{}.json", pkg_id));
+                    let pkg_path = config.pr_packages_dir.join(format!("{}.json", pkg_id));
                     if pkg_path.exists() {
                         println!("    Draft: {} (present)", short_pkg);
                     } else {
@@ -1108,10 +1424,7 @@ fn goal_recover(
         println!("Agent PID: {}", pid);
     }
     if let Some(pkg_id) = target.pr_package_id {
-        let pkg_path = config
-            .workspace_root
-            .join(".ta/pr_packages")
-            .join(format!("{}.json", pkg_id));
+        let pkg_path = config.pr_packages_dir.join(format!("{}.json", pkg_id));
         let pkg_status = if pkg_path.exists() {
             "present and valid"
         } else {
@@ -1128,8 +1441,7 @@ fn goal_recover(
     // --- Choose recovery action ---
     let has_valid_draft = target.pr_package_id.is_some_and(|pkg_id| {
         config
-            .workspace_root
-            .join(".ta/pr_packages")
+            .pr_packages_dir
             .join(format!("{}.json", pkg_id))
             .exists()
     });
@@ -1191,6 +1503,8 @@ fn goal_recover(
                         summary: format!("Recovered draft for: {}", target.title),
                         latest: false,
                         apply_context_file: None,
+                        tests: None,
+                        base_ref: None,
                     },
                     config,
                 )?;
@@ -1230,6 +1544,8 @@ fn goal_recover(
                         summary: format!("Recovered draft for: {}", target.title),
                         latest: false,
                         apply_context_file: None,
+                        tests: None,
+                        base_ref: None,
                     },
                     config,
                 )?;
@@ -1304,10 +1620,7 @@ fn diagnose_goal(
         GoalRunState::Failed { reason } => {
             // Check if a valid draft exists — indicates watchdog race.
             if let Some(pkg_id) = goal.pr_package_id {
-                let pkg_path = config
-                    .workspace_root
-                    .join(".ta/pr_packages")
-                    .join(format!("{}.json", pkg_id));
+                let pkg_path = config.pr_packages_dir.join(format!("{}.json", pkg_id));
                 if pkg_path.exists() {
                     return Some(
                         "Watchdog overrode clean exit with failed state — draft is valid."
@@ -1408,18 +1721,218 @@ fn diagnose_goal(
     }
 }
 
+/// Where a goal's manifest shown by `--show-policy` came from.
+enum ManifestSource {
+    /// Recorded when the goal started.
+    Issued,
+    /// No record exists: recompiled from the profile the goal would have
+    /// been issued (read-only goals get the read-only profile, everything
+    /// else the default developer profile) with its `manifest_id` and start
+    /// time.
+    Reconstructed {
+        profile: Box<ta_policy::AlignmentProfile>,
+        name: &'static str,
+    },
+}
+
+/// The manifest recorded for `goal` at issuance, if any.
+fn issued_manifest(
+    config: &GatewayConfig,
+    goal: &GoalRun,
+) -> anyhow::Result<Option<ta_policy::CapabilityManifest>> {
+    Ok(ta_policy::ManifestStore::new(config.issued_manifests_dir()).load(goal.manifest_id)?)
+}
+
+/// The capability manifest a goal runs under: the recorded one when it
+/// exists, otherwise a reconstruction.
+fn goal_manifest(
+    config: &GatewayConfig,
+    goal: &GoalRun,
+) -> anyhow::Result<(ta_policy::CapabilityManifest, ManifestSource)> {
+    if let Some(manifest) = issued_manifest(config, goal)? {
+        return Ok((manifest, ManifestSource::Issued));
+    }
+    let (profile, name) = if goal.readonly {
+        (
+            ta_policy::AlignmentProfile::read_only(),
+            "read-only profile",
+        )
+    } else {
+        (
+            ta_policy::AlignmentProfile::default_developer(),
+            "default developer profile",
+        )
+    };
+    let mut manifest = ta_policy::PolicyCompiler::compile_with_id(
+        goal.manifest_id,
+        &goal.agent_id,
+        &profile,
+        &ta_policy::CompilerOptions::default(),
+    )
+    .map_err(|e| anyhow::anyhow!("policy compilation failed: {}", e))?;
+    let validity = manifest.expires_at - manifest.issued_at;
+    manifest.issued_at = goal.created_at;
+    manifest.expires_at = goal.created_at + validity;
+    Ok((
+        manifest,
+        ManifestSource::Reconstructed {
+            profile: Box::new(profile),
+            name,
+        },
+    ))
+}
+
+/// `ta goal status --show-policy`: the goal's capability grants, with any
+/// approval gates and limits `policy` adds to them.
+fn format_goal_policy(
+    config: &GatewayConfig,
+    goal: &GoalRun,
+    policy: &ta_policy::PolicyDocument,
+) -> anyhow::Result<String> {
+    let (manifest, source) = goal_manifest(config, goal)?;
+    let mut out = String::new();
+    out.push_str("\n--- Capability Policy ---\n");
+    match &source {
+        ManifestSource::Issued => {
+            out.push_str(&format!("Manifest: {} (as issued)\n", manifest.manifest_id));
+        }
+        ManifestSource::Reconstructed { name, .. } => {
+            out.push_str(&format!(
+                "Manifest: {} (reconstructed from the {})\n",
+                manifest.manifest_id, name
+            ));
+            out.push_str(
+                "Note:     no issued manifest is on record for this goal; the grants below \
+                 are a reconstruction, not the manifest the agent actually received.\n",
+            );
+        }
+    }
+    out.push_str(&format!("Issued:   {}\n", manifest.issued_at.to_rfc3339()));
+    out.push_str(&format!(
+        "Expires:  {}{}\n",
+        manifest.expires_at.to_rfc3339(),
+        if manifest.is_expired() {
+            " (expired — every call is denied)"
+        } else {
+            ""
+        }
+    ));
+    out.push_str("Grants:\n");
+    let forbidden = policy
+        .agents
+        .get(&goal.agent_id)
+        .map(|agent| agent.forbidden_actions.as_slice())
+        .unwrap_or_default();
+    for grant in &manifest.grants {
+        let scheme = grant
+            .resource_pattern
+            .split_once("://")
+            .map(|(scheme, _)| scheme);
+        let scheme_policy = scheme.and_then(|s| policy.schemes.get(s));
+        let mut notes = Vec::new();
+        if forbidden.contains(&format!("{}_{}", grant.tool, grant.verb)) {
+            notes.push(format!("forbidden for {} by policy", goal.agent_id));
+        }
+        if ta_policy::engine::APPROVAL_REQUIRED_VERBS.contains(&grant.verb.as_str())
+            || scheme_policy.is_some_and(|p| p.approval_required.contains(&grant.verb))
+        {
+            notes.push("requires approval".to_string());
+        }
+        if let Some(max) = scheme_policy.and_then(|p| p.max_actions_per_session) {
+            notes.push(format!("max {} actions per session", max));
+        }
+        let action = format!("{}.{}", grant.tool, grant.verb);
+        if notes.is_empty() {
+            out.push_str(&format!("  {:<16} {}\n", action, grant.resource_pattern));
+        } else {
+            out.push_str(&format!(
+                "  {:<16} {}  ({})\n",
+                action,
+                grant.resource_pattern,
+                notes.join(", ")
+            ));
+        }
+    }
+    if let ManifestSource::Reconstructed { profile, .. } = &source {
+        let profile_forbidden = &profile.autonomy_envelope.forbidden_actions;
+        if !profile_forbidden.is_empty() {
+            out.push_str(&format!("Forbidden: {}\n", profile_forbidden.join(", ")));
+        }
+    }
+    if let Some(limit) = policy.escalation.action_count_limit {
+        out.push_str(&format!(
+            "Escalation: human approval after {} actions\n",
+            limit
+        ));
+    }
+    out.push_str("Anything not granted above is denied (default deny).\n");
+    Ok(out)
+}
+
+/// `ta goal export-manifest`: the manifest recorded when the goal started,
+/// as JSON. Fails when none was recorded rather than exporting a guess.
+fn export_manifest(
+    store: &GoalRunStore,
+    config: &GatewayConfig,
+    id: &str,
+    output: Option<&std::path::Path>,
+) -> anyhow::Result<()> {
+    let goal_run_id = resolve_goal_id(id, store)?;
+    let goal = store
+        .get(goal_run_id)?
+        .ok_or_else(|| anyhow::anyhow!("Goal run not found: {}", goal_run_id))?;
+    let manifest = issued_manifest(config, &goal)?.ok_or_else(|| {
+        anyhow::anyhow!(
+            "No issued manifest is on record for goal {} (manifest {}).\n  \
+             Manifests are recorded when a goal starts through the MCP gateway or \
+             `ta run --readonly`; goals started otherwise have none to export.\n  \
+             `ta goal status {} --show-policy` shows a reconstruction.",
+            goal.display_tag(),
+            goal.manifest_id,
+            id
+        )
+    })?;
+    let json = serde_json::to_string_pretty(&manifest)?;
+    match output {
+        Some(path) => {
+            std::fs::write(path, format!("{}\n", json))
+                .map_err(|e| anyhow::anyhow!("Cannot write {}: {}", path.display(), e))?;
+            println!(
+                "Wrote manifest {} ({} grant(s)) for goal {} to {}",
+                manifest.manifest_id,
+                manifest.grants.len(),
+                goal.display_tag(),
+                path.display()
+            );
+            println!(
+                "  Audit it with: ta policy explain --manifest {}",
+                path.display()
+            );
+        }
+        None => println!("{}", json),
+    }
+    Ok(())
+}
+
 fn show_status(
     store: &GoalRunStore,
     config: &GatewayConfig,
     id: &str,
     json_output: bool,
+    record: bool,
+    show_policy: bool,
 ) -> anyhow::Result<()> {
     let goal_run_id = resolve_goal_id(id, store)?;
     match store.get(goal_run_id)? {
         Some(g) => {
+            if json_output && record {
+                let packages = load_all_packages_silent(config);
+                let record = g.to_record(latest_draft(&g, &packages));
+                println!("{}", serde_json::to_string_pretty(&record)?);
+                return Ok(());
+            }
             if json_output {
-                let json = serde_json::to_string_pretty(&g)?;
-                println!("{}", json);
+                println!("{}", serde_json::to_string_pretty(&g)?);
                 return Ok(());
             }
 
@@ -1477,15 +1990,29 @@ fn show_status(
             }
             println!("Created:  {}", g.created_at.to_rfc3339());
             println!("Updated:  {}", g.updated_at.to_rfc3339());
+            match g.last_heartbeat {
+                Some(at) => println!(
+                    "Heartbeat: {}s ago",
+                    (chrono::Utc::now() - at).num_seconds().max(0)
+                ),
+                None if g.state == GoalRunState::Running => println!("Heartbeat: none received"),
+                None => {}
+            }
             if let Some(ref src) = g.source_dir {
                 println!("Source:   {}", src.display());
             }
             if let Some(ref phase) = g.plan_phase {
                 println!("Phase:    {}", phase);
             }
+            if let Some(ref cmd) = g.agent_command {
+                println!("Command:  {}", cmd);
+            }
             if let Some(parent_id) = g.parent_goal_id {
                 println!("Parent:   {} (follow-up)", parent_id);
             }
+            if let Some(original_id) = g.cloned_from {
+                println!("Clone of: {}", original_id);
+            }
             if let Some(ref macro_id) = g.parent_macro_id {
                 println!("Macro:    {} (sub-goal of macro)", macro_id);
             }
@@ -1523,6 +2050,15 @@ fn show_status(
                             }
                             println!("Checked:  {}", vcs.last_checked.to_rfc3339());
                         }
+                        if let Some(outcome) =
+                            draft.submit_outcome.as_ref().filter(|o| o.is_partial())
+                        {
+                            println!("\n--- Submit incomplete ---");
+                            for (step, status) in outcome.steps() {
+                                println!("{:<9} {}", format!("{}:", step), status);
+                            }
+                            println!("Retry:    ta draft resubmit {}", &pr_id.to_string()[..8]);
+                        }
                     }
                 }
             } else {
@@ -1553,6 +2089,21 @@ fn show_status(
                     }
                 }
             }
+
+            if show_policy {
+                let policy = ta_policy::PolicyCascade::load(
+                    &config.workspace_root,
+                    &g.agent_id,
+                    Some(g.goal_run_id),
+                    None,
+                    &ta_policy::CliOverrides::default(),
+                )
+                .unwrap_or_else(|e| {
+                    eprintln!("Warning: cannot load policy ({}), showing defaults.", e);
+                    ta_policy::PolicyDocument::default()
+                });
+                print!("{}", format_goal_policy(config, &g, &policy)?);
+            }
         }
         None => {
             eprintln!("Goal run not found: {}", id);
@@ -1563,6 +2114,82 @@ fn show_status(
     Ok(())
 }
 
+/// Audit events shown under the status in `ta goal status --watch`.
+const WATCH_RECENT_EVENTS: usize = 10;
+
+/// Whether `ta goal status --watch` keeps polling a goal in this state: the
+/// agent is still starting, working, waiting on input, or building its draft.
+fn is_watchable_state(state: &GoalRunState) -> bool {
+    matches!(
+        state,
+        GoalRunState::Created
+            | GoalRunState::Configured
+            | GoalRunState::Running
+            | GoalRunState::AwaitingInput { .. }
+            | GoalRunState::Finalizing { .. }
+            | GoalRunState::DraftPending { .. }
+    )
+}
+
+/// `ta goal status --watch`: redraw the goal status and its recent audit
+/// events until the goal leaves the running phase.
+fn watch_status(
+    store: &GoalRunStore,
+    config: &GatewayConfig,
+    id: &str,
+    interval: u64,
+) -> anyhow::Result<()> {
+    let goal_run_id = resolve_goal_id(id, store)?;
+    let goal_id_str = goal_run_id.to_string();
+    let poll_interval = std::time::Duration::from_secs(interval.max(1));
+
+    loop {
+        let goal = store
+            .get(goal_run_id)?
+            .ok_or_else(|| anyhow::anyhow!("Goal run not found: {}", id))?;
+
+        print!("\x1B[2J\x1B[H");
+        println!(
+            "Watching goal {} — refreshed {} (every {}s, Ctrl-C to stop)\n",
+            goal.shortref(),
+            chrono::Local::now().format("%H:%M:%S"),
+            poll_interval.as_secs()
+        );
+        show_status(store, config, &goal_id_str, false, false, false)?;
+
+        println!("\n--- Recent audit events ---");
+        let events = if config.audit_log.exists() {
+            super::audit::events_for_goal(&config.audit_log, &goal_id_str)?
+        } else {
+            Vec::new()
+        };
+        if events.is_empty() {
+            println!("(none yet)");
+        }
+        for event in events
+            .iter()
+            .skip(events.len().saturating_sub(WATCH_RECENT_EVENTS))
+        {
+            println!(
+                "{}  {:<16} {}",
+                event.timestamp.format("%H:%M:%S"),
+                format!("{:?}", event.action),
+                event
+                    .tool_name
+                    .as_deref()
+                    .or(event.target_uri.as_deref())
+                    .unwrap_or("-"),
+            );
+        }
+
+        if !is_watchable_state(&goal.state) {
+            println!("\nGoal is {} — stopping watch.", goal.state);
+            return Ok(());
+        }
+        std::thread::sleep(poll_interval);
+    }
+}
+
 fn delete_goal(
     store: &GoalRunStore,
     config: &GatewayConfig,
@@ -1933,9 +2560,9 @@ fn verify_constitution(
 
     // Rule 1.5: Audit chain — audit log must exist and be non-empty.
     println!("  Rule 1.5 (Append-Only Audit):");
-    let audit_log = config.workspace_root.join(".ta/audit.jsonl");
+    let audit_log = &config.audit_log;
     if audit_log.exists() {
-        let content = std::fs::read_to_string(&audit_log).unwrap_or_default();
+        let content = std::fs::read_to_string(audit_log).unwrap_or_default();
         let entries = content.lines().filter(|l| !l.trim().is_empty()).count();
         if entries > 0 {
             println!("    [OK]   Audit log exists with {} entries", entries);
@@ -1945,7 +2572,7 @@ fn verify_constitution(
             fail_count += 1;
         }
     } else {
-        println!("    [INFO] No audit log found at .ta/audit.jsonl");
+        println!("    [INFO] No audit log found at {}", audit_log.display());
         pass_count += 1;
     }
 
@@ -1992,7 +2619,7 @@ fn verify_constitution(
 
     // v0.14.7.2: TRACE-1 — Every staging dir in .ta/staging/ has a goal record.
     println!("  TRACE-1 (§5.6 — Orphaned Staging Dirs):");
-    let staging_root = config.workspace_root.join(".ta").join("staging");
+    let staging_root = config.staging_dir.clone();
     if staging_root.exists() {
         let all_goals = goal_store.list().unwrap_or_default();
         let known_staging: std::collections::HashSet<std::path::PathBuf> =
@@ -2312,10 +2939,7 @@ fn purge_goals(
                 );
             }
             if let Some(pkg_id) = goal.pr_package_id {
-                let pkg_path = config
-                    .workspace_root
-                    .join(".ta/pr_packages")
-                    .join(format!("{}.json", pkg_id));
+                let pkg_path = config.pr_packages_dir.join(format!("{}.json", pkg_id));
                 if pkg_path.exists() {
                     println!("           draft: {}", short_id);
                 }
@@ -2337,10 +2961,7 @@ fn purge_goals(
 
         // Remove associated draft package.
         if let Some(pkg_id) = goal.pr_package_id {
-            let pkg_path = config
-                .workspace_root
-                .join(".ta/pr_packages")
-                .join(format!("{}.json", pkg_id));
+            let pkg_path = config.pr_packages_dir.join(format!("{}.json", pkg_id));
             if pkg_path.exists() {
                 if let Err(e) = std::fs::remove_file(&pkg_path) {
                     eprintln!(
@@ -2473,8 +3094,8 @@ fn write_gc_audit_entry(config: &GatewayConfig, goal: &GoalRun, gc_reason: &str)
     }
 }
 
-/// Approximate directory size in bytes (non-recursive for speed — counts immediate files).
-fn dir_size_bytes(path: &std::path::Path) -> u64 {
+/// Total size in bytes of the files under `path`.
+pub(crate) fn dir_size_bytes(path: &std::path::Path) -> u64 {
     walkdir(path)
 }
 
@@ -2494,7 +3115,7 @@ fn walkdir(path: &std::path::Path) -> u64 {
     total
 }
 
-fn format_bytes(bytes: u64) -> String {
+pub(crate) fn format_bytes(bytes: u64) -> String {
     if bytes >= 1_073_741_824 {
         format!("{:.1} GB", bytes as f64 / 1_073_741_824.0)
     } else if bytes >= 1_048_576 {
@@ -2936,7 +3557,7 @@ fn goal_post_mortem(config: &GatewayConfig, store: &GoalRunStore, id: &str) -> a
 
     if goal.state == GoalRunState::Running {
         let idle_minutes = chrono::Utc::now()
-            .signed_duration_since(goal.updated_at)
+            .signed_duration_since(goal.last_activity())
             .num_minutes();
         if idle_minutes > 60 {
             causes.push(format!(
@@ -3489,7 +4110,7 @@ fn _old_doctor_impl(config: &GatewayConfig) -> anyhow::Result<()> {
     // (a) Stale staging dirs: subdirs older than 7 days with no active goal.
     {
         print!("  GC: stale staging dirs... ");
-        let staging_dir = config.workspace_root.join(".ta").join("staging");
+        let staging_dir = config.staging_dir.clone();
         if staging_dir.exists() {
             let seven_days_secs: u64 = 7 * 24 * 3600;
             let now_secs = std::time::SystemTime::now()
@@ -3806,6 +4427,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -3822,6 +4444,39 @@ mod tests {
         assert!(goals[0].workspace_path.join("src/main.rs").exists());
     }
 
+    #[test]
+    fn start_goal_records_timeout_and_startup_sweep_fails_idle_goal() {
+        let project = TempDir::new().unwrap();
+        std::fs::write(project.path().join("README.md"), "# Test\n").unwrap();
+
+        let config = GatewayConfig::for_project(project.path());
+        let store = GoalRunStore::new(&config.goals_dir).unwrap();
+
+        start_goal(
+            &config,
+            &store,
+            "Stuck goal",
+            Some(project.path()),
+            "",
+            "test-agent",
+            None,
+            None,
+            None,
+            Some(60),
+        )
+        .unwrap();
+
+        let mut goal = store.list().unwrap().remove(0);
+        assert_eq!(goal.timeout_secs, Some(60));
+
+        goal.updated_at = chrono::Utc::now() - chrono::Duration::seconds(120);
+        store.save(&goal).unwrap();
+        check_timed_out_goals(&config);
+
+        let goal = store.get(goal.goal_run_id).unwrap().unwrap();
+        assert!(matches!(goal.state, GoalRunState::Failed { ref reason } if reason == "timed out"));
+    }
+
     // ── v0.4.1.2 tests: follow-up draft continuity ──
 
     #[test]
@@ -3843,6 +4498,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -3894,6 +4550,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -3928,6 +4585,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -3966,6 +4624,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -4008,6 +4667,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -4024,6 +4684,52 @@ mod tests {
         assert!(matches!(g.state, GoalRunState::Failed { .. }));
     }
 
+    #[test]
+    fn clone_goal_copies_settings_into_fresh_goal() {
+        let project = TempDir::new().unwrap();
+        std::fs::write(project.path().join("README.md"), "# Test\n").unwrap();
+
+        let config = GatewayConfig::for_project(project.path());
+        let store = GoalRunStore::new(&config.goals_dir).unwrap();
+
+        let original_id = start_goal(
+            &config,
+            &store,
+            "Redo me",
+            Some(project.path()),
+            "A long objective nobody wants to retype",
+            "test-agent",
+            Some("v0.1"),
+            None,
+            None,
+            None,
+        )
+        .unwrap();
+        let original = store.get(original_id).unwrap().unwrap();
+
+        let clone_id = clone_goal(
+            &config,
+            &store,
+            &original.goal_run_id.to_string(),
+            false,
+            None,
+        )
+        .unwrap();
+        let clone = store.get(clone_id).unwrap().unwrap();
+
+        assert_ne!(clone.goal_run_id, original.goal_run_id);
+        assert_ne!(clone.manifest_id, original.manifest_id);
+        assert_ne!(clone.workspace_path, original.workspace_path);
+        assert!(clone.workspace_path.join("README.md").exists());
+        assert_eq!(clone.title, original.title);
+        assert_eq!(clone.objective, original.objective);
+        assert_eq!(clone.plan_phase.as_deref(), Some("v0.1"));
+        assert_eq!(clone.source_dir, original.source_dir);
+        assert_eq!(clone.agent_id, "test-agent");
+        assert_eq!(clone.cloned_from, Some(original.goal_run_id));
+        assert!(clone.parent_goal_id.is_none());
+    }
+
     #[test]
     fn resolve_goal_id_by_prefix() {
         let temp = TempDir::new().unwrap();
@@ -4039,6 +4745,7 @@ mod tests {
                 phase: None,
                 follow_up: None,
                 objective_file: None,
+                timeout: None,
             },
             &config,
         )
@@ -4056,11 +4763,45 @@ mod tests {
         let resolved = resolve_goal_id(prefix, &store).unwrap();
         assert_eq!(resolved, goal_id);
 
-        // Short prefix rejected.
+        // 4-char prefix resolves; shorter is rejected.
+        let resolved = resolve_goal_id(&goal_id.to_string()[..4], &store).unwrap();
+        assert_eq!(resolved, goal_id);
         let result = resolve_goal_id("abc", &store);
         assert!(result.is_err());
     }
 
+    #[test]
+    fn resolve_goal_id_lists_candidates_when_ambiguous() {
+        let temp = TempDir::new().unwrap();
+        let store = GoalRunStore::new(temp.path().join("goals")).unwrap();
+        for (id, title) in [
+            ("abcd1111-0000-4000-8000-000000000000", "First"),
+            ("abcd2222-0000-4000-8000-000000000000", "Second"),
+        ] {
+            let mut goal = ta_goal::GoalRun::new(
+                title,
+                "objective",
+                "test-agent",
+                temp.path().join("staging"),
+                temp.path().join("store"),
+            );
+            goal.goal_run_id = Uuid::parse_str(id).unwrap();
+            store.save(&goal).unwrap();
+        }
+
+        let err = resolve_goal_id("ABCD", &store).unwrap_err().to_string();
+        assert!(err.contains("matches 2 goals"), "{}", err);
+        assert!(
+            err.contains("abcd1111-0000-4000-8000-000000000000  First"),
+            "{}",
+            err
+        );
+        assert!(err.contains("Second"), "{}", err);
+
+        let resolved = resolve_goal_id("abcd2", &store).unwrap();
+        assert_eq!(resolved.to_string(), "abcd2222-0000-4000-8000-000000000000");
+    }
+
     // ── v0.11.3 tests: inspect, post-mortem, pre-flight, doctor ──
 
     #[test]
@@ -4074,6 +4815,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn watch_stops_on_terminal_states() {
+        assert!(is_watchable_state(&GoalRunState::Running));
+        assert!(is_watchable_state(&GoalRunState::DraftPending {
+            pending_since: chrono::Utc::now(),
+            exit_code: 0,
+        }));
+        assert!(!is_watchable_state(&GoalRunState::PrReady));
+        assert!(!is_watchable_state(&GoalRunState::Applied));
+        assert!(!is_watchable_state(&GoalRunState::Failed {
+            reason: "boom".into(),
+        }));
+    }
+
     #[test]
     fn format_bytes_display() {
         assert_eq!(format_bytes(0), "0 B");
@@ -4126,6 +4881,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -4157,6 +4913,7 @@ mod tests {
             None,
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -4166,6 +4923,72 @@ mod tests {
         goal_post_mortem(&config, &store, &id).unwrap();
     }
 
+    #[test]
+    fn goal_policy_lists_grants_and_limits() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = GatewayConfig::for_project(dir.path());
+        let mut goal = ta_goal::GoalRun::new(
+            "Test",
+            "obj",
+            "test-agent",
+            dir.path().to_path_buf(),
+            dir.path().join("store"),
+        );
+        let mut policy = ta_policy::PolicyDocument::default();
+        policy.schemes.insert(
+            "fs".to_string(),
+            ta_policy::SchemePolicy {
+                max_actions_per_session: Some(50),
+                ..Default::default()
+            },
+        );
+
+        let text = format_goal_policy(&config, &goal, &policy).unwrap();
+        assert!(text.contains(&goal.manifest_id.to_string()), "{}", text);
+        assert!(
+            text.contains("reconstructed from the default developer profile"),
+            "{}",
+            text
+        );
+        assert!(text.contains("no issued manifest is on record"), "{}", text);
+        assert!(text.contains("fs.write_patch"), "{}", text);
+        assert!(
+            text.contains("requires approval, max 50 actions per session"),
+            "{}",
+            text
+        );
+        assert!(text.contains("Forbidden: network_external"), "{}", text);
+        assert!(text.contains("default deny"), "{}", text);
+
+        goal.readonly = true;
+        let text = format_goal_policy(&config, &goal, &policy).unwrap();
+        assert!(text.contains("read-only profile"), "{}", text);
+        assert!(!text.contains("fs.write_patch"), "{}", text);
+
+        // A recorded manifest is shown as issued, even when it differs from
+        // what the profile would compile to.
+        let issued = ta_policy::CapabilityManifest {
+            manifest_id: goal.manifest_id,
+            agent_id: goal.agent_id.clone(),
+            grants: vec![ta_policy::CapabilityGrant {
+                tool: "fs".to_string(),
+                verb: "read".to_string(),
+                resource_pattern: "fs://workspace/docs/**".to_string(),
+            }],
+            issued_at: goal.created_at,
+            expires_at: goal.created_at + chrono::Duration::hours(8),
+            includes: vec![],
+        };
+        ta_policy::ManifestStore::new(config.issued_manifests_dir())
+            .save(&issued)
+            .unwrap();
+        let text = format_goal_policy(&config, &goal, &policy).unwrap();
+        assert!(text.contains("(as issued)"), "{}", text);
+        assert!(!text.contains("reconstructed"), "{}", text);
+        assert!(text.contains("fs://workspace/docs/**"), "{}", text);
+        assert!(!text.contains("fs.list"), "{}", text);
+    }
+
     #[test]
     fn read_recent_events_empty_when_no_events() {
         let dir = tempfile::tempdir().unwrap();
@@ -4239,6 +5062,7 @@ mod tests {
             Some("v0.99.2"),
             None,
             None,
+            None,
         )
         .unwrap();
 
@@ -4259,4 +5083,24 @@ mod tests {
             plan_after
         );
     }
+
+    #[test]
+    fn staging_options_reads_workflow_toml_in_source_dir() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(
+            staging_options(dir.path()),
+            (ta_workspace::OverlayStagingMode::Full, true)
+        );
+
+        std::fs::create_dir_all(dir.path().join(".ta")).unwrap();
+        std::fs::write(
+            dir.path().join(".ta/workflow.toml"),
+            "[staging]\nstrategy = \"smart\"\n\n[workspace]\nparallel = false\n",
+        )
+        .unwrap();
+        assert_eq!(
+            staging_options(dir.path()),
+            (ta_workspace::OverlayStagingMode::Smart, false)
+        );
+    }
 }
@@ -225,10 +225,13 @@ fn run_init(
         Some(v.to_string())
     } else if interactive {
         let has_git = project_root.join(".git").exists();
+        let has_hg = project_root.join(".hg").exists();
         let has_p4 = project_root.join(".p4config").exists();
         let has_svn = project_root.join(".svn").exists();
         let detected = if has_git {
             "git"
+        } else if has_hg {
+            "hg"
         } else if has_p4 {
             "perforce"
         } else if has_svn {
@@ -236,7 +239,7 @@ fn run_init(
         } else {
             "none"
         };
-        let vcs_ans = prompt("VCS backend (git/perforce/none)", detected);
+        let vcs_ans = prompt("VCS backend (git/hg/perforce/none)", detected);
         Some(vcs_ans)
     } else {
         None
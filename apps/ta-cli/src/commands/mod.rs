@@ -14,6 +14,7 @@ pub mod conversation;
 pub mod credentials;
 pub mod daemon;
 pub mod dev;
+pub mod diff_handlers;
 pub mod doctor;
 pub mod draft;
 pub mod email_manager;
@@ -58,3 +59,4 @@ pub mod verify;
 pub mod version_guard;
 pub mod webhook;
 pub mod workflow;
+pub mod workspace;
@@ -895,6 +895,10 @@ fn run_new(
         None,  // existing_goal_id = None
         None,  // workflow = default (single-agent)
         None,  // persona_name = None
+        None,  // env_file
+        None,  // agent_command
+        &[],   // context_files
+        None,  // timeout
     )?;
 
     // 12. Post-creation handoff.
@@ -908,9 +912,9 @@ fn run_new(
 /// Validate a VCS adapter name.
 fn validate_vcs(vcs: &str) -> anyhow::Result<()> {
     match vcs {
-        "git" | "svn" | "perforce" | "none" => Ok(()),
+        "git" | "hg" | "svn" | "perforce" | "none" => Ok(()),
         other => anyhow::bail!(
-            "Unknown VCS adapter: '{}'. Available: git, svn, perforce, none",
+            "Unknown VCS adapter: '{}'. Available: git, hg, svn, perforce, none",
             other
         ),
     }
@@ -936,7 +940,7 @@ fn prompt_vcs() -> anyhow::Result<String> {
         other => {
             // Accept raw adapter names too.
             match other {
-                "git" | "svn" | "perforce" | "none" => other,
+                "git" | "hg" | "svn" | "perforce" | "none" => other,
                 _ => {
                     eprintln!("Unknown choice '{}', defaulting to none.", other);
                     "none"
@@ -984,6 +988,15 @@ fn setup_vcs(project_dir: &Path, vcs_name: &str, ta_dir: &Path) -> anyhow::Resul
             write_vcs_workflow_config(ta_dir, "git")?;
             Ok(Some("git".to_string()))
         }
+        "hg" => {
+            write_vcs_workflow_config(ta_dir, "hg")?;
+            if project_dir.join(".hg").exists() {
+                println!("  VCS: hg (Mercurial repository already initialized)");
+            } else {
+                println!("  VCS: hg (run `hg init` or `hg clone` to create the repository)");
+            }
+            Ok(Some("hg".to_string()))
+        }
         "svn" => {
             write_vcs_workflow_config(ta_dir, "svn")?;
             println!("  VCS: svn (run `svn checkout` to connect to your repository)");
@@ -1028,6 +1041,18 @@ branch_prefix = "feature/"
 target_branch = "main"
 merge_strategy = "squash"
 remote = "origin"
+"#
+        }
+        "hg" => {
+            r#"
+[submit]
+adapter = "hg"
+auto_submit = true
+auto_review = false
+
+[submit.hg]
+bookmark_prefix = "ta/"
+remote = "default"
 "#
         }
         "svn" => {
@@ -1683,6 +1708,7 @@ mod tests {
     #[test]
     fn valid_vcs_names() {
         assert!(validate_vcs("git").is_ok());
+        assert!(validate_vcs("hg").is_ok());
         assert!(validate_vcs("svn").is_ok());
         assert!(validate_vcs("perforce").is_ok());
         assert!(validate_vcs("none").is_ok());
@@ -1793,6 +1819,19 @@ mod tests {
         assert!(content.contains("[submit.git]"));
     }
 
+    #[test]
+    fn write_vcs_workflow_config_hg_parses_as_submit_config() {
+        let dir = TempDir::new().unwrap();
+        let ta_dir = dir.path().join(".ta");
+        std::fs::create_dir_all(&ta_dir).unwrap();
+
+        write_vcs_workflow_config(&ta_dir, "hg").unwrap();
+
+        let wf = ta_submit::WorkflowConfig::load_or_default(&ta_dir.join("workflow.toml"));
+        assert_eq!(wf.submit.adapter, "hg");
+        assert_eq!(wf.submit.hg.remote, "default");
+    }
+
     #[test]
     fn write_vcs_workflow_config_skips_if_submit_exists() {
         let dir = TempDir::new().unwrap();
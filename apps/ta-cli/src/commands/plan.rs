@@ -50,12 +50,20 @@ pub enum PlanCommands {
         check_versions: bool,
     },
     /// Show the next pending phase and suggest creating a goal for it.
+    ///
+    /// With `--start`, immediately runs `ta run` for that phase instead.
     Next {
         /// Only consider phases whose ID starts with this prefix (e.g. `--filter v0.15`).
         /// Phases not matching are skipped as if they don't exist.
         /// When no matching pending phase is found, emits the same "all complete" signal.
         #[arg(long)]
         filter: Option<String>,
+        /// Start a goal for the next pending phase (equivalent to running the suggested command).
+        #[arg(long)]
+        start: bool,
+        /// Agent system to use with `--start` (default: claude-code).
+        #[arg(long, default_value = "claude-code", requires = "start")]
+        agent: String,
     },
     /// Show plan change history (status transitions recorded in .ta/plan_history.jsonl).
     History,
@@ -439,7 +447,17 @@ pub fn execute(cmd: &PlanCommands, config: &GatewayConfig) -> anyhow::Result<()>
             }
             result
         }
-        PlanCommands::Next { filter } => show_next(config, filter.as_deref()),
+        PlanCommands::Next {
+            filter,
+            start,
+            agent,
+        } => {
+            if *start {
+                start_next(config, filter.as_deref(), agent)
+            } else {
+                show_next(config, filter.as_deref())
+            }
+        }
         PlanCommands::History => show_history(config),
         PlanCommands::Validate { phase } => validate_phase(config, phase),
         PlanCommands::Init { source, yes } => plan_init(config, source, *yes),
@@ -1815,7 +1833,14 @@ pub fn check_version_sync(phases: &[PlanPhase]) -> Option<String> {
     }
 }
 
-fn show_next(config: &GatewayConfig, filter: Option<&str>) -> anyhow::Result<()> {
+/// The next pending phase in `PLAN.md`, optionally restricted to IDs with `filter` as prefix.
+///
+/// The search starts after the current in_progress phase (if any) so a phase
+/// that is already claimed is never suggested again.
+fn next_pending_phase(
+    config: &GatewayConfig,
+    filter: Option<&str>,
+) -> anyhow::Result<Option<PlanPhase>> {
     let phases = load_plan(&config.workspace_root)?;
 
     // Apply prefix filter when provided — only consider matching phases.
@@ -1828,19 +1853,18 @@ fn show_next(config: &GatewayConfig, filter: Option<&str>) -> anyhow::Result<()>
         phases
     };
 
-    // Find next pending. Start the search after the current in_progress phase
-    // (if any) so we don't re-suggest a phase that is already claimed.
     let after_current = find_in_progress(&filtered).map(|p| p.id.as_str());
+    Ok(find_next_pending(&filtered, after_current).cloned())
+}
 
-    let next = find_next_pending(&filtered, after_current);
-
-    match next {
+fn show_next(config: &GatewayConfig, filter: Option<&str>) -> anyhow::Result<()> {
+    match next_pending_phase(config, filter)? {
         Some(phase) => {
             println!("Next pending phase:");
             println!("  Phase {} — {}", phase.id, phase.title);
             println!();
             println!("To start working on it:");
-            println!("  {}", suggest_next_goal_command(phase));
+            println!("  {}", suggest_next_goal_command(&phase));
         }
         None => {
             println!("All plan phases are complete or in progress.");
@@ -1850,6 +1874,45 @@ fn show_next(config: &GatewayConfig, filter: Option<&str>) -> anyhow::Result<()>
     Ok(())
 }
 
+/// `ta plan next --start`: run the goal that `ta plan next` would suggest.
+fn start_next(config: &GatewayConfig, filter: Option<&str>, agent: &str) -> anyhow::Result<()> {
+    let Some(phase) = next_pending_phase(config, filter)? else {
+        println!("All plan phases are complete or in progress.");
+        return Ok(());
+    };
+
+    // Same title/objective as `suggest_next_goal_command`.
+    let goal_title = format!("implement {}", phase.title);
+    println!("Starting goal for phase {} — {}...", phase.id, phase.title);
+
+    super::run::execute(
+        config,
+        Some(&goal_title),
+        agent,
+        None, // source
+        &goal_title,
+        Some(&phase.id),
+        None,  // follow_up
+        None,  // follow_up_draft
+        None,  // follow_up_goal
+        None,  // objective_file
+        false, // no_launch
+        true,  // interactive
+        false, // macro_goal
+        None,  // resume
+        false, // headless
+        false, // skip_verify
+        false, // quiet
+        None,  // existing_goal_id
+        None,  // workflow
+        None,  // persona_name
+        None,  // env_file
+        None,  // agent_command
+        &[],   // context_files
+        None,  // timeout
+    )
+}
+
 fn show_history(config: &GatewayConfig) -> anyhow::Result<()> {
     let entries = load_history(&config.workspace_root)?;
 
@@ -2433,6 +2496,10 @@ fn plan_add(
         None,  // existing_goal_id = None
         None,  // workflow = default (single-agent)
         None,  // persona_name = None
+        None,  // env_file
+        None,  // agent_command
+        &[],   // context_files
+        None,  // timeout
     )
 }
 
@@ -2685,6 +2752,10 @@ fn plan_from(
         None,  // existing_goal_id = None
         None,  // workflow = default (single-agent)
         None,  // persona_name = None
+        None,  // env_file
+        None,  // agent_command
+        &[],   // context_files
+        None,  // timeout
     )
 }
 
@@ -2795,6 +2866,10 @@ fn plan_new(
         None,  // existing_goal_id
         None,  // workflow
         None,  // persona_name
+        None,  // env_file
+        None,  // agent_command
+        &[],   // context_files
+        None,  // timeout
     )
 }
 
@@ -4918,6 +4993,10 @@ fn plan_build(
             None,  // existing_goal_id
             None,  // workflow
             None,  // persona_name
+            None,  // env_file
+            None,  // agent_command
+            &[],   // context_files
+            None,  // timeout
         )?;
 
         phases_built += 1;
@@ -5690,6 +5769,31 @@ Build it.
         assert_eq!(phases[1].status, PlanStatus::Pending);
     }
 
+    #[test]
+    fn next_pending_phase_skips_claimed_and_honours_filter() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(
+            dir.path().join("PLAN.md"),
+            "# Plan\n\n\
+             ### v0.1.0 — Setup\n<!-- status: done -->\n\n\
+             ### v0.1.1 — Parser\n<!-- status: in_progress -->\n\n\
+             ### v0.1.2 — Checker\n<!-- status: pending -->\n\n\
+             ### v0.2.0 — Release\n<!-- status: pending -->\n",
+        )
+        .unwrap();
+        let config = GatewayConfig::for_project(dir.path());
+
+        let next = next_pending_phase(&config, None).unwrap().unwrap();
+        assert_eq!(next.id, "v0.1.2");
+        assert_eq!(
+            suggest_next_goal_command(&next),
+            "ta run \"implement Checker\" --phase v0.1.2"
+        );
+        let next = next_pending_phase(&config, Some("v0.2")).unwrap().unwrap();
+        assert_eq!(next.id, "v0.2.0");
+        assert!(next_pending_phase(&config, Some("v0.3")).unwrap().is_none());
+    }
+
     #[test]
     fn parse_plan_with_invalid_regex_returns_empty() {
         let schema = PlanSchema {
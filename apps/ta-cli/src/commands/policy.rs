@@ -1,9 +1,13 @@
 // policy.rs — Policy management CLI commands (v0.9.8.1).
 
+use chrono::{DateTime, Utc};
 use clap::Subcommand;
+use ta_audit::{AuditAction, AuditEvent, AuditLog};
 use ta_changeset::draft_package::DraftPackage;
+use ta_goal::GoalRunStore;
 use ta_mcp_gateway::GatewayConfig;
 use ta_policy::auto_approve::{self, DraftInfo};
+use ta_policy::{EvaluationStep, PolicyDecision};
 use uuid::Uuid;
 
 #[derive(Subcommand)]
@@ -14,13 +18,105 @@ pub enum PolicyCommands {
         draft_id: String,
     },
     /// Show the resolved policy document for a project.
-    Show,
+    Show {
+        /// Also show each capability manifest in `.ta/manifests/` with its
+        /// includes resolved and grants flattened.
+        #[arg(long)]
+        resolve: bool,
+    },
+    /// Compile an alignment profile into capability grants without
+    /// installing anything.
+    ///
+    /// PROFILE is a YAML file holding an alignment profile, or an agent config
+    /// with an `alignment:` block. With `--diff`, compares the result against
+    /// the installed manifest `.ta/manifests/<agent>.yaml`.
+    Compile {
+        /// Path to the alignment profile or agent config.
+        profile: std::path::PathBuf,
+        /// Agent whose installed manifest to compare against (defaults to the
+        /// profile's file name).
+        #[arg(long)]
+        agent: Option<String>,
+        /// Print added, removed, and changed grants instead of the full list.
+        #[arg(long)]
+        diff: bool,
+        /// Write the compiled grants to `.ta/manifests/<agent>.yaml`, the
+        /// manifest the gateway issues to the agent at goal start.
+        #[arg(long)]
+        install: bool,
+    },
+    /// Reconstruct the timeline of policy decisions made during a goal.
+    ///
+    /// Denials and approval gates are always recorded. Allowed calls and
+    /// per-step evaluation traces appear only when `[audit] policy_trace`
+    /// is enabled in `.ta/workflow.toml`.
+    Trace {
+        /// Goal ID (full UUID, 8+ character prefix, or tag).
+        goal_id: String,
+        /// Print each evaluation step under its decision.
+        #[arg(long)]
+        verbose: bool,
+        /// Emit the timeline as JSON.
+        #[arg(long)]
+        json: bool,
+    },
+    /// Explain what a capability manifest allows, offline.
+    ///
+    /// Reads a manifest written by `ta goal export-manifest` and lists its
+    /// grants and validity window. With `--action` and `--target`, evaluates
+    /// one request against it as of the time it was issued.
+    Explain {
+        /// Manifest JSON file.
+        #[arg(long)]
+        manifest: std::path::PathBuf,
+        /// Action to evaluate, as `tool.verb` (e.g., `fs.write_patch`).
+        #[arg(long, requires = "target")]
+        action: Option<String>,
+        /// Target URI to evaluate the action on (e.g., `fs://workspace/src/main.rs`).
+        #[arg(long, requires = "action")]
+        target: Option<String>,
+    },
 }
 
 pub fn execute(cmd: &PolicyCommands, config: &GatewayConfig) -> anyhow::Result<()> {
     match cmd {
         PolicyCommands::Check { draft_id } => check_draft(config, draft_id),
-        PolicyCommands::Show => show_policy(config),
+        PolicyCommands::Show { resolve } => {
+            show_policy(config)?;
+            if *resolve {
+                show_resolved_manifests(config)?;
+            }
+            Ok(())
+        }
+        PolicyCommands::Compile {
+            profile,
+            agent,
+            diff,
+            install,
+        } => compile_profile(config, profile, agent.as_deref(), *diff, *install),
+        PolicyCommands::Trace {
+            goal_id,
+            verbose,
+            json,
+        } => trace_goal(config, goal_id, *verbose, *json),
+        PolicyCommands::Explain {
+            manifest,
+            action,
+            target,
+        } => {
+            let content = std::fs::read_to_string(manifest).map_err(|e| {
+                anyhow::anyhow!("Cannot read manifest {}: {}", manifest.display(), e)
+            })?;
+            let manifest: ta_policy::CapabilityManifest =
+                serde_json::from_str(&content).map_err(|e| {
+                    anyhow::anyhow!("{} is not a capability manifest: {}", manifest.display(), e)
+                })?;
+            print!("{}", explain_manifest(&manifest));
+            if let (Some(action), Some(target)) = (action, target) {
+                print!("{}", explain_request(&manifest, action, target)?);
+            }
+            Ok(())
+        }
     }
 }
 
@@ -197,50 +293,647 @@ fn show_policy(config: &GatewayConfig) -> anyhow::Result<()> {
     Ok(())
 }
 
-fn find_draft_package(config: &GatewayConfig, prefix: &str) -> anyhow::Result<DraftPackage> {
-    let dir = &config.pr_packages_dir;
-    if !dir.exists() {
-        anyhow::bail!("No drafts directory found at {}", dir.display());
+/// Print every manifest file in `.ta/manifests/` after include resolution.
+/// Manifests that fail to compile are reported and skipped.
+fn show_resolved_manifests(config: &GatewayConfig) -> anyhow::Result<()> {
+    let manifests_dir = config.installed_manifests_dir();
+    let mut paths: Vec<_> = match std::fs::read_dir(&manifests_dir) {
+        Ok(entries) => entries
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| {
+                p.extension()
+                    .is_some_and(|ext| ext == "yaml" || ext == "yml" || ext == "json")
+            })
+            .collect(),
+        Err(_) => Vec::new(),
+    };
+    if paths.is_empty() {
+        println!("No capability manifests in {}.", manifests_dir.display());
+        return Ok(());
     }
+    paths.sort();
 
-    let entries = std::fs::read_dir(dir)?;
-    let mut matches = Vec::new();
-
-    for entry in entries.flatten() {
-        let path = entry.path();
-        if path.extension().is_some_and(|ext| ext == "json") {
-            let filename = path
-                .file_stem()
-                .map(|s| s.to_string_lossy().to_string())
-                .unwrap_or_default();
-            if filename.starts_with(prefix) || prefix.len() >= 8 && filename.contains(prefix) {
-                if let Ok(content) = std::fs::read_to_string(&path) {
-                    if let Ok(pkg) = serde_json::from_str::<DraftPackage>(&content) {
-                        matches.push(pkg);
-                    }
+    let options = ta_policy::CompilerOptions::default();
+    for path in paths {
+        println!("# {}", path.display());
+        match ta_policy::PolicyCompiler::compile_manifest_file(&path, &manifests_dir, &options) {
+            Ok(manifest) => {
+                println!("agent_id: {}", manifest.agent_id);
+                if !manifest.includes.is_empty() {
+                    println!("includes: [{}]", manifest.includes.join(", "));
                 }
+                println!("grants:");
+                println!("{}", serde_yaml::to_string(&manifest.grants)?);
             }
+            Err(e) => println!("error: {}\n", e),
         }
     }
+    Ok(())
+}
+
+/// Read an alignment profile from a bare profile file or an agent config's
+/// `alignment:` block.
+fn load_alignment_profile(path: &std::path::Path) -> anyhow::Result<ta_policy::AlignmentProfile> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read profile {}: {}", path.display(), e))?;
+    let value: serde_yaml::Value = serde_yaml::from_str(&content)?;
+    let profile = value.get("alignment").cloned().unwrap_or(value);
+    serde_yaml::from_value(profile).map_err(|e| {
+        anyhow::anyhow!(
+            "{} is not an alignment profile or agent config with an `alignment:` block: {}",
+            path.display(),
+            e
+        )
+    })
+}
+
+fn compile_profile(
+    config: &GatewayConfig,
+    profile_path: &std::path::Path,
+    agent: Option<&str>,
+    diff: bool,
+    install: bool,
+) -> anyhow::Result<()> {
+    let profile = load_alignment_profile(profile_path)?;
+    let agent_id = match agent {
+        Some(a) => a.to_string(),
+        None => profile_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+    };
+    let options = ta_policy::CompilerOptions::default();
+    let compiled = ta_policy::PolicyCompiler::compile(&agent_id, &profile, &options)?;
+
+    let manifests_dir = config.installed_manifests_dir();
+    let installed_path = manifests_dir.join(format!("{}.yaml", agent_id));
+    if diff {
+        let installed = if installed_path.exists() {
+            ta_policy::PolicyCompiler::compile_manifest_file(
+                &installed_path,
+                &manifests_dir,
+                &options,
+            )?
+            .grants
+        } else {
+            println!(
+                "No installed manifest at {} — every grant is new.",
+                installed_path.display()
+            );
+            Vec::new()
+        };
+
+        let changes = ta_policy::diff_grants(&installed, &compiled.grants);
+        if changes.is_empty() {
+            println!(
+                "No changes: {} grants the same capabilities as {}.",
+                profile_path.display(),
+                installed_path.display()
+            );
+        } else {
+            print!("{}", render_grant_changes(&changes));
+        }
+    } else if !install {
+        println!("# Compiled grants for '{}' (not installed)", agent_id);
+        println!("{}", serde_yaml::to_string(&compiled.grants)?);
+    }
+
+    if install {
+        install_manifest(&installed_path, &agent_id, &compiled.grants)?;
+        println!(
+            "Installed {} grant(s) for '{}' at {}. Goals this agent starts through the gateway are issued this manifest.",
+            compiled.grants.len(),
+            agent_id,
+            installed_path.display()
+        );
+    }
+    Ok(())
+}
 
-    // Also try parsing as UUID and looking for exact match.
-    if matches.is_empty() {
-        if let Ok(uuid) = Uuid::parse_str(prefix) {
-            let path = dir.join(format!("{}.json", uuid));
-            if path.exists() {
-                let content = std::fs::read_to_string(&path)?;
-                matches.push(serde_json::from_str::<DraftPackage>(&content)?);
+/// Write `grants` as the installed manifest file for `agent_id`.
+fn install_manifest(
+    path: &std::path::Path,
+    agent_id: &str,
+    grants: &[ta_policy::CapabilityGrant],
+) -> anyhow::Result<()> {
+    #[derive(serde::Serialize)]
+    struct InstalledManifest<'a> {
+        agent_id: &'a str,
+        grants: &'a [ta_policy::CapabilityGrant],
+    }
+
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let yaml = serde_yaml::to_string(&InstalledManifest { agent_id, grants })?;
+    std::fs::write(path, yaml)
+        .map_err(|e| anyhow::anyhow!("Cannot write {}: {}", path.display(), e))
+}
+
+/// One line per changed capability: `+` added, `-` removed, `~` changed scope.
+fn render_grant_changes(changes: &[ta_policy::GrantChange]) -> String {
+    use ta_policy::GrantChange;
+
+    let mut out = String::new();
+    let (mut added, mut removed, mut changed) = (0, 0, 0);
+    for change in changes {
+        match change {
+            GrantChange::Added {
+                tool,
+                verb,
+                patterns,
+            } => {
+                added += 1;
+                out.push_str(&format!("+ {} {}  {}\n", tool, verb, patterns.join(", ")));
+            }
+            GrantChange::Removed {
+                tool,
+                verb,
+                patterns,
+            } => {
+                removed += 1;
+                out.push_str(&format!("- {} {}  {}\n", tool, verb, patterns.join(", ")));
+            }
+            GrantChange::Changed {
+                tool,
+                verb,
+                old,
+                new,
+            } => {
+                changed += 1;
+                out.push_str(&format!(
+                    "~ {} {}  {} -> {}\n",
+                    tool,
+                    verb,
+                    old.join(", "),
+                    new.join(", ")
+                ));
+            }
+        }
+    }
+    out.push_str(&format!(
+        "\n{} added, {} removed, {} changed.\n",
+        added, removed, changed
+    ));
+    out
+}
+
+/// One gateway policy decision reconstructed from the audit log.
+#[derive(Debug, serde::Serialize)]
+struct PolicyTraceEntry {
+    timestamp: DateTime<Utc>,
+    agent_id: String,
+    tool: String,
+    verb: String,
+    target: String,
+    decision: PolicyDecision,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    matching_grant: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    steps: Vec<EvaluationStep>,
+}
+
+/// Pull the policy decisions recorded for `goal_id` out of an audit stream,
+/// oldest first. Entries whose metadata cannot be parsed are skipped.
+fn collect_policy_trace(
+    events: impl IntoIterator<Item = AuditEvent>,
+    goal_id: Uuid,
+) -> Vec<PolicyTraceEntry> {
+    events
+        .into_iter()
+        .filter(|e| e.action == AuditAction::PolicyDecision && e.goal_run_id == Some(goal_id))
+        .filter_map(|e| {
+            let decision: PolicyDecision =
+                serde_json::from_value(e.metadata.get("decision")?.clone()).ok()?;
+            let trace = e.metadata.get("trace");
+            let matching_grant = trace
+                .and_then(|t| t.get("matching_grant"))
+                .and_then(|g| g.as_str())
+                .map(String::from);
+            let steps = trace
+                .and_then(|t| t.get("steps"))
+                .and_then(|s| serde_json::from_value(s.clone()).ok())
+                .unwrap_or_default();
+            let field = |key: &str| {
+                e.metadata
+                    .get(key)
+                    .and_then(|v| v.as_str())
+                    .unwrap_or("?")
+                    .to_string()
+            };
+            Some(PolicyTraceEntry {
+                timestamp: e.timestamp,
+                tool: field("tool"),
+                verb: field("verb"),
+                target: e.target_uri.clone().unwrap_or_default(),
+                agent_id: e.agent_id,
+                decision,
+                matching_grant,
+                steps,
+            })
+        })
+        .collect()
+}
+
+fn trace_goal(
+    config: &GatewayConfig,
+    goal_id: &str,
+    verbose: bool,
+    json: bool,
+) -> anyhow::Result<()> {
+    let store = GoalRunStore::new(&config.goals_dir)?;
+    let goal_id = super::goal::resolve_goal_id(goal_id, &store)?;
+    let entries = collect_policy_trace(
+        AuditLog::iter_segments(&config.audit_log)?.filter_map(Result::ok),
+        goal_id,
+    );
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&entries)?);
+        return Ok(());
+    }
+
+    let title = store
+        .get(goal_id)?
+        .map(|g| g.title)
+        .unwrap_or_else(|| "(goal record not found)".to_string());
+    println!(
+        "Policy trace for goal {} — \"{}\"",
+        &goal_id.to_string()[..8],
+        title
+    );
+    println!();
+
+    if entries.is_empty() {
+        println!("No policy decisions recorded for this goal.");
+    }
+
+    let (mut allowed, mut gated, mut denied) = (0usize, 0usize, 0usize);
+    for entry in &entries {
+        let label = match &entry.decision {
+            PolicyDecision::Allow => {
+                allowed += 1;
+                "ALLOW"
+            }
+            PolicyDecision::RequireApproval { .. } => {
+                gated += 1;
+                "GATED"
+            }
+            PolicyDecision::Deny { .. } => {
+                denied += 1;
+                "DENY"
+            }
+        };
+        println!(
+            "  {}  {:<5}  {}.{}  {}",
+            entry.timestamp.format("%H:%M:%S"),
+            label,
+            entry.tool,
+            entry.verb,
+            entry.target
+        );
+        if let (Some(code), Some(reason)) = (entry.decision.reason_code(), entry.decision.reason())
+        {
+            println!("              [{}] {}", code, reason);
+        } else if let Some(grant) = &entry.matching_grant {
+            println!("              grant: {}", grant);
+        }
+        if verbose {
+            for step in &entry.steps {
+                println!("                - {}: {}", step.check, step.outcome);
             }
         }
     }
 
-    match matches.len() {
-        0 => anyhow::bail!("No draft found matching '{}'", prefix),
-        1 => Ok(matches.into_iter().next().unwrap()),
-        n => anyhow::bail!(
-            "Ambiguous prefix '{}' matches {} drafts. Use a longer prefix.",
-            prefix,
-            n
-        ),
+    println!();
+    println!(
+        "{} decision(s): {} allowed, {} gated, {} denied",
+        entries.len(),
+        allowed,
+        gated,
+        denied
+    );
+    if allowed == 0 && entries.iter().all(|e| e.steps.is_empty()) {
+        println!(
+            "Only denials and approval gates were recorded. Set `policy_trace = true` under \
+             [audit] in .ta/workflow.toml to capture every decision with its trace."
+        );
+    }
+    Ok(())
+}
+
+/// `ta policy explain`: what a manifest allows, grant by grant.
+fn explain_manifest(manifest: &ta_policy::CapabilityManifest) -> String {
+    let mut out = format!(
+        "Manifest {} for agent {}\n",
+        manifest.manifest_id, manifest.agent_id
+    );
+    out.push_str(&format!(
+        "Valid:    {} to {}{}\n",
+        manifest.issued_at.to_rfc3339(),
+        manifest.expires_at.to_rfc3339(),
+        if manifest.is_expired() {
+            " (expired)"
+        } else {
+            ""
+        }
+    ));
+    if !manifest.includes.is_empty() {
+        out.push_str(&format!("Includes: {}\n", manifest.includes.join(", ")));
+    }
+    out.push_str(&format!("Grants ({}):\n", manifest.grants.len()));
+    for grant in &manifest.grants {
+        let action = format!("{}.{}", grant.tool, grant.verb);
+        if ta_policy::engine::APPROVAL_REQUIRED_VERBS.contains(&grant.verb.as_str()) {
+            out.push_str(&format!(
+                "  {:<16} {}  (requires human approval)\n",
+                action, grant.resource_pattern
+            ));
+        } else {
+            out.push_str(&format!("  {:<16} {}\n", action, grant.resource_pattern));
+        }
+    }
+    out.push_str(
+        "Anything not granted above is denied (default deny). Project policy \
+         (.ta/policy.yaml) can add approval gates and limits on top.\n",
+    );
+    out
+}
+
+/// Evaluate one `tool.verb` on `target` against `manifest` while it was
+/// valid, so an expired manifest from a finished goal still answers
+/// "could the agent have done this?".
+fn explain_request(
+    manifest: &ta_policy::CapabilityManifest,
+    action: &str,
+    target: &str,
+) -> anyhow::Result<String> {
+    let (tool, verb) = action
+        .split_once('.')
+        .filter(|(tool, verb)| !tool.is_empty() && !verb.is_empty())
+        .ok_or_else(|| anyhow::anyhow!("Invalid action '{}': expected tool.verb", action))?;
+    let mut as_issued = manifest.clone();
+    as_issued.expires_at = chrono::DateTime::<Utc>::MAX_UTC;
+    let mut engine = ta_policy::PolicyEngine::new();
+    engine.load_manifest(as_issued);
+    let trace = engine.evaluate_with_trace(&ta_policy::PolicyRequest {
+        agent_id: manifest.agent_id.clone(),
+        tool: tool.to_string(),
+        verb: verb.to_string(),
+        target_uri: target.to_string(),
+    });
+
+    let label = match &trace.decision {
+        PolicyDecision::Allow => "ALLOW",
+        PolicyDecision::RequireApproval { .. } => "GATED (requires human approval)",
+        PolicyDecision::Deny { .. } => "DENY",
+    };
+    let mut out = format!("\n{} {}: {}\n", action, target, label);
+    if let Some(grant) = &trace.matching_grant {
+        out.push_str(&format!("  grant: {}\n", grant));
+    }
+    if let (Some(code), Some(reason)) = (trace.decision.reason_code(), trace.decision.reason()) {
+        out.push_str(&format!("  [{}] {}\n", code, reason));
+    }
+    for step in &trace.steps {
+        out.push_str(&format!("  - {}: {}\n", step.check, step.outcome));
+    }
+    Ok(out)
+}
+
+fn find_draft_package(config: &GatewayConfig, prefix: &str) -> anyhow::Result<DraftPackage> {
+    let id = super::draft::resolve_draft_id_flexible(config, Some(prefix))?;
+    super::draft::load_package(config, Uuid::parse_str(&id)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ta_policy::PolicyReasonCode;
+
+    fn decision_event(goal: Uuid, decision: &PolicyDecision, trace: bool) -> AuditEvent {
+        let mut metadata = serde_json::json!({
+            "tool": "fs",
+            "verb": "read",
+            "decision": decision,
+        });
+        if trace {
+            metadata["trace"] = serde_json::json!({
+                "decision": decision,
+                "steps": [{"check": "grant_match", "outcome": "matched", "terminal": true}],
+                "grants_checked": ["fs.read on fs://workspace/**"],
+                "matching_grant": "fs.read on fs://workspace/**",
+            });
+        }
+        AuditEvent::new("agent-1", AuditAction::PolicyDecision)
+            .with_target("fs://workspace/src/lib.rs")
+            .with_goal_run_id(goal)
+            .with_metadata(metadata)
+    }
+
+    #[test]
+    fn load_alignment_profile_accepts_agent_config() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let profile = "principal: owner\n\
+                       autonomy_envelope:\n\
+                       \x20 bounded_actions: [fs_read, fs_write_patch]\n";
+        let bare = dir.path().join("bare.yaml");
+        std::fs::write(&bare, profile).unwrap();
+        let agent = dir.path().join("codex.yaml");
+        std::fs::write(
+            &agent,
+            format!(
+                "command: codex\nalignment:\n{}",
+                profile
+                    .lines()
+                    .map(|l| format!("  {}\n", l))
+                    .collect::<String>()
+            ),
+        )
+        .unwrap();
+
+        assert_eq!(
+            load_alignment_profile(&bare).unwrap(),
+            load_alignment_profile(&agent).unwrap()
+        );
+        std::fs::write(dir.path().join("junk.yaml"), "command: codex\n").unwrap();
+        assert!(load_alignment_profile(&dir.path().join("junk.yaml")).is_err());
+    }
+
+    #[test]
+    fn compile_diff_against_installed_manifest() {
+        let project = tempfile::TempDir::new().unwrap();
+        let config = GatewayConfig::for_project(project.path());
+        let manifests = project.path().join(".ta/manifests");
+        std::fs::create_dir_all(&manifests).unwrap();
+        std::fs::write(
+            manifests.join("coder.yaml"),
+            "grants:\n- { tool: fs, verb: read, resource_pattern: 'fs://workspace/**' }\n",
+        )
+        .unwrap();
+        let profile = project.path().join("coder.yaml");
+        std::fs::write(
+            &profile,
+            "principal: owner\nautonomy_envelope:\n  bounded_actions: [fs_read, fs_apply]\n",
+        )
+        .unwrap();
+        compile_profile(&config, &profile, None, true, false).unwrap();
+
+        let installed = vec![];
+        let compiled = ta_policy::PolicyCompiler::compile(
+            "coder",
+            &load_alignment_profile(&profile).unwrap(),
+            &ta_policy::CompilerOptions::default(),
+        )
+        .unwrap();
+        let rendered = render_grant_changes(&ta_policy::diff_grants(&installed, &compiled.grants));
+        assert!(rendered.contains("+ fs apply  fs://workspace/**"));
+        assert!(rendered.ends_with("2 added, 0 removed, 0 changed.\n"));
+    }
+
+    #[test]
+    fn compile_install_writes_the_manifest_the_gateway_issues() {
+        let project = tempfile::TempDir::new().unwrap();
+        let config = GatewayConfig::for_project(project.path());
+        let profile = project.path().join("coder.yaml");
+        std::fs::write(
+            &profile,
+            "principal: owner\nautonomy_envelope:\n  bounded_actions: [fs_read, fs_apply]\n",
+        )
+        .unwrap();
+        compile_profile(&config, &profile, None, false, true).unwrap();
+
+        let options = ta_policy::CompilerOptions::default();
+        let installed = ta_policy::PolicyCompiler::compile_installed_manifest(
+            &config.installed_manifests_dir(),
+            "coder",
+            &options,
+        )
+        .unwrap()
+        .expect("manifest installed");
+        let compiled = ta_policy::PolicyCompiler::compile(
+            "coder",
+            &load_alignment_profile(&profile).unwrap(),
+            &options,
+        )
+        .unwrap();
+        assert_eq!(installed.agent_id, "coder");
+        assert!(ta_policy::diff_grants(&installed.grants, &compiled.grants).is_empty());
+
+        // Agent IDs that are not plain file names never resolve to a file.
+        assert!(ta_policy::PolicyCompiler::compile_installed_manifest(
+            &config.installed_manifests_dir(),
+            "../coder",
+            &options,
+        )
+        .unwrap()
+        .is_none());
+    }
+
+    #[test]
+    fn collect_policy_trace_filters_by_goal_and_keeps_order() {
+        let goal = Uuid::new_v4();
+        let other = Uuid::new_v4();
+        let deny = PolicyDecision::deny(PolicyReasonCode::NoMatchingGrant, "no grant");
+        let events = vec![
+            decision_event(goal, &PolicyDecision::Allow, true),
+            decision_event(other, &deny, false),
+            AuditEvent::new("agent-1", AuditAction::ToolCall).with_goal_run_id(goal),
+            decision_event(goal, &deny, false),
+        ];
+
+        let entries = collect_policy_trace(events, goal);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].decision, PolicyDecision::Allow);
+        assert_eq!(
+            entries[0].matching_grant.as_deref(),
+            Some("fs.read on fs://workspace/**")
+        );
+        assert_eq!(entries[0].steps.len(), 1);
+        assert_eq!(entries[0].tool, "fs");
+        assert_eq!(
+            entries[1].decision.reason_code(),
+            Some(PolicyReasonCode::NoMatchingGrant)
+        );
+        assert!(entries[1].steps.is_empty());
+    }
+
+    #[test]
+    fn exported_goal_manifest_explains_offline_after_expiry() {
+        let project = tempfile::TempDir::new().unwrap();
+        let config = GatewayConfig::for_project(project.path());
+        let path = project.path().join("manifest.json");
+        let export = |goal_id: uuid::Uuid| {
+            super::super::goal::execute(
+                &super::super::goal::GoalCommands::ExportManifest {
+                    id: goal_id.to_string(),
+                    output: Some(path.clone()),
+                },
+                &config,
+            )
+        };
+
+        // A goal with no manifest on record has nothing to export.
+        super::super::goal::execute(
+            &super::super::goal::GoalCommands::Start {
+                title: "Manifest export".to_string(),
+                source: Some(project.path().to_path_buf()),
+                objective: "Audit grants".to_string(),
+                agent: "test-agent".to_string(),
+                phase: None,
+                follow_up: None,
+                objective_file: None,
+                timeout: None,
+            },
+            &config,
+        )
+        .unwrap();
+        let unissued = GoalRunStore::new(&config.goals_dir)
+            .unwrap()
+            .list()
+            .unwrap()
+            .remove(0);
+        let err = export(unissued.goal_run_id).unwrap_err().to_string();
+        assert!(err.contains("No issued manifest"), "{}", err);
+        assert!(!path.exists());
+
+        // A goal started through the gateway exports the manifest it was issued.
+        let goal = ta_mcp_gateway::GatewayState::new(config.clone())
+            .unwrap()
+            .start_goal("Manifest export", "Audit grants", "test-agent")
+            .unwrap();
+        export(goal.goal_run_id).unwrap();
+
+        let mut manifest: ta_policy::CapabilityManifest =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        let issued = ta_policy::ManifestStore::new(config.issued_manifests_dir())
+            .load(goal.manifest_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(manifest.manifest_id, goal.manifest_id);
+        assert_eq!(manifest.agent_id, "test-agent");
+        assert_eq!(manifest.issued_at, issued.issued_at);
+        assert_eq!(manifest.grants, issued.grants);
+
+        // A long-finished goal: the manifest has expired, grants still explain.
+        manifest.issued_at -= chrono::Duration::days(2);
+        manifest.expires_at = manifest.issued_at + chrono::Duration::hours(8);
+        let text = explain_manifest(&manifest);
+        assert!(text.contains("(expired)"), "{}", text);
+        assert!(text.contains("fs.apply"), "{}", text);
+        assert!(text.contains("(requires human approval)"), "{}", text);
+
+        let target = "fs://workspace/src/main.rs";
+        let read = explain_request(&manifest, "fs.read", target).unwrap();
+        assert!(
+            read.contains("fs.read fs://workspace/src/main.rs: ALLOW"),
+            "{}",
+            read
+        );
+        let apply = explain_request(&manifest, "fs.apply", target).unwrap();
+        assert!(apply.contains(": GATED"), "{}", apply);
+        let net = explain_request(&manifest, "net.fetch", "https://example.com").unwrap();
+        assert!(net.contains(": DENY"), "{}", net);
+        assert!(explain_request(&manifest, "read", target).is_err());
     }
 }
@@ -47,7 +47,7 @@ pub enum PrCommands {
         /// Detail level: top (one-line), medium (with explanations), full (with diffs).
         #[arg(long, default_value = "medium")]
         detail: String,
-        /// Output format: terminal (default), markdown, json, html.
+        /// Output format: terminal (default), markdown, json, html, sarif.
         #[arg(long, default_value = "terminal")]
         format: String,
         /// Enable ANSI color output (terminal format only). Default: off.
@@ -730,6 +730,8 @@ fn to_draft_command(cmd: &PrCommands) -> draft::DraftCommands {
             summary: summary.clone(),
             latest: *latest,
             apply_context_file: None,
+            tests: None,
+            base_ref: None,
         },
         PrCommands::List { goal } => draft::DraftCommands::List {
             goal: goal.clone(),
@@ -739,6 +741,9 @@ fn to_draft_command(cmd: &PrCommands) -> draft::DraftCommands {
             limit: None,
             all: true,
             json: false,
+            kind: vec![],
+            status: vec![],
+            sort: None,
         },
         PrCommands::View {
             id,
@@ -758,12 +763,25 @@ fn to_draft_command(cmd: &PrCommands) -> draft::DraftCommands {
             color: *color,
             json: false,
             section: None,
+            kind: vec![],
+            order: "as-is".to_string(),
+            since: None,
+            undecided_only: false,
+            schema: false,
+            no_redact: false,
+            diff_context: None,
+            compact: false,
+            blame: false,
+            watch: false,
+            interval: 2,
         },
         PrCommands::Approve { id, reviewer } => draft::DraftCommands::Approve {
             id: Some(id.clone()),
             reviewer: reviewer.clone(),
             reviewer_as: None,
             force_override: false,
+            expire: None,
+            assigned: false,
         },
         PrCommands::Deny {
             id,
@@ -803,6 +821,7 @@ fn to_draft_command(cmd: &PrCommands) -> draft::DraftCommands {
             approve_patterns: approve_patterns.clone(),
             reject_patterns: reject_patterns.clone(),
             discuss_patterns: discuss_patterns.clone(),
+            defer_patterns: vec![],
             skip_verify: false,
             phase: None,
             require_review: false,
@@ -813,6 +832,11 @@ fn to_draft_command(cmd: &PrCommands) -> draft::DraftCommands {
             status: false,
             auto_repair: false,
             skip_plan_merge: false,
+            no_plan_update: false,
+            backup: false,
+            interactive: false,
+            interactive_per_file: false,
+            target_subdir: vec![],
         },
         // Checks and Fix are handled before reaching this function.
         PrCommands::Checks { .. } | PrCommands::Fix { .. } => {
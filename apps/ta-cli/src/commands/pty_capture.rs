@@ -156,15 +156,26 @@ impl PtySession {
         working_dir: &Path,
         env_vars: &std::collections::HashMap<String, String>,
     ) -> io::Result<Self> {
-        Self::spawn_with_sink(command, args, working_dir, env_vars, Arc::new(TerminalSink))
+        Self::spawn_with_sink(
+            command,
+            args,
+            working_dir,
+            env_vars,
+            false,
+            Arc::new(TerminalSink),
+        )
     }
 
     /// Spawn with a custom output sink for pluggable output routing.
+    ///
+    /// When `clear_env` is set the child starts from an empty environment
+    /// holding only `env_vars`.
     pub fn spawn_with_sink(
         command: &str,
         args: &[String],
         working_dir: &Path,
         env_vars: &std::collections::HashMap<String, String>,
+        clear_env: bool,
         output_sink: Arc<dyn OutputSink>,
     ) -> io::Result<Self> {
         let (master_owned, slave_owned) = open_pty()?;
@@ -237,6 +248,11 @@ impl PtySession {
                 .collect();
 
             // Set environment variables.
+            if clear_env {
+                for (key, _) in std::env::vars_os() {
+                    std::env::remove_var(key);
+                }
+            }
             for (key, value) in env_vars {
                 std::env::set_var(key, value);
             }
@@ -386,9 +402,14 @@ pub struct PtyLaunchConfig<'a> {
     pub args: Vec<String>,
     pub working_dir: &'a Path,
     pub env_vars: &'a std::collections::HashMap<String, String>,
+    /// Start the agent with only `env_vars` instead of the inherited environment.
+    pub clear_env: bool,
     /// Optional output sink. Defaults to `TerminalSink` (stdout) if `None`.
     /// Provide a custom sink to route output to Slack, email, webhook, etc.
     pub output_sink: Option<Arc<dyn OutputSink>>,
+    /// Called with the child's PID right after spawn, so callers can record it
+    /// for liveness checks while the session runs.
+    pub pid_callback: Option<&'a dyn Fn(u32)>,
 }
 
 /// Result of an interactive PTY session, including captured output.
@@ -428,8 +449,12 @@ pub fn run_interactive_pty(config: PtyLaunchConfig<'_>) -> io::Result<PtySession
         &config.args,
         config.working_dir,
         config.env_vars,
+        config.clear_env,
         sink,
     )?;
+    if let Some(cb) = config.pid_callback {
+        cb(pty.child_pid() as u32);
+    }
 
     let mut captured_output: Vec<CapturedChunk> = Vec::new();
     let mut human_inputs: Vec<CapturedInput> = Vec::new();
@@ -718,7 +743,9 @@ mod tests {
             args: vec!["interactive test".to_string()],
             working_dir: &dir,
             env_vars: &env,
+            clear_env: false,
             output_sink: None,
+            pid_callback: None,
         })
         .expect("run should succeed");
 
@@ -746,6 +773,7 @@ mod tests {
             &["sink test".to_string()],
             &dir,
             &env,
+            false,
             sink.clone(),
         )
         .expect("spawn should succeed");
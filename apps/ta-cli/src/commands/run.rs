@@ -10,12 +10,12 @@
 
 use std::cmp::Reverse;
 use std::io::IsTerminal;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 #[cfg(unix)]
 use ta_changeset::{InteractionKind, InteractionRequest, InteractionResponse, Urgency};
 use ta_changeset::{InteractiveSession, InteractiveSessionState, InteractiveSessionStore};
-use ta_goal::GoalRunStore;
+use ta_goal::{GoalRun, GoalRunState, GoalRunStore};
 use ta_mcp_gateway::GatewayConfig;
 
 use super::plan;
@@ -69,9 +69,8 @@ struct AgentLaunchConfig {
     interactive: Option<ta_changeset::InteractiveConfig>,
     /// Agent alignment profile (v0.4.0).
     /// Compiled into CapabilityManifest grants by the Policy Compiler.
-    /// Read via YAML deserialization; will be used by gateway during goal start.
+    /// `coordination.handoff` is run after the draft is built.
     #[serde(default)]
-    #[allow(dead_code)]
     alignment: Option<ta_policy::AlignmentProfile>,
     /// Extra args appended in headless mode (v0.10.18.4).
     /// E.g., `["--output-format", "stream-json"]` for Claude Code.
@@ -113,6 +112,11 @@ struct AgentLaunchConfig {
     /// When `true`, goals with no state update for `stale_threshold_secs` emit `GoalStale`.
     #[serde(default)]
     heartbeat_required: bool,
+
+    /// Start the agent from an empty environment holding only `env` (set by
+    /// `ta run --env-file`, never from YAML).
+    #[serde(skip)]
+    clear_env: bool,
 }
 
 /// Auto-answer configuration for interactive prompts (v0.10.18.5).
@@ -221,6 +225,7 @@ fn builtin_agent_config(agent_id: &str) -> AgentLaunchConfig {
             runtime: Default::default(),
             // Claude Code does not send heartbeats — disable stale checking (v0.13.14).
             heartbeat_required: false,
+            clear_env: false,
         },
         "codex" => AgentLaunchConfig {
             command: "codex".to_string(),
@@ -244,6 +249,7 @@ fn builtin_agent_config(agent_id: &str) -> AgentLaunchConfig {
             context_file: None,
             runtime: Default::default(),
             heartbeat_required: false,
+            clear_env: false,
         },
         "claude-flow" => AgentLaunchConfig {
             command: "npx".to_string(),
@@ -300,6 +306,7 @@ fn builtin_agent_config(agent_id: &str) -> AgentLaunchConfig {
             context_file: None,
             runtime: Default::default(),
             heartbeat_required: false,
+            clear_env: false,
         },
         _ => AgentLaunchConfig {
             command: agent_id.to_string(),
@@ -319,6 +326,7 @@ fn builtin_agent_config(agent_id: &str) -> AgentLaunchConfig {
             context_file: None,
             runtime: Default::default(),
             heartbeat_required: false,
+            clear_env: false,
         },
     }
 }
@@ -352,9 +360,42 @@ fn framework_to_launch_config(manifest: &ta_runtime::AgentFrameworkManifest) ->
         context_file: None,
         runtime: Default::default(),
         heartbeat_required: false,
+        clear_env: false,
     }
 }
 
+/// Build a launch config for `ta run --agent-command "<binary> [args]"`.
+///
+/// The command runs in the staging workspace like any other agent. TA injects
+/// the goal context into the framework context file and `.ta/agent_context.md`,
+/// but passes no prompt argument unless the command contains `{prompt}`.
+fn agent_command_launch_config(command_line: &str) -> anyhow::Result<AgentLaunchConfig> {
+    let parts: Vec<&str> = command_line.split_whitespace().collect();
+    let (command, args) = parts
+        .split_first()
+        .ok_or_else(|| anyhow::anyhow!("--agent-command must not be empty"))?;
+    Ok(AgentLaunchConfig {
+        command: command.to_string(),
+        args_template: args.iter().map(|a| a.to_string()).collect(),
+        injects_context_file: true,
+        injects_settings: false,
+        pre_launch: None,
+        env: Default::default(),
+        shell: None,
+        name: Some(command.to_string()),
+        description: Some("Custom agent command".to_string()),
+        interactive: None,
+        alignment: None,
+        headless_args: Vec::new(),
+        non_interactive_env: Default::default(),
+        auto_answers: Vec::new(),
+        context_file: Some(".ta/agent_context.md".to_string()),
+        runtime: Default::default(),
+        heartbeat_required: false,
+        clear_env: false,
+    })
+}
+
 // ── Smart Follow-Up Resolution (v0.10.9) ────────────────────────
 
 /// Resolve smart follow-up flags into concrete title, phase, follow-up ID, and context.
@@ -987,6 +1028,918 @@ pub fn execute_swarm(
     Ok(())
 }
 
+// ── Plan-all: drive every pending PLAN.md phase ──────────────────
+
+/// Progress of `ta run --plan-all`, persisted to `.ta/plan-all.json` so an
+/// interrupted or paused run resumes at the phase it was on.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct PlanAllState {
+    agent: String,
+    auto_apply: bool,
+    /// The phase currently being run, reviewed, or applied.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    active: Option<PlanAllPhase>,
+    /// Phases finished by this run, in order.
+    #[serde(default)]
+    completed: Vec<String>,
+    started_at: chrono::DateTime<chrono::Utc>,
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+struct PlanAllPhase {
+    phase: String,
+    /// When the agent was last launched for this phase; goals created
+    /// earlier belong to previous attempts.
+    launched_at: chrono::DateTime<chrono::Utc>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    goal_id: Option<uuid::Uuid>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    draft_id: Option<uuid::Uuid>,
+}
+
+impl PlanAllState {
+    fn path(config: &GatewayConfig) -> std::path::PathBuf {
+        config.plan_all_state_path()
+    }
+
+    fn load(config: &GatewayConfig) -> Option<Self> {
+        let content = std::fs::read_to_string(Self::path(config)).ok()?;
+        serde_json::from_str(&content).ok()
+    }
+
+    fn save(&mut self, config: &GatewayConfig) -> anyhow::Result<()> {
+        self.updated_at = chrono::Utc::now();
+        let path = Self::path(config);
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// What `ta run --plan-all` does next.
+#[derive(Debug)]
+enum PlanAllStep {
+    /// No pending phases remain.
+    Complete,
+    /// Launch the agent for the active phase (first attempt, or a retry of an
+    /// attempt that produced no draft).
+    Launch,
+    /// The active phase's draft is waiting for review.
+    AwaitReview(Box<ta_changeset::DraftPackage>),
+    /// The active phase's draft is approved; apply it.
+    Apply(uuid::Uuid),
+    /// The active phase's draft was denied, closed, or superseded.
+    Stopped(Box<ta_changeset::DraftPackage>),
+    /// The active phase's draft was applied, but its push or review failed.
+    SubmitIncomplete(Box<ta_changeset::DraftPackage>),
+}
+
+/// Decide the next plan-all step, advancing past applied drafts and claiming
+/// the next pending phase when nothing is active.
+fn next_plan_all_step(
+    config: &GatewayConfig,
+    state: &mut PlanAllState,
+) -> anyhow::Result<PlanAllStep> {
+    use ta_changeset::DraftStatus;
+
+    loop {
+        let Some(active) = state.active.as_mut() else {
+            let phases = plan::load_plan(&config.workspace_root)?;
+            let Some(next) = plan::find_next_pending(&phases, None) else {
+                return Ok(PlanAllStep::Complete);
+            };
+            state.active = Some(PlanAllPhase {
+                phase: next.id.clone(),
+                launched_at: chrono::Utc::now(),
+                goal_id: None,
+                draft_id: None,
+            });
+            return Ok(PlanAllStep::Launch);
+        };
+
+        // Pick up the goal and draft the last launch produced.
+        if active.goal_id.is_none() {
+            active.goal_id = GoalRunStore::new(&config.goals_dir)?
+                .list()?
+                .into_iter()
+                .find(|g| {
+                    g.created_at >= active.launched_at
+                        && g.plan_phase
+                            .as_deref()
+                            .is_some_and(|p| plan::phase_ids_match(p, &active.phase))
+                })
+                .map(|g| g.goal_run_id);
+        }
+        if active.draft_id.is_none() {
+            if let Some(goal_id) = active.goal_id {
+                let goal_id = goal_id.to_string();
+                active.draft_id = super::draft::load_all_packages(config)?
+                    .into_iter()
+                    .filter(|p| p.goal.goal_id == goal_id)
+                    .max_by_key(|p| p.created_at)
+                    .map(|p| p.package_id);
+            }
+        }
+        let Some(draft_id) = active.draft_id else {
+            // Finished (or deferred) by hand since the last launch: move on.
+            let phases = plan::load_plan(&config.workspace_root)?;
+            let settled = phases.iter().any(|p| {
+                plan::phase_ids_match(&p.id, &active.phase)
+                    && matches!(
+                        p.status,
+                        plan::PlanStatus::Done | plan::PlanStatus::Deferred
+                    )
+            });
+            if settled {
+                state.active = None;
+                continue;
+            }
+            return Ok(PlanAllStep::Launch);
+        };
+
+        let pkg = super::draft::load_package(config, draft_id)?;
+        match pkg.status {
+            DraftStatus::Applied { .. }
+                if pkg.submit_outcome.as_ref().is_some_and(|o| o.is_partial()) =>
+            {
+                return Ok(PlanAllStep::SubmitIncomplete(Box::new(pkg)))
+            }
+            DraftStatus::Applied { .. } => {
+                state.completed.push(active.phase.clone());
+                state.active = None;
+            }
+            DraftStatus::Approved { .. } => return Ok(PlanAllStep::Apply(draft_id)),
+            DraftStatus::Draft | DraftStatus::PendingReview => {
+                return Ok(PlanAllStep::AwaitReview(Box::new(pkg)))
+            }
+            DraftStatus::Denied { .. }
+            | DraftStatus::Closed { .. }
+            | DraftStatus::Superseded { .. } => return Ok(PlanAllStep::Stopped(Box::new(pkg))),
+        }
+    }
+}
+
+/// Whether the project's auto-approve policy (`.ta/policy.yaml`) lets this
+/// draft through without a human. Returns the blockers when it does not.
+fn plan_all_auto_approval(
+    config: &GatewayConfig,
+    pkg: &ta_changeset::DraftPackage,
+) -> Result<(), Vec<String>> {
+    let policy_path = config.workspace_root.join(".ta/policy.yaml");
+    let doc: ta_policy::PolicyDocument = std::fs::read_to_string(&policy_path)
+        .ok()
+        .and_then(|content| serde_yaml::from_str(&content).ok())
+        .unwrap_or_default();
+    let draft_info = ta_policy::DraftInfo {
+        changed_paths: pkg
+            .changes
+            .artifacts
+            .iter()
+            .map(|a| a.resource_uri.clone())
+            .collect(),
+        lines_changed: 0, // approximate — not available from artifacts
+        plan_phase: pkg.plan_phase.clone(),
+        agent_id: pkg.agent_identity.agent_id.clone(),
+    };
+    match ta_policy::should_auto_approve_draft(&draft_info, &doc) {
+        ta_policy::AutoApproveDecision::Approved { .. } => Ok(()),
+        ta_policy::AutoApproveDecision::Denied { blockers } => Err(blockers),
+    }
+}
+
+/// Run a `ta` subcommand as a child process in the project root.
+fn run_ta_step(config: &GatewayConfig, args: &[&str], what: &str) -> anyhow::Result<()> {
+    let ta_bin = std::env::current_exe()
+        .map_err(|e| anyhow::anyhow!("Could not determine ta binary path: {}", e))?;
+    let status = std::process::Command::new(&ta_bin)
+        .args(args)
+        .current_dir(&config.workspace_root)
+        .status()
+        .map_err(|e| anyhow::anyhow!("Failed to {}: {}", what, e))?;
+    if !status.success() {
+        anyhow::bail!(
+            "Failed to {}: `ta {}` exited with code {}.\n  \
+             Fix the problem, then resume with: ta run --plan-all",
+            what,
+            args.join(" "),
+            status.code().unwrap_or(-1)
+        );
+    }
+    Ok(())
+}
+
+/// Execute every pending PLAN.md phase in order (`ta run --plan-all`).
+///
+/// Each phase runs as a headless goal whose draft is built when the agent
+/// exits. Without `auto_apply` the run pauses at each draft for review; once
+/// it is approved, `ta run --plan-all` applies it and moves on. With
+/// `auto_apply`, drafts that pass the auto-approve policy are approved and
+/// applied without stopping; any other draft pauses as usual. Progress is
+/// kept in `.ta/plan-all.json`, so re-running resumes where it left off.
+pub fn execute_plan_all(
+    config: &GatewayConfig,
+    agent: &str,
+    agent_command: Option<&str>,
+    auto_apply: bool,
+    quiet: bool,
+) -> anyhow::Result<()> {
+    let mut state = match PlanAllState::load(config) {
+        Some(mut saved) => {
+            if let Some(active) = &saved.active {
+                println!("Resuming plan-all at phase {}.", active.phase);
+            }
+            saved.agent = agent.to_string();
+            saved.auto_apply = auto_apply;
+            saved
+        }
+        None => PlanAllState {
+            agent: agent.to_string(),
+            auto_apply,
+            active: None,
+            completed: Vec::new(),
+            started_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        },
+    };
+
+    loop {
+        let step = next_plan_all_step(config, &mut state)?;
+        state.save(config)?;
+        let phase = state
+            .active
+            .as_ref()
+            .map(|a| a.phase.clone())
+            .unwrap_or_default();
+
+        match step {
+            PlanAllStep::Complete => {
+                let _ = std::fs::remove_file(PlanAllState::path(config));
+                println!(
+                    "\nplan-all complete: no pending phases remain ({} applied this run).",
+                    state.completed.len()
+                );
+                return Ok(());
+            }
+            PlanAllStep::Launch => {
+                println!("\n── Phase {} ──────────────────────────────", phase);
+                if let Some(active) = state.active.as_mut() {
+                    active.launched_at = chrono::Utc::now();
+                    active.goal_id = None;
+                    active.draft_id = None;
+                }
+                state.save(config)?;
+                let mut args = vec!["run", "--phase", phase.as_str(), "--agent", agent];
+                if let Some(command) = agent_command {
+                    args.extend(["--agent-command", command]);
+                }
+                args.push("--headless");
+                if quiet {
+                    args.push("--quiet");
+                }
+                run_ta_step(config, &args, &format!("run phase {}", phase))?;
+                // Confirm the run left a draft behind; otherwise stop rather
+                // than relaunching the same phase in a loop.
+                if let PlanAllStep::Launch = next_plan_all_step(config, &mut state)? {
+                    state.save(config)?;
+                    anyhow::bail!(
+                        "Phase {} finished without producing a draft.\n  \
+                         Check `ta goal list`, then resume with: ta run --plan-all",
+                        phase
+                    );
+                }
+            }
+            PlanAllStep::Apply(draft_id) => {
+                println!("Applying approved draft for phase {}...", phase);
+                let id = draft_id.to_string();
+                run_ta_step(
+                    config,
+                    &["draft", "apply", &id],
+                    &format!("apply the draft for phase {}", phase),
+                )?;
+            }
+            PlanAllStep::AwaitReview(pkg) => {
+                let id = ta_changeset::draft_canonical_id(&pkg);
+                if auto_apply {
+                    match plan_all_auto_approval(config, &pkg) {
+                        Ok(()) => {
+                            println!("Draft {} passes the auto-approve policy.", id);
+                            let uuid = pkg.package_id.to_string();
+                            run_ta_step(
+                                config,
+                                &["draft", "approve", &uuid, "--reviewer", "ta-plan-all"],
+                                &format!("approve the draft for phase {}", phase),
+                            )?;
+                            continue;
+                        }
+                        Err(blockers) => {
+                            println!("Draft {} needs human review:", id);
+                            for blocker in &blockers {
+                                println!("  - {}", blocker);
+                            }
+                        }
+                    }
+                }
+                println!("\nPaused: phase {} is waiting for review.", phase);
+                println!("  Review:  ta draft view {}", id);
+                println!("  Approve: ta draft approve {}", id);
+                println!("  Then continue with: ta run --plan-all");
+                return Ok(());
+            }
+            PlanAllStep::Stopped(pkg) => {
+                let id = ta_changeset::draft_canonical_id(&pkg);
+                // Drop the phase so the next run claims whatever is pending —
+                // a denied draft resets its phase to pending, so it is retried.
+                state.active = None;
+                state.save(config)?;
+                anyhow::bail!(
+                    "Draft {} for phase {} is {}; plan-all stopped.\n  \
+                     Re-run `ta run --plan-all` to retry the phase, or mark it \
+                     deferred in PLAN.md to skip it.",
+                    id,
+                    phase,
+                    pkg.status
+                );
+            }
+            PlanAllStep::SubmitIncomplete(pkg) => {
+                let id = ta_changeset::draft_canonical_id(&pkg);
+                // The phase stays active so the next run re-checks the draft.
+                state.save(config)?;
+                anyhow::bail!(
+                    "Draft {} for phase {} was applied, but its submit did not finish; \
+                     plan-all stopped.\n  \
+                     Retry with: ta draft resubmit {}\n  \
+                     Then continue with: ta run --plan-all",
+                    id,
+                    phase,
+                    id
+                );
+            }
+        }
+    }
+}
+
+// ── Adopt an existing work tree ──────────────────────────────────
+
+/// Bring changes made outside TA under review: start a goal against `source`
+/// and make its staging workspace mirror `dir`, so `ta draft build` diffs the
+/// ad-hoc work against source like any agent's output.
+///
+/// `dir` must look like a copy of `source` (see `check_plausible_overlay`).
+/// Excluded paths (build output, `.git/`, `.ta/`) are neither copied nor
+/// deleted. No agent is launched; `ta run --follow-up` continues the work in
+/// the same staging.
+///
+/// With `goal_id`, the work is adopted into a goal recorded by
+/// [`execute_snapshot_only`] instead of a new one, keeping that goal's source
+/// and snapshot as the base for conflict detection.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_resume_staging(
+    config: &GatewayConfig,
+    title: Option<&str>,
+    agent: &str,
+    source: Option<&Path>,
+    objective: &str,
+    phase: Option<&str>,
+    dir: &Path,
+    goal_id: Option<&str>,
+) -> anyhow::Result<()> {
+    let dir = dir
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("Cannot read --resume-staging {}: {}", dir.display(), e))?;
+    if !dir.is_dir() {
+        anyhow::bail!("--resume-staging {} is not a directory", dir.display());
+    }
+    let goal_store = GoalRunStore::new(&config.goals_dir)?;
+    let snapshot_goal = match goal_id {
+        Some(id) => Some(load_snapshot_goal(&goal_store, id)?),
+        None => None,
+    };
+    let source_dir = match (&snapshot_goal, source) {
+        (Some(goal), _) => goal
+            .source_dir
+            .clone()
+            .unwrap_or_else(|| config.workspace_root.clone()),
+        (None, Some(p)) => p.canonicalize()?,
+        (None, None) => config.workspace_root.clone(),
+    };
+    if source_dir.starts_with(&dir) || dir.starts_with(&source_dir) {
+        anyhow::bail!(
+            "--resume-staging {} overlaps the source {}. \
+             Point it at a separate copy of the project.",
+            dir.display(),
+            source_dir.display()
+        );
+    }
+
+    let excludes = super::draft::load_excludes_with_adapter(&source_dir);
+    check_plausible_overlay(&dir, &source_dir, &excludes)?;
+
+    if let Some(mut goal) = snapshot_goal {
+        std::fs::create_dir_all(&goal.workspace_path)?;
+        let (copied, _) = mirror_into_staging(&dir, &goal.workspace_path, &excludes)?;
+        goal.timeout_secs = None;
+        goal.transition(GoalRunState::Configured)?;
+        goal.transition(GoalRunState::Running)?;
+        goal_store.save(&goal)?;
+        println!(
+            "Adopted {} into snapshot goal {}: {} file(s) copied.",
+            dir.display(),
+            goal.goal_run_id,
+            copied
+        );
+        println!("  Conflicts are checked against the source as of the snapshot.");
+        println!("Next steps:");
+        println!("  ta draft build --goal {}", goal.goal_run_id);
+        return Ok(());
+    }
+
+    let dir_name = dir
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| dir.display().to_string());
+    let title = title
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("Adopt changes from {}", dir_name));
+
+    super::goal::execute(
+        &super::goal::GoalCommands::Start {
+            title,
+            source: Some(source_dir),
+            objective: objective.to_string(),
+            agent: agent.to_string(),
+            phase: phase.map(|p| p.to_string()),
+            follow_up: None,
+            objective_file: None,
+            timeout: None,
+        },
+        config,
+    )?;
+    let goal = goal_store
+        .list()?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Failed to find created goal"))?;
+
+    let (copied, removed) = mirror_into_staging(&dir, &goal.workspace_path, &excludes)?;
+    println!();
+    println!(
+        "Adopted {}: {} file(s) copied, {} removed from staging.",
+        dir.display(),
+        copied,
+        removed
+    );
+    println!("Next steps:");
+    println!("  ta draft build --goal {}", goal.goal_run_id);
+    println!(
+        "  ta run --follow-up {}   # let an agent continue the work",
+        &goal.goal_run_id.to_string()[..8]
+    );
+    Ok(())
+}
+
+/// Record a goal and a snapshot of `source` without creating staging or
+/// launching an agent (`ta run --snapshot-only`).
+///
+/// The goal stays `Created` until `ta run --resume-staging DIR --goal-id <id>`
+/// adopts a work tree into it. Apply then detects conflicts against the
+/// source as it was when the snapshot was taken, not when the work arrived.
+pub fn execute_snapshot_only(
+    config: &GatewayConfig,
+    title: &str,
+    agent: &str,
+    source: Option<&Path>,
+    objective: &str,
+    phase: Option<&str>,
+) -> anyhow::Result<()> {
+    let source_dir = match source {
+        Some(p) => p.canonicalize()?,
+        None => config.workspace_root.clone(),
+    };
+    let excludes = super::draft::load_excludes_with_adapter(&source_dir);
+    let snapshot =
+        ta_workspace::SourceSnapshot::capture(&source_dir, |path| excludes.should_skip_path(path))
+            .map_err(|e| anyhow::anyhow!("Cannot snapshot {}: {}", source_dir.display(), e))?;
+
+    let goal_store = GoalRunStore::new(&config.goals_dir)?;
+    let goal_run_id = uuid::Uuid::new_v4();
+    let mut goal = GoalRun::new(
+        title,
+        objective,
+        agent,
+        config.staging_dir.join(goal_run_id.to_string()),
+        config.store_dir.join(goal_run_id.to_string()),
+    );
+    goal.goal_run_id = goal_run_id;
+    goal.source_dir = Some(source_dir.clone());
+    goal.base_ref = ta_workspace::overlay::get_git_head_sha(&source_dir);
+    goal.plan_phase = phase.map(str::to_string);
+    goal.source_snapshot = Some(serde_json::to_value(&snapshot)?);
+    // Waiting for the work is expected; an idle timeout must not fail it.
+    goal.timeout_secs = Some(0);
+    goal_store.save_with_tag(&mut goal)?;
+
+    println!("Snapshot recorded: {}", goal.goal_run_id);
+    println!("  Title:  {}", goal.title);
+    println!(
+        "  Source: {} ({} file(s){})",
+        source_dir.display(),
+        snapshot.file_count(),
+        goal.base_ref
+            .as_deref()
+            .map(|sha| format!(", git {}", &sha[..sha.len().min(8)]))
+            .unwrap_or_default()
+    );
+    println!("No staging was created and no agent was launched.");
+    println!("Bring the work in later with:");
+    println!(
+        "  ta run --resume-staging <DIR> --goal-id {}",
+        &goal.goal_run_id.to_string()[..8]
+    );
+    Ok(())
+}
+
+/// Start a goal whose staging is the source plus an externally produced patch
+/// (`ta run --diff-only`).
+///
+/// The patch is applied with `git apply` semantics: all hunks or none. A patch
+/// that does not apply cleanly removes the goal again and reports the failing
+/// hunk. No agent is launched; `ta draft build` packages the result for review.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_diff_only(
+    config: &GatewayConfig,
+    title: Option<&str>,
+    agent: &str,
+    source: Option<&Path>,
+    objective: &str,
+    phase: Option<&str>,
+    patch: &Path,
+) -> anyhow::Result<()> {
+    let patch = patch
+        .canonicalize()
+        .map_err(|e| anyhow::anyhow!("Cannot read --diff-only {}: {}", patch.display(), e))?;
+    let patch_text = std::fs::read_to_string(&patch)
+        .map_err(|e| anyhow::anyhow!("Cannot read --diff-only {}: {}", patch.display(), e))?;
+    if patch_text.trim().is_empty() {
+        anyhow::bail!("--diff-only {} is empty", patch.display());
+    }
+    let source_dir = match source {
+        Some(p) => p.canonicalize()?,
+        None => config.workspace_root.clone(),
+    };
+    let patch_name = patch
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| patch.display().to_string());
+    let title = title
+        .map(str::to_string)
+        .unwrap_or_else(|| format!("Apply {}", patch_name));
+    let objective = if objective.trim().is_empty() {
+        format!("Review changes from patch {}", patch_name)
+    } else {
+        objective.to_string()
+    };
+
+    let goal_store = GoalRunStore::new(&config.goals_dir)?;
+    super::goal::execute(
+        &super::goal::GoalCommands::Start {
+            title,
+            source: Some(source_dir),
+            objective,
+            agent: agent.to_string(),
+            phase: phase.map(|p| p.to_string()),
+            follow_up: None,
+            objective_file: None,
+            timeout: None,
+        },
+        config,
+    )?;
+    let goal = goal_store
+        .list()?
+        .into_iter()
+        .next()
+        .ok_or_else(|| anyhow::anyhow!("Failed to find created goal"))?;
+
+    let files = match apply_patch_to_staging(&goal.workspace_path, &patch, &patch_text) {
+        Ok(files) => files,
+        Err(e) => {
+            let _ = std::fs::remove_dir_all(&goal.workspace_path);
+            let _ = goal_store.delete(goal.goal_run_id);
+            return Err(e);
+        }
+    };
+    println!();
+    println!(
+        "Applied {} to staging: {} file(s) changed.",
+        patch.display(),
+        files
+    );
+    println!("Next steps:");
+    println!("  ta draft build --goal {}", goal.goal_run_id);
+    Ok(())
+}
+
+/// Apply `patch` inside `staging` and return how many files it touches.
+///
+/// Staging has no `.git`, and repository discovery is capped at its parent,
+/// so `git apply` patches plain files the way GNU patch would, even when
+/// staging sits inside the project's own repository.
+fn apply_patch_to_staging(staging: &Path, patch: &Path, patch_text: &str) -> anyhow::Result<usize> {
+    let ceiling = staging.parent().unwrap_or(staging);
+    let git_apply = |args: &[&str]| {
+        std::process::Command::new("git")
+            .arg("apply")
+            .args(args)
+            .arg(patch)
+            .current_dir(staging)
+            .env_remove("GIT_DIR")
+            .env_remove("GIT_WORK_TREE")
+            .env("GIT_CEILING_DIRECTORIES", ceiling)
+            .output()
+            .map_err(|e| anyhow::anyhow!("Failed to run git apply: {}", e))
+    };
+
+    let numstat = git_apply(&["--numstat"])?;
+    let output = if numstat.status.success() {
+        git_apply(&[])?
+    } else {
+        numstat.clone()
+    };
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let mut message = format!(
+            "Patch {} does not apply cleanly:\n{}",
+            patch.display(),
+            stderr.trim_end()
+        );
+        if let Some(hunk) = stderr
+            .lines()
+            .find_map(|line| failing_hunk(line, patch_text))
+        {
+            message.push_str("\n\nFailing hunk:\n");
+            message.push_str(&hunk);
+        }
+        anyhow::bail!(message);
+    }
+    Ok(String::from_utf8_lossy(&numstat.stdout).lines().count())
+}
+
+/// The hunk named by a `git apply` error line such as
+/// `error: patch failed: src/lib.rs:12`, copied from the patch text.
+fn failing_hunk(error_line: &str, patch_text: &str) -> Option<String> {
+    let location = error_line.trim().strip_prefix("error: patch failed: ")?;
+    let (file, line) = location.rsplit_once(':')?;
+    let start: usize = line.parse().ok()?;
+
+    let mut current_file = None;
+    let mut hunk: Vec<&str> = Vec::new();
+    for text in patch_text.lines() {
+        if !hunk.is_empty() {
+            if text.starts_with("@@") || text.starts_with("diff ") || text.starts_with("--- ") {
+                break;
+            }
+            hunk.push(text);
+            continue;
+        }
+        if let Some(path) = text.strip_prefix("+++ ") {
+            let path = path.split('\t').next().unwrap_or(path);
+            current_file = Some(path.strip_prefix("b/").unwrap_or(path));
+        } else if let Some(range) = text.strip_prefix("@@ -") {
+            let old_start = range
+                .split([',', ' '])
+                .next()
+                .and_then(|n| n.parse::<usize>().ok());
+            if current_file == Some(file) && old_start == Some(start) {
+                hunk.push(text);
+            }
+        }
+    }
+    (!hunk.is_empty()).then(|| hunk.join("\n"))
+}
+
+/// Load the goal `ta run --resume-staging --goal-id` adopts into: one recorded
+/// by `--snapshot-only` that has not received its work yet.
+fn load_snapshot_goal(store: &GoalRunStore, id: &str) -> anyhow::Result<GoalRun> {
+    let goal_id = super::goal::resolve_goal_id(id, store)?;
+    let goal = store
+        .get(goal_id)?
+        .ok_or_else(|| anyhow::anyhow!("Goal {} not found", goal_id))?;
+    if goal.state != GoalRunState::Created || goal.source_snapshot.is_none() {
+        anyhow::bail!(
+            "Goal {} is not a pending snapshot (state: {}). \
+             --resume-staging --goal-id only adopts into goals recorded with \
+             `ta run --snapshot-only`.",
+            &goal_id.to_string()[..8],
+            goal.state
+        );
+    }
+    Ok(goal)
+}
+
+// ── Read-only analysis goals ────────────────────────────────────
+
+/// Staging-relative file a `--readonly` agent writes its report to.
+pub(crate) const READONLY_REPORT_FILE: &str = "TA_REPORT.md";
+
+/// The capability manifest issued to a `--readonly` goal: fs read/list only.
+pub(crate) fn readonly_manifest(goal: &GoalRun) -> anyhow::Result<ta_policy::CapabilityManifest> {
+    ta_policy::PolicyCompiler::compile_with_id(
+        goal.manifest_id,
+        &goal.agent_id,
+        &ta_policy::AlignmentProfile::read_only(),
+        &ta_policy::CompilerOptions::default(),
+    )
+    .map_err(|e| anyhow::anyhow!("policy compilation failed: {}", e))
+}
+
+/// Objective handed to a `--readonly` agent: the user's objective plus the
+/// read-only contract and where the report goes.
+fn readonly_objective(objective: &str) -> String {
+    let mut text = objective.trim().to_string();
+    if !text.is_empty() {
+        text.push_str("\n\n");
+    }
+    text.push_str(&format!(
+        "This is a read-only analysis goal. Do not create, modify, or delete any \
+         project files — writes are denied by policy and will not be packaged. \
+         Write your findings as a markdown report to `{}` in the workspace root; \
+         that report is the deliverable reviewers will see.",
+        READONLY_REPORT_FILE
+    ));
+    text
+}
+
+/// Start an analysis-only goal (`ta run --readonly`).
+///
+/// Creates the goal record flagged read-only, prints the read/list-only
+/// manifest it runs under, then hands off to [`execute`] with the record's
+/// ID, the same way `ta_goal_start` pre-creates goals for `--goal-id`.
+#[allow(clippy::too_many_arguments)]
+pub fn execute_readonly(
+    config: &GatewayConfig,
+    title: &str,
+    agent: &str,
+    source: Option<&Path>,
+    objective: &str,
+    phase: Option<&str>,
+    interactive: bool,
+    headless: bool,
+    quiet: bool,
+    persona_name: Option<&str>,
+    env_file: Option<&Path>,
+    agent_command: Option<&str>,
+    context_files: &[PathBuf],
+    timeout: Option<u64>,
+) -> anyhow::Result<()> {
+    let objective = readonly_objective(objective);
+    let goal_store = GoalRunStore::new(&config.goals_dir)?;
+    let goal_run_id = uuid::Uuid::new_v4();
+    let mut goal = GoalRun::new(
+        title,
+        objective.as_str(),
+        agent,
+        config.staging_dir.join(goal_run_id.to_string()),
+        config.store_dir.join(goal_run_id.to_string()),
+    );
+    goal.goal_run_id = goal_run_id;
+    goal.readonly = true;
+    goal.plan_phase = phase.map(str::to_string);
+    if timeout.is_some() {
+        goal.timeout_secs = timeout;
+    }
+
+    let manifest = readonly_manifest(&goal)?;
+    ta_policy::ManifestStore::new(config.issued_manifests_dir()).save(&manifest)?;
+    goal.transition(GoalRunState::Configured)?;
+    goal.transition(GoalRunState::Running)?;
+    goal_store.save(&goal)?;
+
+    if !quiet {
+        let grants: Vec<String> = manifest
+            .grants
+            .iter()
+            .map(|g| format!("{} {}", g.tool, g.verb))
+            .collect();
+        println!(
+            "Read-only goal: capabilities limited to {} — gateway writes are denied, \
+             and other file changes are left out of the draft.",
+            grants.join(", ")
+        );
+        println!("  Report: {} (in staging)", READONLY_REPORT_FILE);
+    }
+
+    let goal_id = goal_run_id.to_string();
+    execute(
+        config,
+        Some(title),
+        agent,
+        source,
+        &objective,
+        phase,
+        None,  // follow_up
+        None,  // follow_up_draft
+        None,  // follow_up_goal
+        None,  // objective_file
+        false, // no_launch
+        interactive,
+        false, // macro_goal
+        None,  // resume
+        headless,
+        false, // skip_verify
+        quiet,
+        Some(&goal_id),
+        None, // workflow
+        persona_name,
+        env_file,
+        agent_command,
+        context_files,
+        None,
+    )
+}
+
+/// Relative paths (forward slashes) of regular files under `root`, skipping
+/// excluded names. Symlinks are not followed.
+fn tree_files(
+    root: &Path,
+    excludes: &ta_workspace::ExcludePatterns,
+) -> std::io::Result<std::collections::BTreeSet<String>> {
+    let mut files = std::collections::BTreeSet::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            if excludes.should_exclude(&entry.file_name().to_string_lossy()) {
+                continue;
+            }
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                pending.push(entry.path());
+            } else if file_type.is_file() {
+                if let Ok(rel) = entry.path().strip_prefix(root) {
+                    files.insert(rel.to_string_lossy().replace('\\', "/"));
+                }
+            }
+        }
+    }
+    Ok(files)
+}
+
+/// Refuse to adopt a directory that is not recognisably a copy of `source`:
+/// at least half of the source's files must exist at the same paths in
+/// `dir`. Otherwise the draft would delete most of the project.
+fn check_plausible_overlay(
+    dir: &Path,
+    source: &Path,
+    excludes: &ta_workspace::ExcludePatterns,
+) -> anyhow::Result<()> {
+    let source_files = tree_files(source, excludes)?;
+    if source_files.is_empty() {
+        return Ok(());
+    }
+    let dir_files = tree_files(dir, excludes)?;
+    let shared = source_files.intersection(&dir_files).count();
+    if shared * 2 < source_files.len() {
+        anyhow::bail!(
+            "{} does not look like a working copy of {}: only {} of {} source files \
+             exist there. Pass the project source it was copied from with --source.",
+            dir.display(),
+            source.display(),
+            shared,
+            source_files.len()
+        );
+    }
+    Ok(())
+}
+
+/// Make `staging` match `dir`: copy every non-excluded file from `dir` and
+/// delete staged files that `dir` no longer has. Returns (copied, removed).
+fn mirror_into_staging(
+    dir: &Path,
+    staging: &Path,
+    excludes: &ta_workspace::ExcludePatterns,
+) -> anyhow::Result<(usize, usize)> {
+    let wanted = tree_files(dir, excludes)?;
+    let mut removed = 0;
+    for rel in tree_files(staging, excludes)?.difference(&wanted) {
+        std::fs::remove_file(staging.join(rel))?;
+        removed += 1;
+    }
+    for rel in &wanted {
+        let dest = staging.join(rel);
+        if let Some(parent) = dest.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        // Smart staging may have linked the file to source; never write through.
+        if dest.is_symlink() {
+            std::fs::remove_file(&dest)?;
+        }
+        std::fs::copy(dir.join(rel), &dest)?;
+    }
+    Ok((wanted.len(), removed))
+}
+
 // ── Public API ──────────────────────────────────────────────────
 
 #[allow(clippy::too_many_arguments)]
@@ -1011,7 +1964,18 @@ pub fn execute(
     existing_goal_id: Option<&str>,
     workflow: Option<&str>,
     persona_name: Option<&str>,
+    env_file: Option<&Path>,
+    agent_command: Option<&str>,
+    context_files: &[PathBuf],
+    timeout: Option<u64>,
 ) -> anyhow::Result<()> {
+    // Parse --env-file up front so a bad file fails before any staging work.
+    let env_file_vars = env_file.map(load_env_file).transpose()?;
+    validate_context_files(context_files)?;
+    if let Some(cmd) = agent_command {
+        agent_command_launch_config(cmd)?;
+    }
+
     // ── Resume an existing session ──────────────────────────────
     if let Some(session_id_prefix) = resume {
         #[cfg(unix)]
@@ -1458,7 +2422,7 @@ pub fn execute(
     let goal_store = GoalRunStore::new(&config.goals_dir)?;
 
     let goal = if let Some(existing_id) = existing_goal_id {
-        let goal_uuid = uuid::Uuid::parse_str(existing_id)
+        let goal_uuid = super::goal::resolve_goal_id(existing_id, &goal_store)
             .map_err(|e| anyhow::anyhow!("Invalid --goal-id '{}': {}", existing_id, e))?;
         let mut existing = goal_store
             .get(goal_uuid)?
@@ -1473,32 +2437,18 @@ pub fn execute(
         let source_dir = source_dir.canonicalize().unwrap_or(source_dir);
         let excludes = ta_workspace::ExcludePatterns::load(&source_dir);
         // v0.13.13: Use configured staging strategy.
-        let workflow = ta_submit::config::WorkflowConfig::load_or_default(&source_dir);
-        let staging_mode = match workflow.staging.strategy {
-            ta_submit::config::StagingStrategy::Full => ta_workspace::OverlayStagingMode::Full,
-            ta_submit::config::StagingStrategy::Smart => ta_workspace::OverlayStagingMode::Smart,
-            ta_submit::config::StagingStrategy::RefsCow => {
-                ta_workspace::OverlayStagingMode::RefsCow
-            }
-            ta_submit::config::StagingStrategy::ProjFs => ta_workspace::OverlayStagingMode::ProjFs,
-        };
-        let overlay = ta_workspace::OverlayWorkspace::create_with_strategy(
-            goal_uuid.to_string(),
+        let overlay = super::goal::create_goal_overlay(
+            &goal_uuid.to_string(),
             &source_dir,
             &config.staging_dir,
             excludes,
-            staging_mode,
         )?;
 
-        // Capture source snapshot for conflict detection.
-        let snapshot_json = overlay
-            .snapshot()
-            .and_then(|snap| serde_json::to_value(snap).ok());
-
-        // Update goal with overlay workspace paths.
-        existing.workspace_path = overlay.staging_dir().to_path_buf();
+        // Update goal with overlay workspace paths and the source snapshot
+        // for conflict detection.
+        super::goal::record_goal_overlay(&mut existing, &overlay);
+        existing.base_ref = ta_workspace::overlay::get_git_head_sha(&source_dir);
         existing.source_dir = Some(source_dir);
-        existing.source_snapshot = snapshot_json;
         if let Some(p) = phase {
             existing.plan_phase = Some(p.to_string());
         }
@@ -1518,6 +2468,7 @@ pub fn execute(
                 phase: phase.map(|p| p.to_string()),
                 follow_up: follow_up.cloned(),
                 objective_file: objective_file.map(|p| p.to_path_buf()),
+                timeout,
             },
             config,
         )?;
@@ -1530,13 +2481,37 @@ pub fn execute(
             .clone()
     };
 
-    // Mark as macro goal if --macro was specified, and store heartbeat_required (v0.13.14).
+    // Custom agent binary: an explicit --agent-command wins, then the command
+    // the goal was created with (clones), then the parent goal's (follow-ups).
+    let agent_command: Option<String> = agent_command
+        .map(str::to_string)
+        .or_else(|| goal.agent_command.clone())
+        .or_else(|| {
+            goal.parent_goal_id
+                .and_then(|id| goal_store.get(id).ok().flatten())
+                .and_then(|parent| parent.agent_command)
+        });
+    if let Some(ref cmd) = agent_command {
+        agent_config = agent_command_launch_config(cmd)?;
+        if !quiet {
+            println!("Agent command: {}", cmd);
+        }
+    }
+    let coordination = agent_config
+        .alignment
+        .as_ref()
+        .map(|a| a.coordination.clone())
+        .unwrap_or_default();
+
+    // Mark as macro goal if --macro was specified, store heartbeat_required (v0.13.14)
+    // and the custom agent command.
     {
         let mut updated_goal = goal.clone();
         if macro_goal {
             updated_goal.is_macro = true;
         }
         updated_goal.heartbeat_required = agent_config.heartbeat_required;
+        updated_goal.agent_command = agent_command;
         goal_store.save(&updated_goal)?;
     }
 
@@ -1587,6 +2562,14 @@ pub fn execute(
     }
 
     // 2. Inject context and settings into the staging workspace.
+    let reference_section = stage_context_files(&staging_path, context_files)?;
+    if reference_section.is_some() && !quiet {
+        println!(
+            "Context files: {} copied to {}/",
+            context_files.len(),
+            CONTEXT_FILES_DIR
+        );
+    }
     if agent_config.injects_context_file {
         // Load context budget config (v0.14.3.1).
         let ctx_wf = ta_submit::WorkflowConfig::load_or_default(
@@ -1654,6 +2637,10 @@ pub fn execute(
             }
         }
 
+        if let Some(section) = &reference_section {
+            channel.inject_reference_files(section)?;
+        }
+
         // v0.14.3.1: Warn at goal start when projected context > 80% of budget.
         if context_budget_chars > 0 {
             let sizes = compute_context_section_sizes(
@@ -1692,6 +2679,13 @@ pub fn execute(
             ctx_file,
             goal.source_dir.as_deref(),
         )?;
+        if let Some(section) = &reference_section {
+            use std::io::Write as _;
+            std::fs::OpenOptions::new()
+                .append(true)
+                .open(staging_path.join(ctx_file))?
+                .write_all(section.as_bytes())?;
+        }
     }
     if agent_config.injects_settings {
         // Load security profile from workflow.toml to apply level-appropriate
@@ -1881,6 +2875,25 @@ pub fn execute(
         }
     }
 
+    // --env-file: launch the agent from a scrubbed environment holding only the
+    // base set, TA's own injected vars, and the file's entries (file wins).
+    if let Some(file_vars) = env_file_vars {
+        let mut env = base_agent_env();
+        env.extend(std::mem::take(&mut agent_config.env));
+        env.extend(file_vars);
+        agent_config.env = env;
+        agent_config.clear_env = true;
+        if !quiet {
+            println!(
+                "Agent environment: {} variable(s) from {} (inherited environment scrubbed)",
+                agent_config.env.len(),
+                env_file
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_default()
+            );
+        }
+    }
+
     // Emit GoalStarted event to FsEventStore (v0.9.4.1).
     // Skip when reusing an existing goal — the MCP tool already emitted GoalStarted.
     if existing_goal_id.is_none() {
@@ -2040,15 +3053,7 @@ pub fn execute(
     // immediately after spawn so the daemon watchdog can check liveness.
     let goal_run_id = goal.goal_run_id;
     let goals_dir_for_pid = config.goals_dir.clone();
-    let save_pid = move |pid: u32| {
-        if let Ok(store) = GoalRunStore::new(&goals_dir_for_pid) {
-            if let Ok(Some(mut g)) = store.get(goal_run_id) {
-                g.agent_pid = Some(pid);
-                let _ = store.save(&g);
-                tracing::info!(goal_id = %goal_run_id, pid = pid, "Stored agent PID for watchdog");
-            }
-        }
-    };
+    let save_pid = move |pid: u32| record_agent_pid(&goals_dir_for_pid, goal_run_id, pid);
 
     // Events directory for lifecycle event emission (v0.13.3).
     let events_dir_for_launch = config.workspace_root.join(".ta").join("events");
@@ -2088,16 +3093,21 @@ pub fn execute(
     } else if interactive {
         #[cfg(unix)]
         {
-            launch_agent_interactive(&agent_config, &staging_path, &prompt, &mut session_store).map(
-                |(exit, log)| {
-                    (
-                        exit,
-                        log.iter()
-                            .map(|(req, resp)| (format!("{}", req), format!("{}", resp)))
-                            .collect(),
-                    )
-                },
+            launch_agent_interactive(
+                &agent_config,
+                &staging_path,
+                &prompt,
+                &mut session_store,
+                Some(&save_pid),
             )
+            .map(|(exit, log)| {
+                (
+                    exit,
+                    log.iter()
+                        .map(|(req, resp)| (format!("{}", req), format!("{}", resp)))
+                        .collect(),
+                )
+            })
         }
         #[cfg(not(unix))]
         {
@@ -2994,6 +4004,8 @@ pub fn execute(
                         summary: format!("Changes from goal: {}", title),
                         latest: false,
                         apply_context_file: None,
+                        tests: None,
+                        base_ref: None,
                     },
                     config,
                 )?;
@@ -3073,6 +4085,21 @@ pub fn execute(
         auto_capture_goal_completion(config, &goal, &staging_path);
     }
 
+    // 7d. Hand the goal to the next agent if the alignment profile says so.
+    if draft_built {
+        if let Err(e) = run_coordination_handoff(
+            config,
+            &goal_id,
+            title,
+            agent,
+            &coordination,
+            headless,
+            quiet,
+        ) {
+            eprintln!("Warning: coordination handoff failed: {}", e);
+        }
+    }
+
     // 8. Mark interactive session as completed.
     if let Some((store, mut session)) = session_store {
         if draft_built {
@@ -3253,6 +4280,9 @@ fn execute_resume(
 
     let mut session_store = Some((store, session));
 
+    let goal_run_id = goal.goal_run_id;
+    let save_pid = |pid: u32| record_agent_pid(&config.goals_dir, goal_run_id, pid);
+
     let launch_result = if let Some(ref cmd_str) = resume_cmd {
         // Use the resume command from agent config.
         let parts: Vec<&str> = cmd_str.split_whitespace().collect();
@@ -3278,12 +4308,25 @@ fn execute_resume(
             context_file: None,
             runtime: Default::default(),
             heartbeat_required: false,
+            clear_env: false,
         };
 
-        launch_agent_interactive(&resume_config, staging_path, "", &mut session_store)
+        launch_agent_interactive(
+            &resume_config,
+            staging_path,
+            "",
+            &mut session_store,
+            Some(&save_pid),
+        )
     } else {
         // Re-launch with the original prompt (empty for resume).
-        launch_agent_interactive(&agent_config, staging_path, "", &mut session_store)
+        launch_agent_interactive(
+            &agent_config,
+            staging_path,
+            "",
+            &mut session_store,
+            Some(&save_pid),
+        )
     };
 
     match launch_result {
@@ -3304,6 +4347,12 @@ fn execute_resume(
         }
     }
 
+    // The agent has exited — drop its PID so liveness checks don't see a stale one.
+    if let Ok(Some(mut g)) = goal_store.get(goal_run_id) {
+        g.agent_pid = None;
+        let _ = goal_store.save(&g);
+    }
+
     // Mark session as paused (can be resumed again) or completed.
     if let Some((store, mut session)) = session_store {
         session.log_message("ta-system", "Agent exited from resumed session");
@@ -3346,7 +4395,132 @@ fn find_session_by_prefix(
     }
 }
 
-// ── Agent launch ────────────────────────────────────────────────
+// ── Agent launch ────────────────────────────────────────────────
+
+/// Variables carried over from TA's own environment when `--env-file` scrubs
+/// the rest — just enough for the agent binary to start and find its tools.
+const AGENT_BASE_ENV: &[&str] = &[
+    "PATH",
+    "HOME",
+    "USER",
+    "LOGNAME",
+    "SHELL",
+    "TERM",
+    "LANG",
+    "TMPDIR",
+    // Windows needs these to locate system DLLs and per-user config.
+    "SYSTEMROOT",
+    "USERPROFILE",
+    "APPDATA",
+    "LOCALAPPDATA",
+    "TEMP",
+    "TMP",
+    "PATHEXT",
+    "COMSPEC",
+];
+
+fn base_agent_env() -> std::collections::HashMap<String, String> {
+    AGENT_BASE_ENV
+        .iter()
+        .filter_map(|key| std::env::var(key).ok().map(|v| (key.to_string(), v)))
+        .collect()
+}
+
+/// Parse a `--env-file`: `KEY=VALUE` per line, `#` comments and blank lines
+/// ignored, an optional `export ` prefix, and matching surrounding quotes on
+/// the value stripped.
+fn load_env_file(path: &Path) -> anyhow::Result<std::collections::HashMap<String, String>> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("Cannot read env file {}: {}", path.display(), e))?;
+    parse_env_file(&content).map_err(|e| anyhow::anyhow!("{}: {}", path.display(), e))
+}
+
+fn parse_env_file(content: &str) -> Result<std::collections::HashMap<String, String>, String> {
+    let mut vars = std::collections::HashMap::new();
+    for (idx, raw) in content.lines().enumerate() {
+        let line = raw.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let line = line.strip_prefix("export ").unwrap_or(line);
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected KEY=VALUE, got '{}'", idx + 1, raw))?;
+        let key = key.trim();
+        let valid_key = key
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid_key {
+            return Err(format!("line {}: invalid variable name '{}'", idx + 1, key));
+        }
+        let value = value.trim();
+        let value = ['"', '\'']
+            .iter()
+            .find_map(|q| {
+                value
+                    .strip_prefix(*q)
+                    .and_then(|v| v.strip_suffix(*q))
+                    .filter(|_| value.len() >= 2)
+            })
+            .unwrap_or(value);
+        vars.insert(key.to_string(), value.to_string());
+    }
+    Ok(vars)
+}
+
+/// Staging-relative directory that `--context-file`s are copied into.
+const CONTEXT_FILES_DIR: &str = ".ta/context";
+
+/// Check `--context-file` paths before any staging work: each must be a
+/// regular file, and file names must be unique since they share one directory.
+fn validate_context_files(files: &[PathBuf]) -> anyhow::Result<()> {
+    let mut seen = std::collections::HashSet::new();
+    for path in files {
+        if !path.is_file() {
+            anyhow::bail!("--context-file {} is not a readable file", path.display());
+        }
+        let name = path
+            .file_name()
+            .ok_or_else(|| anyhow::anyhow!("--context-file {} has no file name", path.display()))?;
+        if !seen.insert(name.to_os_string()) {
+            anyhow::bail!(
+                "--context-file: more than one file is named '{}'. Rename one so both \
+                 fit in {}/.",
+                name.to_string_lossy(),
+                CONTEXT_FILES_DIR
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Copy `--context-file`s into `<staging>/.ta/context/` and return the agent
+/// instruction section that lists them, or `None` when there are none.
+///
+/// `.ta/` is excluded from draft diffs, so the copies never show up as changes.
+fn stage_context_files(staging_path: &Path, files: &[PathBuf]) -> anyhow::Result<Option<String>> {
+    if files.is_empty() {
+        return Ok(None);
+    }
+    let dir = staging_path.join(CONTEXT_FILES_DIR);
+    std::fs::create_dir_all(&dir)?;
+    let mut section = String::from(
+        "\n## Reference Files\n\n\
+         The user supplied these files as reference material for this goal. Read them \
+         before you start. They are not part of the project: do not edit them or copy \
+         them into the source tree.\n\n",
+    );
+    for path in files {
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        std::fs::copy(path, dir.join(name.as_ref())).map_err(|e| {
+            anyhow::anyhow!("Failed to copy --context-file {}: {}", path.display(), e)
+        })?;
+        section.push_str(&format!("- `{}/{}`\n", CONTEXT_FILES_DIR, name));
+    }
+    Ok(Some(section))
+}
 
 /// Build a `Command` for `command` with `args`, handling Windows `.cmd`/`.bat` wrappers.
 ///
@@ -3404,6 +4578,9 @@ fn launch_agent(
     let mut cmd = resolve_agent_command(&config.command, &args);
     cmd.current_dir(staging_path);
 
+    if config.clear_env {
+        cmd.env_clear();
+    }
     for (key, value) in &config.env {
         cmd.env(key, value);
     }
@@ -3435,6 +4612,9 @@ fn launch_agent_headless(
 
     let mut cmd = std::process::Command::new(&config.command);
     cmd.current_dir(staging_path);
+    if config.clear_env {
+        cmd.env_clear();
+    }
 
     for arg_template in &config.args_template {
         let arg = arg_template.replace("{prompt}", prompt);
@@ -3493,6 +4673,7 @@ fn launch_agent_interactive(
     staging_path: &Path,
     prompt: &str,
     session_store: &mut Option<(InteractiveSessionStore, InteractiveSession)>,
+    pid_callback: Option<&dyn Fn(u32)>,
 ) -> std::io::Result<(
     std::process::ExitStatus,
     Vec<(InteractionRequest, InteractionResponse)>,
@@ -3510,7 +4691,9 @@ fn launch_agent_interactive(
         args,
         working_dir: staging_path,
         env_vars: &config.env,
+        clear_env: config.clear_env,
         output_sink: None, // Default: TerminalSink (stdout). Replace for Slack/email.
+        pid_callback,
     };
 
     let result = pty_capture::run_interactive_pty(pty_config)?;
@@ -3558,6 +4741,18 @@ fn launch_agent_interactive(
     Ok((result.exit_status, guidance_log))
 }
 
+/// Store the agent's PID on its goal record so the daemon watchdog and
+/// `ta session status` can check liveness (v0.11.2.4).
+fn record_agent_pid(goals_dir: &Path, goal_id: uuid::Uuid, pid: u32) {
+    if let Ok(store) = GoalRunStore::new(goals_dir) {
+        if let Ok(Some(mut g)) = store.get(goal_id) {
+            g.agent_pid = Some(pid);
+            let _ = store.save(&g);
+            tracing::info!(goal_id = %goal_id, pid = pid, "Stored agent PID for watchdog");
+        }
+    }
+}
+
 /// Token counts accumulated from a headless agent's stream-json output.
 #[derive(Debug, Default, Clone)]
 struct AgentTokens {
@@ -3662,6 +4857,7 @@ fn launch_agent_via_runtime(
         command: config.command.clone(),
         args,
         env,
+        clear_env: config.clear_env,
         working_dir: staging_path.to_path_buf(),
         stdin_mode,
         stdout_mode,
@@ -3987,6 +5183,136 @@ fn try_spawn_background_draft_build(
 ///
 /// Returns the canonical display ID (`<shortref>/<seq>` when available, else UUID prefix)
 /// so that the ID shown in completion messages resolves via `ta draft view/approve/apply`.
+/// Agents that already ran in a chain of coordination handoffs,
+/// comma-separated. Set on each handoff's `ta run` so cycles are refused.
+const HANDOFF_CHAIN_ENV: &str = "TA_HANDOFF_CHAIN";
+
+/// Run the handoff declared in the agent's alignment profile
+/// (`coordination.handoff`): start a follow-up goal for the next agent with
+/// `ta run --follow-up-goal`, the way serial-phases chains phases, so it
+/// inherits this goal's context and draft.
+fn run_coordination_handoff(
+    config: &GatewayConfig,
+    goal_id: &str,
+    title: &str,
+    agent: &str,
+    coordination: &ta_policy::CoordinationConfig,
+    headless: bool,
+    quiet: bool,
+) -> anyhow::Result<()> {
+    let mut chain = parse_handoff_chain(std::env::var(HANDOFF_CHAIN_ENV).ok().as_deref());
+    chain.push(agent.to_string());
+    let handoff = match coordination.next_handoff(&chain) {
+        Ok(Some(handoff)) => handoff,
+        Ok(None) => return Ok(()),
+        Err(e) => {
+            eprintln!("\nSkipping coordination handoff: {}", e);
+            return Ok(());
+        }
+    };
+    let Some(draft_id) = find_latest_draft_id(config, goal_id) else {
+        // Background builds finish after `ta run` returns.
+        println!(
+            "\nHandoff to '{}' needs this goal's draft. Once it is built, run:\n  \
+             ta run --agent {} --follow-up-goal {}",
+            handoff.to, handoff.to, goal_id
+        );
+        return Ok(());
+    };
+
+    let ta_bin = std::env::current_exe().map_err(|e| {
+        anyhow::anyhow!(
+            "Could not determine ta binary path for subprocess invocation: {}",
+            e
+        )
+    })?;
+    let mut cmd = handoff_command(
+        &ta_bin,
+        &config.workspace_root,
+        goal_id,
+        title,
+        &draft_id,
+        handoff,
+        &chain,
+        headless,
+        quiet,
+    );
+    println!(
+        "\nHanding off to '{}' (coordination: {}).",
+        handoff.to,
+        chain.join(" -> ")
+    );
+    let status = cmd
+        .status()
+        .map_err(|e| anyhow::anyhow!("Failed to launch agent '{}': {}", handoff.to, e))?;
+    if !status.success() {
+        anyhow::bail!(
+            "agent '{}' exited with code {}. Retry with: ta run --agent {} --follow-up-goal {}",
+            handoff.to,
+            status.code().unwrap_or(-1),
+            handoff.to,
+            goal_id
+        );
+    }
+    Ok(())
+}
+
+fn parse_handoff_chain(value: Option<&str>) -> Vec<String> {
+    value
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|a| !a.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// The `ta run` invocation for a handoff's follow-up goal.
+#[allow(clippy::too_many_arguments)]
+fn handoff_command(
+    ta_bin: &Path,
+    workspace_root: &Path,
+    goal_id: &str,
+    title: &str,
+    draft_id: &str,
+    handoff: &ta_policy::Handoff,
+    chain: &[String],
+    headless: bool,
+    quiet: bool,
+) -> std::process::Command {
+    let role = handoff.role.as_deref().unwrap_or(&handoff.to);
+    let previous = chain.last().map(String::as_str).unwrap_or("previous");
+    let objective = handoff.objective.clone().unwrap_or_else(|| {
+        format!(
+            "Continue \"{}\" as the {}, starting from the {} agent's draft {}.",
+            title,
+            role,
+            previous,
+            &draft_id[..8.min(draft_id.len())]
+        )
+    });
+    let mut cmd = std::process::Command::new(ta_bin);
+    cmd.arg("run")
+        .arg(format!("{} ({})", title, role))
+        .arg("--agent")
+        .arg(&handoff.to)
+        .arg("--follow-up-goal")
+        .arg(goal_id)
+        .arg("--objective")
+        .arg(objective);
+    // A daemon-spawned run hands off headless too: no terminal, no
+    // daemon or clean-tree pre-checks.
+    if headless {
+        cmd.arg("--headless");
+    }
+    if quiet {
+        cmd.arg("--quiet");
+    }
+    cmd.env(HANDOFF_CHAIN_ENV, chain.join(","))
+        .current_dir(workspace_root);
+    cmd
+}
+
 pub(crate) fn find_latest_draft_id(config: &GatewayConfig, goal_id: &str) -> Option<String> {
     use ta_changeset::draft_package::DraftPackage;
     use ta_changeset::draft_resolver::draft_canonical_id;
@@ -4635,10 +5961,14 @@ fn build_parent_context_section(
                             context.push_str("**Review discussion:**\n\n");
                             for (idx, comment) in comments.comments.iter().enumerate() {
                                 context.push_str(&format!(
-                                    "{}. **{}** ({}): {}\n",
+                                    "{}. **{}** ({}{}): {}\n",
                                     idx + 1,
                                     comment.commenter,
                                     comment.created_at.format("%Y-%m-%d %H:%M UTC"),
+                                    comment
+                                        .location_label()
+                                        .map(|l| format!(", {}", l))
+                                        .unwrap_or_default(),
                                     comment.text
                                 ));
                             }
@@ -4681,6 +6011,10 @@ Use these tools to interact with TA during your session:
   - `action: "status"` — Check the review status of a draft
   - `action: "list"` — List all drafts for this goal
 
+- **`ta_draft_build`** — Build a draft from the files you edited directly in
+  your workspace (the same diff `ta draft build` uses). Fails if the project
+  requires summaries and `.ta/change_summary.json` doesn't describe every file.
+
 - **`ta_goal`** — Manage sub-goals
   - `action: "start"` — Create a sub-goal within this macro session
   - `action: "status"` — Check the status of a sub-goal
@@ -4970,6 +6304,7 @@ Rules for per-target descriptions:
 - `depends_on`: list of other file paths this change requires (e.g., if you add a function call, it depends on the file where the function is defined)
 - `depended_by`: list of other file paths that would break if this change is reverted
 - Be honest about dependencies — the reviewer uses this to decide which changes to accept individually
+- `commit`: set to `false` for scratch files or local notes that should be applied but left out of the VCS commit (defaults to `true`)
 
 ## Task Completion Enforcement (REQUIRED)
 
@@ -6386,6 +7721,203 @@ mod tests {
         assert_eq!(count_changed_files(staging.path(), source.path()), 0);
     }
 
+    #[test]
+    fn resume_staging_adopts_work_tree_as_goal_staging() {
+        let project = TempDir::new().unwrap();
+        std::fs::create_dir_all(project.path().join("src")).unwrap();
+        std::fs::write(project.path().join("README.md"), "# Test\n").unwrap();
+        std::fs::write(project.path().join("src/lib.rs"), "pub fn a() {}\n").unwrap();
+        std::fs::write(project.path().join("src/old.rs"), "// gone\n").unwrap();
+        let config = GatewayConfig::for_project(project.path());
+
+        // A manual edit session: one file changed, one added, one deleted.
+        let work = TempDir::new().unwrap();
+        std::fs::create_dir_all(work.path().join("src")).unwrap();
+        std::fs::write(work.path().join("README.md"), "# Test\n").unwrap();
+        std::fs::write(work.path().join("src/lib.rs"), "pub fn a() { b() }\n").unwrap();
+        std::fs::write(work.path().join("src/new.rs"), "pub fn b() {}\n").unwrap();
+        std::fs::create_dir_all(work.path().join("target")).unwrap();
+        std::fs::write(work.path().join("target/out.o"), "junk").unwrap();
+
+        execute_resume_staging(
+            &config,
+            None,
+            "claude-code",
+            Some(project.path()),
+            "",
+            None,
+            work.path(),
+            None,
+        )
+        .unwrap();
+
+        let goal_store = GoalRunStore::new(&config.goals_dir).unwrap();
+        let goal = goal_store.list().unwrap().remove(0);
+        let staging = &goal.workspace_path;
+        assert!(goal.title.starts_with("Adopt changes from"));
+        assert_eq!(
+            std::fs::read_to_string(staging.join("src/lib.rs")).unwrap(),
+            "pub fn a() { b() }\n"
+        );
+        assert!(staging.join("src/new.rs").exists());
+        assert!(!staging.join("src/old.rs").exists());
+        assert!(
+            !staging.join("target").exists(),
+            "excluded paths are not adopted"
+        );
+        // Source is untouched.
+        assert!(project.path().join("src/old.rs").exists());
+    }
+
+    #[test]
+    fn diff_only_applies_patch_to_staging_or_reports_failing_hunk() {
+        let project = TempDir::new().unwrap();
+        std::fs::write(project.path().join("README.md"), "# Test\nintro\n").unwrap();
+        let config = GatewayConfig::for_project(project.path());
+        let patches = TempDir::new().unwrap();
+        let good = patches.path().join("fix.patch");
+        std::fs::write(
+            &good,
+            "--- a/README.md\n+++ b/README.md\n@@ -1,2 +1,2 @@\n # Test\n-intro\n+Intro.\n",
+        )
+        .unwrap();
+
+        execute_diff_only(&config, None, "claude-code", None, "", None, &good).unwrap();
+        let goal_store = GoalRunStore::new(&config.goals_dir).unwrap();
+        let goal = goal_store.list().unwrap().remove(0);
+        assert_eq!(goal.title, "Apply fix.patch");
+        assert_eq!(
+            std::fs::read_to_string(goal.workspace_path.join("README.md")).unwrap(),
+            "# Test\nIntro.\n"
+        );
+        assert_eq!(
+            std::fs::read_to_string(project.path().join("README.md")).unwrap(),
+            "# Test\nintro\n",
+            "the source is untouched"
+        );
+
+        let bad = patches.path().join("stale.patch");
+        std::fs::write(
+            &bad,
+            "--- a/README.md\n+++ b/README.md\n@@ -1,2 +1,2 @@\n # Test\n-outro\n+Outro.\n",
+        )
+        .unwrap();
+        let err = execute_diff_only(&config, None, "claude-code", None, "", None, &bad)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("does not apply cleanly"), "{}", err);
+        assert!(err.contains("Failing hunk:\n@@ -1,2 +1,2 @@"), "{}", err);
+        assert!(err.contains("-outro"), "{}", err);
+        assert_eq!(
+            goal_store.list().unwrap().len(),
+            1,
+            "the failed goal is removed"
+        );
+    }
+
+    #[test]
+    fn snapshot_only_records_base_for_later_resume_staging() {
+        let project = TempDir::new().unwrap();
+        std::fs::write(project.path().join("README.md"), "# Test\n").unwrap();
+        std::fs::write(project.path().join("lib.rs"), "pub fn a() {}\n").unwrap();
+        let config = GatewayConfig::for_project(project.path());
+
+        execute_snapshot_only(
+            &config,
+            "Baseline",
+            "claude-code",
+            Some(project.path()),
+            "",
+            None,
+        )
+        .unwrap();
+        let goal_store = GoalRunStore::new(&config.goals_dir).unwrap();
+        let goal = goal_store.list().unwrap().remove(0);
+        assert_eq!(goal.state, GoalRunState::Created);
+        assert!(!goal.workspace_path.exists(), "no staging is created");
+        let recorded = goal.source_snapshot.clone().unwrap();
+
+        // Source moves on after the snapshot; the work arrives later.
+        std::fs::write(project.path().join("README.md"), "# Changed\n").unwrap();
+        let work = TempDir::new().unwrap();
+        std::fs::write(work.path().join("README.md"), "# Test\n").unwrap();
+        std::fs::write(work.path().join("lib.rs"), "pub fn a() { 1; }\n").unwrap();
+        let id = goal.goal_run_id.to_string();
+        execute_resume_staging(
+            &config,
+            None,
+            "claude-code",
+            None,
+            "",
+            None,
+            work.path(),
+            Some(&id[..8]),
+        )
+        .unwrap();
+
+        let goals = goal_store.list().unwrap();
+        assert_eq!(goals.len(), 1, "adopts into the snapshot goal");
+        let goal = &goals[0];
+        assert_eq!(goal.state, GoalRunState::Running);
+        assert_eq!(goal.source_snapshot.as_ref(), Some(&recorded));
+        assert_eq!(
+            std::fs::read_to_string(goal.workspace_path.join("lib.rs")).unwrap(),
+            "pub fn a() { 1; }\n"
+        );
+        let snapshot: ta_workspace::SourceSnapshot = serde_json::from_value(recorded).unwrap();
+        let conflicts = snapshot
+            .detect_conflicts(project.path(), |_| false)
+            .unwrap();
+        assert_eq!(conflicts.len(), 1, "{:?}", conflicts);
+
+        // The goal is no longer a pending snapshot.
+        let err = execute_resume_staging(
+            &config,
+            None,
+            "claude-code",
+            None,
+            "",
+            None,
+            work.path(),
+            Some(&id[..8]),
+        )
+        .unwrap_err();
+        assert!(
+            err.to_string().contains("not a pending snapshot"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn resume_staging_rejects_unrelated_directory() {
+        let project = TempDir::new().unwrap();
+        for name in ["a.rs", "b.rs", "c.rs"] {
+            std::fs::write(project.path().join(name), "x\n").unwrap();
+        }
+        let config = GatewayConfig::for_project(project.path());
+        let unrelated = TempDir::new().unwrap();
+        std::fs::write(unrelated.path().join("a.rs"), "x\n").unwrap();
+
+        let err = execute_resume_staging(
+            &config,
+            Some("Adopt"),
+            "claude-code",
+            Some(project.path()),
+            "",
+            None,
+            unrelated.path(),
+            None,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("only 1 of 3"), "{}", err);
+        assert!(GoalRunStore::new(&config.goals_dir)
+            .unwrap()
+            .list()
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn run_creates_goal_and_restores_on_no_launch() {
         let project = TempDir::new().unwrap();
@@ -6420,13 +7952,18 @@ mod tests {
             None,  // no existing goal id
             None,  // workflow = default (single-agent)
             None,  // persona_name = None
+            None,  // env_file
+            None,  // agent_command
+            &[],   // context_files
+            Some(900),
         )
         .unwrap();
 
-        // Verify goal was created.
+        // Verify goal was created, with the --timeout recorded.
         let goal_store = GoalRunStore::new(&config.goals_dir).unwrap();
         let goals = goal_store.list().unwrap();
         assert_eq!(goals.len(), 1);
+        assert_eq!(goals[0].timeout_secs, Some(900));
 
         // With --no-launch, injected files should be restored so they
         // don't contaminate a subsequent `ta pr build` diff.
@@ -6437,6 +7974,68 @@ mod tests {
         assert!(!goals[0].workspace_path.join(SETTINGS_REL_PATH).exists());
     }
 
+    #[test]
+    fn agent_command_launch_config_splits_binary_and_args() {
+        let cfg = agent_command_launch_config("my-agent --fast {prompt}").unwrap();
+        assert_eq!(cfg.command, "my-agent");
+        assert_eq!(cfg.args_template, vec!["--fast", "{prompt}"]);
+        assert!(cfg.injects_context_file);
+        assert!(!cfg.injects_settings);
+        assert_eq!(cfg.context_file.as_deref(), Some(".ta/agent_context.md"));
+        assert!(agent_command_launch_config("   ").is_err());
+    }
+
+    #[test]
+    fn agent_command_is_persisted_and_reused_by_follow_ups() {
+        let project = TempDir::new().unwrap();
+        std::fs::write(project.path().join("README.md"), "# Test\n").unwrap();
+        let config = GatewayConfig::for_project(project.path());
+        let run = |title: &str, follow_up: Option<&Option<String>>, command: Option<&str>| {
+            execute(
+                &config,
+                Some(title),
+                "claude-code",
+                Some(project.path()),
+                "Test objective",
+                None,
+                follow_up,
+                None,
+                None,
+                None,
+                true, // no_launch
+                false,
+                false,
+                None,
+                false,
+                false,
+                true, // quiet
+                None,
+                None,
+                None,
+                None,
+                command,
+                &[],
+                None,
+            )
+            .unwrap();
+        };
+
+        run("Custom agent goal", None, Some("my-agent --flag"));
+        let goal_store = GoalRunStore::new(&config.goals_dir).unwrap();
+        let parent = goal_store.list().unwrap().remove(0);
+        assert_eq!(parent.agent_command.as_deref(), Some("my-agent --flag"));
+
+        let parent_id = Some(parent.goal_run_id.to_string());
+        run("Follow-up goal", Some(&parent_id), None);
+        let follow_up = goal_store
+            .list()
+            .unwrap()
+            .into_iter()
+            .find(|g| g.parent_goal_id == Some(parent.goal_run_id))
+            .unwrap();
+        assert_eq!(follow_up.agent_command.as_deref(), Some("my-agent --flag"));
+    }
+
     #[test]
     fn run_injects_context_for_agent() {
         // Verify that inject + restore roundtrip works for the agent path.
@@ -6745,6 +8344,59 @@ mod tests {
         assert!(!unknown.injects_settings);
     }
 
+    #[test]
+    fn parse_env_file_reads_key_values() {
+        let vars = parse_env_file(
+            "# agent env\n\
+             RUST_LOG=debug\n\
+             export API_KEY=\"sk-123\"\n\
+             \n\
+             EMPTY=\n\
+             QUOTED='a=b'\n",
+        )
+        .unwrap();
+        assert_eq!(vars.len(), 4);
+        assert_eq!(vars["RUST_LOG"], "debug");
+        assert_eq!(vars["API_KEY"], "sk-123");
+        assert_eq!(vars["EMPTY"], "");
+        assert_eq!(vars["QUOTED"], "a=b");
+
+        let err = parse_env_file("RUST_LOG=debug\nnot a pair\n").unwrap_err();
+        assert!(err.contains("line 2"), "{}", err);
+        assert!(parse_env_file("1BAD=x").is_err());
+    }
+
+    #[test]
+    fn context_files_are_validated_and_staged() {
+        let docs = TempDir::new().unwrap();
+        let staging = TempDir::new().unwrap();
+        std::fs::create_dir_all(docs.path().join("v2")).unwrap();
+        let design = docs.path().join("design.md");
+        let api = docs.path().join("v2/api.yaml");
+        std::fs::write(&design, "# Design\n").unwrap();
+        std::fs::write(&api, "openapi: 3.0.0\n").unwrap();
+
+        let files = vec![design.clone(), api.clone()];
+        validate_context_files(&files).unwrap();
+        let section = stage_context_files(staging.path(), &files)
+            .unwrap()
+            .unwrap();
+        assert!(section.contains("## Reference Files"));
+        assert!(section.contains("`.ta/context/design.md`"));
+        assert!(section.contains("`.ta/context/api.yaml`"));
+        assert_eq!(
+            std::fs::read_to_string(staging.path().join(".ta/context/api.yaml")).unwrap(),
+            "openapi: 3.0.0\n"
+        );
+        assert!(stage_context_files(staging.path(), &[]).unwrap().is_none());
+
+        let missing = docs.path().join("missing.md");
+        assert!(validate_context_files(&[missing]).is_err());
+        std::fs::write(docs.path().join("v2/design.md"), "other").unwrap();
+        let err = validate_context_files(&[design, docs.path().join("v2/design.md")]).unwrap_err();
+        assert!(err.to_string().contains("more than one"), "{}", err);
+    }
+
     #[test]
     fn agent_config_loads_from_yaml() {
         let project = TempDir::new().unwrap();
@@ -6925,6 +8577,10 @@ pre_launch:
             comments: Some(comment_thread),
             amendment: None,
             kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         };
 
         let parent_draft = DraftPackage {
@@ -6999,8 +8655,11 @@ pre_launch:
             display_id: None,
             tag: None,
             vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
             parent_draft_id: None,
             pending_approvals: vec![],
+            review_assignments: vec![],
             supervisor_review: None,
             ignored_artifacts: vec![],
             baseline_artifacts: vec![],
@@ -7010,6 +8669,11 @@ pre_launch:
             draft_seq: 0,
             plan_phase: None,
             plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
         };
 
         // Save the draft package.
@@ -7093,6 +8757,10 @@ pre_launch:
             comments: None, // No comments yet
             amendment: None,
             kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         };
 
         let parent_draft = DraftPackage {
@@ -7167,8 +8835,11 @@ pre_launch:
             display_id: None,
             tag: None,
             vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
             parent_draft_id: None,
             pending_approvals: vec![],
+            review_assignments: vec![],
             supervisor_review: None,
             ignored_artifacts: vec![],
             baseline_artifacts: vec![],
@@ -7178,6 +8849,11 @@ pre_launch:
             draft_seq: 0,
             plan_phase: None,
             plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
         };
 
         super::super::draft::save_package(&config, &parent_draft).unwrap();
@@ -7577,28 +9253,14 @@ non_interactive_env:
         let changes = overlay.diff_all().unwrap();
 
         // .mcp.json must NOT appear in the diff.
-        let mcp_in_diff = changes.iter().any(|c| {
-            let path = match c {
-                ta_workspace::overlay::OverlayChange::Modified { path, .. } => path,
-                ta_workspace::overlay::OverlayChange::Created { path, .. } => path,
-                ta_workspace::overlay::OverlayChange::Deleted { path } => path,
-            };
-            path == ".mcp.json"
-        });
+        let mcp_in_diff = changes.iter().any(|c| c.path() == ".mcp.json");
         assert!(
             !mcp_in_diff,
             ".mcp.json must be excluded from overlay diff (TA-managed file)"
         );
 
         // main.rs change SHOULD appear.
-        let main_in_diff = changes.iter().any(|c| {
-            let path = match c {
-                ta_workspace::overlay::OverlayChange::Modified { path, .. } => path,
-                ta_workspace::overlay::OverlayChange::Created { path, .. } => path,
-                ta_workspace::overlay::OverlayChange::Deleted { path } => path,
-            };
-            path == "main.rs"
-        });
+        let main_in_diff = changes.iter().any(|c| c.path() == "main.rs");
         assert!(main_in_diff, "main.rs change must appear in diff");
     }
 
@@ -8190,4 +9852,181 @@ plan_pending_window = 7
             "plan_history.jsonl should be in the commit"
         );
     }
+
+    // ── plan-all ──────────────────────────────────────────────────
+
+    #[test]
+    fn plan_all_steps_through_review_and_apply() {
+        let project = TempDir::new().unwrap();
+        let root = project.path();
+        std::fs::write(
+            root.join("PLAN.md"),
+            "### v0.1.0 — First\n<!-- status: pending -->\n\n\
+             ### v0.2.0 — Second\n<!-- status: pending -->\n",
+        )
+        .unwrap();
+        std::fs::write(root.join("README.md"), "# Original\n").unwrap();
+        let config = GatewayConfig::for_project(root);
+        let mut state = PlanAllState {
+            agent: "test-agent".to_string(),
+            auto_apply: false,
+            active: None,
+            completed: Vec::new(),
+            started_at: chrono::Utc::now(),
+            updated_at: chrono::Utc::now(),
+        };
+
+        // Nothing active: claim the first pending phase.
+        assert!(matches!(
+            next_plan_all_step(&config, &mut state).unwrap(),
+            PlanAllStep::Launch
+        ));
+        assert_eq!(state.active.as_ref().unwrap().phase, "v0.1.0");
+
+        // The agent run produced a goal and a draft: wait for review.
+        super::super::goal::execute(
+            &super::super::goal::GoalCommands::Start {
+                title: "First".to_string(),
+                source: Some(root.to_path_buf()),
+                objective: "First".to_string(),
+                agent: "test-agent".to_string(),
+                phase: Some("v0.1.0".to_string()),
+                follow_up: None,
+                objective_file: None,
+                timeout: None,
+            },
+            &config,
+        )
+        .unwrap();
+        let goal = GoalRunStore::new(&config.goals_dir)
+            .unwrap()
+            .list()
+            .unwrap()[0]
+            .clone();
+        std::fs::write(goal.workspace_path.join("README.md"), "# First\n").unwrap();
+        super::super::draft::build_package(&config, &goal.goal_run_id.to_string(), "First", false)
+            .unwrap();
+        let mut pkg = match next_plan_all_step(&config, &mut state).unwrap() {
+            PlanAllStep::AwaitReview(pkg) => *pkg,
+            other => panic!("expected AwaitReview, got {:?}", other),
+        };
+        assert!(plan_all_auto_approval(&config, &pkg).is_err());
+
+        // Applied with a failed push: the phase is held until resubmitted.
+        pkg.status = ta_changeset::DraftStatus::Applied {
+            applied_at: chrono::Utc::now(),
+            applied_via: Default::default(),
+        };
+        let mut outcome = ta_changeset::SubmitOutcome::requested(true, false);
+        outcome.commit = ta_changeset::SubmitStepStatus::Succeeded;
+        outcome.push = ta_changeset::SubmitStepStatus::Failed {
+            error: "no remote".to_string(),
+        };
+        pkg.submit_outcome = Some(outcome.clone());
+        super::super::draft::save_package(&config, &pkg).unwrap();
+        assert!(matches!(
+            next_plan_all_step(&config, &mut state).unwrap(),
+            PlanAllStep::SubmitIncomplete(_)
+        ));
+        assert!(state.completed.is_empty());
+
+        // Once fully submitted (and the phase marked done), the next phase starts.
+        outcome.push = ta_changeset::SubmitStepStatus::Succeeded;
+        pkg.submit_outcome = Some(outcome);
+        super::super::draft::save_package(&config, &pkg).unwrap();
+        let content = std::fs::read_to_string(root.join("PLAN.md")).unwrap();
+        std::fs::write(
+            root.join("PLAN.md"),
+            plan::update_phase_status(&content, "v0.1.0", plan::PlanStatus::Done),
+        )
+        .unwrap();
+        assert!(matches!(
+            next_plan_all_step(&config, &mut state).unwrap(),
+            PlanAllStep::Launch
+        ));
+        assert_eq!(state.completed, vec!["v0.1.0".to_string()]);
+        assert_eq!(state.active.as_ref().unwrap().phase, "v0.2.0");
+
+        // State survives a restart; a phase finished by hand is skipped.
+        state.save(&config).unwrap();
+        let mut state = PlanAllState::load(&config).unwrap();
+        let content = std::fs::read_to_string(root.join("PLAN.md")).unwrap();
+        std::fs::write(
+            root.join("PLAN.md"),
+            plan::update_phase_status(&content, "v0.2.0", plan::PlanStatus::Done),
+        )
+        .unwrap();
+        assert!(matches!(
+            next_plan_all_step(&config, &mut state).unwrap(),
+            PlanAllStep::Complete
+        ));
+    }
+
+    #[test]
+    fn handoff_runs_follow_up_goal_for_next_agent() {
+        let yaml = "command: claude\nargs_template: []\nalignment:\n  principal: owner\n  autonomy_envelope:\n    \
+                    bounded_actions: [fs_read]\n  coordination:\n    handoff:\n      \
+                    to: reviewer\n      role: code reviewer\n";
+        let agent: AgentLaunchConfig = serde_yaml::from_str(yaml).unwrap();
+        let coordination = agent.alignment.unwrap().coordination;
+        let mut chain = parse_handoff_chain(Some("planner, "));
+        chain.push("implementer".to_string());
+        let handoff = coordination.next_handoff(&chain).unwrap().unwrap();
+
+        let cmd = handoff_command(
+            Path::new("/usr/bin/ta"),
+            Path::new("/project"),
+            "goal-1234",
+            "Fix login",
+            "abcdef0123",
+            handoff,
+            &chain,
+            true,
+            true,
+        );
+        let args: Vec<&str> = cmd.get_args().map(|a| a.to_str().unwrap()).collect();
+        assert_eq!(
+            args,
+            vec![
+                "run",
+                "Fix login (code reviewer)",
+                "--agent",
+                "reviewer",
+                "--follow-up-goal",
+                "goal-1234",
+                "--objective",
+                "Continue \"Fix login\" as the code reviewer, starting from the implementer agent's draft abcdef01.",
+                "--headless",
+                "--quiet",
+            ]
+        );
+        let env: Vec<_> = cmd.get_envs().collect();
+        assert_eq!(
+            env,
+            vec![(
+                std::ffi::OsStr::new(HANDOFF_CHAIN_ENV),
+                Some(std::ffi::OsStr::new("planner,implementer"))
+            )]
+        );
+    }
+
+    #[test]
+    fn record_agent_pid_stores_pid_on_goal() {
+        let project = TempDir::new().unwrap();
+        let config = GatewayConfig::for_project(project.path());
+        let goal_store = GoalRunStore::new(&config.goals_dir).unwrap();
+        let goal = GoalRun::new(
+            "Interactive goal",
+            "Run in a PTY",
+            "test-agent",
+            project.path().join(".ta/staging/pty"),
+            project.path().join(".ta/store/pty"),
+        );
+        goal_store.save(&goal).unwrap();
+
+        record_agent_pid(&config.goals_dir, goal.goal_run_id, 4242);
+
+        let saved = goal_store.get(goal.goal_run_id).unwrap().unwrap();
+        assert_eq!(saved.agent_pid, Some(4242));
+    }
 }
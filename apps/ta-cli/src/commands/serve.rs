@@ -1,4 +1,4 @@
-// serve.rs — Start the MCP server on stdio.
+// serve.rs — Start the MCP server on stdio (or HTTP + SSE with --http).
 //
 // This delegates to the same logic as ta-daemon, allowing users to
 // start the server via `ta serve` without needing to know the binary name.
@@ -8,7 +8,11 @@ use std::path::Path;
 use rmcp::ServiceExt;
 use ta_mcp_gateway::{GatewayConfig, TaGatewayServer};
 
-pub fn execute(project_root: &Path) -> anyhow::Result<()> {
+pub fn execute(
+    project_root: &Path,
+    http: Option<&str>,
+    token: Option<String>,
+) -> anyhow::Result<()> {
     // Honor TA_PROJECT_ROOT env var if set (used when launched as MCP server
     // subprocess via .mcp.json). Falls back to --project-root CLI arg.
     let effective_root = std::env::var("TA_PROJECT_ROOT")
@@ -19,6 +23,24 @@ pub fn execute(project_root: &Path) -> anyhow::Result<()> {
     let server = TaGatewayServer::new(config)?;
 
     let rt = tokio::runtime::Runtime::new()?;
+    if let Some(addr) = http {
+        let addr = ta_mcp_gateway::http::resolve_http_addr(addr)?;
+        let token = token.or_else(|| std::env::var("TA_SERVE_TOKEN").ok());
+        ta_mcp_gateway::http::check_http_bind(&addr, token.as_deref())?;
+        eprintln!(
+            "Serving MCP over HTTP at http://{}{}{}",
+            addr,
+            ta_mcp_gateway::http::MCP_HTTP_PATH,
+            if token.is_some() {
+                " (bearer token required)"
+            } else {
+                ""
+            }
+        );
+        return rt
+            .block_on(ta_mcp_gateway::http::serve_http(server, addr, token))
+            .map_err(|e| anyhow::anyhow!("MCP HTTP server error: {}", e));
+    }
     rt.block_on(async {
         let transport = rmcp::transport::stdio();
         let server_handle = server
@@ -3,6 +3,8 @@
 // Interactive session commands (existing):
 //   ta session list        — list active interactive sessions across channels
 //   ta session show <id>   — display session details and message history
+//   ta session status <id> — goal, last activity, and whether the agent is alive
+//   ta session transcript <id> — export the full session transcript
 //   ta session resume <id> — resume a paused interactive session
 //   ta session pause <id>  — pause a running session
 //   ta session abort <id>  — abort a session
@@ -18,7 +20,7 @@
 use std::io::{self, BufRead, Write};
 
 use clap::Subcommand;
-use ta_changeset::{InteractiveSessionState, InteractiveSessionStore};
+use ta_changeset::{InteractiveSession, InteractiveSessionState, InteractiveSessionStore};
 use ta_goal::GoalRunStore;
 use ta_mcp_gateway::GatewayConfig;
 use ta_memory::memory_store_from_config;
@@ -45,6 +47,15 @@ pub enum SessionCommands {
         /// Session ID (full or prefix).
         id: String,
     },
+    /// Export the full ordered transcript of a session: human input, agent
+    /// output, and TA notifications.
+    Transcript {
+        /// Session ID (full or prefix).
+        id: String,
+        /// Output format: markdown or text.
+        #[arg(long, default_value = "markdown")]
+        format: TranscriptFormat,
+    },
     /// Resume a paused interactive session.
     Resume {
         /// Session ID (full or prefix).
@@ -68,8 +79,8 @@ pub enum SessionCommands {
     },
     /// Show session status summary (v0.6.0).
     Status {
-        /// Workflow session ID to inspect (full UUID or prefix). If omitted, shows
-        /// interactive session summary.
+        /// Interactive or workflow session ID to inspect (full UUID or prefix).
+        /// If omitted, shows interactive session summary.
         id: Option<String>,
         /// Auto-refresh every 2 seconds (requires a workflow session ID).
         #[arg(long)]
@@ -158,6 +169,12 @@ pub enum SessionCommands {
     },
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum TranscriptFormat {
+    Markdown,
+    Text,
+}
+
 pub fn execute(cmd: &SessionCommands, config: &GatewayConfig) -> anyhow::Result<()> {
     let store = InteractiveSessionStore::new(config.interactive_sessions_dir.clone())?;
 
@@ -166,10 +183,15 @@ pub fn execute(cmd: &SessionCommands, config: &GatewayConfig) -> anyhow::Result<
             if *workflow {
                 list_workflow_sessions(config, *all)
             } else {
-                list_sessions(&store, *all)
+                list_sessions(config, &store, *all)
             }
         }
         SessionCommands::Show { id } => show_session(&store, id),
+        SessionCommands::Transcript { id, format } => {
+            let session = find_session(&store, id)?;
+            print!("{}", render_transcript(&session, *format));
+            Ok(())
+        }
         SessionCommands::Resume { id, agent } => {
             let agent = agent.as_deref().unwrap_or("claude-code");
             super::run::execute(
@@ -193,6 +215,10 @@ pub fn execute(cmd: &SessionCommands, config: &GatewayConfig) -> anyhow::Result<
                 None,  // no existing goal id
                 None,  // workflow = default (single-agent)
                 None,  // persona_name = None
+                None,  // env_file
+                None,  // agent_command
+                &[],   // context_files
+                None,  // timeout
             )
         }
         SessionCommands::Pause { id } => pause_session(config, id),
@@ -231,7 +257,11 @@ pub fn execute(cmd: &SessionCommands, config: &GatewayConfig) -> anyhow::Result<
     }
 }
 
-fn list_sessions(store: &InteractiveSessionStore, all: bool) -> anyhow::Result<()> {
+fn list_sessions(
+    config: &GatewayConfig,
+    store: &InteractiveSessionStore,
+    all: bool,
+) -> anyhow::Result<()> {
     let sessions = if all {
         store.list()?
     } else {
@@ -247,19 +277,26 @@ fn list_sessions(store: &InteractiveSessionStore, all: bool) -> anyhow::Result<(
         return Ok(());
     }
 
+    let goal_store = GoalRunStore::new(&config.goals_dir)?;
     println!(
-        "{:<38} {:<38} {:<12} {:<14} {:<10}",
-        "SESSION ID", "GOAL ID", "AGENT", "STATE", "ELAPSED"
+        "{:<38} {:<38} {:<12} {:<14} {:<6} {:<10}",
+        "SESSION ID", "GOAL ID", "AGENT", "STATE", "ALIVE", "ELAPSED"
     );
-    println!("{}", "-".repeat(112));
+    println!("{}", "-".repeat(119));
 
     for s in &sessions {
+        let goal = goal_store.get(s.goal_id).ok().flatten();
+        let alive = match AgentProcess::of(goal.as_ref()) {
+            AgentProcess::Running(_) => "yes",
+            _ => "no",
+        };
         println!(
-            "{:<38} {:<38} {:<12} {:<14} {:<10}",
+            "{:<38} {:<38} {:<12} {:<14} {:<6} {:<10}",
             s.session_id,
             s.goal_id,
             truncate(&s.agent_id, 10),
             s.state.to_string(),
+            alive,
             s.elapsed_display(),
         );
     }
@@ -268,22 +305,114 @@ fn list_sessions(store: &InteractiveSessionStore, all: bool) -> anyhow::Result<(
     Ok(())
 }
 
-fn show_session(store: &InteractiveSessionStore, id: &str) -> anyhow::Result<()> {
-    // Try exact UUID parse first, then prefix match.
-    let session = if let Ok(uuid) = Uuid::parse_str(id) {
-        store.load(uuid)?
-    } else {
-        let all = store.list()?;
-        let matches: Vec<_> = all
-            .into_iter()
-            .filter(|s| s.session_id.to_string().starts_with(id))
-            .collect();
-        match matches.len() {
-            0 => anyhow::bail!("No session found matching '{}'", id),
-            1 => matches.into_iter().next().unwrap(),
-            n => anyhow::bail!("Ambiguous prefix '{}' matches {} sessions", id, n),
+/// The agent process behind an interactive session, from its goal's
+/// recorded PID. `ta run` clears the PID when the agent exits normally.
+#[derive(Debug, PartialEq, Eq)]
+enum AgentProcess {
+    Running(u32),
+    /// A PID is recorded but the process is gone (crash, killed terminal).
+    Gone(u32),
+    NotRunning,
+}
+
+impl AgentProcess {
+    fn of(goal: Option<&ta_goal::GoalRun>) -> Self {
+        match goal.and_then(|g| g.agent_pid) {
+            Some(pid) if super::daemon::is_process_alive(pid) => AgentProcess::Running(pid),
+            Some(pid) => AgentProcess::Gone(pid),
+            None => AgentProcess::NotRunning,
         }
-    };
+    }
+}
+
+impl std::fmt::Display for AgentProcess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AgentProcess::Running(pid) => write!(f, "running (pid {})", pid),
+            AgentProcess::Gone(pid) => write!(f, "not running (pid {} exited uncleanly)", pid),
+            AgentProcess::NotRunning => write!(f, "not running"),
+        }
+    }
+}
+
+/// Detailed status of one interactive session (`ta session status <id>`).
+fn render_interactive_status(
+    session: &InteractiveSession,
+    goal: Option<&ta_goal::GoalRun>,
+) -> String {
+    let mut out = String::new();
+    out.push_str(&format!("Session:   {}\n", session.session_id));
+    match goal {
+        Some(g) => out.push_str(&format!(
+            "Goal:      {} — {} ({})\n",
+            g.goal_run_id, g.title, g.state
+        )),
+        None => out.push_str(&format!(
+            "Goal:      {} (goal record not found)\n",
+            session.goal_id
+        )),
+    }
+    out.push_str(&format!("Agent:     {}\n", session.agent_id));
+    out.push_str(&format!("Channel:   {}\n", session.channel_id));
+    out.push_str(&format!("State:     {}\n", session.state));
+    out.push_str(&format!("Started:   {}\n", session.created_at.to_rfc3339()));
+    let idle = (chrono::Utc::now() - session.updated_at)
+        .num_seconds()
+        .max(0);
+    out.push_str(&format!(
+        "Last activity: {} ({} ago)\n",
+        session.updated_at.to_rfc3339(),
+        if idle < 60 {
+            format!("{}s", idle)
+        } else if idle < 3600 {
+            format!("{}m", idle / 60)
+        } else {
+            format!("{}h {}m", idle / 3600, (idle % 3600) / 60)
+        }
+    ));
+    if let Some(msg) = session.messages.iter().max_by_key(|m| m.timestamp) {
+        out.push_str(&format!(
+            "Last message: {}: {}\n",
+            msg.sender,
+            truncate(msg.content.lines().next().unwrap_or_default(), 60)
+        ));
+    }
+    let process = AgentProcess::of(goal);
+    out.push_str(&format!("Agent process: {}\n", process));
+    if !session.draft_ids.is_empty() {
+        out.push_str(&format!("Drafts:    {}\n", session.draft_ids.len()));
+    }
+    if session.is_alive() && !matches!(process, AgentProcess::Running(_)) {
+        let short = &session.session_id.to_string()[..8];
+        out.push_str(&format!(
+            "\nThe session is {} but its agent is not running. \
+             Resume with `ta session resume {}` or close with `ta session close {}`.\n",
+            session.state, short, short
+        ));
+    }
+    out
+}
+
+/// Load an interactive session by full UUID or unique ID prefix.
+fn find_session(store: &InteractiveSessionStore, id: &str) -> anyhow::Result<InteractiveSession> {
+    // Try exact UUID parse first, then prefix match.
+    if let Ok(uuid) = Uuid::parse_str(id) {
+        return Ok(store.load(uuid)?);
+    }
+    let all = store.list()?;
+    let matches: Vec<_> = all
+        .into_iter()
+        .filter(|s| s.session_id.to_string().starts_with(id))
+        .collect();
+    match matches.len() {
+        0 => anyhow::bail!("No session found matching '{}'", id),
+        1 => Ok(matches.into_iter().next().unwrap()),
+        n => anyhow::bail!("Ambiguous prefix '{}' matches {} sessions", id, n),
+    }
+}
+
+fn show_session(store: &InteractiveSessionStore, id: &str) -> anyhow::Result<()> {
+    let session = find_session(store, id)?;
 
     println!("Session:   {}", session.session_id);
     println!("Goal:      {}", session.goal_id);
@@ -314,6 +443,78 @@ fn show_session(store: &InteractiveSessionStore, id: &str) -> anyhow::Result<()>
     Ok(())
 }
 
+/// Render a session's message log as a transcript, oldest message first.
+///
+/// Messages from `human` and `agent` are the exchange itself; any other
+/// sender (normally `ta-system`) is rendered as a notification.
+fn render_transcript(session: &InteractiveSession, format: TranscriptFormat) -> String {
+    let mut messages: Vec<_> = session.messages.iter().collect();
+    // Stable: messages logged in the same instant keep their logged order.
+    messages.sort_by_key(|m| m.timestamp);
+
+    let mut out = String::new();
+    match format {
+        TranscriptFormat::Markdown => {
+            out.push_str(&format!("# Session {}\n\n", session.session_id));
+            out.push_str(&format!("- **Goal:** {}\n", session.goal_id));
+            out.push_str(&format!("- **Agent:** {}\n", session.agent_id));
+            out.push_str(&format!("- **Channel:** {}\n", session.channel_id));
+            out.push_str(&format!("- **State:** {}\n", session.state));
+            out.push_str(&format!(
+                "- **Started:** {}\n",
+                session.created_at.to_rfc3339()
+            ));
+            if messages.is_empty() {
+                out.push_str("\n_No messages recorded._\n");
+            }
+            for msg in messages {
+                let time = msg.timestamp.format("%Y-%m-%d %H:%M:%S UTC");
+                let body = msg.content.trim_end();
+                match msg.sender.as_str() {
+                    "human" => {
+                        out.push_str(&format!("\n### Human — {}\n\n", time));
+                        for line in body.lines() {
+                            out.push_str(format!("> {}", line).trim_end());
+                            out.push('\n');
+                        }
+                    }
+                    "agent" => {
+                        // Agent output is verbatim terminal text, not markdown.
+                        let fence = if body.contains("```") { "~~~~" } else { "```" };
+                        out.push_str(&format!(
+                            "\n### Agent — {}\n\n{}text\n{}\n{}\n",
+                            time, fence, body, fence
+                        ));
+                    }
+                    sender => {
+                        out.push_str(&format!("\n*{} — {}:* {}\n", time, sender, body));
+                    }
+                }
+            }
+        }
+        TranscriptFormat::Text => {
+            out.push_str(&format!(
+                "Session {} (goal {}, agent {}, {})\n",
+                session.session_id, session.goal_id, session.agent_id, session.state
+            ));
+            for msg in messages {
+                let time = msg.timestamp.format("%Y-%m-%d %H:%M:%S");
+                let mut lines = msg.content.trim_end().lines();
+                out.push_str(&format!(
+                    "[{}] {}: {}\n",
+                    time,
+                    msg.sender,
+                    lines.next().unwrap_or_default()
+                ));
+                for line in lines {
+                    out.push_str(&format!("    {}\n", line));
+                }
+            }
+        }
+    }
+    out
+}
+
 fn pause_session(config: &GatewayConfig, id: &str) -> anyhow::Result<()> {
     let sessions_dir = config.workspace_root.join(".ta/sessions");
     let manager = SessionManager::new(sessions_dir)?;
@@ -341,8 +542,18 @@ fn abort_session(config: &GatewayConfig, id: &str, reason: Option<&str>) -> anyh
 }
 
 fn session_status(config: &GatewayConfig, id: Option<&str>, live: bool) -> anyhow::Result<()> {
-    // If an ID is provided, show the workflow session status dashboard.
+    // An interactive session ID shows that session; any other ID is looked
+    // up as a workflow session and shows its status dashboard.
     if let Some(id) = id {
+        let store = InteractiveSessionStore::new(config.interactive_sessions_dir.clone())?;
+        if let Ok(session) = find_session(&store, id) {
+            if live {
+                anyhow::bail!("--live applies to workflow sessions only");
+            }
+            let goal = GoalRunStore::new(&config.goals_dir)?.get(session.goal_id)?;
+            print!("{}", render_interactive_status(&session, goal.as_ref()));
+            return Ok(());
+        }
         return workflow_session_status(config, id, live);
     }
 
@@ -1423,7 +1634,6 @@ fn truncate(s: &str, max: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ta_changeset::InteractiveSession;
     use tempfile::TempDir;
 
     #[test]
@@ -1437,6 +1647,77 @@ mod tests {
         assert!(sessions.is_empty());
     }
 
+    #[test]
+    fn transcript_renders_messages_in_order() {
+        let mut session = InteractiveSession::new(
+            Uuid::new_v4(),
+            "cli:tty0".to_string(),
+            "claude-code".to_string(),
+        );
+        session.log_message("agent", "Reading src/auth.rs");
+        session.log_message("human", "Focus on the login path\nand skip OAuth");
+        session.log_message("ta-system", "Agent exited, draft built");
+        // An out-of-order entry (e.g. merged from another channel) sorts by time.
+        session.messages[0].timestamp =
+            session.messages[2].timestamp + chrono::Duration::seconds(1);
+
+        let md = render_transcript(&session, TranscriptFormat::Markdown);
+        let human = md.find("### Human").unwrap();
+        let system = md.find("ta-system:").unwrap();
+        let agent = md.find("### Agent").unwrap();
+        assert!(human < system && system < agent, "{}", md);
+        assert!(md.contains("> Focus on the login path\n> and skip OAuth\n"));
+        assert!(md.contains("```text\nReading src/auth.rs\n```"));
+
+        let text = render_transcript(&session, TranscriptFormat::Text);
+        assert!(text.contains("] human: Focus on the login path\n    and skip OAuth\n"));
+        assert!(text.trim_end().ends_with("agent: Reading src/auth.rs"));
+    }
+
+    #[test]
+    fn interactive_status_reports_goal_and_agent_liveness() {
+        let temp = TempDir::new().unwrap();
+        let mut goal = ta_goal::GoalRun::new(
+            "Pair on auth",
+            "",
+            "claude-code",
+            temp.path().join("staging"),
+            temp.path().join("store"),
+        );
+        let mut session = InteractiveSession::new(
+            goal.goal_run_id,
+            "cli:tty0".to_string(),
+            "claude-code".to_string(),
+        );
+        session.log_message("human", "Focus on the login path\nand skip OAuth");
+
+        goal.agent_pid = Some(std::process::id());
+        let status = render_interactive_status(&session, Some(&goal));
+        assert!(status.contains("— Pair on auth ("), "{}", status);
+        assert!(status.contains("Last message: human: Focus on the login path\n"));
+        assert!(status.contains(&format!(
+            "Agent process: running (pid {})",
+            std::process::id()
+        )));
+        assert!(!status.contains("not running"), "{}", status);
+
+        goal.agent_pid = None;
+        let status = render_interactive_status(&session, Some(&goal));
+        assert!(
+            status.contains("Agent process: not running\n"),
+            "{}",
+            status
+        );
+        assert!(
+            status.contains("Resume with `ta session resume"),
+            "{}",
+            status
+        );
+
+        let status = render_interactive_status(&session, None);
+        assert!(status.contains("(goal record not found)"), "{}", status);
+    }
+
     #[test]
     fn show_session_by_prefix() {
         let temp = TempDir::new().unwrap();
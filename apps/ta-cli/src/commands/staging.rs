@@ -144,12 +144,7 @@ fn inspect(config: &GatewayConfig, goal_id_hint: Option<&str>) -> anyhow::Result
     }
 
     // Diff summary: how many files changed.
-    let overlay = OverlayWorkspace::open(
-        goal.goal_run_id.to_string(),
-        &source_dir,
-        &staging_dir,
-        excludes,
-    );
+    let overlay = super::draft::open_goal_overlay(&goal, &source_dir, excludes)?;
     match overlay.list_changes() {
         Ok(changes) => {
             let modified = changes.iter().filter(|(_, k)| *k == "modified").count();
@@ -47,6 +47,10 @@ pub fn execute(config: &GatewayConfig, deep: bool) -> anyhow::Result<()> {
         }
     };
 
+    if let Some(ref profile) = config.profile {
+        println!("│  Profile: {}", profile);
+    }
+
     if let Some(ref phase) = next_phase {
         println!("│  Next phase: {}", phase);
     }
@@ -92,8 +96,8 @@ pub fn execute(config: &GatewayConfig, deep: bool) -> anyhow::Result<()> {
     let urgent_goals: Vec<_> = all_goals
         .iter()
         .filter(|g| {
-            // Stuck: running but updated >2h ago.
-            let stale = (now - g.updated_at).num_hours() >= 2;
+            // Stuck: running with no heartbeat or update for >2h.
+            let stale = (now - g.last_activity()).num_hours() >= 2;
             matches!(g.state, GoalRunState::Running | GoalRunState::Configured) && stale
         })
         .collect();
@@ -437,11 +441,11 @@ fn deep_status(config: &GatewayConfig) -> anyhow::Result<()> {
     // Disk usage
     println!("│");
     println!("│  Disk usage:");
-    let staging_dir = config.workspace_root.join(".ta/staging");
+    let staging_dir = &config.staging_dir;
     if staging_dir.exists() {
         let mut total_size = 0u64;
         let mut count = 0u32;
-        if let Ok(entries) = std::fs::read_dir(&staging_dir) {
+        if let Ok(entries) = std::fs::read_dir(staging_dir) {
             for entry in entries.flatten() {
                 if entry.path().is_dir() {
                     total_size += walkdir_size(&entry.path());
@@ -711,6 +715,7 @@ mod tests {
             title: title.to_string(),
             objective: String::new(),
             agent_id: String::new(),
+            agent_command: None,
             state: ta_goal::GoalRunState::Failed {
                 reason: "test".to_string(),
             },
@@ -718,8 +723,10 @@ mod tests {
             workspace_path: std::path::PathBuf::new(),
             store_path: std::path::PathBuf::new(),
             source_dir: None,
+            base_ref: None,
             plan_phase: None,
             parent_goal_id: None,
+            cloned_from: None,
             source_snapshot: None,
             is_macro: false,
             parent_macro_id: None,
@@ -732,17 +739,21 @@ mod tests {
             project_name: None,
             agent_pid: None,
             heartbeat_required: false,
+            last_heartbeat: None,
             pr_url: None,
             pr_package_id: None,
             progress_note: None,
             vcs_isolation: None,
             initiated_by: None,
             memory_entries_created: vec![],
+            readonly: false,
+            source_roots: Vec::new(),
             created_at: Utc::now(),
             updated_at: Utc::now(),
             input_tokens: 0,
             output_tokens: 0,
             agent_model: String::new(),
+            timeout_secs: None,
         };
         // Should match: this is a system reviewer goal.
         assert!(is_terminal_reviewer_goal(&make_goal(
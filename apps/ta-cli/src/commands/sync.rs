@@ -45,9 +45,9 @@ pub fn execute(config: &GatewayConfig) -> anyhow::Result<()> {
     );
 
     // Warn about active staging workspaces.
-    let staging_dir = project_root.join(".ta/staging");
+    let staging_dir = &config.staging_dir;
     if staging_dir.exists() {
-        let active_count = std::fs::read_dir(&staging_dir)
+        let active_count = std::fs::read_dir(staging_dir)
             .map(|entries| entries.filter_map(|e| e.ok()).count())
             .unwrap_or(0);
         if active_count > 0 {
@@ -0,0 +1,217 @@
+// workspace.rs — Inspect how TA sees a source tree.
+//
+// Files matching an exclude pattern (.taignore, the built-in defaults, or the
+// VCS adapter's metadata dirs) are never staged or diffed, so they silently
+// drop out of drafts. `ta workspace excludes` lists what was filtered and the
+// pattern responsible.
+
+use std::path::{Path, PathBuf};
+
+use clap::Subcommand;
+use ta_goal::GoalRunStore;
+use ta_mcp_gateway::GatewayConfig;
+use ta_workspace::ExcludePatterns;
+
+#[derive(Subcommand)]
+pub enum WorkspaceCommands {
+    /// List every excluded file or directory and the pattern that matched it.
+    Excludes {
+        /// Source tree to check (defaults to the project root).
+        #[arg(long, conflicts_with = "goal")]
+        source: Option<PathBuf>,
+        /// Check a goal's source and its staging workspace instead.
+        #[arg(long)]
+        goal: Option<String>,
+    },
+}
+
+pub fn execute(cmd: &WorkspaceCommands, config: &GatewayConfig) -> anyhow::Result<()> {
+    match cmd {
+        WorkspaceCommands::Excludes { source, goal } => {
+            show_excludes(config, source.as_deref(), goal.as_deref())
+        }
+    }
+}
+
+/// A path skipped by the exclude patterns. Excluded directories are not
+/// descended into, so one entry covers everything beneath it.
+#[derive(Debug, PartialEq, Eq)]
+struct ExcludedEntry {
+    /// Path relative to the scanned root, `/`-separated.
+    path: String,
+    /// Files under an excluded directory; `None` for a single file.
+    dir_files: Option<usize>,
+    /// The pattern that matched.
+    pattern: String,
+}
+
+fn show_excludes(
+    config: &GatewayConfig,
+    source: Option<&Path>,
+    goal: Option<&str>,
+) -> anyhow::Result<()> {
+    let (source_dir, staging_dir) = match goal {
+        Some(id) => {
+            let store = GoalRunStore::new(&config.goals_dir)?;
+            let goal_id = super::goal::resolve_goal_id(id, &store)?;
+            let goal = store
+                .get(goal_id)?
+                .ok_or_else(|| anyhow::anyhow!("Goal {} not found", goal_id))?;
+            let source_dir = goal
+                .source_dir
+                .clone()
+                .unwrap_or_else(|| config.workspace_root.clone());
+            (source_dir, Some(goal.workspace_path))
+        }
+        None => (
+            source
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| config.workspace_root.clone()),
+            None,
+        ),
+    };
+    if !source_dir.is_dir() {
+        anyhow::bail!("Source {} is not a directory", source_dir.display());
+    }
+
+    let excludes = super::draft::load_excludes_with_adapter(&source_dir);
+    let taignore = ExcludePatterns::load(&source_dir);
+    println!(
+        "Exclude patterns for {}: {} active",
+        source_dir.display(),
+        excludes.patterns().len()
+    );
+
+    let mut trees = vec![("Source", source_dir.clone())];
+    if let Some(staging) = staging_dir.filter(|p| p.is_dir()) {
+        trees.push(("Staging", staging));
+    }
+    for (label, root) in trees {
+        let entries = list_excluded(&root, &excludes)?;
+        println!();
+        println!("{} {}: {} excluded", label, root.display(), entries.len());
+        let width = entries.iter().map(|e| e.path.len() + 1).max().unwrap_or(0);
+        for entry in &entries {
+            let (path, size) = match entry.dir_files {
+                Some(n) => (format!("{}/", entry.path), format!(" ({} file(s))", n)),
+                None => (entry.path.clone(), String::new()),
+            };
+            println!(
+                "  {:<width$}  {} [{}]{}",
+                path,
+                entry.pattern,
+                pattern_origin(&entry.pattern, &excludes, &taignore),
+                size,
+                width = width
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Walk `root` the way staging does and collect every excluded entry.
+fn list_excluded(root: &Path, excludes: &ExcludePatterns) -> std::io::Result<Vec<ExcludedEntry>> {
+    let mut entries = Vec::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let entry = entry?;
+            let file_type = entry.file_type()?;
+            let name = entry.file_name().to_string_lossy().into_owned();
+            let Some(pattern) = excludes.matching_pattern(&name) else {
+                if file_type.is_dir() {
+                    pending.push(entry.path());
+                }
+                continue;
+            };
+            let path = entry
+                .path()
+                .strip_prefix(root)
+                .map(|rel| rel.to_string_lossy().replace('\\', "/"))
+                .unwrap_or(name);
+            entries.push(ExcludedEntry {
+                path,
+                dir_files: file_type.is_dir().then(|| count_files(&entry.path())),
+                pattern: pattern.to_string(),
+            });
+        }
+    }
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(entries)
+}
+
+fn count_files(dir: &Path) -> usize {
+    let mut count = 0;
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        let Ok(read) = std::fs::read_dir(&dir) else {
+            continue;
+        };
+        for entry in read.flatten() {
+            match entry.file_type() {
+                Ok(t) if t.is_dir() => pending.push(entry.path()),
+                Ok(_) => count += 1,
+                Err(_) => {}
+            }
+        }
+    }
+    count
+}
+
+/// Where an active pattern comes from. `taignore` is `ExcludePatterns::load`,
+/// i.e. the defaults plus `.taignore`, without the VCS adapter's additions.
+fn pattern_origin(
+    pattern: &str,
+    excludes: &ExcludePatterns,
+    taignore: &ExcludePatterns,
+) -> &'static str {
+    let has = |set: &ExcludePatterns| set.patterns().iter().any(|p| p == pattern);
+    if !has(excludes) {
+        "built-in"
+    } else if has(&ExcludePatterns::defaults()) {
+        "default"
+    } else if has(taignore) {
+        ".taignore"
+    } else {
+        "VCS adapter"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn list_excluded_reports_matching_pattern_and_origin() {
+        let project = TempDir::new().unwrap();
+        let root = project.path();
+        std::fs::write(root.join(".taignore"), "*.log\n").unwrap();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(root.join("src/main.rs"), "fn main() {}\n").unwrap();
+        std::fs::write(root.join("src/debug.log"), "noise\n").unwrap();
+        std::fs::create_dir_all(root.join("target/debug")).unwrap();
+        std::fs::write(root.join("target/debug/a"), "").unwrap();
+        std::fs::write(root.join("target/debug/b"), "").unwrap();
+        std::fs::create_dir_all(root.join(".ta")).unwrap();
+
+        let excludes = ExcludePatterns::load(root);
+        let entries = list_excluded(root, &excludes).unwrap();
+        let summary: Vec<(&str, Option<usize>, &str)> = entries
+            .iter()
+            .map(|e| (e.path.as_str(), e.dir_files, e.pattern.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (".ta", Some(0), ".ta"),
+                ("src/debug.log", None, "*.log"),
+                ("target", Some(2), "target/"),
+            ]
+        );
+
+        assert_eq!(pattern_origin("*.log", &excludes, &excludes), ".taignore");
+        assert_eq!(pattern_origin("target/", &excludes, &excludes), "default");
+        assert_eq!(pattern_origin(".ta", &excludes, &excludes), "built-in");
+    }
+}
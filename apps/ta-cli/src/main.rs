@@ -45,11 +45,23 @@ struct Cli {
     #[arg(long, global = true)]
     startup_profile: bool,
 
+    /// Use an isolated TA context: goals, drafts, staging, and the audit log
+    /// live under .ta/profiles/<name>/. Defaults to $TA_PROFILE.
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
+
+    /// Air-gapped mode: no VCS detection, git commands, or network submit.
+    /// `ta draft apply` becomes a plain filesystem copy. Same as
+    /// `[submit] offline = true` in .ta/workflow.toml or TA_OFFLINE=1.
+    #[arg(long, global = true)]
+    offline: bool,
+
     #[command(subcommand)]
     command: Option<Commands>,
 }
 
 #[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
 enum Commands {
     // ── DASHBOARD ───────────────────────────────────────────────────────────
     /// Project-wide status dashboard: active agents, pending drafts, next phase.
@@ -76,6 +88,12 @@ enum Commands {
         /// Agent system to use (claude-code, codex, etc.).
         #[arg(long, default_value = "claude-code")]
         agent: String,
+        /// Launch an arbitrary agent binary instead of a named agent system,
+        /// e.g. --agent-command "my-agent --flag". Runs in the staging
+        /// workspace with context files injected; `{prompt}` in the command
+        /// is replaced with the goal prompt. Follow-ups reuse the command.
+        #[arg(long, value_name = "COMMAND")]
+        agent_command: Option<String>,
         /// Source directory to overlay (defaults to project root).
         #[arg(long)]
         source: Option<PathBuf>,
@@ -97,6 +115,10 @@ enum Commands {
         /// Read objective from a file instead of --objective.
         #[arg(long)]
         objective_file: Option<PathBuf>,
+        /// Fail the goal if it makes no progress for this many seconds
+        /// (overrides `[gc] goal_timeout_secs`).
+        #[arg(long)]
+        timeout: Option<u64>,
         /// Don't launch the agent — just set up the workspace.
         #[arg(long)]
         no_launch: bool,
@@ -121,6 +143,16 @@ enum Commands {
         /// Agent persona to apply (name of .ta/personas/<name>.toml).
         #[arg(long)]
         persona: Option<String>,
+        /// Launch the agent with only the `KEY=VALUE` lines in this file plus a
+        /// minimal base set (PATH, HOME, ...). The rest of the inherited
+        /// environment is scrubbed.
+        #[arg(long)]
+        env_file: Option<PathBuf>,
+        /// Reference material for the agent (design docs, API specs, ...).
+        /// Copied into the staging workspace's `.ta/context/` and listed in the
+        /// injected agent instructions. Repeatable.
+        #[arg(long = "context-file", value_name = "PATH", conflicts_with_all = ["resume", "resume_staging"])]
+        context_file: Vec<PathBuf>,
         /// Suppress streaming agent output; still print completion/failure summary.
         /// Default for daemon-dispatched and channel-dispatched goals.
         /// Inverse: omit --quiet (current interactive default) shows full output.
@@ -179,6 +211,37 @@ enum Commands {
         /// `ta onboard`. Pass this flag to bypass that check in CI or automation.
         #[arg(long)]
         skip_onboard_check: bool,
+        /// Adopt an existing work tree: start a goal whose staging mirrors
+        /// this directory, so `ta draft build` diffs it against --source.
+        /// The directory must be a copy of the source. No agent is launched.
+        /// With --goal-id, adopts into a goal recorded by --snapshot-only.
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["resume", "follow_up", "phases", "sub_goals"])]
+        resume_staging: Option<PathBuf>,
+        /// Record a goal and a snapshot of the source tree without creating
+        /// staging or launching an agent. Bring the work in later with
+        /// `--resume-staging DIR --goal-id <id>`; conflict detection at apply
+        /// compares against this snapshot.
+        #[arg(long, conflicts_with_all = ["resume", "follow_up", "follow_up_draft", "follow_up_goal", "goal_id", "objective_file", "no_launch", "macro_goal", "phases", "sub_goals", "resume_staging", "readonly"])]
+        snapshot_only: bool,
+        /// Start a goal from an existing patch instead of an agent: the
+        /// patch is applied to a fresh staging copy of --source, ready for
+        /// `ta draft build`. A patch that doesn't apply cleanly is rejected.
+        #[arg(long, value_name = "PATCH", conflicts_with_all = ["resume", "follow_up", "follow_up_draft", "follow_up_goal", "goal_id", "objective_file", "no_launch", "macro_goal", "phases", "sub_goals", "resume_staging", "snapshot_only", "readonly"])]
+        diff_only: Option<PathBuf>,
+        /// Analysis-only goal: the agent may read and list files but every
+        /// write is denied by policy. Its deliverable is a report written to
+        /// TA_REPORT.md, which `ta draft build` packages for review.
+        #[arg(long, conflicts_with_all = ["resume", "follow_up", "follow_up_draft", "follow_up_goal", "goal_id", "objective_file", "no_launch", "macro_goal", "phases", "sub_goals", "resume_staging"])]
+        readonly: bool,
+        /// Run every pending PLAN.md phase in order: start a goal, launch the
+        /// agent, build the draft, and pause for review before the next phase.
+        /// Progress is saved in .ta/plan-all.json; re-run to resume.
+        #[arg(long, conflicts_with_all = ["title", "phase", "resume", "follow_up", "follow_up_draft", "follow_up_goal", "goal_id", "objective_file", "no_launch", "macro_goal", "phases", "sub_goals", "resume_staging", "readonly", "context_file"])]
+        plan_all: bool,
+        /// With --plan-all: approve and apply drafts that pass the
+        /// auto-approve policy (.ta/policy.yaml) without pausing.
+        #[arg(long, requires = "plan_all")]
+        auto_apply: bool,
     },
     /// Review and manage draft packages.
     Draft {
@@ -400,6 +463,17 @@ enum Commands {
         #[command(subcommand)]
         command: commands::events::EventsCommands,
     },
+    /// Check the external diff handlers configured in .ta/diff-handlers.toml.
+    #[command(name = "diff-handlers")]
+    DiffHandlers {
+        #[command(subcommand)]
+        command: commands::diff_handlers::DiffHandlersCommands,
+    },
+    /// Inspect how TA sees the source tree (e.g. which files are excluded).
+    Workspace {
+        #[command(subcommand)]
+        command: commands::workspace::WorkspaceCommands,
+    },
     /// Manage approval tokens for non-interactive workflows.
     Token {
         #[command(subcommand)]
@@ -575,8 +649,23 @@ enum Commands {
         #[command(subcommand)]
         command: commands::config::ConfigCommands,
     },
-    /// Start the MCP server on stdio.
-    Serve,
+    /// Start the MCP server on stdio, or over HTTP + SSE with `--http`.
+    Serve {
+        /// Serve MCP over HTTP + SSE on this address instead of stdio
+        /// (default 127.0.0.1:7800; a bare port binds to localhost).
+        /// The endpoint is `/mcp`.
+        #[arg(
+            long,
+            value_name = "ADDR",
+            num_args = 0..=1,
+            default_missing_value = ta_mcp_gateway::http::DEFAULT_HTTP_ADDR
+        )]
+        http: Option<String>,
+        /// Bearer token clients must send with `--http`. Required for
+        /// non-loopback addresses. Falls back to `TA_SERVE_TOKEN`.
+        #[arg(long, requires = "http")]
+        token: Option<String>,
+    },
     /// Build the project using the configured build adapter.
     ///
     /// Auto-detects the build system (Cargo, npm, Make) or uses the adapter
@@ -819,6 +908,28 @@ fn main() -> anyhow::Result<()> {
         commands::terms::ensure_accepted()?;
     }
 
+    // Export the profile so spawned `ta` processes and the daemon share it.
+    if let Some(profile) = &cli.profile {
+        std::env::set_var(ta_mcp_gateway::config::PROFILE_ENV, profile);
+    }
+    // Likewise for offline mode, so spawned agents, `ta` processes, and the
+    // daemon never reach for git or the network either.
+    if cli.offline {
+        std::env::set_var(ta_submit::OFFLINE_ENV, "1");
+    }
+    if let Ok(profile) = std::env::var(ta_mcp_gateway::config::PROFILE_ENV) {
+        if !profile.is_empty() && !GatewayConfig::is_valid_profile_name(&profile) {
+            anyhow::bail!(
+                "Invalid profile name '{}': use letters, digits, '-' and '_' only",
+                profile
+            );
+        }
+    }
+
+    // One correlation ID per command ties together every audit event it
+    // records (`ta audit query --correlation <id>`).
+    ta_audit::set_correlation_id(Some(uuid::Uuid::new_v4()));
+
     let project_root = cli.project_root.canonicalize().unwrap_or(cli.project_root);
     let t_project_root = startup_begin.elapsed();
     let config = GatewayConfig::for_project(&project_root);
@@ -843,6 +954,7 @@ fn main() -> anyhow::Result<()> {
 
     // Startup health check: warn about stale drafts (v0.3.6).
     commands::draft::check_stale_drafts(&config);
+    commands::goal::check_timed_out_goals(&config);
     let t_health = startup_begin.elapsed();
 
     if cli.startup_profile {
@@ -882,6 +994,7 @@ fn main() -> anyhow::Result<()> {
         Commands::Run {
             title,
             agent,
+            agent_command,
             source,
             objective,
             phase,
@@ -889,6 +1002,7 @@ fn main() -> anyhow::Result<()> {
             follow_up_draft,
             follow_up_goal,
             objective_file,
+            timeout,
             no_launch,
             interactive,
             macro_goal,
@@ -896,6 +1010,8 @@ fn main() -> anyhow::Result<()> {
             headless,
             skip_verify,
             persona,
+            env_file,
+            context_file,
             quiet,
             goal_id,
             workflow,
@@ -904,15 +1020,105 @@ fn main() -> anyhow::Result<()> {
             sub_goals,
             integrate,
             skip_onboard_check,
+            resume_staging,
+            snapshot_only,
+            diff_only,
+            readonly,
+            plan_all,
+            auto_apply,
         } => {
             // First-run gate: warn if provider is not yet configured.
             commands::onboard::check_provider_configured(*skip_onboard_check)?;
 
+            if *plan_all {
+                return commands::run::execute_plan_all(
+                    &config,
+                    agent,
+                    agent_command.as_deref(),
+                    *auto_apply,
+                    *quiet,
+                );
+            }
+
             // Phase-aware title resolution: if the positional title looks like
             // a phase ID (e.g., "v0.9.8.1", "0.9.8.1", "phase 0.9.8.1"),
             // look it up in PLAN.md and use the phase title + set --phase.
             let (resolved_title, resolved_phase) = resolve_phase_title(title, phase, &project_root);
 
+            if *readonly {
+                let run_title = resolved_title
+                    .as_deref()
+                    .ok_or_else(|| anyhow::anyhow!("ta run --readonly needs a goal title"))?;
+                return commands::run::execute_readonly(
+                    &config,
+                    run_title,
+                    agent,
+                    source.as_deref(),
+                    objective,
+                    resolved_phase.as_deref(),
+                    *interactive,
+                    *headless,
+                    *quiet,
+                    persona.as_deref(),
+                    env_file.as_deref(),
+                    agent_command.as_deref(),
+                    context_file,
+                    *timeout,
+                );
+            }
+
+            if *snapshot_only {
+                let run_title = resolved_title
+                    .as_deref()
+                    .ok_or_else(|| anyhow::anyhow!("ta run --snapshot-only needs a goal title"))?;
+                return commands::run::execute_snapshot_only(
+                    &config,
+                    run_title,
+                    agent,
+                    source.as_deref(),
+                    objective,
+                    resolved_phase.as_deref(),
+                );
+            }
+
+            if let Some(patch) = diff_only {
+                return commands::run::execute_diff_only(
+                    &config,
+                    resolved_title.as_deref(),
+                    agent,
+                    source.as_deref(),
+                    objective,
+                    resolved_phase.as_deref(),
+                    patch,
+                );
+            }
+
+            if let Some(dir) = resume_staging {
+                return commands::run::execute_resume_staging(
+                    &config,
+                    resolved_title.as_deref(),
+                    agent,
+                    source.as_deref(),
+                    objective,
+                    resolved_phase.as_deref(),
+                    dir,
+                    goal_id.as_deref(),
+                );
+            }
+
+            if agent_command.is_some() && (phases.is_some() || !sub_goals.is_empty()) {
+                anyhow::bail!(
+                    "--agent-command is only supported for single-agent runs \
+                     (not with --phases or --sub-goals)"
+                );
+            }
+            if !context_file.is_empty() && (phases.is_some() || !sub_goals.is_empty()) {
+                anyhow::bail!(
+                    "--context-file is only supported for single-agent runs \
+                     (not with --phases or --sub-goals)"
+                );
+            }
+
             // serial-phases: dispatch to execute_serial_phases when --phases is provided.
             if workflow.as_deref() == Some("serial-phases") || phases.is_some() {
                 if let Some(phase_list) = phases {
@@ -955,9 +1161,15 @@ fn main() -> anyhow::Result<()> {
                 goal_id.as_deref(),
                 workflow.as_deref(),
                 persona.as_deref(),
+                env_file.as_deref(),
+                agent_command.as_deref(),
+                context_file,
+                *timeout,
             )
         }
         Commands::Events { command } => commands::events::execute(command, &config),
+        Commands::DiffHandlers { command } => commands::diff_handlers::execute(command, &config),
+        Commands::Workspace { command } => commands::workspace::execute(command, &config),
         Commands::Token { command } => commands::token::execute(command, &config),
         Commands::Dev {
             agent,
@@ -1058,12 +1270,12 @@ fn main() -> anyhow::Result<()> {
         Commands::Runbook { command } => commands::runbook::execute(command, &config),
         Commands::Connector { command } => commands::connector::execute(command, &config),
         Commands::Webhook { command } => commands::webhook::execute(command, &config),
-        Commands::Serve => {
+        Commands::Serve { http, token } => {
             // First-run gate: warn if provider is not yet configured.
             // TA_SKIP_ONBOARD_CHECK=1 bypasses in CI.
             let skip = std::env::var("TA_SKIP_ONBOARD_CHECK").is_ok_and(|v| v == "1");
             commands::onboard::check_provider_configured(skip)?;
-            commands::serve::execute(&project_root)
+            commands::serve::execute(&project_root, http.as_deref(), token.clone())
         }
         Commands::Build { test } => commands::build::execute(&config, *test),
         Commands::Sync => commands::sync::execute(&config),
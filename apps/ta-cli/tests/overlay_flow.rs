@@ -163,14 +163,7 @@ fn overlay_flow_goal_to_apply() {
         changes.len()
     );
 
-    let change_paths: Vec<String> = changes
-        .iter()
-        .map(|c| match c {
-            ta_workspace::overlay::OverlayChange::Modified { path, .. }
-            | ta_workspace::overlay::OverlayChange::Created { path, .. }
-            | ta_workspace::overlay::OverlayChange::Deleted { path } => path.clone(),
-        })
-        .collect();
+    let change_paths: Vec<String> = changes.iter().map(|c| c.path().to_string()).collect();
 
     assert!(change_paths.contains(&"src/lib.rs".to_string()));
     assert!(change_paths.contains(&"src/main.rs".to_string()));
@@ -184,7 +177,8 @@ fn overlay_flow_goal_to_apply() {
     let mut artifacts = Vec::new();
     for change in &changes {
         match change {
-            ta_workspace::overlay::OverlayChange::Modified { path, .. } => {
+            ta_workspace::overlay::OverlayChange::Modified { path, .. }
+            | ta_workspace::overlay::OverlayChange::ModeChanged { path, .. } => {
                 artifacts.push((path.clone(), ChangeType::Modify));
             }
             ta_workspace::overlay::OverlayChange::Created { path, .. } => {
@@ -193,6 +187,9 @@ fn overlay_flow_goal_to_apply() {
             ta_workspace::overlay::OverlayChange::Deleted { path } => {
                 artifacts.push((path.clone(), ChangeType::Delete));
             }
+            ta_workspace::overlay::OverlayChange::Renamed { to, .. } => {
+                artifacts.push((to.clone(), ChangeType::Rename));
+            }
         }
     }
 
@@ -0,0 +1,370 @@
+// checkpoint.rs — Trusted checkpoints for incremental audit verification.
+//
+// Re-walking a large audit log from the first event on every verify is
+// wasteful. A checkpoint records where the chain stood after a full,
+// successful verification: the hash of the head event, how many events were
+// covered, and where the head event sits on disk (segment file name, line
+// and byte offset). The checkpoint is signed by an `AttestationBackend` so a
+// forged checkpoint cannot vouch for a rewritten log.
+//
+// Incremental verification seeks straight to the recorded head event,
+// confirms it still hashes to `head_hash`, and then walks only the events
+// appended after it — continuing into newer rotated segments and the active
+// file. If the active file was rotated since the checkpoint, the head event
+// is found at the same offset in one of the rotated segments.
+//
+// Checkpoints are appended to `<stem>.checkpoints.jsonl` next to the log;
+// the last line is the current trusted checkpoint.
+
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::attestation::{AttestationBackend, AttestationRecord};
+use crate::error::AuditError;
+use crate::event::AuditEvent;
+use crate::hasher;
+use crate::log::AuditLog;
+
+/// A signed record of the audit chain head at a point where the whole log
+/// was verified.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditCheckpoint {
+    pub created_at: DateTime<Utc>,
+    /// Hash of the head event's raw JSON line (what the next event's
+    /// `previous_hash` must equal).
+    pub head_hash: String,
+    /// Events covered by the checkpoint, across all segments.
+    pub events: usize,
+    /// File name of the segment holding the head event when recorded.
+    pub segment: String,
+    /// 1-based line number of the head event within that segment.
+    pub line: usize,
+    /// Byte offset of the head event's line within that segment.
+    pub offset: u64,
+    /// Signature over the checkpoint with `attestation = null`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attestation: Option<AttestationRecord>,
+}
+
+/// Result of verifying the events appended after a checkpoint.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckpointVerifyReport {
+    /// Events covered by the checkpoint (not re-verified).
+    pub checkpoint_events: usize,
+    /// Events appended after the checkpoint and verified now.
+    pub new_events: usize,
+    /// Segment in which the checkpoint head event was found.
+    pub anchor_segment: PathBuf,
+}
+
+impl AuditCheckpoint {
+    /// Verify the full chain of the log at `log_path` and capture a checkpoint
+    /// at its head. Returns `Ok(None)` when the log has no events.
+    pub fn capture(log_path: impl AsRef<Path>) -> Result<Option<Self>, AuditError> {
+        let log_path = log_path.as_ref();
+        let report = AuditLog::verify_segments(log_path)?;
+
+        for file in report.segments.iter().rev() {
+            if let Some((line, offset, text)) = last_line(file)? {
+                return Ok(Some(Self {
+                    created_at: Utc::now(),
+                    head_hash: hasher::hash_str(&text),
+                    events: report.events,
+                    segment: file_name(file),
+                    line,
+                    offset,
+                    attestation: None,
+                }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Path of the checkpoint file for the log at `log_path`
+    /// (`.ta/audit.jsonl` → `.ta/audit.checkpoints.jsonl`).
+    pub fn path_for(log_path: &Path) -> PathBuf {
+        let stem = log_path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| "audit".to_string());
+        log_path.with_file_name(format!("{}.checkpoints.jsonl", stem))
+    }
+
+    fn canonical_bytes(&self) -> Result<Vec<u8>, AuditError> {
+        let mut unsigned = self.clone();
+        unsigned.attestation = None;
+        Ok(serde_json::to_vec(&unsigned)?)
+    }
+
+    /// Sign the checkpoint with `backend`, replacing any existing signature.
+    pub fn sign(&mut self, backend: &dyn AttestationBackend) -> Result<(), AuditError> {
+        let payload = self.canonical_bytes()?;
+        let record = backend
+            .sign(&payload)
+            .map_err(|e| AuditError::AttestationFailed(e.to_string()))?;
+        self.attestation = Some(record);
+        Ok(())
+    }
+
+    /// True when the checkpoint carries a valid signature from `backend`.
+    pub fn verify_signature(&self, backend: &dyn AttestationBackend) -> bool {
+        let (Some(record), Ok(payload)) = (&self.attestation, self.canonical_bytes()) else {
+            return false;
+        };
+        backend.verify(&payload, record).unwrap_or(false)
+    }
+
+    /// Append this checkpoint to the checkpoint file for `log_path`.
+    pub fn append(&self, log_path: &Path) -> Result<(), AuditError> {
+        let path = Self::path_for(log_path);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|source| AuditError::OpenFailed { path, source })?;
+        writeln!(file, "{}", serde_json::to_string(self)?)?;
+        Ok(())
+    }
+
+    /// The most recent checkpoint recorded for `log_path`, if any.
+    pub fn latest(log_path: &Path) -> Result<Option<Self>, AuditError> {
+        let path = Self::path_for(log_path);
+        if !path.exists() {
+            return Ok(None);
+        }
+        match last_line(&path)? {
+            Some((_, _, text)) => Ok(Some(serde_json::from_str(&text)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Verify only the events appended to `log_path` after this checkpoint.
+    ///
+    /// Confirms the checkpoint's head event is still present and unchanged,
+    /// then checks that every later event chains from it. The caller is
+    /// responsible for checking the checkpoint's signature first.
+    pub fn verify_since(
+        &self,
+        log_path: impl AsRef<Path>,
+    ) -> Result<CheckpointVerifyReport, AuditError> {
+        let files = AuditLog::segments(log_path.as_ref())?;
+
+        // The head event is normally in the segment it was recorded in; if
+        // that file has since been rotated, it is in one of the rotated
+        // segments, newest first.
+        let (mut candidates, rest): (Vec<usize>, Vec<usize>) =
+            (0..files.len()).partition(|&i| file_name(&files[i]) == self.segment);
+        candidates.extend(rest.into_iter().rev());
+
+        let mut anchor = None;
+        for i in candidates {
+            if let Some(reader) = self.open_after_head(&files[i])? {
+                anchor = Some((i, reader));
+                break;
+            }
+        }
+        let Some((anchor_index, reader)) = anchor else {
+            return Err(AuditError::CheckpointNotFound {
+                head_hash: self.head_hash.clone(),
+                segment: self.segment.clone(),
+                line: self.line,
+            });
+        };
+
+        let mut previous_hash = Some(self.head_hash.clone());
+        let mut new_events = 0;
+        let mut check = |path: &Path, line_num: usize, text: &str| {
+            let event: AuditEvent = serde_json::from_str(text)?;
+            if event.previous_hash != previous_hash {
+                return Err(AuditError::SegmentIntegrityViolation {
+                    path: path.to_path_buf(),
+                    line: line_num,
+                    expected: previous_hash.clone().unwrap_or_else(|| "None".to_string()),
+                    actual: event.previous_hash.unwrap_or_else(|| "None".to_string()),
+                });
+            }
+            previous_hash = Some(hasher::hash_str(text));
+            new_events += 1;
+            Ok(())
+        };
+
+        for (n, text) in reader.lines().enumerate() {
+            let text = text?;
+            if !text.trim().is_empty() {
+                check(&files[anchor_index], self.line + n + 1, &text)?;
+            }
+        }
+        for file in &files[anchor_index + 1..] {
+            let reader =
+                BufReader::new(File::open(file).map_err(|source| AuditError::OpenFailed {
+                    path: file.clone(),
+                    source,
+                })?);
+            for (n, text) in reader.lines().enumerate() {
+                let text = text?;
+                if !text.trim().is_empty() {
+                    check(file, n + 1, &text)?;
+                }
+            }
+        }
+
+        Ok(CheckpointVerifyReport {
+            checkpoint_events: self.events,
+            new_events,
+            anchor_segment: files[anchor_index].clone(),
+        })
+    }
+
+    /// Open `file` positioned just past the head event, or `None` when the
+    /// line at the recorded offset is not the head event.
+    fn open_after_head(&self, file: &Path) -> Result<Option<BufReader<File>>, AuditError> {
+        let handle = File::open(file).map_err(|source| AuditError::OpenFailed {
+            path: file.to_path_buf(),
+            source,
+        })?;
+        if handle.metadata()?.len() <= self.offset {
+            return Ok(None);
+        }
+        let mut reader = BufReader::new(handle);
+        reader.seek(SeekFrom::Start(self.offset))?;
+        let mut head = String::new();
+        reader.read_line(&mut head)?;
+        if hasher::hash_str(strip_newline(&head)) == self.head_hash {
+            Ok(Some(reader))
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// The last non-empty line of `path` as `(1-based line, byte offset, text)`.
+fn last_line(path: &Path) -> Result<Option<(usize, u64, String)>, AuditError> {
+    let file = File::open(path).map_err(|source| AuditError::OpenFailed {
+        path: path.to_path_buf(),
+        source,
+    })?;
+    let mut reader = BufReader::new(file);
+    let mut last = None;
+    let mut offset = 0u64;
+    let mut line_num = 0;
+    let mut buf = String::new();
+    loop {
+        buf.clear();
+        let read = reader.read_line(&mut buf)?;
+        if read == 0 {
+            break;
+        }
+        line_num += 1;
+        let text = strip_newline(&buf);
+        if !text.trim().is_empty() {
+            last = Some((line_num, offset, text.to_string()));
+        }
+        offset += read as u64;
+    }
+    Ok(last)
+}
+
+/// Strip the line terminator the way `BufRead::lines` does.
+fn strip_newline(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::attestation::SoftwareAttestationBackend;
+    use crate::event::AuditAction;
+    use crate::log::RotationPolicy;
+    use tempfile::tempdir;
+
+    fn append_n(log: &mut AuditLog, n: usize) {
+        for i in 0..n {
+            let mut event = AuditEvent::new(format!("agent-{}", i), AuditAction::ToolCall);
+            log.append(&mut event).unwrap();
+        }
+    }
+
+    #[test]
+    fn checkpoint_verifies_only_new_events_across_rotation() {
+        let dir = tempdir().unwrap();
+        let log_path = dir.path().join("audit.jsonl");
+        let backend =
+            SoftwareAttestationBackend::load_or_generate(&dir.path().join("keys")).unwrap();
+
+        let mut log = AuditLog::open(&log_path).unwrap();
+        append_n(&mut log, 5);
+
+        let mut checkpoint = AuditCheckpoint::capture(&log_path).unwrap().unwrap();
+        assert_eq!(checkpoint.events, 5);
+        assert_eq!(checkpoint.line, 5);
+        checkpoint.sign(&backend).unwrap();
+        checkpoint.append(&log_path).unwrap();
+
+        let latest = AuditCheckpoint::latest(&log_path).unwrap().unwrap();
+        assert!(latest.verify_signature(&backend));
+        let report = latest.verify_since(&log_path).unwrap();
+        assert_eq!(report.new_events, 0);
+
+        // New events land in rotated segments and the active file; the head
+        // event is now in a rotated segment.
+        let mut log = log.with_rotation(RotationPolicy {
+            max_bytes: 600,
+            keep_segments: 0,
+        });
+        append_n(&mut log, 7);
+        assert!(!AuditLog::rotated_segments(&log_path).unwrap().is_empty());
+
+        let report = latest.verify_since(&log_path).unwrap();
+        assert_eq!(report.checkpoint_events, 5);
+        assert_eq!(report.new_events, 7);
+        assert_ne!(report.anchor_segment, log_path);
+
+        // A tampered checkpoint no longer carries a valid signature.
+        let mut forged = latest.clone();
+        forged.events = 1;
+        assert!(!forged.verify_signature(&backend));
+    }
+
+    #[test]
+    fn checkpoint_detects_tampering_after_and_at_head() {
+        let dir = tempdir().unwrap();
+        let log_path = dir.path().join("audit.jsonl");
+        {
+            let mut log = AuditLog::open(&log_path).unwrap();
+            append_n(&mut log, 3);
+        }
+        let checkpoint = AuditCheckpoint::capture(&log_path).unwrap().unwrap();
+        {
+            let mut log = AuditLog::open(&log_path).unwrap();
+            append_n(&mut log, 2);
+        }
+
+        // Drop the fourth event: the fifth no longer chains from the head.
+        let content = std::fs::read_to_string(&log_path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        let removed = [lines[..3].join("\n"), lines[4].to_string()].join("\n") + "\n";
+        std::fs::write(&log_path, &removed).unwrap();
+        assert!(matches!(
+            checkpoint.verify_since(&log_path),
+            Err(AuditError::SegmentIntegrityViolation { line: 4, .. })
+        ));
+
+        // Rewrite the head event itself: the checkpoint can no longer anchor.
+        std::fs::write(&log_path, content.replacen("agent-2", "agent-x", 1)).unwrap();
+        assert!(matches!(
+            checkpoint.verify_since(&log_path),
+            Err(AuditError::CheckpointNotFound { .. })
+        ));
+    }
+}
@@ -32,6 +32,29 @@ pub enum AuditError {
         actual: String,
     },
 
+    /// A rotated audit segment's hash chain is broken.
+    #[error(
+        "integrity check failed in segment {} at line {line}: expected hash {expected}, got {actual}",
+        path.display()
+    )]
+    SegmentIntegrityViolation {
+        path: PathBuf,
+        line: usize,
+        expected: String,
+        actual: String,
+    },
+
+    /// The head event recorded by an audit checkpoint is no longer in the
+    /// log — it was rewritten, truncated, or pruned past the checkpoint.
+    #[error(
+        "checkpoint head event (hash {head_hash}, {segment} line {line}) not found in the audit log"
+    )]
+    CheckpointNotFound {
+        head_hash: String,
+        segment: String,
+        line: usize,
+    },
+
     /// Failed to read a file for hashing.
     #[error("failed to hash file at {path}: {source}")]
     HashFileFailed {
@@ -5,10 +5,29 @@
 // each event includes a `previous_hash` linking it to the prior event,
 // enabling tamper detection.
 
+use std::sync::RwLock;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+/// Correlation ID for the current process (see [`set_correlation_id`]).
+static CORRELATION_ID: RwLock<Option<Uuid>> = RwLock::new(None);
+
+/// Set the correlation ID for this process.
+///
+/// Every event appended afterwards without its own `correlation_id` carries
+/// this one. `ta` mints one per command, so all events of a single operation
+/// (e.g. one `ta draft apply`) can be queried together.
+pub fn set_correlation_id(id: Option<Uuid>) {
+    *CORRELATION_ID.write().unwrap_or_else(|e| e.into_inner()) = id;
+}
+
+/// The correlation ID set for this process, if any.
+pub fn correlation_id() -> Option<Uuid> {
+    *CORRELATION_ID.read().unwrap_or_else(|e| e.into_inner())
+}
+
 /// What kind of action this event records.
 ///
 /// In Rust, an `enum` can carry data in each variant (called a "tagged union"
@@ -28,6 +47,8 @@ pub enum AuditAction {
     Error,
     /// A draft was auto-approved by policy (v0.10.15).
     AutoApproval,
+    /// A closed or denied draft was reopened for review.
+    Reopen,
 }
 
 // ── Decision Observability (v0.3.3) ──
@@ -132,6 +153,12 @@ pub struct AuditEvent {
     /// Populated whenever `goal_run_id` is set.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub shortref: Option<String>,
+
+    /// Ties together the events of one logical operation, such as a single
+    /// `ta draft apply`. Filled from [`correlation_id()`] at append time when
+    /// not set explicitly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<Uuid>,
 }
 
 impl AuditEvent {
@@ -156,6 +183,7 @@ impl AuditEvent {
             goal_run_id: None,
             attestation: None,
             shortref: None,
+            correlation_id: None,
         }
     }
 
@@ -227,6 +255,14 @@ impl AuditEvent {
         self.shortref = Some(shortref.into());
         self
     }
+
+    /// Set the correlation ID explicitly and return self.
+    ///
+    /// Overrides the process-wide ID from [`set_correlation_id`].
+    pub fn with_correlation_id(mut self, id: Uuid) -> Self {
+        self.correlation_id = Some(id);
+        self
+    }
 }
 
 #[cfg(test)]
@@ -352,6 +388,12 @@ mod tests {
         assert_eq!(json, "\"auto_approval\"");
     }
 
+    #[test]
+    fn reopen_action_serializes_as_snake_case() {
+        let json = serde_json::to_string(&AuditAction::Reopen).unwrap();
+        assert_eq!(json, "\"reopen\"");
+    }
+
     #[test]
     fn caller_mode_and_tool_name_in_event() {
         let event = AuditEvent::new("agent-1", AuditAction::ToolCall)
@@ -22,12 +22,14 @@
 // in the same directory and include it as a submodule.
 pub mod attestation;
 pub mod chain;
+pub mod checkpoint;
 pub mod drift;
 pub mod error;
 pub mod event;
 pub mod hasher;
 pub mod ledger;
 pub mod log;
+pub mod redact;
 
 // Re-export the main types at the crate root for convenience.
 // Users can write `use ta_audit::AuditLog` instead of `use ta_audit::log::AuditLog`.
@@ -35,14 +37,18 @@ pub use attestation::{
     AttestationBackend, AttestationError, AttestationRecord, SoftwareAttestationBackend,
 };
 pub use chain::{sign_entry, verify_entry_sig, verify_hmac_chain, AuditHmacKey, ChainVerifyEntry};
+pub use checkpoint::{AuditCheckpoint, CheckpointVerifyReport};
 pub use drift::{
     constitution_violation_finding, BaselineStore, BehavioralBaseline, DraftSummary, DriftFinding,
     DriftReport, DriftSeverity, DriftSignal,
 };
 pub use error::AuditError;
-pub use event::{Alternative, AuditAction, AuditEvent, DecisionReasoning};
+pub use event::{
+    correlation_id, set_correlation_id, Alternative, AuditAction, AuditEvent, DecisionReasoning,
+};
 pub use ledger::{
     migrate_from_history, ArtifactRecord, AuditDisposition, AuditEntry, GoalAuditLedger,
     LedgerFilter,
 };
-pub use log::AuditLog;
+pub use log::{AuditEventIter, AuditLog, RotationPolicy, SegmentVerifyReport};
+pub use redact::{RedactConfig, Redactor, REDACTED};
@@ -42,6 +42,9 @@ pub enum CommitIntent {
     RequestSend,
     /// Request to publish a social media post.
     RequestPost,
+    /// Apply the filesystem change but leave it out of the VCS commit
+    /// (scratch files, local notes the reviewer should still see).
+    NoCommit,
 }
 
 /// A single staged mutation — the fundamental unit of the review system.
@@ -343,9 +343,11 @@ impl ChannelFactory for TerminalChannelFactory {
 
     fn build_review(
         &self,
-        _config: &serde_json::Value,
+        config: &serde_json::Value,
     ) -> Result<Box<dyn ReviewChannel>, ReviewChannelError> {
-        Ok(Box::new(crate::terminal_channel::TerminalChannel::stdio()))
+        Ok(Box::new(
+            crate::terminal_channel::TerminalChannel::stdio_from_config(config)?,
+        ))
     }
 
     fn build_session(
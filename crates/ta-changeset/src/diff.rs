@@ -28,6 +28,28 @@ pub enum DiffContent {
         content: String,
     },
 
+    /// A unified diff too large to keep inline, stored in the content-addressed
+    /// blob store. Like `CreateFileBlob`, this is a persisted form only; it is
+    /// resolved back to `UnifiedDiff` on load.
+    UnifiedDiffBlob {
+        /// SHA-256 of the diff text (hex).
+        hash: String,
+        /// Size of the diff text in bytes.
+        size_bytes: u64,
+    },
+
+    /// A brand new file whose content lives in the content-addressed blob
+    /// store (`.ta/blobs/<hash>`) rather than inline.
+    ///
+    /// This is the persisted form written by a `JsonFileStore` with a blob
+    /// store attached; it is resolved back to `CreateFile` on load.
+    CreateFileBlob {
+        /// SHA-256 of the file content (hex).
+        hash: String,
+        /// Size of the content in bytes.
+        size_bytes: u64,
+    },
+
     /// A file is being deleted entirely.
     DeleteFile,
 
@@ -40,6 +62,10 @@ pub enum DiffContent {
         /// SHA-256 hash of the binary content.
         hash: String,
     },
+
+    /// Content is unchanged; only the file mode (executable bit) changes.
+    /// Modes are git-style: `0o100644` or `0o100755`.
+    ModeChange { old_mode: u32, new_mode: u32 },
 }
 
 #[cfg(test)]
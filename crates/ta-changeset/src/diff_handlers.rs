@@ -25,7 +25,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::path::Path;
-use std::process::Command;
+use std::process::{Child, Command};
 use thiserror::Error;
 
 /// Configuration for external diff handlers loaded from `.ta/diff-handlers.toml`.
@@ -114,6 +114,48 @@ impl DiffHandlersConfig {
     }
 }
 
+impl HandlerRule {
+    /// Arguments for launching this handler on `file_path` (`{file}` substituted).
+    pub fn args_for(&self, file_path: &Path) -> Vec<String> {
+        let file_str = file_path.to_string_lossy();
+        self.args
+            .iter()
+            .map(|arg| arg.replace("{file}", &file_str))
+            .collect()
+    }
+
+    /// Spawn the handler on `file_path` without waiting for it to exit.
+    pub fn spawn(&self, file_path: &Path) -> Result<Child, DiffHandlerError> {
+        Command::new(&self.command)
+            .args(self.args_for(file_path))
+            .spawn()
+            .map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    DiffHandlerError::CommandNotFound(self.command.clone())
+                } else {
+                    DiffHandlerError::LaunchFailed(format!("{}: {}", self.command, e))
+                }
+            })
+    }
+
+    /// Describe why this rule's pattern can never match, if it can't.
+    ///
+    /// Invalid globs and `{a,b}` brace alternatives (unsupported by the matcher)
+    /// silently match nothing, so they are reported here instead.
+    pub fn pattern_problem(&self) -> Option<String> {
+        if let Err(e) = glob::Pattern::new(&self.pattern) {
+            return Some(format!("invalid glob pattern: {}", e));
+        }
+        if self.pattern.contains('{') && self.pattern.contains(',') {
+            return Some(
+                "brace alternatives like {a,b} are not supported; use one rule per pattern"
+                    .to_string(),
+            );
+        }
+        None
+    }
+}
+
 /// Check if a glob pattern matches a file path.
 ///
 /// Supports basic glob syntax:
@@ -132,25 +174,7 @@ fn pattern_matches(pattern: &str, path: &str) -> bool {
 /// Launch an external handler for a file.
 fn launch_handler(handler: &HandlerRule, file_path: &Path) -> Result<(), DiffHandlerError> {
     let file_str = file_path.to_string_lossy();
-
-    // Substitute {file} placeholder in args.
-    let args: Vec<String> = handler
-        .args
-        .iter()
-        .map(|arg| arg.replace("{file}", &file_str))
-        .collect();
-
-    // Launch the command.
-    let result = Command::new(&handler.command)
-        .args(&args)
-        .spawn()
-        .map_err(|e| {
-            if e.kind() == std::io::ErrorKind::NotFound {
-                DiffHandlerError::CommandNotFound(handler.command.clone())
-            } else {
-                DiffHandlerError::LaunchFailed(format!("{}: {}", handler.command, e))
-            }
-        })?;
+    let result = handler.spawn(file_path)?;
 
     tracing::info!(
         "Launched {} {} with PID {:?}",
@@ -303,12 +327,7 @@ args = ["{file}"]
             description: None,
         };
 
-        let file_path = Path::new("/tmp/test.test");
-        let args: Vec<String> = handler
-            .args
-            .iter()
-            .map(|arg| arg.replace("{file}", &file_path.to_string_lossy()))
-            .collect();
+        let args = handler.args_for(Path::new("/tmp/test.test"));
 
         assert_eq!(args[0], "--input");
         assert_eq!(args[1], "/tmp/test.test");
@@ -316,6 +335,25 @@ args = ["{file}"]
         assert_eq!(args[3], "/tmp/test.test.out");
     }
 
+    #[test]
+    fn test_pattern_problem() {
+        let rule = |pattern: &str| HandlerRule {
+            pattern: pattern.to_string(),
+            command: "viewer".to_string(),
+            args: vec![],
+            description: None,
+        };
+        assert!(rule("assets/**/*.png").pattern_problem().is_none());
+        assert!(rule("*.{png,jpg}")
+            .pattern_problem()
+            .unwrap()
+            .contains("brace"));
+        assert!(rule("[*.png")
+            .pattern_problem()
+            .unwrap()
+            .contains("invalid"));
+    }
+
     // Note: We don't test actual command launching here because it depends on the system.
     // Manual testing required for verifying launch behavior.
 }
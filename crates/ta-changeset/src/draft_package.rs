@@ -216,6 +216,53 @@ pub struct Artifact {
     /// binary diff and show a human-readable frame/resolution summary).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub kind: Option<ArtifactKind>,
+    /// Applied to the target but excluded from the submit workflow's commit
+    /// (the artifact's changeset carries `CommitIntent::NoCommit`).
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub no_commit: bool,
+    /// Previous location of a `ChangeType::Rename` artifact, as a resource URI.
+    /// `resource_uri` holds the new location.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub renamed_from: Option<String>,
+    /// Executable-bit change recorded for the file; applying the artifact
+    /// sets the new mode on the target (no-op on non-Unix platforms).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode_change: Option<ModeChange>,
+    /// Line endings the staged file was converted to at build
+    /// (`[workspace] line_endings` or `.gitattributes` `eol`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_endings_normalized: Option<LineEnding>,
+}
+
+/// A text file line ending.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEnding {
+    Lf,
+    Crlf,
+}
+
+impl fmt::Display for LineEnding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineEnding::Lf => write!(f, "lf"),
+            LineEnding::Crlf => write!(f, "crlf"),
+        }
+    }
+}
+
+/// A file mode change on an artifact, as git-style modes
+/// (`0o100644` / `0o100755`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ModeChange {
+    pub old_mode: u32,
+    pub new_mode: u32,
+}
+
+impl fmt::Display for ModeChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "mode {:o} → {:o}", self.old_mode, self.new_mode)
+    }
 }
 
 /// Record of a human amendment to an artifact (v0.3.4).
@@ -249,7 +296,8 @@ pub enum AmendmentType {
 /// Per-artifact review disposition.
 ///
 /// Tracks the reviewer's decision on each individual artifact,
-/// enabling selective approval (approve some, reject others, discuss the rest).
+/// enabling selective approval (approve some, reject others, discuss the rest,
+/// defer what isn't ready yet).
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "snake_case")]
 pub enum ArtifactDisposition {
@@ -262,6 +310,9 @@ pub enum ArtifactDisposition {
     Rejected,
     /// Needs discussion before deciding.
     Discuss,
+    /// Set aside for a later apply: excluded from the current apply without
+    /// blocking the rest, and the draft stays open so it can be applied later.
+    Deferred,
 }
 
 impl fmt::Display for ArtifactDisposition {
@@ -271,6 +322,7 @@ impl fmt::Display for ArtifactDisposition {
             ArtifactDisposition::Approved => write!(f, "approved"),
             ArtifactDisposition::Rejected => write!(f, "rejected"),
             ArtifactDisposition::Discuss => write!(f, "discuss"),
+            ArtifactDisposition::Deferred => write!(f, "deferred"),
         }
     }
 }
@@ -307,6 +359,23 @@ pub enum ChangeType {
     Rename,
 }
 
+impl std::str::FromStr for ChangeType {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "add" => Ok(ChangeType::Add),
+            "modify" => Ok(ChangeType::Modify),
+            "delete" => Ok(ChangeType::Delete),
+            "rename" => Ok(ChangeType::Rename),
+            _ => Err(format!(
+                "Invalid change kind: '{}'. Valid kinds: add, modify, delete, rename",
+                s
+            )),
+        }
+    }
+}
+
 /// A staged change to an external resource (Drive, Gmail, DB, etc.).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PatchSet {
@@ -350,6 +419,22 @@ pub struct RiskFinding {
     pub evidence_refs: Vec<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mitigation: Option<String>,
+    /// Where in the changes the finding applies, when it is tied to a file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<FindingLocation>,
+}
+
+/// File (and optionally line range) a risk finding points at.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FindingLocation {
+    /// Workspace-relative path of the artifact, e.g. `src/main.rs`.
+    pub path: String,
+    /// First line of the finding (1-based).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_line: Option<u32>,
+    /// Last line of the finding (1-based, inclusive); defaults to `start_line`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end_line: Option<u32>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -361,6 +446,8 @@ pub enum RiskCategory {
     ExternalComm,
     PromptInjection,
     PolicyViolation,
+    /// The test command passed to `ta draft build --tests` failed.
+    TestFailure,
     Unknown,
 }
 
@@ -467,6 +554,48 @@ pub struct ApprovalRecord {
     pub approved_at: DateTime<Utc>,
 }
 
+// ---- File Review Assignment ----
+
+/// Delegated review of the artifacts matching `pattern`, recorded by
+/// `ta draft assign`.
+///
+/// Apply refuses a matching artifact until `required_approvals` distinct
+/// assigned reviewers have signed off with `ta draft approve --assigned`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileReviewAssignment {
+    /// URI pattern selecting the artifacts (same syntax as `--approve`).
+    pub pattern: String,
+    /// Reviewers who own review of the matching artifacts.
+    pub reviewers: Vec<String>,
+    /// Sign-offs needed from distinct assigned reviewers.
+    #[serde(default = "default_required_approvals")]
+    pub required_approvals: u32,
+    pub assigned_at: DateTime<Utc>,
+    /// Sign-offs recorded so far.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub approvals: Vec<ApprovalRecord>,
+}
+
+impl FileReviewAssignment {
+    /// Whether this assignment covers the artifact at `uri`.
+    pub fn covers(&self, uri: &str) -> bool {
+        crate::uri_pattern::matches_uri(&self.pattern, uri)
+    }
+
+    /// Sign-offs from assigned reviewers; approvals by anyone else never count.
+    pub fn signed_off(&self) -> usize {
+        self.approvals
+            .iter()
+            .filter(|a| self.reviewers.contains(&a.reviewer))
+            .count()
+    }
+
+    /// Whether enough assigned reviewers have signed off.
+    pub fn is_satisfied(&self) -> bool {
+        self.signed_off() >= self.required_approvals as usize
+    }
+}
+
 // ---- Draft Package (top level) ----
 
 /// The Draft Package — a complete, reviewable milestone deliverable.
@@ -520,12 +649,54 @@ pub struct DraftPackage {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub vcs_status: Option<VcsTrackingInfo>,
 
+    /// Which submit steps (commit/push/review) succeeded when the draft was
+    /// applied with `--submit`. A partial failure is retried by
+    /// `ta draft resubmit`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub submit_outcome: Option<SubmitOutcome>,
+
     /// Parent draft ID for follow-up goals (v0.12.2.1).
     /// When set, this draft is a follow-up to the parent draft. Used for chain
     /// display (`ta draft view` combined impact) and chain apply (`ta draft apply --chain`).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent_draft_id: Option<Uuid>,
 
+    /// Draft this package was carved out of by `ta draft split`.
+    /// Split drafts share the original's goal and staging workspace, so each
+    /// one can be reviewed, applied, and committed independently.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub split_from: Option<Uuid>,
+
+    /// Drafts created from this one by `ta draft split`, in group order
+    /// (leftover last). Set on the original, which is marked `Superseded`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub split_into: Vec<Uuid>,
+
+    /// Drafts combined into this one by `ta draft combine` (each now `Superseded`).
+    /// Artifacts from other goals' staging are copied into this draft's staging.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub merged_from: Vec<Uuid>,
+
+    /// Paths dropped at build because their effective diff was empty
+    /// (`[workspace] drop_noop`). Shown in `ta draft view` so reviewers
+    /// know the draft was trimmed.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub noop_dropped: Vec<String>,
+
+    /// SHA-256 of each workspace artifact's staged content when the draft was
+    /// built, keyed by resource URI; an empty hash marks a file the draft
+    /// deletes. Apply refuses artifacts whose staging copy no longer matches,
+    /// so what gets applied is what was reviewed. Empty for older drafts.
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub staged_hashes: std::collections::BTreeMap<String, String>,
+
+    /// SHA-256 over the artifact set (paths, change types, and staged content
+    /// hashes) computed at build time. Two drafts with the same hash propose
+    /// identical changes; `ta draft build` uses it to avoid creating a twin of
+    /// a draft that is still under review. `None` for older drafts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_hash: Option<String>,
+
     /// Accumulated reviewer approvals for multi-party governance (v0.14.2).
     /// Empty for single-approver workflows (legacy / require_approvals = 1).
     /// Grows as each reviewer calls `ta draft approve --as <identity>`.
@@ -534,6 +705,11 @@ pub struct DraftPackage {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub pending_approvals: Vec<ApprovalRecord>,
 
+    /// Per-file review delegations from `ta draft assign`. An artifact covered
+    /// by an assignment is not applied until that assignment is satisfied.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub review_assignments: Vec<FileReviewAssignment>,
+
     /// AI supervisor review embedded after agent exit (v0.13.17.4).
     /// Present when supervisor is enabled; `None` when disabled or skipped.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -617,6 +793,152 @@ pub struct DraftPackage {
     pub plan_md_base: Option<String>,
 }
 
+impl DraftPackage {
+    /// Hash of the artifact set, independent of artifact order. Call after
+    /// `staged_hashes` is populated so content changes alter the hash.
+    pub fn compute_content_hash(&self) -> String {
+        use sha2::{Digest, Sha256};
+        let mut artifacts: Vec<&Artifact> = self.changes.artifacts.iter().collect();
+        artifacts.sort_by(|a, b| a.resource_uri.cmp(&b.resource_uri));
+        let mut hasher = Sha256::new();
+        for artifact in artifacts {
+            let staged = self
+                .staged_hashes
+                .get(&artifact.resource_uri)
+                .map(String::as_str)
+                .unwrap_or("");
+            hasher.update(
+                format!(
+                    "{}\0{:?}\0{}\n",
+                    artifact.resource_uri, artifact.change_type, staged
+                )
+                .as_bytes(),
+            );
+        }
+        format!("{:x}", hasher.finalize())
+    }
+
+    /// The review assignments covering the artifact at `uri`.
+    pub fn review_assignments_for(&self, uri: &str) -> Vec<&FileReviewAssignment> {
+        self.review_assignments
+            .iter()
+            .filter(|a| a.covers(uri))
+            .collect()
+    }
+
+    /// Drop the sign-offs of every assignment covering one of `uris`, whose
+    /// content changed since the reviewers signed off. Returns how many
+    /// assignments lost approvals.
+    pub fn reset_assignment_approvals(&mut self, uris: &[&str]) -> usize {
+        let mut reset = 0;
+        for assignment in &mut self.review_assignments {
+            if !assignment.approvals.is_empty() && uris.iter().any(|u| assignment.covers(u)) {
+                assignment.approvals.clear();
+                reset += 1;
+            }
+        }
+        reset
+    }
+}
+
+// ---- Draft Summary (listing view) ----
+
+/// The subset of a [`DraftPackage`] needed to list and filter drafts.
+///
+/// Deserializes from the same JSON as a full package. Everything else — plan,
+/// provenance, review records, per-artifact detail — is skipped while parsing,
+/// so listing hundreds of drafts never holds their full contents in memory.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DraftSummary {
+    pub package_id: Uuid,
+    pub created_at: DateTime<Utc>,
+    pub goal: DraftSummaryGoal,
+    /// `summary.what_changed` of the package.
+    #[serde(rename = "summary", deserialize_with = "summary_what_changed")]
+    pub what_changed: String,
+    /// Change type of each artifact, in artifact order.
+    #[serde(rename = "changes", deserialize_with = "changes_artifact_types")]
+    pub artifact_change_types: Vec<ChangeType>,
+    #[serde(default)]
+    pub status: DraftStatus,
+    #[serde(default)]
+    pub display_id: Option<String>,
+    #[serde(default)]
+    pub tag: Option<String>,
+    #[serde(default)]
+    pub vcs_status: Option<VcsTrackingInfo>,
+    #[serde(default)]
+    pub parent_draft_id: Option<Uuid>,
+    #[serde(default)]
+    pub goal_shortref: Option<String>,
+    #[serde(default)]
+    pub draft_seq: u32,
+    #[serde(default)]
+    pub plan_phase: Option<String>,
+}
+
+/// Goal identity carried by a [`DraftSummary`].
+#[derive(Debug, Clone, Deserialize)]
+pub struct DraftSummaryGoal {
+    pub goal_id: String,
+    pub title: String,
+}
+
+impl From<&DraftPackage> for DraftSummary {
+    fn from(pkg: &DraftPackage) -> Self {
+        Self {
+            package_id: pkg.package_id,
+            created_at: pkg.created_at,
+            goal: DraftSummaryGoal {
+                goal_id: pkg.goal.goal_id.clone(),
+                title: pkg.goal.title.clone(),
+            },
+            what_changed: pkg.summary.what_changed.clone(),
+            artifact_change_types: pkg
+                .changes
+                .artifacts
+                .iter()
+                .map(|a| a.change_type.clone())
+                .collect(),
+            status: pkg.status.clone(),
+            display_id: pkg.display_id.clone(),
+            tag: pkg.tag.clone(),
+            vcs_status: pkg.vcs_status.clone(),
+            parent_draft_id: pkg.parent_draft_id,
+            goal_shortref: pkg.goal_shortref.clone(),
+            draft_seq: pkg.draft_seq,
+            plan_phase: pkg.plan_phase.clone(),
+        }
+    }
+}
+
+fn summary_what_changed<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct SummaryText {
+        what_changed: String,
+    }
+    SummaryText::deserialize(deserializer).map(|s| s.what_changed)
+}
+
+fn changes_artifact_types<'de, D>(deserializer: D) -> Result<Vec<ChangeType>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    struct ArtifactType {
+        change_type: ChangeType,
+    }
+    #[derive(Deserialize)]
+    struct ChangesTypes {
+        artifacts: Vec<ArtifactType>,
+    }
+    ChangesTypes::deserialize(deserializer)
+        .map(|c| c.artifacts.into_iter().map(|a| a.change_type).collect())
+}
+
 /// VCS tracking information for post-apply lifecycle monitoring (v0.11.2.3).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct VcsTrackingInfo {
@@ -636,6 +958,87 @@ pub struct VcsTrackingInfo {
     pub commit_sha: Option<String>,
     /// When VCS status was last checked/updated.
     pub last_checked: DateTime<Utc>,
+    /// Keys of the review comments already posted to the PR by
+    /// `ta draft comment-export`, so a re-run doesn't post them twice.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub exported_comments: Vec<String>,
+}
+
+/// How one step of the submit workflow went.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SubmitStepStatus {
+    /// The step was not requested for this apply.
+    Skipped,
+    /// Requested, but not attempted because an earlier step failed.
+    NotRun,
+    Succeeded,
+    Failed {
+        error: String,
+    },
+}
+
+impl SubmitStepStatus {
+    /// Whether the step was requested and has not succeeded yet.
+    pub fn needs_retry(&self) -> bool {
+        matches!(self, Self::NotRun | Self::Failed { .. })
+    }
+}
+
+impl std::fmt::Display for SubmitStepStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Skipped => write!(f, "skipped"),
+            Self::NotRun => write!(f, "not run"),
+            Self::Succeeded => write!(f, "ok"),
+            Self::Failed { error } => write!(f, "failed: {}", error),
+        }
+    }
+}
+
+/// Result of each step of `ta draft apply --submit`. Once the commit lands,
+/// a failed push or review no longer rolls the apply back; it is recorded
+/// here so the draft doesn't look fully submitted when it isn't.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmitOutcome {
+    pub commit: SubmitStepStatus,
+    pub push: SubmitStepStatus,
+    pub review: SubmitStepStatus,
+    /// When the outcome was last updated (apply or resubmit).
+    pub updated_at: DateTime<Utc>,
+}
+
+impl SubmitOutcome {
+    /// Outcome before any step has run: requested steps are `NotRun`.
+    pub fn requested(push: bool, review: bool) -> Self {
+        let step = |requested| {
+            if requested {
+                SubmitStepStatus::NotRun
+            } else {
+                SubmitStepStatus::Skipped
+            }
+        };
+        Self {
+            commit: SubmitStepStatus::NotRun,
+            push: step(push),
+            review: step(review),
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Whether a requested step failed or never ran.
+    pub fn is_partial(&self) -> bool {
+        self.steps().iter().any(|(_, status)| status.needs_retry())
+    }
+
+    /// Steps in submit order, by name.
+    pub fn steps(&self) -> [(&'static str, &SubmitStepStatus); 3] {
+        [
+            ("commit", &self.commit),
+            ("push", &self.push),
+            ("review", &self.review),
+        ]
+    }
 }
 
 /// A warning from a pre-draft verification command failure (v0.10.8).
@@ -781,6 +1184,10 @@ pub enum DraftStatus {
     Approved {
         approved_by: String,
         approved_at: DateTime<Utc>,
+        /// When this approval lapses (`ta draft approve --expire`). An expired
+        /// approval must be renewed before `ta draft apply` will act on it.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        approval_expires_at: Option<DateTime<Utc>>,
     },
     Denied {
         reason: String,
@@ -805,6 +1212,20 @@ pub enum DraftStatus {
     },
 }
 
+impl DraftStatus {
+    /// Whether this is an `Approved` status whose `approval_expires_at` is at
+    /// or before `now`. Approvals without an expiry never lapse.
+    pub fn approval_expired(&self, now: DateTime<Utc>) -> bool {
+        matches!(
+            self,
+            DraftStatus::Approved {
+                approval_expires_at: Some(expires_at),
+                ..
+            } if *expires_at <= now
+        )
+    }
+}
+
 impl std::fmt::Display for DraftStatus {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -898,8 +1319,11 @@ pub fn make_test_pkg(goal_shortref: &str, draft_seq: u32) -> DraftPackage {
         display_id: None,
         tag: None,
         vcs_status: None,
+        submit_outcome: None,
+        content_hash: None,
         parent_draft_id: None,
         pending_approvals: vec![],
+        review_assignments: vec![],
         supervisor_review: None,
         ignored_artifacts: vec![],
         baseline_artifacts: vec![],
@@ -909,6 +1333,11 @@ pub fn make_test_pkg(goal_shortref: &str, draft_seq: u32) -> DraftPackage {
         draft_seq,
         plan_phase: None,
         plan_md_base: None,
+        split_from: None,
+        split_into: Vec::new(),
+        merged_from: Vec::new(),
+        noop_dropped: Vec::new(),
+        staged_hashes: Default::default(),
     }
 }
 
@@ -1018,6 +1447,10 @@ mod tests {
                     comments: None,
                     amendment: None,
                     kind: None,
+                    no_commit: false,
+                    renamed_from: None,
+                    mode_change: None,
+                    line_endings_normalized: None,
                 }],
                 patch_sets: vec![],
                 pending_actions: vec![],
@@ -1051,8 +1484,11 @@ mod tests {
             display_id: None,
             tag: None,
             vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
             parent_draft_id: None,
             pending_approvals: vec![],
+            review_assignments: vec![],
             supervisor_review: None,
             ignored_artifacts: vec![],
             baseline_artifacts: vec![],
@@ -1062,6 +1498,11 @@ mod tests {
             draft_seq: 0,
             plan_phase: None,
             plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
         }
     }
 
@@ -1090,6 +1531,7 @@ mod tests {
         let status = DraftStatus::Approved {
             approved_by: "reviewer".to_string(),
             approved_at: Utc::now(),
+            approval_expires_at: None,
         };
         assert_eq!(status.to_string(), "approved");
 
@@ -1114,6 +1556,31 @@ mod tests {
         assert_eq!(status, DraftStatus::Draft);
     }
 
+    #[test]
+    fn approval_expiry() {
+        let now = Utc::now();
+        let approved = |expires| DraftStatus::Approved {
+            approved_by: "reviewer".to_string(),
+            approved_at: now,
+            approval_expires_at: expires,
+        };
+        assert!(!approved(None).approval_expired(now));
+        assert!(!approved(Some(now + chrono::Duration::hours(1))).approval_expired(now));
+        assert!(approved(Some(now)).approval_expired(now));
+        assert!(!DraftStatus::PendingReview.approval_expired(now));
+
+        // Older drafts without the field still deserialize.
+        let legacy: DraftStatus = serde_json::from_str(&format!(
+            r#"{{"status":"approved","approved_by":"r","approved_at":"{}"}}"#,
+            now.to_rfc3339()
+        ))
+        .unwrap();
+        assert!(!legacy.approval_expired(now));
+        assert!(!serde_json::to_string(&legacy)
+            .unwrap()
+            .contains("approval_expires_at"));
+    }
+
     #[test]
     fn draft_package_json_contains_required_fields() {
         // Verify the serialized JSON includes all required fields from the schema.
@@ -1143,6 +1610,7 @@ mod tests {
             description: "API key detected in file".to_string(),
             evidence_refs: vec!["line 42".to_string()],
             mitigation: Some("Remove the key".to_string()),
+            location: None,
         };
         let json = serde_json::to_string(&finding).unwrap();
         assert!(json.contains("\"secrets\""));
@@ -1179,6 +1647,10 @@ mod tests {
             serde_json::to_string(&ArtifactDisposition::Discuss).unwrap(),
             "\"discuss\""
         );
+        assert_eq!(
+            serde_json::to_string(&ArtifactDisposition::Deferred).unwrap(),
+            "\"deferred\""
+        );
     }
 
     #[test]
@@ -1198,6 +1670,10 @@ mod tests {
             comments: None,
             amendment: None,
             kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         };
         let json = serde_json::to_string(&artifact).unwrap();
         let restored: Artifact = serde_json::from_str(&json).unwrap();
@@ -1315,6 +1791,10 @@ mod tests {
             comments: None,
             amendment: None,
             kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         };
         let json = serde_json::to_string(&artifact).unwrap();
         let restored: Artifact = serde_json::from_str(&json).unwrap();
@@ -1477,6 +1957,10 @@ mod tests {
                 reason: Some("Deduplicated struct".to_string()),
             }),
             kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         };
         let json = serde_json::to_string(&artifact).unwrap();
         let restored: Artifact = serde_json::from_str(&json).unwrap();
@@ -1563,6 +2047,7 @@ mod tests {
             review_state: Some("open".to_string()),
             commit_sha: Some("abc1234".to_string()),
             last_checked: Utc::now(),
+            exported_comments: Vec::new(),
         };
         let json = serde_json::to_string(&vcs).unwrap();
         assert!(json.contains("\"branch\""));
@@ -1596,6 +2081,7 @@ mod tests {
             review_state: None,
             commit_sha: Some("def5678".to_string()),
             last_checked: Utc::now(),
+            exported_comments: Vec::new(),
         });
         let json = serde_json::to_string(&pkg).unwrap();
         assert!(json.contains("\"tag\""));
@@ -1695,6 +2181,10 @@ mod tests {
             comments: None,
             amendment: None,
             kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         }
     }
 
@@ -1767,4 +2257,46 @@ mod tests {
         let warn = check_missing_decisions(&pkg);
         assert!(warn.is_none());
     }
+
+    #[test]
+    fn draft_summary_deserializes_listing_fields_from_full_package() {
+        let mut pkg = test_package();
+        pkg.tag = Some("auth-fix".to_string());
+        pkg.plan_phase = Some("v0.9.0".to_string());
+        pkg.status = DraftStatus::PendingReview;
+        let json = serde_json::to_string(&pkg).unwrap();
+
+        let summary: DraftSummary = serde_json::from_str(&json).unwrap();
+        let expected = DraftSummary::from(&pkg);
+        assert_eq!(summary.package_id, expected.package_id);
+        assert_eq!(summary.goal.goal_id, "goal-1");
+        assert_eq!(summary.goal.title, expected.goal.title);
+        assert_eq!(summary.what_changed, "Added test file");
+        assert_eq!(summary.artifact_change_types, vec![ChangeType::Add]);
+        assert_eq!(summary.status, DraftStatus::PendingReview);
+        assert_eq!(summary.tag.as_deref(), Some("auth-fix"));
+        assert_eq!(summary.plan_phase.as_deref(), Some("v0.9.0"));
+        assert_eq!(summary.draft_seq, pkg.draft_seq);
+    }
+
+    #[test]
+    fn content_hash_ignores_order_but_tracks_content() {
+        let mut a = test_package();
+        a.changes.artifacts = vec![
+            make_artifact("fs://workspace/src/a.rs"),
+            make_artifact("fs://workspace/src/b.rs"),
+        ];
+        a.staged_hashes
+            .insert("fs://workspace/src/a.rs".to_string(), "aa".to_string());
+        a.staged_hashes
+            .insert("fs://workspace/src/b.rs".to_string(), "bb".to_string());
+
+        let mut b = a.clone();
+        b.changes.artifacts.reverse();
+        assert_eq!(a.compute_content_hash(), b.compute_content_hash());
+
+        b.staged_hashes
+            .insert("fs://workspace/src/b.rs".to_string(), "changed".to_string());
+        assert_ne!(a.compute_content_hash(), b.compute_content_hash());
+    }
 }
@@ -4,7 +4,7 @@
 //! explanations for reviewers: summary → explanation → full diff.
 
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
@@ -86,19 +86,115 @@ impl ExplanationSidecar {
         }
     }
 
+    /// Path of the sidecar for `file_path`: `<file_path>.diff.explanation.yaml`.
+    pub fn sidecar_path<P: AsRef<Path>>(file_path: P) -> PathBuf {
+        PathBuf::from(format!(
+            "{}.diff.explanation.yaml",
+            file_path.as_ref().display()
+        ))
+    }
+
+    /// Check that the sidecar carries a usable explanation.
+    ///
+    /// A sidecar that parses but has an empty summary or explanation (for
+    /// example an unfilled [`template`](Self::template)) is rejected.
+    pub fn validate(&self) -> Result<(), ChangeSetError> {
+        if self.summary.trim().is_empty() {
+            return Err(ChangeSetError::InvalidData(
+                "explanation sidecar has an empty 'summary'".to_string(),
+            ));
+        }
+        if self.explanation.trim().is_empty() {
+            return Err(ChangeSetError::InvalidData(
+                "explanation sidecar has an empty 'explanation'".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Write a sidecar for `file_path` from explanation tiers.
+    ///
+    /// `file_path` is both where the sidecar goes (next to the file) and the
+    /// value recorded in `file`, so pass it relative to the workspace root when
+    /// running from there. `fs://workspace/` URIs in `related_artifacts` are
+    /// written back as plain paths. Returns the sidecar path.
+    pub fn write_for_file<P: AsRef<Path>>(
+        file_path: P,
+        tiers: &ExplanationTiers,
+    ) -> Result<PathBuf, ChangeSetError> {
+        let sidecar = Self {
+            file: file_path.as_ref().display().to_string(),
+            summary: tiers.summary.clone(),
+            explanation: tiers.explanation.clone(),
+            tags: tiers.tags.clone(),
+            related_artifacts: tiers
+                .related_artifacts
+                .iter()
+                .map(|uri| {
+                    uri.strip_prefix("fs://workspace/")
+                        .unwrap_or(uri)
+                        .to_string()
+                })
+                .collect(),
+        };
+        sidecar.validate()?;
+        let yaml = serde_yaml::to_string(&sidecar).map_err(|e| {
+            ChangeSetError::InvalidData(format!("Failed to serialize explanation sidecar: {}", e))
+        })?;
+        let path = Self::sidecar_path(file_path);
+        fs::write(&path, yaml)?;
+        Ok(path)
+    }
+
+    /// A commented skeleton sidecar for agents to fill in.
+    ///
+    /// The empty `summary` and `explanation` fail [`validate`](Self::validate)
+    /// until they are written, so an unfilled template is reported rather than
+    /// ingested.
+    pub fn template() -> String {
+        "\
+# Explanation sidecar: save as <file>.diff.explanation.yaml next to the changed file.
+# Path of the changed file, relative to the workspace root.
+file: path/to/changed_file.rs
+# One line: what changed.
+summary: \"\"
+# A paragraph: what changed, why, and what else it affects (use `|` for
+# multiple lines).
+explanation: \"\"
+# Optional labels, e.g. [security, breaking-change].
+tags: []
+# Optional paths of other files that belong to this change.
+related_artifacts: []
+"
+        .to_string()
+    }
+
+    /// Load and validate the sidecar for a given file path.
+    ///
+    /// Returns None if the sidecar doesn't exist (sidecars are optional), and
+    /// `Some(Err(..))` if it exists but cannot be read, parsed, or validated.
+    pub fn load_for_file<P: AsRef<Path>>(file_path: P) -> Option<Result<Self, ChangeSetError>> {
+        let sidecar_path = Self::sidecar_path(file_path);
+        if !sidecar_path.exists() {
+            return None;
+        }
+        Some(Self::from_file(&sidecar_path).and_then(|sidecar| {
+            sidecar.validate().map_err(|e| {
+                ChangeSetError::InvalidData(format!("{}: {}", sidecar_path.display(), e))
+            })?;
+            Ok(sidecar)
+        }))
+    }
+
     /// Find explanation sidecar for a given file path.
     ///
     /// Looks for: `<file_path>.diff.explanation.yaml`
     ///
     /// Returns None if the sidecar doesn't exist (this is not an error —
-    /// sidecars are optional).
+    /// sidecars are optional) or is invalid; use
+    /// [`load_for_file`](Self::load_for_file) to see why.
     pub fn find_for_file<P: AsRef<Path>>(file_path: P) -> Option<Self> {
-        let sidecar_path = format!("{}.diff.explanation.yaml", file_path.as_ref().display());
-        if Path::new(&sidecar_path).exists() {
-            Self::from_file(sidecar_path).ok()
-        } else {
-            None
-        }
+        Self::load_for_file(file_path).and_then(Result::ok)
     }
 }
 
@@ -215,4 +311,64 @@ explanation: "Test explanation"
             .to_string()
             .contains("Failed to parse explanation sidecar YAML"));
     }
+
+    #[test]
+    fn write_for_file_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("main.rs");
+        let tiers = ExplanationTiers {
+            summary: "Add entry point".to_string(),
+            explanation: "Adds main() that parses args.".to_string(),
+            tags: vec!["cli".to_string()],
+            related_artifacts: vec!["fs://workspace/src/args.rs".to_string()],
+        };
+
+        let written = ExplanationSidecar::write_for_file(&file_path, &tiers).unwrap();
+        assert_eq!(written, ExplanationSidecar::sidecar_path(&file_path));
+
+        let sidecar = ExplanationSidecar::load_for_file(&file_path)
+            .unwrap()
+            .unwrap();
+        assert_eq!(sidecar.related_artifacts, vec!["src/args.rs"]);
+        assert_eq!(sidecar.into_tiers(), tiers);
+    }
+
+    #[test]
+    fn write_for_file_rejects_empty_summary() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("main.rs");
+        let tiers = ExplanationTiers {
+            summary: "  ".to_string(),
+            explanation: "Something".to_string(),
+            tags: vec![],
+            related_artifacts: vec![],
+        };
+        assert!(ExplanationSidecar::write_for_file(&file_path, &tiers).is_err());
+        assert!(!ExplanationSidecar::sidecar_path(&file_path).exists());
+    }
+
+    #[test]
+    fn unfilled_template_parses_but_fails_validation() {
+        let sidecar: ExplanationSidecar =
+            serde_yaml::from_str(&ExplanationSidecar::template()).unwrap();
+        let err = sidecar.validate().unwrap_err().to_string();
+        assert!(err.contains("summary"), "{}", err);
+    }
+
+    #[test]
+    fn load_for_file_reports_invalid_sidecar() {
+        let dir = tempfile::tempdir().unwrap();
+        let file_path = dir.path().join("lib.rs");
+        fs::write(
+            ExplanationSidecar::sidecar_path(&file_path),
+            "summary: [not, a, string\n",
+        )
+        .unwrap();
+
+        let err = ExplanationSidecar::load_for_file(&file_path)
+            .unwrap()
+            .unwrap_err();
+        assert!(err.to_string().contains("Failed to parse"));
+        assert!(ExplanationSidecar::find_for_file(&file_path).is_none());
+    }
 }
@@ -27,6 +27,9 @@ pub enum InteractionKind {
     Escalation,
     /// Agent is asking the human a question mid-execution.
     AgentQuestion,
+    /// Agent is at a branch point and asks the human to pick one of several
+    /// options. Answered with `Decision::Choice`.
+    Choice { options: Vec<String> },
     /// Extension point for future interaction types.
     Custom(String),
 }
@@ -39,6 +42,7 @@ impl fmt::Display for InteractionKind {
             InteractionKind::PlanNegotiation => write!(f, "plan_negotiation"),
             InteractionKind::Escalation => write!(f, "escalation"),
             InteractionKind::AgentQuestion => write!(f, "agent_question"),
+            InteractionKind::Choice { .. } => write!(f, "choice"),
             InteractionKind::Custom(name) => write!(f, "custom:{}", name),
         }
     }
@@ -147,6 +151,15 @@ impl InteractionRequest {
         )
     }
 
+    /// Create a Choice interaction request: `question` with numbered `options`.
+    pub fn choice(question: &str, options: Vec<String>) -> Self {
+        Self::new(
+            InteractionKind::Choice { options },
+            serde_json::json!({ "question": question }),
+            Urgency::Blocking,
+        )
+    }
+
     /// Create an Escalation interaction request.
     pub fn escalation(reason: &str, details: serde_json::Value) -> Self {
         Self::new(
@@ -182,6 +195,8 @@ pub enum Decision {
     Discuss,
     /// Skip this interaction for now (non-blocking interactions only).
     SkipForNow,
+    /// Picked an option of an `InteractionKind::Choice` (zero-based index).
+    Choice { index: usize },
 }
 
 impl fmt::Display for Decision {
@@ -191,6 +206,7 @@ impl fmt::Display for Decision {
             Decision::Reject { reason } => write!(f, "rejected: {}", reason),
             Decision::Discuss => write!(f, "discuss"),
             Decision::SkipForNow => write!(f, "skipped"),
+            Decision::Choice { index } => write!(f, "chose option {}", index + 1),
         }
     }
 }
@@ -298,7 +314,10 @@ impl Notification {
 }
 
 /// Notification severity level.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+///
+/// Variants are ordered from least to most severe, so levels can be compared
+/// against a threshold (`level >= NotificationLevel::Warning`).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "snake_case")]
 pub enum NotificationLevel {
     Debug,
@@ -329,6 +348,11 @@ pub struct ChannelCapabilities {
 
     /// Whether the channel supports threaded discussions.
     pub supports_threads: bool,
+
+    /// Seconds the channel waits for a human before answering with a default
+    /// decision. `None` means responses wait for the human indefinitely.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_timeout_secs: Option<u64>,
 }
 
 #[cfg(test)]
@@ -371,6 +395,27 @@ mod tests {
         assert_eq!(req.context["reason"], "exceeded token budget");
     }
 
+    #[test]
+    fn choice_request_round_trips() {
+        let req = InteractionRequest::choice("Which way?", vec!["left".into(), "right".into()]);
+        assert_eq!(req.kind.to_string(), "choice");
+        assert_eq!(req.context["question"], "Which way?");
+        let json = serde_json::to_string(&req).unwrap();
+        let back: InteractionRequest = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            back.kind,
+            InteractionKind::Choice {
+                options: vec!["left".into(), "right".into()]
+            }
+        );
+
+        let decision = Decision::Choice { index: 1 };
+        assert_eq!(decision.to_string(), "chose option 2");
+        let json = serde_json::to_value(&decision).unwrap();
+        assert_eq!(json["decision"], "choice");
+        assert_eq!(json["index"], 1);
+    }
+
     #[test]
     fn interaction_response_creation() {
         let id = Uuid::new_v4();
@@ -31,6 +31,7 @@ pub mod plugin_resolver;
 pub mod pr_package;
 pub mod project_manifest;
 pub mod registry_client;
+pub mod resource_uri;
 pub mod review_channel;
 pub mod review_report;
 pub mod review_session;
@@ -59,7 +60,8 @@ pub use diff::DiffContent;
 pub use diff_handlers::{DiffHandlerError, DiffHandlersConfig, HandlerRule};
 pub use draft_package::{
     ActionKind, ApplyProvenance, ApprovalRecord, DesignAlternative, DraftPackage, DraftStatus,
-    ExplanationTiers, IgnoredArtifact, PendingAction, ValidationEntry, VcsTrackingInfo,
+    DraftSummary, DraftSummaryGoal, ExplanationTiers, FileReviewAssignment, IgnoredArtifact,
+    ModeChange, PendingAction, SubmitOutcome, SubmitStepStatus, ValidationEntry, VcsTrackingInfo,
     WorkPlanData, WorkPlanDataDecision, WorkPlanDataStep,
 };
 pub use draft_resolver::{draft_canonical_id, resolve_draft, DraftResolveError};
@@ -71,16 +73,17 @@ pub use interaction::{
 };
 pub use interactive_session_store::InteractiveSessionStore;
 pub use multi_channel::{MultiChannelStrategy, MultiReviewChannel};
-pub use output_adapters::{DetailLevel, OutputAdapter, OutputFormat, RenderContext};
+pub use output_adapters::{ArtifactOrder, DetailLevel, OutputAdapter, OutputFormat, RenderContext};
+pub use resource_uri::ResourceUri;
 pub use review_channel::{build_channel, ReviewChannel, ReviewChannelConfig, ReviewChannelError};
 pub use review_session::{
-    ArtifactReview, Comment, CommentThread, DispositionCounts, ReviewReasoning, ReviewSession,
-    ReviewState, SessionNote,
+    ArtifactReview, ArtifactSetDiff, Comment, CommentAnchor, CommentThread, DispositionCounts,
+    ReanchorSummary, ReviewReasoning, ReviewSession, ReviewSessionExport, ReviewState, SessionNote,
 };
 pub use review_session_store::ReviewSessionStore;
 pub use session_channel::{
     HumanInput, InteractiveConfig, InteractiveSession, InteractiveSessionState, OutputStream,
-    SessionChannel, SessionChannelError, SessionEvent, SessionMessage,
+    SessionChannel, SessionChannelError, SessionEvent, SessionMessage, TimeoutDecision,
 };
 pub use sources::{
     CachedItem, ExternalSource, LockEntry, Lockfile, PackageManifest, SourceCache, SourceError,
@@ -189,6 +189,12 @@ impl ReviewChannel for MultiReviewChannel {
             caps.supports_async = caps.supports_async || c.supports_async;
             caps.supports_rich_media = caps.supports_rich_media || c.supports_rich_media;
             caps.supports_threads = caps.supports_threads || c.supports_threads;
+            // Any human-facing channel may answer by default after its timeout.
+            caps.response_timeout_secs = match (caps.response_timeout_secs, c.response_timeout_secs)
+            {
+                (Some(a), Some(b)) => Some(a.min(b)),
+                (a, b) => a.or(b),
+            };
         }
         caps
     }
@@ -1,7 +1,16 @@
 //! html.rs — HTML output adapter with JavaScript-free progressive disclosure.
+//!
+//! The page is a single self-contained file (inline CSS/JS, no external
+//! assets) so `ta draft view <id> --format html > review.html` opens offline.
+//! Large drafts get a file-tree sidebar grouped by directory with jump-to
+//! anchors, and a sticky header with per-disposition counts.
+
+use std::collections::BTreeMap;
 
 use crate::error::ChangeSetError;
-use crate::output_adapters::{matches_file_filters, DetailLevel, OutputAdapter, RenderContext};
+use crate::output_adapters::{
+    matches_file_filters, matches_kind_filters, DetailLevel, OutputAdapter, RenderContext,
+};
 use crate::pr_package::{Artifact, ArtifactDisposition, ChangeType};
 
 #[derive(Default)]
@@ -18,6 +27,7 @@ impl HtmlAdapter {
             ArtifactDisposition::Approved => r#"<span class="status approved">approved</span>"#,
             ArtifactDisposition::Rejected => r#"<span class="status denied">rejected</span>"#,
             ArtifactDisposition::Discuss => r#"<span class="status discuss">discuss</span>"#,
+            ArtifactDisposition::Deferred => r#"<span class="status deferred">deferred</span>"#,
         }
     }
 
@@ -30,10 +40,128 @@ impl HtmlAdapter {
         }
     }
 
+    /// Stable anchor id for an artifact's `<details>` block.
+    ///
+    /// The readable slug folds punctuation to `-`, so `a/b.rs` and `a-b.rs`
+    /// share one; a short hash of the URI keeps the id unique.
+    fn file_anchor(&self, resource_uri: &str) -> String {
+        use sha2::{Digest, Sha256};
+        let slug: String = resource_uri
+            .strip_prefix("fs://workspace/")
+            .unwrap_or(resource_uri)
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '-' || c == '_' {
+                    c
+                } else {
+                    '-'
+                }
+            })
+            .collect();
+        let digest = Sha256::digest(resource_uri.as_bytes());
+        format!(
+            "file-{}-{:02x}{:02x}{:02x}{:02x}",
+            slug, digest[0], digest[1], digest[2], digest[3]
+        )
+    }
+
+    /// Comment-count badge from the live review session (empty when there are none).
+    fn comment_badge(&self, count: usize) -> String {
+        match count {
+            0 => String::new(),
+            1 => r#" <span class="comments">1 comment</span>"#.to_string(),
+            n => format!(r#" <span class="comments">{} comments</span>"#, n),
+        }
+    }
+
+    /// Sticky header with approve/reject/pending/discuss counts.
+    fn review_bar(&self, ctx: &RenderContext, artifacts: &[&Artifact]) -> String {
+        let count = |d: ArtifactDisposition| {
+            artifacts
+                .iter()
+                .filter(|a| ctx.disposition_for(a) == d)
+                .count()
+        };
+        let reviewer = ctx
+            .review_session
+            .map(|s| {
+                format!(
+                    " <span class=\"reviewer\">reviewing: {}</span>",
+                    escape_html(&s.reviewer)
+                )
+            })
+            .unwrap_or_default();
+        format!(
+            "<div class=\"review-bar\"><strong>{} files</strong> \
+             <span class=\"status approved\">{} approved</span> \
+             <span class=\"status denied\">{} rejected</span> \
+             <span class=\"status pending\">{} pending</span> \
+             <span class=\"status discuss\">{} discuss</span> \
+             <span class=\"status deferred\">{} deferred</span>{}</div>\n",
+            artifacts.len(),
+            count(ArtifactDisposition::Approved),
+            count(ArtifactDisposition::Rejected),
+            count(ArtifactDisposition::Pending),
+            count(ArtifactDisposition::Discuss),
+            count(ArtifactDisposition::Deferred),
+            reviewer,
+        )
+    }
+
+    /// Collapsible file-tree sidebar, one `<details>` per directory.
+    fn file_tree(&self, ctx: &RenderContext, artifacts: &[&Artifact]) -> String {
+        let mut dirs: BTreeMap<&str, Vec<(&str, &Artifact)>> = BTreeMap::new();
+        for artifact in artifacts {
+            let path = artifact
+                .resource_uri
+                .strip_prefix("fs://workspace/")
+                .unwrap_or(&artifact.resource_uri);
+            let (dir, name) = match path.rfind('/') {
+                Some(i) => (&path[..i], &path[i + 1..]),
+                None => ("", path),
+            };
+            dirs.entry(dir).or_default().push((name, artifact));
+        }
+
+        let mut out = String::from("<nav class=\"sidebar\">\n<h3>Files</h3>\n");
+        for (dir, files) in &dirs {
+            let label = if dir.is_empty() { "./" } else { dir };
+            out.push_str(&format!(
+                "<details open data-key=\"tree-{}\">\n<summary>{}/ <span class=\"count\">({})</span></summary>\n<ul>\n",
+                dir.replace('/', "-"),
+                escape_html(label.trim_end_matches('/')),
+                files.len()
+            ));
+            for (name, artifact) in files {
+                out.push_str(&format!(
+                    "<li>{}<a href=\"#{}\" class=\"{}\">{}</a></li>\n",
+                    self.change_badge(&artifact.change_type),
+                    self.file_anchor(&artifact.resource_uri),
+                    ctx.disposition_for(artifact),
+                    escape_html(name)
+                ));
+            }
+            out.push_str("</ul>\n</details>\n");
+        }
+        out.push_str("</nav>\n");
+        out
+    }
+
     fn css(&self) -> &str {
         r#"
         <style>
-            body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif; max-width: 1200px; margin: 0 auto; padding: 20px; line-height: 1.6; }
+            body { font-family: -apple-system, BlinkMacSystemFont, 'Segoe UI', sans-serif; max-width: 1500px; margin: 0 auto; padding: 20px; line-height: 1.6; }
+            .layout { display: flex; gap: 24px; align-items: flex-start; }
+            .layout main { flex: 1; min-width: 0; }
+            .sidebar { position: sticky; top: 60px; width: 280px; flex-shrink: 0; max-height: calc(100vh - 80px); overflow-y: auto; font-size: 13px; border-right: 1px solid #e5e7eb; padding-right: 12px; }
+            .sidebar ul { list-style: none; margin: 4px 0 8px 0; padding-left: 12px; }
+            .sidebar li { white-space: nowrap; overflow: hidden; text-overflow: ellipsis; margin: 2px 0; }
+            .sidebar a { color: #1f2937; text-decoration: none; }
+            .sidebar a:hover { text-decoration: underline; }
+            .sidebar a.rejected { text-decoration: line-through; color: #991b1b; }
+            .sidebar .badge { width: 18px; height: 18px; line-height: 18px; font-size: 11px; margin-right: 4px; }
+            .sidebar .count { color: #9ca3af; font-weight: 400; }
+            .review-bar { position: sticky; top: 0; z-index: 10; background: #fff; border-bottom: 1px solid #e5e7eb; padding: 10px 0; margin-bottom: 20px; display: flex; gap: 10px; align-items: center; }
             h1, h2, h3 { color: #333; }
             .header { background: #f5f5f5; padding: 20px; border-radius: 8px; margin-bottom: 30px; }
             .status { display: inline-block; padding: 4px 12px; border-radius: 4px; font-weight: 600; text-transform: uppercase; font-size: 12px; }
@@ -41,6 +169,7 @@ impl HtmlAdapter {
             .status.approved { background: #d1fae5; color: #065f46; }
             .status.denied { background: #fee2e2; color: #991b1b; }
             .status.discuss { background: #dbeafe; color: #1e40af; }
+            .status.deferred { background: #f3f4f6; color: #4b5563; }
             .artifact { background: white; border: 1px solid #e5e7eb; border-radius: 8px; padding: 20px; margin-bottom: 20px; }
             .badge { display: inline-block; width: 24px; height: 24px; text-align: center; border-radius: 4px; font-weight: 700; margin-right: 8px; }
             .badge.add { background: #d1fae5; color: #065f46; }
@@ -62,6 +191,8 @@ impl HtmlAdapter {
             .decision-title { font-weight: 600; color: #0c4a6e; }
             .decision-alts { color: #6b7280; font-size: 14px; }
             .decision-rationale { color: #374151; margin-top: 6px; }
+            .comments { display: inline-block; padding: 4px 10px; border-radius: 4px; font-size: 12px; background: #f3f4f6; color: #374151; }
+            .reviewer { color: #6b7280; font-size: 13px; margin-left: auto; }
             .confidence { background: #e0f2fe; color: #0369a1; padding: 2px 8px; border-radius: 10px; font-size: 12px; margin-left: 8px; }
         </style>
         <script>
@@ -74,6 +205,13 @@ impl HtmlAdapter {
                     localStorage.setItem(key, el.open ? 'open' : 'closed');
                 });
             });
+            // Jump-to anchors: open the target file (and its parents) so it is visible.
+            function openTarget() {
+                var el = location.hash && document.getElementById(location.hash.slice(1));
+                while (el) { if (el.tagName === 'DETAILS') { el.open = true; } el = el.parentElement; }
+            }
+            window.addEventListener('hashchange', openTarget);
+            openTarget();
         });
         </script>
         "#
@@ -98,6 +236,22 @@ impl OutputAdapter for HtmlAdapter {
         let show_files =
             ctx.section_filter.is_none() || ctx.section_filter == Some(SectionFilter::Files);
 
+        let mut artifacts: Vec<&Artifact> = pkg
+            .changes
+            .artifacts
+            .iter()
+            .filter(|a| matches_file_filters(&a.resource_uri, &ctx.file_filters))
+            .filter(|a| matches_kind_filters(&a.change_type, &ctx.kind_filters))
+            .collect();
+        ctx.artifact_order.sort(&mut artifacts, &pkg.risk);
+
+        html.push_str(&self.review_bar(ctx, &artifacts));
+        html.push_str("<div class=\"layout\">\n");
+        if show_files && !artifacts.is_empty() {
+            html.push_str(&self.file_tree(ctx, &artifacts));
+        }
+        html.push_str("<main>\n");
+
         if show_summary {
             // Header
             html.push_str("<div class=\"header\">\n");
@@ -183,13 +337,6 @@ impl OutputAdapter for HtmlAdapter {
         }
 
         if show_files {
-            let artifacts: Vec<&Artifact> = pkg
-                .changes
-                .artifacts
-                .iter()
-                .filter(|a| matches_file_filters(&a.resource_uri, &ctx.file_filters))
-                .collect();
-
             html.push_str(&format!(
                 "<details open data-key=\"files\">\n<summary><h2 style=\"display:inline\">Changed Files ({})</h2></summary>\n",
                 artifacts.len()
@@ -198,13 +345,14 @@ impl OutputAdapter for HtmlAdapter {
             for artifact in &artifacts {
                 // Each file is wrapped in a collapsible <details>
                 html.push_str(&format!(
-                    "<details data-key=\"file-{}\">\n",
-                    artifact.resource_uri.replace('/', "-")
+                    "<details id=\"{0}\" data-key=\"{0}\">\n",
+                    self.file_anchor(&artifact.resource_uri)
                 ));
                 html.push_str(&format!(
-                    "<summary class=\"artifact\">{} {} <strong>{}</strong>",
+                    "<summary class=\"artifact\">{} {}{} <strong>{}</strong>",
                     self.change_badge(&artifact.change_type),
-                    self.disposition_badge(&artifact.disposition),
+                    self.disposition_badge(&ctx.disposition_for(artifact)),
+                    self.comment_badge(ctx.comment_count(artifact)),
                     artifact.resource_uri
                 ));
 
@@ -230,6 +378,27 @@ impl OutputAdapter for HtmlAdapter {
                     }
                 }
 
+                // Review-session comment thread for this file.
+                if let Some(review) = ctx
+                    .review_session
+                    .and_then(|s| s.artifact_reviews.get(&artifact.resource_uri))
+                    .filter(|r| !r.comments.comments.is_empty())
+                {
+                    html.push_str("<div class=\"meta\">\n");
+                    for comment in &review.comments.comments {
+                        html.push_str(&format!(
+                            "<p><strong>{}{}:</strong> {}</p>\n",
+                            escape_html(&comment.commenter),
+                            comment
+                                .location_label()
+                                .map(|l| format!(" ({})", escape_html(&l)))
+                                .unwrap_or_default(),
+                            escape_html(&comment.text)
+                        ));
+                    }
+                    html.push_str("</div>\n");
+                }
+
                 // Diffs are always shown in a nested collapsible (collapsed by default)
                 if let Some(provider) = ctx.diff_provider {
                     if let Ok(diff) = provider.get_diff(&artifact.diff_ref) {
@@ -264,6 +433,7 @@ impl OutputAdapter for HtmlAdapter {
             "<div class=\"meta\">Generated by Trusted Autonomy v{}</div>\n",
             pkg.package_version
         ));
+        html.push_str("</main>\n</div>\n");
         html.push_str("</body>\n</html>");
 
         Ok(html)
@@ -274,9 +444,275 @@ impl OutputAdapter for HtmlAdapter {
     }
 }
 
+/// Escape text for safe inclusion in HTML element content and attributes.
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::output_adapters::ArtifactOrder;
+
+    fn package_with_artifacts(
+        artifacts: Vec<crate::draft_package::Artifact>,
+    ) -> crate::draft_package::DraftPackage {
+        use crate::draft_package::*;
+        use chrono::Utc;
+        use uuid::Uuid;
+
+        DraftPackage {
+            package_version: "1.0.0".to_string(),
+            package_id: Uuid::nil(),
+            created_at: Utc::now(),
+            goal: Goal {
+                goal_id: "g1".to_string(),
+                title: "Test".to_string(),
+                objective: "Test".to_string(),
+                success_criteria: vec![],
+                constraints: vec![],
+                parent_goal_title: None,
+            },
+            iteration: Iteration {
+                iteration_id: "i1".to_string(),
+                sequence: 1,
+                workspace_ref: WorkspaceRef {
+                    ref_type: "staging_dir".to_string(),
+                    ref_name: "staging/g1/1".to_string(),
+                    base_ref: None,
+                },
+            },
+            agent_identity: AgentIdentity {
+                agent_id: "a1".to_string(),
+                agent_type: "test".to_string(),
+                constitution_id: "default".to_string(),
+                capability_manifest_hash: "abc".to_string(),
+                orchestrator_run_id: None,
+            },
+            summary: Summary {
+                what_changed: "test".to_string(),
+                why: "test".to_string(),
+                impact: "none".to_string(),
+                rollback_plan: "revert".to_string(),
+                open_questions: vec![],
+                alternatives_considered: vec![],
+            },
+            plan: Plan {
+                completed_steps: vec![],
+                next_steps: vec![],
+                decision_log: vec![],
+            },
+            changes: Changes {
+                artifacts,
+                patch_sets: vec![],
+                pending_actions: vec![],
+            },
+            risk: Risk {
+                risk_score: 0,
+                findings: vec![],
+                policy_decisions: vec![],
+            },
+            provenance: Provenance {
+                inputs: vec![],
+                tool_trace_hash: "hash".to_string(),
+            },
+            review_requests: ReviewRequests {
+                requested_actions: vec![],
+                reviewers: vec![],
+                required_approvals: 1,
+                notes_to_reviewer: None,
+            },
+            signatures: Signatures {
+                package_hash: "hash".to_string(),
+                agent_signature: "sig".to_string(),
+                gateway_attestation: None,
+            },
+            status: DraftStatus::Draft,
+            verification_warnings: vec![],
+            validation_log: vec![],
+            display_id: None,
+            tag: None,
+            vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
+            parent_draft_id: None,
+            pending_approvals: vec![],
+            review_assignments: vec![],
+            supervisor_review: None,
+            ignored_artifacts: vec![],
+            baseline_artifacts: vec![],
+            agent_decision_log: vec![],
+            work_plan: None,
+            goal_shortref: None,
+            draft_seq: 0,
+            plan_phase: None,
+            plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
+        }
+    }
+
+    fn artifact(path: &str, disposition: ArtifactDisposition) -> Artifact {
+        Artifact {
+            resource_uri: format!("fs://workspace/{}", path),
+            change_type: ChangeType::Modify,
+            disposition,
+            diff_ref: String::new(),
+            rationale: None,
+            explanation_tiers: None,
+            comments: None,
+            amendment: None,
+            tests_run: vec![],
+            dependencies: vec![],
+            kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
+        }
+    }
+
+    #[test]
+    fn html_file_tree_sidebar_groups_by_directory_with_anchors() {
+        let pkg = package_with_artifacts(vec![
+            artifact("src/main.rs", ArtifactDisposition::Approved),
+            artifact("src/lib.rs", ArtifactDisposition::Rejected),
+            artifact("docs/guide.md", ArtifactDisposition::Pending),
+            artifact("README.md", ArtifactDisposition::Approved),
+        ]);
+        let adapter = HtmlAdapter::new();
+        let ctx = RenderContext {
+            package: &pkg,
+            detail_level: DetailLevel::Top,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        let html = adapter.render(&ctx).unwrap();
+
+        assert!(html.contains(r#"<nav class="sidebar">"#));
+        assert!(html.contains("<summary>src/ "));
+        assert!(html.contains("<summary>docs/ "));
+        // Sidebar links point at the per-file <details> anchors.
+        let main_anchor = adapter.file_anchor("fs://workspace/src/main.rs");
+        assert!(main_anchor.starts_with("file-src-main-rs-"));
+        assert!(html.contains(&format!("href=\"#{}\"", main_anchor)));
+        assert!(html.contains(&format!("<details id=\"{}\"", main_anchor)));
+        assert!(html.contains(&format!(
+            "<details id=\"{}\"",
+            adapter.file_anchor("fs://workspace/README.md")
+        )));
+        // Sticky header counts.
+        assert!(html.contains("4 files"));
+        assert!(html.contains("2 approved"));
+        assert!(html.contains("1 rejected"));
+        assert!(html.contains("1 pending"));
+        // Self-contained: no external stylesheets or scripts.
+        assert!(!html.contains("<link"));
+        assert!(!html.contains("src=\"http"));
+    }
+
+    #[test]
+    fn review_session_overrides_dispositions_and_shows_comments() {
+        let pkg = package_with_artifacts(vec![
+            artifact("src/main.rs", ArtifactDisposition::Pending),
+            artifact("src/lib.rs", ArtifactDisposition::Pending),
+        ]);
+        let mut session =
+            crate::review_session::ReviewSession::new(pkg.package_id, "alice".to_string());
+        session.set_disposition("fs://workspace/src/main.rs", ArtifactDisposition::Approved);
+        session.add_comment("fs://workspace/src/lib.rs", "alice", "needs <tests>");
+        session.add_comment("fs://workspace/src/lib.rs", "agent", "added");
+        let mut ctx = RenderContext {
+            package: &pkg,
+            detail_level: DetailLevel::Medium,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: Some(&session),
+        };
+
+        let html = HtmlAdapter::new().render(&ctx).unwrap();
+        assert!(html.contains("1 approved"));
+        assert!(html.contains("1 pending"));
+        assert!(html.contains("reviewing: alice"));
+        assert!(html.contains(r#"<span class="comments">2 comments</span>"#));
+        assert!(html.contains("needs &lt;tests&gt;"));
+
+        ctx.detail_level = DetailLevel::Top;
+        let md = crate::output_adapters::markdown::MarkdownAdapter::new()
+            .render(&ctx)
+            .unwrap();
+        assert!(
+            md.contains("**Progress**: 1 approved, 0 rejected, 0 discuss, 0 deferred, 1 pending")
+        );
+        assert!(md.contains("src/main.rs** — modified · ✅ approved\n"));
+        assert!(md.contains("src/lib.rs** — modified · ⏳ pending · 2 comments\n"));
+
+        // Without a session the package's own dispositions are shown.
+        ctx.review_session = None;
+        let plain = HtmlAdapter::new().render(&ctx).unwrap();
+        assert!(plain.contains("0 approved"));
+        assert!(!plain.contains("class=\"comments\""));
+    }
+
+    #[test]
+    fn html_sidebar_escapes_file_names() {
+        let pkg =
+            package_with_artifacts(vec![artifact("src/<odd>.rs", ArtifactDisposition::Pending)]);
+        let adapter = HtmlAdapter::new();
+        let ctx = RenderContext {
+            package: &pkg,
+            detail_level: DetailLevel::Top,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        let html = adapter.render(&ctx).unwrap();
+        assert!(html.contains("&lt;odd&gt;.rs</a>"));
+        assert!(html.contains(r#"id="file-src--odd--rs-"#));
+    }
+
+    #[test]
+    fn file_anchors_are_unique_when_slugs_collide() {
+        let pkg = package_with_artifacts(vec![
+            artifact("a/b.rs", ArtifactDisposition::Pending),
+            artifact("a-b.rs", ArtifactDisposition::Pending),
+        ]);
+        let adapter = HtmlAdapter::new();
+        let nested = adapter.file_anchor("fs://workspace/a/b.rs");
+        let flat = adapter.file_anchor("fs://workspace/a-b.rs");
+        assert_ne!(nested, flat);
+        assert_eq!(nested, adapter.file_anchor("fs://workspace/a/b.rs"));
+
+        let ctx = RenderContext {
+            package: &pkg,
+            detail_level: DetailLevel::Top,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        let html = adapter.render(&ctx).unwrap();
+        assert_eq!(html.matches(&format!("id=\"{}\"", nested)).count(), 1);
+        assert_eq!(html.matches(&format!("id=\"{}\"", flat)).count(), 1);
+    }
 
     #[test]
     fn disposition_badge_renders_all_variants() {
@@ -364,6 +800,10 @@ mod tests {
                     tests_run: vec![],
                     dependencies: vec![],
                     kind: None,
+                    no_commit: false,
+                    renamed_from: None,
+                    mode_change: None,
+                    line_endings_normalized: None,
                 }],
                 patch_sets: vec![],
                 pending_actions: vec![],
@@ -394,8 +834,11 @@ mod tests {
             display_id: None,
             tag: None,
             vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
             parent_draft_id: None,
             pending_approvals: vec![],
+            review_assignments: vec![],
             supervisor_review: None,
             ignored_artifacts: vec![],
             baseline_artifacts: vec![],
@@ -405,6 +848,11 @@ mod tests {
             draft_seq: 0,
             plan_phase: None,
             plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
         };
         pkg.status = DraftStatus::PendingReview;
 
@@ -413,8 +861,11 @@ mod tests {
             package: &pkg,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let html = adapter.render(&ctx).unwrap();
         assert!(html.contains(r#"class="status discuss""#));
@@ -481,6 +932,10 @@ mod tests {
                     tests_run: vec![],
                     dependencies: vec![],
                     kind: None,
+                    no_commit: false,
+                    renamed_from: None,
+                    mode_change: None,
+                    line_endings_normalized: None,
                 }],
                 patch_sets: vec![],
                 pending_actions: vec![],
@@ -511,8 +966,11 @@ mod tests {
             display_id: None,
             tag: None,
             vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
             parent_draft_id: None,
             pending_approvals: vec![],
+            review_assignments: vec![],
             supervisor_review: None,
             ignored_artifacts: vec![],
             baseline_artifacts: vec![],
@@ -522,6 +980,11 @@ mod tests {
             draft_seq: 0,
             plan_phase: None,
             plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
         };
 
         let adapter = HtmlAdapter::new();
@@ -529,8 +992,11 @@ mod tests {
             package: &pkg,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let html = adapter.render(&ctx).unwrap();
         // Files wrapped in collapsible <details>
@@ -624,8 +1090,11 @@ mod tests {
             display_id: None,
             tag: None,
             vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
             parent_draft_id: None,
             pending_approvals: vec![],
+            review_assignments: vec![],
             supervisor_review: None,
             ignored_artifacts: vec![],
             baseline_artifacts: vec![],
@@ -635,6 +1104,11 @@ mod tests {
             draft_seq: 0,
             plan_phase: None,
             plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
         };
         pkg.agent_decision_log = vec![DecisionLogEntry {
             decision: "Used Ed25519 over RSA".to_string(),
@@ -650,8 +1124,11 @@ mod tests {
             package: &pkg,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let html = adapter.render(&ctx).unwrap();
         // Decision log section present with details/summary
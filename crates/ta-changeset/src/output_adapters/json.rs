@@ -1,25 +1,68 @@
 //! json.rs — JSON output adapter for machine-readable output.
 
+use serde::Serialize;
+
+use crate::draft_package::DraftPackage;
 use crate::error::ChangeSetError;
-use crate::output_adapters::{OutputAdapter, RenderContext};
+use crate::output_adapters::{ArtifactOrder, OutputAdapter, RenderContext};
+
+/// `$id` of the bundled `schema/draft_package.schema.json`. Emitted as the
+/// top-level `$schema` field by [`JsonAdapter::with_schema`].
+pub const DRAFT_PACKAGE_SCHEMA_URL: &str =
+    "https://raw.githubusercontent.com/Trusted-Autonomy/TrustedAutonomy/main/schema/draft_package.schema.json";
 
 #[derive(Default)]
-pub struct JsonAdapter {}
+pub struct JsonAdapter {
+    include_schema: bool,
+}
 
 impl JsonAdapter {
     pub fn new() -> Self {
-        Self {}
+        Self::default()
+    }
+
+    /// Embed a top-level `$schema` URL so consumers can pin to the schema
+    /// (`package_version` is always present).
+    pub fn with_schema(mut self) -> Self {
+        self.include_schema = true;
+        self
     }
 }
 
+/// A package with `$schema` prepended to its fields.
+#[derive(Serialize)]
+struct SchemaStamped<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    #[serde(flatten)]
+    package: &'a DraftPackage,
+}
+
 impl OutputAdapter for JsonAdapter {
     fn render(&self, ctx: &RenderContext) -> Result<String, ChangeSetError> {
         // For JSON output, we serialize the entire PRPackage
-        // The detail_level and file_filters are ignored — the consumer can filter client-side
+        // The detail_level and file_filters are ignored — the consumer can filter client-side.
+        // The artifact order is applied so consumers see what the reviewer sees.
+        let ordered;
+        let package = if ctx.artifact_order == ArtifactOrder::AsIs {
+            ctx.package
+        } else {
+            let mut pkg = ctx.package.clone();
+            ctx.artifact_order
+                .sort(&mut pkg.changes.artifacts, &pkg.risk);
+            ordered = pkg;
+            &ordered
+        };
 
-        let json = serde_json::to_string_pretty(ctx.package).map_err(|e| {
-            ChangeSetError::InvalidData(format!("JSON serialization failed: {}", e))
-        })?;
+        let json = if self.include_schema {
+            serde_json::to_string_pretty(&SchemaStamped {
+                schema: DRAFT_PACKAGE_SCHEMA_URL,
+                package,
+            })
+        } else {
+            serde_json::to_string_pretty(package)
+        }
+        .map_err(|e| ChangeSetError::InvalidData(format!("JSON serialization failed: {}", e)))?;
 
         Ok(json)
     }
@@ -35,11 +78,11 @@ mod tests {
     use crate::output_adapters::DetailLevel;
     use crate::pr_package::*;
     use chrono::Utc;
+    use serde_json::Value;
     use uuid::Uuid;
 
-    #[test]
-    fn renders_valid_json() {
-        let package = PRPackage {
+    fn test_package() -> PRPackage {
+        PRPackage {
             package_version: "1.0.0".to_string(),
             package_id: Uuid::new_v4(),
             created_at: Utc::now(),
@@ -111,8 +154,11 @@ mod tests {
             display_id: None,
             tag: None,
             vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
             parent_draft_id: None,
             pending_approvals: vec![],
+            review_assignments: vec![],
             supervisor_review: None,
             ignored_artifacts: vec![],
             baseline_artifacts: vec![],
@@ -122,15 +168,54 @@ mod tests {
             draft_seq: 0,
             plan_phase: None,
             plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
+        }
+    }
+
+    fn render(adapter: &JsonAdapter, package: &PRPackage) -> serde_json::Value {
+        let ctx = RenderContext {
+            package,
+            detail_level: DetailLevel::Full,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
         };
+        serde_json::from_str(&adapter.render(&ctx).unwrap()).unwrap()
+    }
+
+    /// Every way `value` violates `schema`, as "<instance path>: <message>".
+    fn schema_errors(schema: &Value, value: &Value) -> Vec<String> {
+        let validator = jsonschema::validator_for(schema).expect("bundled schema compiles");
+        validator
+            .iter_errors(value)
+            .map(|e| format!("{}: {}", e.instance_path, e))
+            .collect()
+    }
+
+    fn bundled_schema() -> Value {
+        serde_json::from_str(include_str!("../../../../schema/draft_package.schema.json")).unwrap()
+    }
 
+    #[test]
+    fn renders_valid_json() {
+        let package = test_package();
         let adapter = JsonAdapter::new();
         let ctx = RenderContext {
             package: &package,
             detail_level: DetailLevel::Full,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
 
         let output = adapter.render(&ctx).unwrap();
@@ -138,4 +223,87 @@ mod tests {
         assert!(output.contains("package_version"));
         assert!(output.contains("package_id"));
     }
+
+    #[test]
+    fn schema_flag_embeds_schema_url_matching_bundled_schema_id() {
+        let package = test_package();
+        let plain = render(&JsonAdapter::new(), &package);
+        assert!(plain.get("$schema").is_none());
+
+        let stamped = render(&JsonAdapter::new().with_schema(), &package);
+        assert_eq!(stamped["$schema"], DRAFT_PACKAGE_SCHEMA_URL);
+        assert_eq!(stamped["$schema"], bundled_schema()["$id"]);
+        assert_eq!(stamped["package_version"], "1.0.0");
+    }
+
+    #[test]
+    fn output_validates_against_bundled_schema() {
+        let mut package = test_package();
+        package.plan.completed_steps = vec!["Agent completed work in staging".to_string()];
+        package.changes.artifacts.push(Artifact {
+            resource_uri: "fs://workspace/src/lib.rs".to_string(),
+            change_type: ChangeType::Modify,
+            diff_ref: "changeset:0".to_string(),
+            tests_run: vec![],
+            disposition: Default::default(),
+            rationale: Some("Refactor".to_string()),
+            dependencies: vec![],
+            explanation_tiers: None,
+            comments: None,
+            amendment: None,
+            kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
+        });
+        package
+            .review_requests
+            .requested_actions
+            .push(RequestedAction {
+                action: "approve".to_string(),
+                targets: vec!["all".to_string()],
+            });
+        package.pending_approvals.push(ApprovalRecord {
+            reviewer: "alice".to_string(),
+            approved_at: Utc::now(),
+        });
+        package.tag = Some("fix-auth-01".to_string());
+        package.display_id = Some("abcd1234-01".to_string());
+        package.goal_shortref = Some("abcd1234".to_string());
+        package.parent_draft_id = Some(Uuid::new_v4());
+        package.plan_phase = Some("v0.1".to_string());
+        package.baseline_artifacts = vec!["PLAN.md".to_string()];
+        package.work_plan = Some(serde_json::json!({ "steps": [] }));
+        package
+            .staged_hashes
+            .insert("fs://workspace/src/lib.rs".to_string(), "ab12".repeat(16));
+        package.content_hash = Some(package.compute_content_hash());
+        package.review_assignments.push(FileReviewAssignment {
+            pattern: "src/**".to_string(),
+            reviewers: vec!["bob".to_string()],
+            required_approvals: 1,
+            assigned_at: Utc::now(),
+            approvals: vec![ApprovalRecord {
+                reviewer: "bob".to_string(),
+                approved_at: Utc::now(),
+            }],
+        });
+        let mut outcome = SubmitOutcome::requested(true, true);
+        outcome.commit = SubmitStepStatus::Succeeded;
+        outcome.push = SubmitStepStatus::Failed {
+            error: "remote rejected".to_string(),
+        };
+        package.submit_outcome = Some(outcome);
+
+        let schema = bundled_schema();
+        for adapter in [JsonAdapter::new(), JsonAdapter::new().with_schema()] {
+            let errors = schema_errors(&schema, &render(&adapter, &package));
+            assert!(
+                errors.is_empty(),
+                "schema violations:\n{}",
+                errors.join("\n")
+            );
+        }
+    }
 }
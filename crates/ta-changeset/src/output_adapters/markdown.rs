@@ -1,10 +1,12 @@
 //! markdown.rs — Markdown output adapter for GitHub PR bodies.
 
+use crate::draft_package::DecisionLogEntry;
 use crate::error::ChangeSetError;
 use crate::output_adapters::{
-    default_summary, matches_file_filters, DetailLevel, OutputAdapter, RenderContext,
+    default_summary, matches_file_filters, matches_kind_filters, DetailLevel, OutputAdapter,
+    RenderContext,
 };
-use crate::pr_package::{Artifact, ChangeType};
+use crate::pr_package::{Artifact, ArtifactDisposition, ChangeType};
 
 #[derive(Default)]
 pub struct MarkdownAdapter {}
@@ -22,6 +24,90 @@ impl MarkdownAdapter {
             ChangeType::Rename => "📝",
         }
     }
+
+    fn disposition_icon(&self, disposition: &ArtifactDisposition) -> &str {
+        match disposition {
+            ArtifactDisposition::Pending => "⏳",
+            ArtifactDisposition::Approved => "✅",
+            ArtifactDisposition::Rejected => "❌",
+            ArtifactDisposition::Discuss => "💬",
+            ArtifactDisposition::Deferred => "⏸️",
+        }
+    }
+
+    /// ` · ✅ approved · 2 comments` suffix for an artifact, empty without a review session.
+    fn review_badge(&self, ctx: &RenderContext, artifact: &Artifact) -> String {
+        if ctx.review_session.is_none() {
+            return String::new();
+        }
+        let disposition = ctx.disposition_for(artifact);
+        let mut badge = format!(" · {} {}", self.disposition_icon(&disposition), disposition);
+        match ctx.comment_count(artifact) {
+            0 => {}
+            1 => badge.push_str(" · 1 comment"),
+            n => badge.push_str(&format!(" · {} comments", n)),
+        }
+        badge
+    }
+
+    /// "Decisions & Alternatives": the agent's decision log and the
+    /// `change_summary.json` decision log, one collapsible block per decision.
+    /// Empty when the draft records no decisions.
+    fn render_decisions(&self, ctx: &RenderContext) -> String {
+        let pkg = ctx.package;
+        let decisions: Vec<&DecisionLogEntry> = pkg
+            .agent_decision_log
+            .iter()
+            .chain(&pkg.plan.decision_log)
+            .collect();
+        let design = &pkg.summary.alternatives_considered;
+        if decisions.is_empty() && design.is_empty() {
+            return String::new();
+        }
+
+        let mut output = String::from("## Decisions & Alternatives\n\n");
+        for entry in decisions {
+            let mut summary = match &entry.context {
+                Some(context) => format!("{} → <b>{}</b>", context, entry.decision),
+                None => format!("<b>{}</b>", entry.decision),
+            };
+            if let Some(confidence) = entry.confidence {
+                summary.push_str(&format!(" ({:.0}% confidence)", confidence * 100.0));
+            }
+            output.push_str(&format!(
+                "<details>\n<summary>{}</summary>\n\n**Rationale**: {}\n\n",
+                summary, entry.rationale
+            ));
+            if !entry.alternatives.is_empty() || !entry.alternatives_considered.is_empty() {
+                output.push_str("**Alternatives considered**:\n");
+                for alt in &entry.alternatives_considered {
+                    output.push_str(&format!(
+                        "- {} — *rejected: {}*\n",
+                        alt.description, alt.rejected_reason
+                    ));
+                }
+                for alt in &entry.alternatives {
+                    output.push_str(&format!("- {}\n", alt));
+                }
+                output.push('\n');
+            }
+            output.push_str("</details>\n\n");
+        }
+
+        // Design-level options from the change summary (chosen vs. considered).
+        if !design.is_empty() {
+            output.push_str("<details>\n<summary><b>Design options</b></summary>\n\n");
+            for alt in design {
+                let marker = if alt.chosen { "chosen" } else { "considered" };
+                output.push_str(&format!(
+                    "- **{}** ({}) — {}\n",
+                    alt.option, marker, alt.rationale
+                ));
+            }
+            output.push_str("\n</details>\n\n");
+        }
+        output
+    }
 }
 
 impl OutputAdapter for MarkdownAdapter {
@@ -47,18 +133,52 @@ impl OutputAdapter for MarkdownAdapter {
         output.push_str(&format!("**Why**: {}\n\n", pkg.summary.why));
         output.push_str(&format!("**Impact**: {}\n\n", pkg.summary.impact));
 
-        // Changes
-        output.push_str(&format!(
-            "## Changes ({} artifacts)\n\n",
-            pkg.changes.artifacts.len()
-        ));
-
-        let artifacts: Vec<&Artifact> = pkg
+        let mut artifacts: Vec<&Artifact> = pkg
             .changes
             .artifacts
             .iter()
             .filter(|a| matches_file_filters(&a.resource_uri, &ctx.file_filters))
+            .filter(|a| matches_kind_filters(&a.change_type, &ctx.kind_filters))
             .collect();
+        ctx.artifact_order.sort(&mut artifacts, &pkg.risk);
+
+        // Review state so far, when rendering alongside a live review session.
+        if let Some(session) = ctx.review_session {
+            let count = |d: ArtifactDisposition| {
+                artifacts
+                    .iter()
+                    .filter(|a| ctx.disposition_for(a) == d)
+                    .count()
+            };
+            output.push_str("## Review\n\n");
+            output.push_str(&format!(
+                "**Reviewer**: {} (updated {})\n\n",
+                session.reviewer,
+                session.updated_at.format("%Y-%m-%d %H:%M:%S")
+            ));
+            output.push_str(&format!(
+                "**Progress**: {} approved, {} rejected, {} discuss, {} deferred, {} pending\n\n",
+                count(ArtifactDisposition::Approved),
+                count(ArtifactDisposition::Rejected),
+                count(ArtifactDisposition::Discuss),
+                count(ArtifactDisposition::Deferred),
+                count(ArtifactDisposition::Pending)
+            ));
+            for note in &session.session_notes {
+                output.push_str(&format!("- {}\n", note.text));
+            }
+            if !session.session_notes.is_empty() {
+                output.push('\n');
+            }
+        }
+
+        output.push_str(&self.render_decisions(ctx));
+
+        // Changes
+        output.push_str(&format!(
+            "## Changes ({} artifacts)\n\n",
+            pkg.changes.artifacts.len()
+        ));
 
         for artifact in artifacts {
             let icon = self.change_icon(&artifact.change_type);
@@ -74,13 +194,38 @@ impl OutputAdapter for MarkdownAdapter {
                             default_summary(&artifact.resource_uri, &artifact.change_type)
                         });
                     output.push_str(&format!(
-                        "- {} **{}** — {}\n",
-                        icon, artifact.resource_uri, summary
+                        "- {} **{}** — {}{}\n",
+                        icon,
+                        artifact.resource_uri,
+                        summary,
+                        self.review_badge(ctx, artifact)
                     ));
                 }
                 DetailLevel::Medium | DetailLevel::Full => {
                     output.push_str(&format!("\n### {} {}\n\n", icon, artifact.resource_uri));
 
+                    if let Some(review) = ctx
+                        .review_session
+                        .and_then(|s| s.artifact_reviews.get(&artifact.resource_uri))
+                    {
+                        output.push_str(&format!(
+                            "**Review**: {} {}\n\n",
+                            self.disposition_icon(&review.disposition),
+                            review.disposition
+                        ));
+                        for comment in &review.comments.comments {
+                            output.push_str(&format!(
+                                "> **{}**{}: {}\n\n",
+                                comment.commenter,
+                                comment
+                                    .location_label()
+                                    .map(|l| format!(" ({})", l))
+                                    .unwrap_or_default(),
+                                comment.text
+                            ));
+                        }
+                    }
+
                     if let Some(tiers) = &artifact.explanation_tiers {
                         output.push_str(&format!("**Summary**: {}\n\n", tiers.summary));
                         output.push_str(&format!("{}\n\n", tiers.explanation));
@@ -134,3 +279,55 @@ impl OutputAdapter for MarkdownAdapter {
         "markdown"
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::draft_package::{make_test_pkg, AlternativeConsidered};
+    use crate::output_adapters::ArtifactOrder;
+
+    fn render(pkg: &crate::draft_package::DraftPackage) -> String {
+        let ctx = RenderContext {
+            package: pkg,
+            detail_level: DetailLevel::Medium,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        MarkdownAdapter::new().render(&ctx).unwrap()
+    }
+
+    #[test]
+    fn renders_decisions_with_rejected_alternatives() {
+        let mut pkg = make_test_pkg("abcd1234", 1);
+        pkg.agent_decision_log = vec![DecisionLogEntry {
+            decision: "Use a BTreeMap for the index".to_string(),
+            rationale: "Deterministic iteration order keeps output stable".to_string(),
+            alternatives: vec![],
+            alternatives_considered: vec![AlternativeConsidered {
+                description: "HashMap".to_string(),
+                rejected_reason: "iteration order varies between runs".to_string(),
+            }],
+            confidence: Some(0.8),
+            context: Some("Index storage".to_string()),
+        }];
+
+        let output = render(&pkg);
+        assert!(output.contains("## Decisions & Alternatives"));
+        assert!(output.contains(
+            "<summary>Index storage → <b>Use a BTreeMap for the index</b> (80% confidence)</summary>"
+        ));
+        assert!(output.contains("**Rationale**: Deterministic iteration order"));
+        assert!(output.contains("- HashMap — *rejected: iteration order varies between runs*"));
+        assert!(output.find("## Decisions").unwrap() < output.find("## Changes").unwrap());
+    }
+
+    #[test]
+    fn omits_decisions_section_without_decisions() {
+        let pkg = make_test_pkg("abcd1234", 1);
+        assert!(!render(&pkg).contains("Decisions & Alternatives"));
+    }
+}
@@ -5,13 +5,18 @@
 //! - **Markdown**: GitHub-ready markdown with collapsible sections
 //! - **JSON**: Machine-readable structured output for CI/CD
 //! - **HTML**: Standalone review page with progressive disclosure
+//! - **SARIF**: Risk findings as SARIF 2.1.0 results for code-scanning tools
 
-use crate::draft_package::DraftPackage;
+use crate::draft_package::{
+    Artifact, ArtifactDisposition, ChangeType, DraftPackage, Risk, Severity,
+};
 use crate::error::ChangeSetError;
+use crate::review_session::ReviewSession;
 
 pub mod html;
 pub mod json;
 pub mod markdown;
+pub mod sarif;
 pub mod terminal;
 
 /// Output format for PR rendering.
@@ -21,6 +26,7 @@ pub enum OutputFormat {
     Markdown,
     Json,
     Html,
+    Sarif,
 }
 
 impl std::str::FromStr for OutputFormat {
@@ -32,8 +38,9 @@ impl std::str::FromStr for OutputFormat {
             "markdown" | "md" => Ok(OutputFormat::Markdown),
             "json" => Ok(OutputFormat::Json),
             "html" => Ok(OutputFormat::Html),
+            "sarif" => Ok(OutputFormat::Sarif),
             _ => Err(format!(
-                "Invalid output format: '{}'. Valid formats: terminal, markdown, json, html",
+                "Invalid output format: '{}'. Valid formats: terminal, markdown, json, html, sarif",
                 s
             )),
         }
@@ -47,6 +54,7 @@ impl std::fmt::Display for OutputFormat {
             OutputFormat::Markdown => write!(f, "markdown"),
             OutputFormat::Json => write!(f, "json"),
             OutputFormat::Html => write!(f, "html"),
+            OutputFormat::Sarif => write!(f, "sarif"),
         }
     }
 }
@@ -129,6 +137,94 @@ impl std::fmt::Display for SectionFilter {
     }
 }
 
+/// Order in which adapters list artifacts (`ta draft view --order`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ArtifactOrder {
+    /// Package order, i.e. the overlay walk at build time. Default.
+    #[default]
+    AsIs,
+    /// Deletes first, then modifies and renames, then adds.
+    ByKind,
+    /// By path, so files in the same directory are listed together.
+    ByPath,
+    /// Files with the most severe risk findings first; the rest by kind.
+    ByRisk,
+}
+
+impl ArtifactOrder {
+    /// Sort `artifacts` into this order. The sort is stable, so ties keep
+    /// package order. `risk` supplies the findings for `ByRisk`.
+    pub fn sort<A: std::borrow::Borrow<Artifact>>(self, artifacts: &mut [A], risk: &Risk) {
+        let kind_rank = |a: &Artifact| match a.change_type {
+            ChangeType::Delete => 0,
+            ChangeType::Modify => 1,
+            ChangeType::Rename => 2,
+            ChangeType::Add => 3,
+        };
+        match self {
+            ArtifactOrder::AsIs => {}
+            ArtifactOrder::ByKind => artifacts.sort_by_key(|a| kind_rank(a.borrow())),
+            ArtifactOrder::ByPath => artifacts.sort_by(|a, b| {
+                let path = |a: &A| workspace_path(&a.borrow().resource_uri).to_string();
+                path(a).split('/').cmp(path(b).split('/'))
+            }),
+            ArtifactOrder::ByRisk => artifacts.sort_by_key(|a| {
+                let a = a.borrow();
+                let path = workspace_path(&a.resource_uri);
+                let worst = risk
+                    .findings
+                    .iter()
+                    .filter(|f| {
+                        f.location
+                            .as_ref()
+                            .is_some_and(|loc| workspace_path(&loc.path) == path)
+                    })
+                    .map(|f| match f.severity {
+                        Severity::Low => 1,
+                        Severity::Medium => 2,
+                        Severity::High => 3,
+                        Severity::Critical => 4,
+                    })
+                    .max()
+                    .unwrap_or(0);
+                (std::cmp::Reverse(worst), kind_rank(a))
+            }),
+        }
+    }
+}
+
+fn workspace_path(uri: &str) -> &str {
+    uri.strip_prefix("fs://workspace/").unwrap_or(uri)
+}
+
+impl std::str::FromStr for ArtifactOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "as-is" | "asis" => Ok(ArtifactOrder::AsIs),
+            "by-kind" | "kind" => Ok(ArtifactOrder::ByKind),
+            "by-path" | "path" => Ok(ArtifactOrder::ByPath),
+            "by-risk" | "risk" => Ok(ArtifactOrder::ByRisk),
+            _ => Err(format!(
+                "Invalid artifact order: '{}'. Valid orders: as-is, by-kind, by-path, by-risk",
+                s
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for ArtifactOrder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ArtifactOrder::AsIs => write!(f, "as-is"),
+            ArtifactOrder::ByKind => write!(f, "by-kind"),
+            ArtifactOrder::ByPath => write!(f, "by-path"),
+            ArtifactOrder::ByRisk => write!(f, "by-risk"),
+        }
+    }
+}
+
 /// Context for rendering a PR package.
 pub struct RenderContext<'a> {
     pub package: &'a DraftPackage,
@@ -136,10 +232,36 @@ pub struct RenderContext<'a> {
     /// Optional: Filter to specific files matching these patterns (glob supported).
     /// Empty vec = show all.
     pub file_filters: Vec<String>,
+    /// Optional: Show only artifacts with these change types (`--kind`).
+    /// Empty vec = show all.
+    pub kind_filters: Vec<ChangeType>,
+    /// Order to list artifacts in. SARIF output, which lists risk findings
+    /// rather than artifacts, ignores it.
+    pub artifact_order: ArtifactOrder,
     /// Optional: Diff content provider (for fetching full diffs).
     pub diff_provider: Option<&'a dyn DiffProvider>,
     /// Optional: Show only one section of the draft view (v0.14.7).
     pub section_filter: Option<SectionFilter>,
+    /// Optional: Live review session whose dispositions and comments should be
+    /// shown alongside the changes. `None` renders the package as stored.
+    pub review_session: Option<&'a ReviewSession>,
+}
+
+impl RenderContext<'_> {
+    /// Disposition to display for an artifact: the review session's, when the
+    /// reviewer has decided on it, otherwise the one recorded in the package.
+    pub fn disposition_for(&self, artifact: &Artifact) -> ArtifactDisposition {
+        self.review_session
+            .and_then(|s| s.get_disposition(&artifact.resource_uri))
+            .unwrap_or_else(|| artifact.disposition.clone())
+    }
+
+    /// Number of review-session comments on an artifact (0 without a session).
+    pub fn comment_count(&self, artifact: &Artifact) -> usize {
+        self.review_session
+            .and_then(|s| s.artifact_reviews.get(&artifact.resource_uri))
+            .map_or(0, |r| r.comments.comments.len())
+    }
 }
 
 /// Trait for fetching diff content.
@@ -225,6 +347,13 @@ pub fn matches_file_filters(uri: &str, filters: &[String]) -> bool {
     })
 }
 
+/// Check whether a change type passes the `--kind` filters.
+///
+/// Returns true if filters is empty (show all).
+pub fn matches_kind_filters(change_type: &ChangeType, filters: &[ChangeType]) -> bool {
+    filters.is_empty() || filters.contains(change_type)
+}
+
 /// Get an adapter instance for the given format.
 ///
 /// The `color` parameter controls ANSI color output for the terminal adapter.
@@ -235,6 +364,7 @@ pub fn get_adapter(format: OutputFormat, color: bool) -> Box<dyn OutputAdapter>
         OutputFormat::Markdown => Box::new(markdown::MarkdownAdapter::new()),
         OutputFormat::Json => Box::new(json::JsonAdapter::new()),
         OutputFormat::Html => Box::new(html::HtmlAdapter::new()),
+        OutputFormat::Sarif => Box::new(sarif::SarifAdapter::new()),
     }
 }
 
@@ -258,6 +388,10 @@ mod tests {
         );
         assert_eq!("json".parse::<OutputFormat>().unwrap(), OutputFormat::Json);
         assert_eq!("html".parse::<OutputFormat>().unwrap(), OutputFormat::Html);
+        assert_eq!(
+            "sarif".parse::<OutputFormat>().unwrap(),
+            OutputFormat::Sarif
+        );
         assert!("invalid".parse::<OutputFormat>().is_err());
     }
 
@@ -279,6 +413,7 @@ mod tests {
         assert_eq!(OutputFormat::Markdown.to_string(), "markdown");
         assert_eq!(OutputFormat::Json.to_string(), "json");
         assert_eq!(OutputFormat::Html.to_string(), "html");
+        assert_eq!(OutputFormat::Sarif.to_string(), "sarif");
     }
 
     #[test]
@@ -287,4 +422,77 @@ mod tests {
         assert_eq!(DetailLevel::Medium.to_string(), "medium");
         assert_eq!(DetailLevel::Full.to_string(), "full");
     }
+
+    #[test]
+    fn artifact_order_sorts_by_kind_path_and_risk() {
+        use crate::draft_package::{FindingLocation, RiskCategory, RiskFinding};
+
+        let artifact = |path: &str, change_type: ChangeType| Artifact {
+            resource_uri: format!("fs://workspace/{}", path),
+            change_type,
+            diff_ref: "changeset:0".to_string(),
+            tests_run: vec![],
+            disposition: ArtifactDisposition::Pending,
+            rationale: None,
+            dependencies: vec![],
+            explanation_tiers: None,
+            comments: None,
+            amendment: None,
+            kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
+        };
+        let artifacts = [
+            artifact("src/b.rs", ChangeType::Add),
+            artifact("README.md", ChangeType::Modify),
+            artifact("src/a.rs", ChangeType::Delete),
+            artifact("docs/x.md", ChangeType::Modify),
+        ];
+        let risk = Risk {
+            risk_score: 50,
+            findings: vec![RiskFinding {
+                category: RiskCategory::Secrets,
+                severity: Severity::High,
+                description: "touches auth".to_string(),
+                evidence_refs: vec![],
+                mitigation: None,
+                location: Some(FindingLocation {
+                    path: "src/b.rs".to_string(),
+                    start_line: None,
+                    end_line: None,
+                }),
+            }],
+            policy_decisions: vec![],
+        };
+        let sorted = |order: &str| {
+            let mut refs: Vec<&Artifact> = artifacts.iter().collect();
+            order
+                .parse::<ArtifactOrder>()
+                .unwrap()
+                .sort(&mut refs, &risk);
+            refs.iter()
+                .map(|a| workspace_path(&a.resource_uri).to_string())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            sorted("as-is"),
+            ["src/b.rs", "README.md", "src/a.rs", "docs/x.md"]
+        );
+        assert_eq!(
+            sorted("by-kind"),
+            ["src/a.rs", "README.md", "docs/x.md", "src/b.rs"]
+        );
+        assert_eq!(
+            sorted("by-path"),
+            ["README.md", "docs/x.md", "src/a.rs", "src/b.rs"]
+        );
+        assert_eq!(
+            sorted("by-risk"),
+            ["src/b.rs", "src/a.rs", "README.md", "docs/x.md"]
+        );
+        assert!("sideways".parse::<ArtifactOrder>().is_err());
+    }
 }
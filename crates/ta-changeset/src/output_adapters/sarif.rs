@@ -0,0 +1,326 @@
+//! sarif.rs — SARIF 2.1.0 output adapter for code-scanning integration.
+//!
+//! Emits the draft's risk findings as SARIF results so tools such as GitHub
+//! code scanning can show them inline. Findings with a location are keyed to
+//! the artifact path and line range; the rest are reported without one.
+
+use serde_json::{json, Value};
+
+use crate::draft_package::{FindingLocation, RiskCategory, RiskFinding, Severity};
+use crate::error::ChangeSetError;
+use crate::output_adapters::{matches_file_filters, OutputAdapter, RenderContext};
+
+const SARIF_SCHEMA_URL: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Default)]
+pub struct SarifAdapter;
+
+impl SarifAdapter {
+    pub fn new() -> Self {
+        Self
+    }
+
+    /// Stable rule ID for a finding category, e.g. `ta/secrets`.
+    fn rule_id(category: &RiskCategory) -> String {
+        let name = serde_json::to_value(category)
+            .ok()
+            .and_then(|v| v.as_str().map(str::to_string))
+            .unwrap_or_else(|| "unknown".to_string());
+        format!("ta/{}", name)
+    }
+
+    fn level(severity: &Severity) -> &'static str {
+        match severity {
+            Severity::Low => "note",
+            Severity::Medium => "warning",
+            Severity::High | Severity::Critical => "error",
+        }
+    }
+
+    fn physical_location(location: &FindingLocation) -> Value {
+        let path = location
+            .path
+            .strip_prefix("fs://workspace/")
+            .unwrap_or(&location.path);
+        let mut physical = json!({
+            "artifactLocation": { "uri": path, "uriBaseId": "%SRCROOT%" },
+        });
+        if let Some(start) = location.start_line {
+            physical["region"] = json!({
+                "startLine": start,
+                "endLine": location.end_line.unwrap_or(start).max(start),
+            });
+        }
+        json!({ "physicalLocation": physical })
+    }
+
+    fn result(finding: &RiskFinding) -> Value {
+        let mut properties = json!({ "severity": finding.severity });
+        if let Some(mitigation) = &finding.mitigation {
+            properties["mitigation"] = json!(mitigation);
+        }
+        if !finding.evidence_refs.is_empty() {
+            properties["evidence"] = json!(finding.evidence_refs);
+        }
+        let mut result = json!({
+            "ruleId": Self::rule_id(&finding.category),
+            "level": Self::level(&finding.severity),
+            "message": { "text": finding.description },
+            "properties": properties,
+        });
+        if let Some(location) = &finding.location {
+            result["locations"] = json!([Self::physical_location(location)]);
+        }
+        result
+    }
+}
+
+impl OutputAdapter for SarifAdapter {
+    fn render(&self, ctx: &RenderContext) -> Result<String, ChangeSetError> {
+        // `--file` narrows located findings to those paths; findings that are
+        // not tied to a file always apply.
+        let findings: Vec<&RiskFinding> = ctx
+            .package
+            .risk
+            .findings
+            .iter()
+            .filter(|f| match &f.location {
+                Some(loc) => {
+                    let path = loc
+                        .path
+                        .strip_prefix("fs://workspace/")
+                        .unwrap_or(&loc.path);
+                    matches_file_filters(&format!("fs://workspace/{}", path), &ctx.file_filters)
+                }
+                None => true,
+            })
+            .collect();
+
+        let mut rules: Vec<String> = findings
+            .iter()
+            .map(|f| Self::rule_id(&f.category))
+            .collect();
+        rules.sort();
+        rules.dedup();
+        let rules: Vec<Value> = rules.into_iter().map(|id| json!({ "id": id })).collect();
+
+        let sarif = json!({
+            "$schema": SARIF_SCHEMA_URL,
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "Trusted Autonomy",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    }
+                },
+                "automationDetails": { "id": format!("ta-draft/{}", ctx.package.package_id) },
+                "results": findings.iter().map(|f| Self::result(f)).collect::<Vec<_>>(),
+            }],
+        });
+        serde_json::to_string_pretty(&sarif)
+            .map_err(|e| ChangeSetError::InvalidData(format!("SARIF serialization failed: {}", e)))
+    }
+
+    fn name(&self) -> &str {
+        "sarif"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output_adapters::{ArtifactOrder, DetailLevel};
+    use crate::pr_package::*;
+    use chrono::Utc;
+    use uuid::Uuid;
+
+    fn test_package() -> PRPackage {
+        PRPackage {
+            package_version: "1.0.0".to_string(),
+            package_id: Uuid::new_v4(),
+            created_at: Utc::now(),
+            goal: Goal {
+                goal_id: "goal-1".to_string(),
+                title: "Test".to_string(),
+                objective: "Test".to_string(),
+                success_criteria: vec![],
+                constraints: vec![],
+                parent_goal_title: None,
+            },
+            iteration: Iteration {
+                iteration_id: "iter-1".to_string(),
+                sequence: 1,
+                workspace_ref: WorkspaceRef {
+                    ref_type: "staging".to_string(),
+                    ref_name: "staging/1".to_string(),
+                    base_ref: None,
+                },
+            },
+            agent_identity: AgentIdentity {
+                agent_id: "agent-1".to_string(),
+                agent_type: "coder".to_string(),
+                constitution_id: "default".to_string(),
+                capability_manifest_hash: "hash".to_string(),
+                orchestrator_run_id: None,
+            },
+            summary: Summary {
+                what_changed: "Test".to_string(),
+                why: "Test".to_string(),
+                impact: "None".to_string(),
+                rollback_plan: "Revert".to_string(),
+                open_questions: vec![],
+                alternatives_considered: vec![],
+            },
+            plan: Plan {
+                completed_steps: vec![],
+                next_steps: vec![],
+                decision_log: vec![],
+            },
+            changes: Changes {
+                artifacts: vec![],
+                patch_sets: vec![],
+                pending_actions: vec![],
+            },
+            risk: Risk {
+                risk_score: 0,
+                findings: vec![],
+                policy_decisions: vec![],
+            },
+            provenance: Provenance {
+                inputs: vec![],
+                tool_trace_hash: "hash".to_string(),
+            },
+            review_requests: ReviewRequests {
+                requested_actions: vec![],
+                reviewers: vec![],
+                required_approvals: 1,
+                notes_to_reviewer: None,
+            },
+            signatures: Signatures {
+                package_hash: "hash".to_string(),
+                agent_signature: "sig".to_string(),
+                gateway_attestation: None,
+            },
+            status: PRStatus::Draft,
+            verification_warnings: vec![],
+            validation_log: vec![],
+            display_id: None,
+            tag: None,
+            vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
+            parent_draft_id: None,
+            pending_approvals: vec![],
+            review_assignments: vec![],
+            supervisor_review: None,
+            ignored_artifacts: vec![],
+            baseline_artifacts: vec![],
+            agent_decision_log: vec![],
+            work_plan: None,
+            goal_shortref: None,
+            draft_seq: 0,
+            plan_phase: None,
+            plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
+        }
+    }
+
+    fn render(package: &PRPackage, file_filters: Vec<String>) -> Value {
+        let ctx = RenderContext {
+            package,
+            detail_level: DetailLevel::Medium,
+            file_filters,
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        serde_json::from_str(&SarifAdapter::new().render(&ctx).unwrap()).unwrap()
+    }
+
+    fn finding(
+        category: RiskCategory,
+        severity: Severity,
+        location: Option<FindingLocation>,
+    ) -> RiskFinding {
+        RiskFinding {
+            category,
+            severity,
+            description: "finding".to_string(),
+            evidence_refs: vec![],
+            mitigation: Some("fix it".to_string()),
+            location,
+        }
+    }
+
+    #[test]
+    fn findings_become_results_with_locations_and_levels() {
+        let mut package = test_package();
+        package.risk.findings = vec![
+            finding(
+                RiskCategory::Secrets,
+                Severity::Critical,
+                Some(FindingLocation {
+                    path: "src/config.rs".to_string(),
+                    start_line: Some(12),
+                    end_line: Some(14),
+                }),
+            ),
+            finding(RiskCategory::TestFailure, Severity::Medium, None),
+        ];
+
+        let sarif = render(&package, vec![]);
+        assert_eq!(sarif["version"], "2.1.0");
+        let run = &sarif["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"].as_array().unwrap().len(), 2);
+
+        let secret = &run["results"][0];
+        assert_eq!(secret["ruleId"], "ta/secrets");
+        assert_eq!(secret["level"], "error");
+        assert_eq!(secret["properties"]["mitigation"], "fix it");
+        let physical = &secret["locations"][0]["physicalLocation"];
+        assert_eq!(physical["artifactLocation"]["uri"], "src/config.rs");
+        assert_eq!(physical["region"]["startLine"], 12);
+        assert_eq!(physical["region"]["endLine"], 14);
+
+        let tests = &run["results"][1];
+        assert_eq!(tests["ruleId"], "ta/test_failure");
+        assert_eq!(tests["level"], "warning");
+        assert!(tests.get("locations").is_none());
+    }
+
+    #[test]
+    fn file_filters_drop_findings_in_other_files() {
+        let mut package = test_package();
+        let at = |path: &str| {
+            Some(FindingLocation {
+                path: path.to_string(),
+                start_line: None,
+                end_line: None,
+            })
+        };
+        package.risk.findings = vec![
+            finding(RiskCategory::Pii, Severity::Low, at("src/a.rs")),
+            finding(RiskCategory::Pii, Severity::Low, at("src/b.rs")),
+            finding(RiskCategory::Unknown, Severity::Low, None),
+        ];
+
+        let sarif = render(&package, vec!["src/a.rs".to_string()]);
+        let results = sarif["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(
+            results[0]["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "src/a.rs"
+        );
+        assert!(results[0]["locations"][0]["physicalLocation"]
+            .get("region")
+            .is_none());
+    }
+}
@@ -5,7 +5,8 @@
 use crate::artifact_kind::ArtifactKind;
 use crate::error::ChangeSetError;
 use crate::output_adapters::{
-    default_summary, matches_file_filters, DetailLevel, OutputAdapter, RenderContext,
+    default_summary, matches_file_filters, matches_kind_filters, DetailLevel, OutputAdapter,
+    RenderContext,
 };
 use crate::pr_package::{Artifact, ChangeType};
 
@@ -28,6 +29,10 @@ fn format_byte_size(bytes: u64) -> String {
 #[derive(Default)]
 pub struct TerminalAdapter {
     color: bool,
+    /// Render only changed lines (`ta draft view --compact`).
+    compact: bool,
+    /// Render one provenance line per artifact (`ta draft view --blame`).
+    blame: bool,
 }
 
 impl TerminalAdapter {
@@ -36,7 +41,27 @@ impl TerminalAdapter {
     }
 
     pub fn with_color(color: bool) -> Self {
-        Self { color }
+        Self {
+            color,
+            ..Self::default()
+        }
+    }
+
+    /// Render a flat list of changed hunks across all files instead of the
+    /// hierarchical view: a `path:line` prefix per run of changed lines,
+    /// followed by its `+`/`-` lines. Context, headers, and summaries are
+    /// omitted. Needs a diff provider; non-text artifacts are skipped.
+    pub fn with_compact(mut self) -> Self {
+        self.compact = true;
+        self
+    }
+
+    /// Render a "who touched this and why" list instead of the change view:
+    /// per artifact, the originating agent, any amendment (by whom and
+    /// when), the disposition, and the one-line rationale. No diffs.
+    pub fn with_blame(mut self) -> Self {
+        self.blame = true;
+        self
     }
 
     /// Strip HTML tags from a string to prevent HTML-rendered content
@@ -161,7 +186,39 @@ impl TerminalAdapter {
             Self::strip_html(&pkg.summary.impact),
             bold = bold,
             reset = reset
-        )
+        ) + &self.render_risk_findings(ctx)
+    }
+
+    /// Risk findings recorded on the draft (e.g. failing `ta draft build --tests`).
+    fn render_risk_findings(&self, ctx: &RenderContext) -> String {
+        let findings = &ctx.package.risk.findings;
+        if findings.is_empty() {
+            return String::new();
+        }
+        let bold = self.bold();
+        let reset = self.reset();
+        let dim = self.dim();
+        let mut output = format!("{bold}Risk Findings:{reset}\n");
+        for finding in findings {
+            let at = match &finding.location {
+                Some(loc) => match loc.start_line {
+                    Some(line) => format!(" {dim}({}:{}){reset}", loc.path, line),
+                    None => format!(" {dim}({}){reset}", loc.path),
+                },
+                None => String::new(),
+            };
+            output.push_str(&format!(
+                "  [{:?}] {}{}\n",
+                finding.severity,
+                Self::strip_html(&finding.description),
+                at
+            ));
+            if let Some(mitigation) = &finding.mitigation {
+                output.push_str(&format!("    {dim}{}{reset}\n", mitigation));
+            }
+        }
+        output.push('\n');
+        output
     }
 
     fn change_icon(&self, change_type: &ChangeType) -> String {
@@ -190,6 +247,7 @@ impl TerminalAdapter {
             crate::pr_package::ArtifactDisposition::Approved => "[approved]",
             crate::pr_package::ArtifactDisposition::Rejected => "[rejected]",
             crate::pr_package::ArtifactDisposition::Discuss => "[discuss]",
+            crate::pr_package::ArtifactDisposition::Deferred => "[deferred]",
         };
 
         let summary_raw = artifact
@@ -200,14 +258,51 @@ impl TerminalAdapter {
             .unwrap_or_else(|| default_summary(&artifact.resource_uri, &artifact.change_type));
         let summary = Self::strip_html(summary_raw);
 
+        let renamed_from = artifact
+            .renamed_from
+            .as_deref()
+            .map(|from| format!(" (from {})", from))
+            .unwrap_or_default();
         // File path on its own line, summary on next line indented to match.
         format!(
-            "  {} {} {}\n    {}",
-            icon, disposition_badge, artifact.resource_uri, summary
+            "  {} {} {}{}{}{}\n    {}",
+            icon,
+            disposition_badge,
+            artifact.resource_uri,
+            renamed_from,
+            Self::file_badge(artifact),
+            self.no_commit_badge(artifact),
+            summary
         )
     }
 
-    fn render_artifact_medium(&self, artifact: &Artifact) -> String {
+    /// File mode change and line-ending normalization, e.g.
+    /// ` (mode 100644 → 100755; line endings → lf)`.
+    fn file_badge(artifact: &Artifact) -> String {
+        let mut notes = Vec::new();
+        if let Some(mode) = artifact.mode_change {
+            notes.push(mode.to_string());
+        }
+        if let Some(ending) = artifact.line_endings_normalized {
+            notes.push(format!("line endings → {}", ending));
+        }
+        if notes.is_empty() {
+            String::new()
+        } else {
+            format!(" ({})", notes.join("; "))
+        }
+    }
+
+    /// Badge for artifacts applied to the target but left out of the VCS commit.
+    fn no_commit_badge(&self, artifact: &Artifact) -> String {
+        if artifact.no_commit {
+            format!(" {}[apply-only, no commit]{}", self.dim(), self.reset())
+        } else {
+            String::new()
+        }
+    }
+
+    fn render_artifact_medium(&self, artifact: &Artifact, ctx: &RenderContext) -> String {
         let mut output = self.render_artifact_top(artifact);
         let dim = self.dim();
         let reset = self.reset();
@@ -243,11 +338,43 @@ impl TerminalAdapter {
             }
         }
 
+        for test in &artifact.tests_run {
+            output.push_str(&format!("    {dim}Tests:{reset} {}\n", test));
+        }
+
+        for assignment in ctx.package.review_assignments_for(&artifact.resource_uri) {
+            let signed: Vec<&str> = assignment
+                .approvals
+                .iter()
+                .filter(|a| assignment.reviewers.contains(&a.reviewer))
+                .map(|a| a.reviewer.as_str())
+                .collect();
+            let state = if assignment.is_satisfied() {
+                "signed off".to_string()
+            } else {
+                format!(
+                    "{} of {} sign-off(s)",
+                    signed.len(),
+                    assignment.required_approvals
+                )
+            };
+            output.push_str(&format!(
+                "    {dim}Review owner:{reset} {} ({}{})\n",
+                assignment.reviewers.join(", "),
+                state,
+                if signed.is_empty() {
+                    String::new()
+                } else {
+                    format!(" by {}", signed.join(", "))
+                }
+            ));
+        }
+
         output
     }
 
     fn render_artifact_full(&self, artifact: &Artifact, ctx: &RenderContext) -> String {
-        let mut output = self.render_artifact_medium(artifact);
+        let mut output = self.render_artifact_medium(artifact, ctx);
         let bold = self.bold();
         let reset = self.reset();
         let dim = self.dim();
@@ -390,6 +517,153 @@ impl TerminalAdapter {
         output
     }
 
+    /// Compact view: every run of `+`/`-` lines across all artifacts, each
+    /// prefixed by `path:line` (the new-file line where the run starts).
+    fn render_compact(
+        &self,
+        artifacts: &[&Artifact],
+        ctx: &RenderContext,
+    ) -> Result<String, ChangeSetError> {
+        let provider = ctx.diff_provider.ok_or_else(|| {
+            ChangeSetError::InvalidData("compact view needs the draft's diffs".to_string())
+        })?;
+        let reset = self.reset();
+        let cyan = self.color_code("\x1b[36m");
+        let green = self.color_code("\x1b[32m");
+        let red = self.color_code("\x1b[31m");
+
+        let mut output = String::new();
+        for artifact in artifacts {
+            if matches!(
+                artifact.kind,
+                Some(
+                    ArtifactKind::Image { .. }
+                        | ArtifactKind::Video { .. }
+                        | ArtifactKind::Binary { .. }
+                        | ArtifactKind::MemorySummary { .. }
+                )
+            ) {
+                continue;
+            }
+            let path = artifact
+                .resource_uri
+                .strip_prefix("fs://workspace/")
+                .unwrap_or(&artifact.resource_uri);
+            let diff = provider.get_diff(&artifact.diff_ref)?;
+
+            // New-file line number of the next line in the current hunk, and
+            // whether the previous diff line was a change (same run).
+            // File headers (`---`/`+++`, rename lines) precede the first hunk.
+            let mut new_line = 1usize;
+            let mut in_run = false;
+            let mut in_hunks = false;
+            for line in diff.lines() {
+                if let Some(header) = line.strip_prefix("@@ ") {
+                    in_hunks = true;
+                    new_line = header
+                        .split_whitespace()
+                        .find_map(|range| range.strip_prefix('+'))
+                        .and_then(|range| range.split(',').next())
+                        .and_then(|start| start.parse().ok())
+                        .unwrap_or(1)
+                        // `+N,0` (nothing on the new side) points at the line before.
+                        .max(1);
+                    in_run = false;
+                    continue;
+                }
+                if !in_hunks {
+                    continue;
+                }
+                let color = match line.chars().next() {
+                    Some('+') => green,
+                    Some('-') => red,
+                    _ => {
+                        if !line.starts_with('\\') {
+                            new_line += 1;
+                        }
+                        in_run = false;
+                        continue;
+                    }
+                };
+                if !in_run {
+                    output.push_str(&format!("{cyan}{}:{}{reset}\n", path, new_line));
+                    in_run = true;
+                }
+                output.push_str(&format!("{color}{}{reset}\n", line));
+                if line.starts_with('+') {
+                    new_line += 1;
+                }
+            }
+        }
+        if output.is_empty() {
+            output.push_str(&format!(
+                "{dim}No changed text lines.{reset}\n",
+                dim = self.dim()
+            ));
+        }
+        Ok(output)
+    }
+
+    fn render_blame(&self, artifacts: &[&Artifact], ctx: &RenderContext) -> String {
+        use crate::draft_package::AmendmentType;
+        use crate::pr_package::ArtifactDisposition;
+
+        let bold = self.bold();
+        let dim = self.dim();
+        let reset = self.reset();
+        let agent = &ctx.package.agent_identity.agent_id;
+
+        let mut output = format!("{bold}Blame ({} artifacts):{reset}\n", artifacts.len());
+        for artifact in artifacts {
+            let path = artifact
+                .resource_uri
+                .strip_prefix("fs://workspace/")
+                .unwrap_or(&artifact.resource_uri);
+            output.push_str(&format!(
+                "{} {bold}{}{reset}\n",
+                self.change_icon(&artifact.change_type),
+                path
+            ));
+
+            let mut parts = vec![format!("agent {}", agent)];
+            if let Some(amendment) = &artifact.amendment {
+                let how = match amendment.amendment_type {
+                    AmendmentType::FileReplaced => "file replaced",
+                    AmendmentType::PatchApplied => "patch applied",
+                    AmendmentType::Dropped => "dropped",
+                };
+                parts.push(format!(
+                    "amended by {} {} ({})",
+                    amendment.amended_by,
+                    amendment.amended_at.format("%Y-%m-%d %H:%M"),
+                    how
+                ));
+            }
+            parts.push(
+                match ctx.disposition_for(artifact) {
+                    ArtifactDisposition::Pending => "pending",
+                    ArtifactDisposition::Approved => "approved",
+                    ArtifactDisposition::Rejected => "rejected",
+                    ArtifactDisposition::Discuss => "discuss",
+                    ArtifactDisposition::Deferred => "deferred",
+                }
+                .to_string(),
+            );
+            let why = artifact
+                .explanation_tiers
+                .as_ref()
+                .map(|t| t.summary.as_str())
+                .or(artifact.rationale.as_deref())
+                .and_then(|text| text.lines().map(str::trim).find(|l| !l.is_empty()));
+            parts.push(match why {
+                Some(line) => Self::strip_html(line).into_owned(),
+                None => format!("{dim}no rationale recorded{reset}"),
+            });
+            output.push_str(&format!("    {}\n", parts.join(" · ")));
+        }
+        output
+    }
+
     /// Build a human-readable summary for a set of image artifacts.
     ///
     /// Used by `ta draft view` to display a summary line like
@@ -611,8 +885,13 @@ impl TerminalAdapter {
                 };
 
                 output.push_str(&format!(
-                    "    {} {} — {}{}\n",
-                    icon, short_path, summary, dep_marker
+                    "    {} {}{}{} — {}{}\n",
+                    icon,
+                    short_path,
+                    Self::file_badge(artifact),
+                    self.no_commit_badge(artifact),
+                    summary,
+                    dep_marker
                 ));
             }
         }
@@ -797,18 +1076,36 @@ impl OutputAdapter for TerminalAdapter {
 
         // Filter artifacts
         let artifacts = &ctx.package.changes.artifacts;
-        let filtered_artifacts: Vec<&Artifact> = artifacts
+        let mut filtered_artifacts: Vec<&Artifact> = artifacts
             .iter()
             .filter(|a| matches_file_filters(&a.resource_uri, &ctx.file_filters))
+            .filter(|a| matches_kind_filters(&a.change_type, &ctx.kind_filters))
             .collect();
+        ctx.artifact_order
+            .sort(&mut filtered_artifacts, &ctx.package.risk);
 
-        if filtered_artifacts.is_empty() && !ctx.file_filters.is_empty() {
+        if filtered_artifacts.is_empty()
+            && !(ctx.file_filters.is_empty() && ctx.kind_filters.is_empty())
+        {
+            let mut filters = ctx.file_filters.clone();
+            filters.extend(
+                ctx.kind_filters
+                    .iter()
+                    .map(|k| format!("kind={}", format!("{:?}", k).to_lowercase())),
+            );
             return Err(ChangeSetError::InvalidData(format!(
                 "No artifacts match filters: {}",
-                ctx.file_filters.join(", ")
+                filters.join(", ")
             )));
         }
 
+        if self.compact {
+            return self.render_compact(&filtered_artifacts, ctx);
+        }
+        if self.blame {
+            return Ok(self.render_blame(&filtered_artifacts, ctx));
+        }
+
         // ── Section filtering: emit only the requested section ──
         match ctx.section_filter {
             Some(SectionFilter::Summary) => {
@@ -846,7 +1143,7 @@ impl OutputAdapter for TerminalAdapter {
                         match ctx.detail_level {
                             DetailLevel::Top => unreachable!(),
                             DetailLevel::Medium => {
-                                output.push_str(&self.render_artifact_medium(artifact));
+                                output.push_str(&self.render_artifact_medium(artifact, ctx));
                                 output.push('\n');
                             }
                             DetailLevel::Full => {
@@ -889,7 +1186,7 @@ impl OutputAdapter for TerminalAdapter {
                 match ctx.detail_level {
                     DetailLevel::Top => unreachable!(),
                     DetailLevel::Medium => {
-                        output.push_str(&self.render_artifact_medium(artifact));
+                        output.push_str(&self.render_artifact_medium(artifact, ctx));
                         output.push('\n');
                     }
                     DetailLevel::Full => {
@@ -918,10 +1215,63 @@ impl OutputAdapter for TerminalAdapter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::output_adapters::ArtifactOrder;
     use crate::pr_package::*;
     use chrono::Utc;
     use uuid::Uuid;
 
+    #[test]
+    fn medium_view_shows_review_owner_per_file() {
+        let adapter = TerminalAdapter::new();
+        let mut package = test_package();
+        package.review_assignments.push(FileReviewAssignment {
+            pattern: "src/**".to_string(),
+            reviewers: vec!["alice".to_string(), "bob".to_string()],
+            required_approvals: 1,
+            assigned_at: Utc::now(),
+            approvals: vec![],
+        });
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Medium,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        let output = adapter.render(&ctx).unwrap();
+        assert!(
+            output.contains("Review owner: alice, bob (0 of 1 sign-off(s))"),
+            "{}",
+            output
+        );
+
+        package.review_assignments[0]
+            .approvals
+            .push(ApprovalRecord {
+                reviewer: "bob".to_string(),
+                approved_at: Utc::now(),
+            });
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Medium,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        let output = adapter.render(&ctx).unwrap();
+        assert!(
+            output.contains("Review owner: alice, bob (signed off by bob)"),
+            "{}",
+            output
+        );
+    }
+
     fn test_package() -> PRPackage {
         PRPackage {
             package_version: "1.0.0".to_string(),
@@ -982,6 +1332,10 @@ mod tests {
                     comments: None,
                     amendment: None,
                     kind: None,
+                    no_commit: false,
+                    renamed_from: None,
+                    mode_change: None,
+                    line_endings_normalized: None,
                 }],
                 patch_sets: vec![],
                 pending_actions: vec![],
@@ -1012,8 +1366,11 @@ mod tests {
             display_id: None,
             tag: None,
             vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
             parent_draft_id: None,
             pending_approvals: vec![],
+            review_assignments: vec![],
             supervisor_review: None,
             ignored_artifacts: vec![],
             baseline_artifacts: vec![],
@@ -1023,6 +1380,11 @@ mod tests {
             draft_seq: 0,
             plan_phase: None,
             plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
         }
     }
 
@@ -1034,8 +1396,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
 
         let output = adapter.render(&ctx).unwrap();
@@ -1048,6 +1413,50 @@ mod tests {
         assert!(!output.contains("\x1b["));
     }
 
+    #[test]
+    fn render_shows_mode_change() {
+        let adapter = TerminalAdapter::new();
+        let mut package = test_package();
+        package.changes.artifacts[0].mode_change = Some(crate::draft_package::ModeChange {
+            old_mode: 0o100644,
+            new_mode: 0o100755,
+        });
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Top,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+
+        let output = adapter.render(&ctx).unwrap();
+        assert!(output.contains("(mode 100644 → 100755)"), "{}", output);
+    }
+
+    #[test]
+    fn render_shows_line_ending_normalization() {
+        let adapter = TerminalAdapter::new();
+        let mut package = test_package();
+        package.changes.artifacts[0].line_endings_normalized =
+            Some(crate::draft_package::LineEnding::Lf);
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Top,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+
+        let output = adapter.render(&ctx).unwrap();
+        assert!(output.contains("(line endings → lf)"), "{}", output);
+    }
+
     #[test]
     fn render_with_color() {
         let adapter = TerminalAdapter::with_color(true);
@@ -1056,8 +1465,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
 
         let output = adapter.render(&ctx).unwrap();
@@ -1074,8 +1486,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Medium,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
 
         let output = adapter.render(&ctx).unwrap();
@@ -1083,6 +1498,76 @@ mod tests {
         assert!(output.contains("security"));
     }
 
+    #[test]
+    fn render_shows_tests_run_and_risk_findings() {
+        let adapter = TerminalAdapter::new();
+        let mut package = test_package();
+        package.changes.artifacts[0].tests_run =
+            vec!["cargo test — FAILED (exit 101, 3.2s)".to_string()];
+        package
+            .risk
+            .findings
+            .push(crate::draft_package::RiskFinding {
+                category: crate::draft_package::RiskCategory::TestFailure,
+                severity: crate::draft_package::Severity::High,
+                description: "Tests failed: `cargo test` exited with 101".to_string(),
+                evidence_refs: vec![],
+                mitigation: Some("Fix the failing tests before approving.".to_string()),
+                location: None,
+            });
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Medium,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        let output = adapter.render(&ctx).unwrap();
+        assert!(output.contains("Risk Findings:"));
+        assert!(output.contains("[High] Tests failed: `cargo test` exited with 101"));
+        assert!(output.contains("Tests: cargo test — FAILED (exit 101, 3.2s)"));
+    }
+
+    #[test]
+    fn no_commit_artifacts_are_badged() {
+        let adapter = TerminalAdapter::new();
+        let mut package = test_package();
+        package.changes.artifacts[0].no_commit = true;
+        for detail_level in [DetailLevel::Top, DetailLevel::Medium] {
+            let ctx = RenderContext {
+                package: &package,
+                detail_level,
+                file_filters: vec![],
+                kind_filters: vec![],
+                artifact_order: ArtifactOrder::AsIs,
+                diff_provider: None,
+                section_filter: None,
+                review_session: None,
+            };
+            let output = adapter.render(&ctx).unwrap();
+            assert!(output.contains("[apply-only, no commit]"), "{}", output);
+        }
+
+        package.changes.artifacts[0].no_commit = false;
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Top,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        assert!(!adapter
+            .render(&ctx)
+            .unwrap()
+            .contains("[apply-only, no commit]"));
+    }
+
     #[test]
     fn file_filter_works() {
         let adapter = TerminalAdapter::new();
@@ -1091,14 +1576,49 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec!["auth.rs".to_string()],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
 
         let output = adapter.render(&ctx).unwrap();
         assert!(output.contains("auth.rs"));
     }
 
+    #[test]
+    fn kind_filter_shows_only_matching_change_types() {
+        let adapter = TerminalAdapter::new();
+        let mut package = test_package();
+        let mut deleted = package.changes.artifacts[0].clone();
+        deleted.resource_uri = "fs://workspace/src/legacy.rs".to_string();
+        deleted.change_type = ChangeType::Delete;
+        package.changes.artifacts.push(deleted);
+
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Top,
+            file_filters: vec![],
+            kind_filters: vec![ChangeType::Delete],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        let output = adapter.render(&ctx).unwrap();
+        assert!(output.contains("legacy.rs"));
+        assert!(!output.contains("auth.rs"));
+
+        let ctx = RenderContext {
+            kind_filters: vec![ChangeType::Add],
+            artifact_order: ArtifactOrder::AsIs,
+            ..ctx
+        };
+        let err = adapter.render(&ctx).unwrap_err().to_string();
+        assert!(err.contains("kind=add"), "{}", err);
+    }
+
     #[test]
     fn file_filter_no_match_returns_error() {
         let adapter = TerminalAdapter::new();
@@ -1107,8 +1627,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec!["nonexistent.rs".to_string()],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
 
         let result = adapter.render(&ctx);
@@ -1124,8 +1647,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Medium,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(
@@ -1189,8 +1715,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(
@@ -1218,13 +1747,20 @@ mod tests {
             comments: None,
             amendment: None,
             kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         });
         let ctx = RenderContext {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(output.contains("What Changed (2 files):"));
@@ -1252,8 +1788,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(output.contains("Design Decisions:"));
@@ -1271,8 +1810,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(!output.contains("Design Decisions:"));
@@ -1286,8 +1828,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Medium,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         // Medium shows both grouped summary and detailed artifacts
@@ -1315,8 +1860,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(output.contains("Agent Decision Log"));
@@ -1336,8 +1884,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(!output.contains("Agent Decision Log"));
@@ -1362,8 +1913,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: Some(SectionFilter::Decisions),
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(output.contains("Chose async over sync"));
@@ -1381,8 +1935,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: Some(SectionFilter::Summary),
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(output.contains("Summary:"));
@@ -1400,8 +1957,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: Some(SectionFilter::Files),
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(output.contains("What Changed"));
@@ -1428,8 +1988,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(output.contains("Ollama thinking-mode config"));
@@ -1459,13 +2022,20 @@ mod tests {
             comments: None,
             amendment: None,
             kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         });
         let ctx = RenderContext {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec!["src/*.rs".to_string()],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         // auth.rs should appear (matches glob src/*.rs)
@@ -1486,8 +2056,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Top,
             file_filters: vec!["totally/nonexistent/path.rs".to_string()],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let result = adapter.render(&ctx);
         assert!(result.is_err());
@@ -1515,6 +2088,10 @@ mod tests {
                 format: Some("PNG".to_string()),
                 frame_index: Some(frame_index),
             }),
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         }
     }
 
@@ -1541,8 +2118,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Full,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: Some(&provider),
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(
@@ -1631,6 +2211,10 @@ mod tests {
                 mime_type: mime.map(|s| s.to_string()),
                 byte_size,
             }),
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         }
     }
 
@@ -1650,6 +2234,10 @@ mod tests {
                 encoding: encoding.map(|s| s.to_string()),
                 line_count,
             }),
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         }
     }
 
@@ -1676,8 +2264,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Full,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: Some(&provider),
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(
@@ -1711,8 +2302,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Full,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: None,
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(
@@ -1796,6 +2390,96 @@ mod tests {
 
     // ── v0.15.0 Text artifact rendering tests ──
 
+    #[test]
+    fn compact_view_lists_changed_runs_with_path_and_line() {
+        let adapter = TerminalAdapter::new().with_compact();
+        let package = test_package();
+
+        struct HunkDiff;
+        impl crate::output_adapters::DiffProvider for HunkDiff {
+            fn get_diff(&self, _: &str) -> Result<String, ChangeSetError> {
+                Ok("--- a/src/auth.rs\n+++ b/src/auth.rs\n\
+                    @@ -10,6 +10,7 @@\n ctx\n-old\n+new\n+extra\n ctx\n ctx\n--- sql comment\n\
+                    @@ -40,2 +41,2 @@\n-a\n+b\n"
+                    .to_string())
+            }
+        }
+
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Full,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: Some(&HunkDiff),
+            section_filter: None,
+            review_session: None,
+        };
+        let output = adapter.render(&ctx).unwrap();
+        assert_eq!(
+            output,
+            "src/auth.rs:11\n-old\n+new\n+extra\n\
+             src/auth.rs:15\n--- sql comment\n\
+             src/auth.rs:41\n-a\n+b\n"
+        );
+    }
+
+    #[test]
+    fn compact_view_requires_diffs() {
+        let adapter = TerminalAdapter::new().with_compact();
+        let package = test_package();
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Full,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        assert!(adapter.render(&ctx).is_err());
+    }
+
+    #[test]
+    fn blame_view_shows_agent_amendment_disposition_and_rationale() {
+        let adapter = TerminalAdapter::new().with_blame();
+        let mut package = test_package();
+        let mut amended = package.changes.artifacts[0].clone();
+        amended.resource_uri = "fs://workspace/src/token.rs".to_string();
+        amended.explanation_tiers = None;
+        amended.rationale = None;
+        amended.disposition = ArtifactDisposition::Approved;
+        amended.amendment = Some(AmendmentRecord {
+            amended_by: "alice".to_string(),
+            amended_at: "2026-03-04T05:06:00Z".parse().unwrap(),
+            amendment_type: AmendmentType::PatchApplied,
+            reason: None,
+        });
+        package.changes.artifacts.push(amended);
+
+        let ctx = RenderContext {
+            package: &package,
+            detail_level: DetailLevel::Medium,
+            file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
+            diff_provider: None,
+            section_filter: None,
+            review_session: None,
+        };
+        let output = adapter.render(&ctx).unwrap();
+        assert_eq!(
+            output,
+            "Blame (2 artifacts):\n\
+             ~ src/auth.rs\n\
+             \x20   agent agent-1 · pending · Migrated to JWT auth\n\
+             ~ src/token.rs\n\
+             \x20   agent agent-1 · amended by alice 2026-03-04 05:06 (patch applied) · approved \
+             · no rationale recorded\n"
+        );
+    }
+
     #[test]
     fn text_artifact_full_view_renders_diff() {
         // Text artifact should fall through to diff rendering.
@@ -1819,8 +2503,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Full,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: Some(&provider),
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(
@@ -1927,6 +2614,10 @@ mod tests {
                 format: format.map(|s| s.to_string()),
                 frame_count: None,
             }),
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         }
     }
 
@@ -1956,8 +2647,11 @@ mod tests {
             package: &package,
             detail_level: DetailLevel::Full,
             file_filters: vec![],
+            kind_filters: vec![],
+            artifact_order: ArtifactOrder::AsIs,
             diff_provider: Some(&provider),
             section_filter: None,
+            review_session: None,
         };
         let output = adapter.render(&ctx).unwrap();
         assert!(
@@ -0,0 +1,182 @@
+// resource_uri.rs — Parsed artifact resource URIs (`scheme://authority/path`).
+//
+// Artifacts name what they change with a URI. Filesystem changes use
+// `fs://workspace/<relative-path>`; other connectors bring their own scheme
+// (`db://orders/rows/42`, `email://outbox/draft-7`). Code that needs the
+// workspace-relative path of a file artifact should go through
+// `ResourceUri::workspace_path` instead of stripping the `fs://workspace/`
+// prefix by hand, so non-file artifacts are recognized rather than mangled.
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::error::ChangeSetError;
+
+/// Scheme of filesystem artifacts.
+pub const FS_SCHEME: &str = "fs";
+
+/// Authority of filesystem artifacts inside the goal's workspace.
+pub const WORKSPACE_AUTHORITY: &str = "workspace";
+
+/// A resource URI split into scheme, authority, and path.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ResourceUri {
+    scheme: String,
+    authority: String,
+    path: String,
+}
+
+impl ResourceUri {
+    /// Parse `scheme://authority/path`. The path may be empty
+    /// (`gmail://inbox`), but the scheme may not.
+    pub fn parse(uri: &str) -> Result<Self, ChangeSetError> {
+        let (scheme, rest) = uri.split_once("://").ok_or_else(|| {
+            ChangeSetError::InvalidData(format!("missing scheme in URI '{}'", uri))
+        })?;
+        let scheme_ok = scheme
+            .chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic())
+            && scheme
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+        if !scheme_ok {
+            return Err(ChangeSetError::InvalidData(format!(
+                "invalid scheme '{}' in URI '{}'",
+                scheme, uri
+            )));
+        }
+        let (authority, path) = rest.split_once('/').unwrap_or((rest, ""));
+        Ok(Self {
+            scheme: scheme.to_string(),
+            authority: authority.to_string(),
+            path: path.to_string(),
+        })
+    }
+
+    /// URI of a file in the goal workspace: `fs://workspace/<path>`.
+    pub fn workspace_file(path: &str) -> Self {
+        Self {
+            scheme: FS_SCHEME.to_string(),
+            authority: WORKSPACE_AUTHORITY.to_string(),
+            path: path.trim_start_matches('/').to_string(),
+        }
+    }
+
+    pub fn scheme(&self) -> &str {
+        &self.scheme
+    }
+
+    pub fn authority(&self) -> &str {
+        &self.authority
+    }
+
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// Whether this names a file in the goal workspace (`fs://workspace/...`).
+    pub fn is_workspace_file(&self) -> bool {
+        self.scheme == FS_SCHEME && self.authority == WORKSPACE_AUTHORITY
+    }
+
+    /// The workspace-relative path, for `fs://workspace/` URIs only.
+    pub fn workspace_path(&self) -> Option<&str> {
+        self.is_workspace_file().then_some(self.path.as_str())
+    }
+}
+
+impl fmt::Display for ResourceUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}://{}", self.scheme, self.authority)?;
+        if !self.path.is_empty() {
+            write!(f, "/{}", self.path)?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for ResourceUri {
+    type Err = ChangeSetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Workspace-relative path of a `fs://workspace/` URI, or `None` for any
+/// other scheme (or an unparseable URI).
+pub fn workspace_path(uri: &str) -> Option<String> {
+    ResourceUri::parse(uri)
+        .ok()
+        .and_then(|u| u.workspace_path().map(str::to_string))
+}
+
+/// Short form for display: the relative path for workspace files, the full
+/// URI for everything else.
+pub fn display_path(uri: &str) -> String {
+    workspace_path(uri).unwrap_or_else(|| uri.to_string())
+}
+
+/// Normalize a reference to a resource: full URIs are kept as they are, bare
+/// paths are taken to be workspace files.
+pub fn normalize(reference: &str) -> String {
+    if reference.contains("://") {
+        reference.to_string()
+    } else {
+        ResourceUri::workspace_file(reference).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_splits_scheme_authority_and_path() {
+        let uri = ResourceUri::parse("fs://workspace/src/main.rs").unwrap();
+        assert_eq!(uri.scheme(), "fs");
+        assert_eq!(uri.authority(), "workspace");
+        assert_eq!(uri.path(), "src/main.rs");
+        assert_eq!(uri.workspace_path(), Some("src/main.rs"));
+        assert_eq!(uri.to_string(), "fs://workspace/src/main.rs");
+
+        let db = ResourceUri::parse("db://orders/rows/42").unwrap();
+        assert_eq!(db.scheme(), "db");
+        assert_eq!(db.authority(), "orders");
+        assert_eq!(db.path(), "rows/42");
+        assert!(!db.is_workspace_file());
+        assert_eq!(db.workspace_path(), None);
+
+        let inbox = ResourceUri::parse("gmail://inbox").unwrap();
+        assert_eq!(inbox.path(), "");
+        assert_eq!(inbox.to_string(), "gmail://inbox");
+    }
+
+    #[test]
+    fn parse_rejects_missing_or_invalid_schemes() {
+        assert!(ResourceUri::parse("src/main.rs").is_err());
+        assert!(ResourceUri::parse("://workspace/x").is_err());
+        assert!(ResourceUri::parse("1fs://workspace/x").is_err());
+    }
+
+    #[test]
+    fn helpers_only_strip_workspace_files() {
+        assert_eq!(
+            workspace_path("fs://workspace/README.md").as_deref(),
+            Some("README.md")
+        );
+        // Another filesystem authority is not the workspace.
+        assert_eq!(workspace_path("fs://home/README.md"), None);
+        assert_eq!(
+            display_path("email://outbox/draft-7"),
+            "email://outbox/draft-7"
+        );
+        assert_eq!(normalize("src/lib.rs"), "fs://workspace/src/lib.rs");
+        assert_eq!(normalize("db://orders/rows/1"), "db://orders/rows/1");
+        assert_eq!(
+            ResourceUri::workspace_file("/abs").to_string(),
+            "fs://workspace/abs"
+        );
+    }
+}
@@ -121,7 +121,10 @@ pub fn build_channel(
     use crate::terminal_channel::{AutoApproveChannel, TerminalChannel};
 
     match config.channel_type.as_str() {
-        "terminal" => Ok(Box::new(TerminalChannel::stdio())),
+        "terminal" => Ok(Box::new(match &config.channel_config {
+            Some(channel_cfg) => TerminalChannel::stdio_from_config(channel_cfg)?,
+            None => TerminalChannel::stdio(),
+        })),
         "auto-approve" => Ok(Box::new(AutoApproveChannel::new())),
         "webhook" => {
             let channel_cfg = config.channel_config.as_ref().ok_or_else(|| {
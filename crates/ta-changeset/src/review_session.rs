@@ -92,6 +92,39 @@ impl ReviewSession {
         &review.comments
     }
 
+    /// Add a comment anchored to a line range of an artifact.
+    pub fn add_anchored_comment(
+        &mut self,
+        artifact_uri: &str,
+        commenter: &str,
+        text: &str,
+        anchor: CommentAnchor,
+    ) -> &CommentThread {
+        self.add_comment(artifact_uri, commenter, text);
+        let thread = &mut self
+            .artifact_reviews
+            .get_mut(artifact_uri)
+            .expect("add_comment creates the review")
+            .comments;
+        if let Some(comment) = thread.comments.last_mut() {
+            comment.anchor = Some(anchor);
+        }
+        thread
+    }
+
+    /// Re-anchor an artifact's line comments against its new content,
+    /// e.g. after `ta draft amend --file`.
+    pub fn reanchor_comments(&mut self, artifact_uri: &str, content: &str) -> ReanchorSummary {
+        let summary = match self.artifact_reviews.get_mut(artifact_uri) {
+            Some(review) => review.comments.reanchor(content),
+            None => ReanchorSummary::default(),
+        };
+        if summary.anchored > 0 {
+            self.touch();
+        }
+        summary
+    }
+
     /// Set the disposition for an artifact.
     pub fn set_disposition(&mut self, artifact_uri: &str, disposition: ArtifactDisposition) {
         self.touch();
@@ -144,6 +177,7 @@ impl ReviewSession {
                 ArtifactDisposition::Approved => counts.approved += 1,
                 ArtifactDisposition::Rejected => counts.rejected += 1,
                 ArtifactDisposition::Discuss => counts.discuss += 1,
+                ArtifactDisposition::Deferred => counts.deferred += 1,
             }
         }
         counts
@@ -214,6 +248,7 @@ impl CommentThread {
             text: text.to_string(),
             created_at: Utc::now(),
             reasoning: None,
+            anchor: None,
         });
     }
 
@@ -224,9 +259,23 @@ impl CommentThread {
             text: text.to_string(),
             created_at: Utc::now(),
             reasoning: Some(reasoning),
+            anchor: None,
         });
     }
 
+    /// Re-anchor every line comment in the thread against `content`.
+    pub fn reanchor(&mut self, content: &str) -> ReanchorSummary {
+        let mut summary = ReanchorSummary::default();
+        for anchor in self.comments.iter_mut().filter_map(|c| c.anchor.as_mut()) {
+            anchor.reanchor(content);
+            summary.anchored += 1;
+            if anchor.uncertain {
+                summary.uncertain += 1;
+            }
+        }
+        summary
+    }
+
     /// Check if the thread is empty.
     pub fn is_empty(&self) -> bool {
         self.comments.is_empty()
@@ -257,6 +306,148 @@ pub struct Comment {
     /// Reviewer can explain *why* they approved/rejected, not just leave text.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub reasoning: Option<ReviewReasoning>,
+    /// The line range the comment is about. `None` = comment on the whole artifact.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub anchor: Option<CommentAnchor>,
+}
+
+impl Comment {
+    /// "line 42", "lines 40-45", with "(position uncertain)" appended when
+    /// re-anchoring lost track of the commented lines. `None` for artifact-level comments.
+    pub fn location_label(&self) -> Option<String> {
+        let anchor = self.anchor.as_ref()?;
+        let mut label = if anchor.start_line == anchor.end_line {
+            format!("line {}", anchor.start_line)
+        } else {
+            format!("lines {}-{}", anchor.start_line, anchor.end_line)
+        };
+        if anchor.uncertain {
+            label.push_str(" (position uncertain)");
+        }
+        Some(label)
+    }
+}
+
+/// Lines of context kept above and below a commented range.
+const ANCHOR_CONTEXT_LINES: usize = 2;
+
+/// Where a line comment points, plus enough of the surrounding text to find
+/// the same spot again after the file changes.
+///
+/// Line numbers alone go stale as soon as an amendment adds or removes lines
+/// above the comment. [`CommentAnchor::reanchor`] looks for the commented
+/// lines (and their context) in the new content and moves the range there.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CommentAnchor {
+    /// First commented line (1-based).
+    pub start_line: u32,
+    /// Last commented line (1-based, inclusive).
+    pub end_line: u32,
+    /// The commented lines as they read when the comment was made.
+    pub snippet: Vec<String>,
+    /// Up to two lines directly above the snippet.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub before: Vec<String>,
+    /// Up to two lines directly below the snippet.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub after: Vec<String>,
+    /// Set when the snippet could not be found after a change; the line
+    /// numbers are a best guess from the surrounding context.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub uncertain: bool,
+}
+
+impl CommentAnchor {
+    /// Anchor lines `start_line..=end_line` (1-based) of `content`. `None`
+    /// if the range is empty or runs past the end of the file.
+    pub fn capture(content: &str, start_line: u32, end_line: u32) -> Option<Self> {
+        let lines: Vec<&str> = content.lines().collect();
+        if start_line == 0 || end_line < start_line || end_line as usize > lines.len() {
+            return None;
+        }
+        let (start, end) = (start_line as usize - 1, end_line as usize);
+        let owned = |range: &[&str]| range.iter().map(|l| l.to_string()).collect();
+        Some(Self {
+            start_line,
+            end_line,
+            snippet: owned(&lines[start..end]),
+            before: owned(&lines[start.saturating_sub(ANCHOR_CONTEXT_LINES)..start]),
+            after: owned(&lines[end..(end + ANCHOR_CONTEXT_LINES).min(lines.len())]),
+            uncertain: false,
+        })
+    }
+
+    /// Move the anchor to where its snippet appears in `content`.
+    ///
+    /// Every exact occurrence of the snippet (ignoring surrounding
+    /// whitespace) is a candidate; the one with the most matching context
+    /// lines wins, then the one nearest the old position. If the snippet
+    /// itself was edited, the range is placed next to the matching context
+    /// above or below it and marked uncertain. With no match at all the line
+    /// numbers are kept (clamped to the file) and marked uncertain.
+    pub fn reanchor(&mut self, content: &str) {
+        let lines: Vec<&str> = content.lines().collect();
+        let len = self.snippet.len().max(1);
+        let old = self.start_line.saturating_sub(1) as usize;
+        let matches_at = |block: &[String], at: usize| {
+            at + block.len() <= lines.len()
+                && block
+                    .iter()
+                    .zip(&lines[at..])
+                    .all(|(want, have)| want.trim() == have.trim())
+        };
+
+        let context_score = |at: usize| {
+            let before =
+                at >= self.before.len() && matches_at(&self.before, at - self.before.len());
+            usize::from(before) + usize::from(matches_at(&self.after, at + len))
+        };
+        let best = (0..=lines.len().saturating_sub(len))
+            .filter(|&at| !self.snippet.is_empty() && matches_at(&self.snippet, at))
+            .max_by_key(|&at| (context_score(at), std::cmp::Reverse(at.abs_diff(old))));
+        if let Some(at) = best {
+            self.move_to(at, len, false);
+            return;
+        }
+
+        // The commented lines changed: fall back to the context around them.
+        let informative = |block: &[String]| block.iter().any(|l| !l.trim().is_empty());
+        let mut guesses = Vec::new();
+        if informative(&self.before) {
+            guesses.extend(
+                (0..lines.len())
+                    .filter(|&at| matches_at(&self.before, at))
+                    .map(|at| at + self.before.len()),
+            );
+        }
+        if informative(&self.after) {
+            guesses.extend(
+                (0..lines.len())
+                    .filter(|&at| matches_at(&self.after, at))
+                    .filter_map(|at| at.checked_sub(len)),
+            );
+        }
+        let at = guesses
+            .into_iter()
+            .min_by_key(|at| at.abs_diff(old))
+            .unwrap_or(old)
+            .min(lines.len().saturating_sub(1));
+        self.move_to(at, len, true);
+    }
+
+    fn move_to(&mut self, at: usize, len: usize, uncertain: bool) {
+        self.start_line = at as u32 + 1;
+        self.end_line = (at + len) as u32;
+        self.uncertain = uncertain;
+    }
+}
+
+/// How many line comments were re-anchored, and how many of those could not
+/// be placed with confidence.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReanchorSummary {
+    pub anchored: usize,
+    pub uncertain: usize,
 }
 
 /// Structured reasoning attached to a review comment (v0.3.3).
@@ -289,6 +480,70 @@ pub struct DispositionCounts {
     pub approved: usize,
     pub rejected: usize,
     pub discuss: usize,
+    pub deferred: usize,
+}
+
+/// A self-contained snapshot of a review session for handing a review off to
+/// another reviewer or machine.
+///
+/// Carries the full session (dispositions, comment threads, notes, and current
+/// focus) plus the draft's artifact set at export time, so the importer can tell
+/// whether its local copy of the draft matches the one that was reviewed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewSessionExport {
+    /// Export format version (see [`ReviewSessionExport::FORMAT_VERSION`]).
+    pub format_version: u32,
+    /// When the export was written.
+    pub exported_at: DateTime<Utc>,
+    /// Resource URIs of the draft's artifacts when the session was exported.
+    pub draft_artifacts: Vec<String>,
+    /// The exported session.
+    pub session: ReviewSession,
+}
+
+impl ReviewSessionExport {
+    /// Current export format version.
+    pub const FORMAT_VERSION: u32 = 1;
+
+    /// Snapshot a session together with the artifact URIs of its draft.
+    pub fn new(session: ReviewSession, mut draft_artifacts: Vec<String>) -> Self {
+        draft_artifacts.sort();
+        draft_artifacts.dedup();
+        Self {
+            format_version: Self::FORMAT_VERSION,
+            exported_at: Utc::now(),
+            draft_artifacts,
+            session,
+        }
+    }
+
+    /// Compare the exported artifact set with the artifacts of the local draft.
+    pub fn artifact_diff(&self, local_artifacts: &[String]) -> ArtifactSetDiff {
+        let local: std::collections::BTreeSet<&str> =
+            local_artifacts.iter().map(String::as_str).collect();
+        let exported: std::collections::BTreeSet<&str> =
+            self.draft_artifacts.iter().map(String::as_str).collect();
+        ArtifactSetDiff {
+            missing_locally: exported.difference(&local).map(|s| s.to_string()).collect(),
+            only_locally: local.difference(&exported).map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+/// Difference between an exported draft's artifact set and the local draft's.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ArtifactSetDiff {
+    /// Artifacts in the exported draft that the local draft does not have.
+    pub missing_locally: Vec<String>,
+    /// Artifacts in the local draft that were not in the exported draft.
+    pub only_locally: Vec<String>,
+}
+
+impl ArtifactSetDiff {
+    /// True when both drafts have the same artifacts.
+    pub fn is_empty(&self) -> bool {
+        self.missing_locally.is_empty() && self.only_locally.is_empty()
+    }
 }
 
 #[cfg(test)]
@@ -338,11 +593,13 @@ mod tests {
         session.set_disposition("fs://workspace/b.rs", ArtifactDisposition::Approved);
         session.set_disposition("fs://workspace/c.rs", ArtifactDisposition::Rejected);
         session.set_disposition("fs://workspace/d.rs", ArtifactDisposition::Discuss);
+        session.set_disposition("fs://workspace/e.rs", ArtifactDisposition::Deferred);
 
         let counts = session.disposition_counts();
         assert_eq!(counts.approved, 2);
         assert_eq!(counts.rejected, 1);
         assert_eq!(counts.discuss, 1);
+        assert_eq!(counts.deferred, 1);
         assert_eq!(counts.pending, 0);
     }
 
@@ -362,6 +619,7 @@ mod tests {
 
         session.set_disposition("fs://workspace/a.rs", ArtifactDisposition::Approved);
         session.set_disposition("fs://workspace/b.rs", ArtifactDisposition::Rejected);
+        session.set_disposition("fs://workspace/c.rs", ArtifactDisposition::Deferred);
 
         assert!(!session.has_unresolved_discuss());
     }
@@ -454,6 +712,66 @@ mod tests {
         assert!(comment.reasoning.is_none());
     }
 
+    #[test]
+    fn anchored_comment_follows_its_lines_through_edits() {
+        let original = "fn a() {}\n\nfn parse() {\n    let x = input.unwrap();\n}\n\nfn b() {}\n";
+        let mut session = ReviewSession::new(Uuid::new_v4(), "reviewer-1".to_string());
+        let uri = "fs://workspace/src/lib.rs";
+        let anchor = CommentAnchor::capture(original, 4, 4).unwrap();
+        assert_eq!(anchor.snippet, vec!["    let x = input.unwrap();"]);
+        assert_eq!(anchor.before, vec!["", "fn parse() {"]);
+        session.add_anchored_comment(uri, "reviewer-1", "Handle the error", anchor);
+        assert!(CommentAnchor::capture(original, 7, 9).is_none());
+
+        // Lines inserted above: the comment moves down with its line.
+        let shifted = format!("use std::io;\nuse std::fs;\n\n{}", original);
+        let summary = session.reanchor_comments(uri, &shifted);
+        assert_eq!(
+            summary,
+            ReanchorSummary {
+                anchored: 1,
+                uncertain: 0
+            }
+        );
+        let comment = &session.artifact_reviews[uri].comments.comments[0];
+        assert_eq!(comment.location_label().as_deref(), Some("line 7"));
+
+        // The commented line itself was rewritten: placed by context, flagged.
+        let rewritten = shifted.replace("input.unwrap()", "input?");
+        let summary = session.reanchor_comments(uri, &rewritten);
+        assert_eq!(
+            summary,
+            ReanchorSummary {
+                anchored: 1,
+                uncertain: 1
+            }
+        );
+        let comment = &session.artifact_reviews[uri].comments.comments[0];
+        assert_eq!(
+            comment.location_label().as_deref(),
+            Some("line 7 (position uncertain)")
+        );
+
+        // The original text comes back: the anchor is certain again.
+        session.reanchor_comments(uri, original);
+        let comment = &session.artifact_reviews[uri].comments.comments[0];
+        assert_eq!(comment.location_label().as_deref(), Some("line 4"));
+
+        // Duplicated lines: context picks the right occurrence.
+        let dup = "    let x = input.unwrap();\n".to_string() + original;
+        session.reanchor_comments(uri, &dup);
+        let comment = &session.artifact_reviews[uri].comments.comments[0];
+        assert_eq!(comment.location_label().as_deref(), Some("line 5"));
+
+        // Unrelated content: keep the old position, clamped, and flag it.
+        session.reanchor_comments(uri, "x\ny\n");
+        let comment = &session.artifact_reviews[uri].comments.comments[0];
+        assert_eq!(
+            comment.location_label().as_deref(),
+            Some("line 2 (position uncertain)")
+        );
+    }
+
     #[test]
     fn review_reasoning_serialization() {
         let reasoning = ReviewReasoning {
@@ -469,4 +787,49 @@ mod tests {
         // Empty alternatives_considered should be skipped in serialization.
         assert!(!json.contains("alternatives_considered"));
     }
+
+    #[test]
+    fn export_round_trips_and_diffs_artifact_sets() {
+        let mut session = ReviewSession::new(Uuid::new_v4(), "reviewer-1".to_string());
+        session.set_disposition("fs://workspace/a.rs", ArtifactDisposition::Approved);
+        session.add_comment("fs://workspace/b.rs", "reviewer-1", "needs a test");
+        session.add_session_note("halfway through");
+        session.current_focus = Some("fs://workspace/b.rs".to_string());
+
+        let export = ReviewSessionExport::new(
+            session.clone(),
+            vec![
+                "fs://workspace/b.rs".to_string(),
+                "fs://workspace/a.rs".to_string(),
+            ],
+        );
+        let json = serde_json::to_string(&export).unwrap();
+        let back: ReviewSessionExport = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(back.format_version, ReviewSessionExport::FORMAT_VERSION);
+        assert_eq!(back.session.session_id, session.session_id);
+        assert_eq!(
+            back.session.current_focus.as_deref(),
+            Some("fs://workspace/b.rs")
+        );
+        assert_eq!(back.session.session_notes.len(), 1);
+        assert_eq!(
+            back.session.get_disposition("fs://workspace/a.rs"),
+            Some(ArtifactDisposition::Approved)
+        );
+
+        let same = vec![
+            "fs://workspace/a.rs".to_string(),
+            "fs://workspace/b.rs".to_string(),
+        ];
+        assert!(back.artifact_diff(&same).is_empty());
+
+        let changed = vec![
+            "fs://workspace/a.rs".to_string(),
+            "fs://workspace/c.rs".to_string(),
+        ];
+        let diff = back.artifact_diff(&changed);
+        assert_eq!(diff.missing_locally, vec!["fs://workspace/b.rs"]);
+        assert_eq!(diff.only_locally, vec!["fs://workspace/c.rs"]);
+    }
 }
@@ -13,6 +13,8 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::interaction::Decision;
+
 /// A bidirectional channel between a human and a TA-mediated agent session.
 ///
 /// Every interaction between human and TA is a message on a channel.
@@ -270,6 +272,16 @@ impl InteractiveSession {
         self.updated_at = Utc::now();
     }
 
+    /// Record a channel event in the session log so it shows up in the
+    /// transcript. Agent output is logged as `agent`; everything else is a
+    /// `ta-system` notification.
+    pub fn log_event(&mut self, event: &SessionEvent) {
+        match event {
+            SessionEvent::AgentOutput { content, .. } => self.log_message("agent", content),
+            other => self.log_message("ta-system", &other.to_string()),
+        }
+    }
+
     /// Record that a draft was reviewed inline during this session.
     pub fn add_draft(&mut self, draft_id: Uuid) {
         if !self.draft_ids.contains(&draft_id) {
@@ -366,6 +378,50 @@ pub struct InteractiveConfig {
     /// Override launch command for resume (e.g., "claude --resume {session_id}").
     #[serde(default)]
     pub resume_cmd: Option<String>,
+
+    /// Seconds a terminal review prompt waits for a human before answering
+    /// with `on_timeout`. Unset waits forever.
+    #[serde(default)]
+    pub response_timeout_secs: Option<u64>,
+
+    /// Decision used when `response_timeout_secs` elapses (default: deny).
+    #[serde(default)]
+    pub on_timeout: TimeoutDecision,
+}
+
+impl InteractiveConfig {
+    /// The response timeout and the decision to return when it elapses, or
+    /// `None` when prompts wait indefinitely.
+    pub fn response_timeout(&self) -> Option<(Duration, Decision)> {
+        let secs = self.response_timeout_secs.filter(|s| *s > 0)?;
+        Some((Duration::from_secs(secs), self.on_timeout.decision(secs)))
+    }
+}
+
+/// What an unanswered prompt resolves to once its response timeout elapses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimeoutDecision {
+    /// Reject the request — nothing proceeds without a human.
+    #[default]
+    Deny,
+    Approve,
+    Discuss,
+    Skip,
+}
+
+impl TimeoutDecision {
+    /// The `Decision` returned after waiting `secs` seconds.
+    pub fn decision(self, secs: u64) -> Decision {
+        match self {
+            TimeoutDecision::Deny => Decision::Reject {
+                reason: format!("no response within {}s", secs),
+            },
+            TimeoutDecision::Approve => Decision::Approve,
+            TimeoutDecision::Discuss => Decision::Discuss,
+            TimeoutDecision::Skip => Decision::SkipForNow,
+        }
+    }
 }
 
 fn default_output_capture() -> String {
@@ -550,6 +606,23 @@ mod tests {
         assert!(config.allow_human_input);
         assert!(config.auto_exit_on.is_none());
         assert!(config.resume_cmd.is_none());
+        assert!(config.response_timeout().is_none());
+    }
+
+    #[test]
+    fn interactive_config_response_timeout() {
+        let config: InteractiveConfig =
+            serde_yaml::from_str("response_timeout_secs: 90\n").unwrap();
+        let (timeout, decision) = config.response_timeout().unwrap();
+        assert_eq!(timeout, Duration::from_secs(90));
+        assert!(matches!(decision, Decision::Reject { ref reason } if reason.contains("90s")));
+
+        let config: InteractiveConfig =
+            serde_yaml::from_str("response_timeout_secs: 30\non_timeout: skip\n").unwrap();
+        assert_eq!(config.response_timeout().unwrap().1, Decision::SkipForNow);
+
+        let config: InteractiveConfig = serde_yaml::from_str("response_timeout_secs: 0\n").unwrap();
+        assert!(config.response_timeout().is_none());
     }
 
     #[test]
@@ -572,6 +645,26 @@ resume_cmd: "claude --resume {session_id}"
         );
     }
 
+    #[test]
+    fn log_event_maps_senders() {
+        let mut session = InteractiveSession::new(
+            Uuid::new_v4(),
+            "cli:tty0".to_string(),
+            "claude-code".to_string(),
+        );
+        session.log_event(&SessionEvent::AgentOutput {
+            stream: OutputStream::StdOut,
+            content: "Editing src/auth.rs".to_string(),
+        });
+        session.log_event(&SessionEvent::StatusUpdate {
+            message: "build passed".to_string(),
+        });
+        assert_eq!(session.messages[0].sender, "agent");
+        assert_eq!(session.messages[0].content, "Editing src/auth.rs");
+        assert_eq!(session.messages[1].sender, "ta-system");
+        assert_eq!(session.messages[1].content, "Status: build passed");
+    }
+
     #[test]
     fn elapsed_display_formatting() {
         let mut session = InteractiveSession::new(
@@ -5,7 +5,8 @@
 
 use std::collections::{HashMap, HashSet};
 
-use crate::draft_package::{Artifact, ArtifactDisposition, DependencyKind};
+use crate::draft_package::{Artifact, ArtifactDisposition, ChangeType, DependencyKind};
+use crate::output_adapters::DiffProvider;
 
 #[cfg(test)]
 use crate::draft_package::ChangeDependency;
@@ -71,6 +72,17 @@ pub enum ValidationWarning {
         artifact: String,
         blocking: Vec<String>,
     },
+    /// A deferred artifact is required by approved ones.
+    DeferredBlockingApproval {
+        artifact: String,
+        blocking: Vec<String>,
+    },
+    /// An approved artifact's diff references files (e.g. Rust modules) that
+    /// are rejected or absent, so the approved set may not build on its own.
+    DanglingReference {
+        artifact: String,
+        missing: Vec<String>,
+    },
 }
 
 /// Hard errors in the dependency graph or configuration.
@@ -310,6 +322,8 @@ impl SupervisorAgent {
     /// - Rejecting artifacts that others depend on (coupled rejections)
     /// - Approving artifacts that depend on rejected ones (broken dependencies)
     /// - "Discuss" artifacts blocking approvals
+    /// - "Deferred" artifacts that approved ones depend on (deferring is
+    ///   otherwise conflict-free)
     ///
     /// And errors for:
     /// - Cyclic dependencies
@@ -349,6 +363,7 @@ impl SupervisorAgent {
                                 Some(ArtifactDisposition::Approved)
                                     | Some(ArtifactDisposition::Discuss)
                                     | Some(ArtifactDisposition::Pending)
+                                    | Some(ArtifactDisposition::Deferred)
                             )
                         })
                         .collect();
@@ -400,6 +415,26 @@ impl SupervisorAgent {
                         });
                     }
                 }
+                ArtifactDisposition::Deferred => {
+                    // Deferring only conflicts when an approved artifact needs it now.
+                    let dependents = self.graph.get_dependents(uri);
+                    let blocked: Vec<String> = dependents
+                        .into_iter()
+                        .filter(|dep_uri| {
+                            matches!(
+                                dispositions.get(dep_uri),
+                                Some(ArtifactDisposition::Approved)
+                            )
+                        })
+                        .collect();
+
+                    if !blocked.is_empty() {
+                        result.add_warning(ValidationWarning::DeferredBlockingApproval {
+                            artifact: uri.clone(),
+                            blocking: blocked,
+                        });
+                    }
+                }
                 ArtifactDisposition::Pending => {
                     // Pending is neutral - no validation needed
                 }
@@ -408,6 +443,215 @@ impl SupervisorAgent {
 
         result
     }
+
+    /// Heuristically check that the approved subset is buildable on its own.
+    ///
+    /// Scans the lines approved artifacts add for references to files the
+    /// apply will leave out — rejected or unapproved new files, approved
+    /// deletions, or files that exist nowhere. Only Rust is understood so far:
+    /// `mod name;` declarations and `use crate::...` paths. `exists` reports
+    /// whether a workspace-relative path exists in the source.
+    ///
+    /// The scan can't see `#[path]` attributes, macros, or re-exports, so
+    /// findings are [`ValidationWarning::DanglingReference`] warnings only.
+    pub fn check_references<F>(
+        &self,
+        artifacts: &[Artifact],
+        diffs: &dyn DiffProvider,
+        exists: F,
+    ) -> Vec<ValidationWarning>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let in_draft: HashMap<&str, &Artifact> = artifacts
+            .iter()
+            .filter_map(|a| workspace_path(&a.resource_uri).map(|path| (path, a)))
+            .collect();
+        let exists_after_apply = |path: &str| match in_draft.get(path) {
+            Some(a) => match a.change_type {
+                ChangeType::Add | ChangeType::Rename => {
+                    a.disposition == ArtifactDisposition::Approved
+                }
+                ChangeType::Delete => a.disposition != ArtifactDisposition::Approved,
+                ChangeType::Modify => true,
+            },
+            None => exists(path),
+        };
+
+        let mut warnings = Vec::new();
+        for artifact in artifacts {
+            if artifact.disposition != ArtifactDisposition::Approved
+                || artifact.change_type == ChangeType::Delete
+            {
+                continue;
+            }
+            let Some(path) = workspace_path(&artifact.resource_uri) else {
+                continue;
+            };
+            if !path.ends_with(".rs") {
+                continue;
+            }
+            let Ok(diff) = diffs.get_diff(&artifact.diff_ref) else {
+                continue;
+            };
+
+            let mut missing: Vec<String> = Vec::new();
+            for line in added_lines(&diff) {
+                for reference in rust_references(path, line) {
+                    if reference.candidates.iter().any(|c| exists_after_apply(c)) {
+                        continue;
+                    }
+                    // A `use` path may name an item rather than a module, so
+                    // it only dangles when the draft itself drops the file.
+                    let dangling = reference.required
+                        || reference
+                            .candidates
+                            .iter()
+                            .any(|c| in_draft.contains_key(c.as_str()));
+                    if dangling && !missing.contains(&reference.candidates[0]) {
+                        missing.push(reference.candidates[0].clone());
+                    }
+                }
+            }
+            if !missing.is_empty() {
+                warnings.push(ValidationWarning::DanglingReference {
+                    artifact: artifact.resource_uri.clone(),
+                    missing,
+                });
+            }
+        }
+        warnings
+    }
+}
+
+/// Workspace-relative path of an `fs://workspace/` artifact URI.
+fn workspace_path(uri: &str) -> Option<&str> {
+    uri.strip_prefix("fs://workspace/")
+}
+
+/// Lines a unified diff adds, without the `+` marker.
+fn added_lines(diff: &str) -> impl Iterator<Item = &str> {
+    diff.lines()
+        .filter(|l| l.starts_with('+') && !l.starts_with("+++"))
+        .map(|l| l[1..].trim())
+}
+
+/// A file one line of Rust refers to, as alternative workspace paths.
+struct RustReference {
+    /// Paths that would satisfy the reference (`foo.rs` or `foo/mod.rs`).
+    candidates: Vec<String>,
+    /// Whether the file must exist: true for `mod` declarations, false for
+    /// `use` path segments that may name items instead of modules.
+    required: bool,
+}
+
+/// Module files referenced by one line of the Rust file at `path`.
+fn rust_references(path: &str, line: &str) -> Vec<RustReference> {
+    let line = strip_visibility(line);
+    if let Some(name) = line
+        .strip_prefix("mod ")
+        .and_then(|rest| rest.trim().strip_suffix(';'))
+        .map(str::trim)
+        .filter(|name| is_rust_ident(name))
+    {
+        return vec![RustReference {
+            candidates: module_candidates(&module_dir(path), name),
+            required: true,
+        }];
+    }
+
+    let Some(tree) = line
+        .strip_prefix("use crate::")
+        .and_then(|rest| rest.trim_end().strip_suffix(';'))
+    else {
+        return Vec::new();
+    };
+    let Some(root) = crate_root(path) else {
+        return Vec::new();
+    };
+    // `use crate::a::{b, c::D};` expands one brace level into `a::b`, `a::c::D`.
+    let paths: Vec<String> = match tree.split_once('{') {
+        Some((prefix, group)) => group
+            .trim_end_matches('}')
+            .split(',')
+            .map(str::trim)
+            .filter(|item| !item.is_empty() && !item.contains('{'))
+            .map(|item| format!("{}{}", prefix, item))
+            .collect(),
+        None => vec![tree.to_string()],
+    };
+
+    let mut references = Vec::new();
+    for use_path in paths {
+        let use_path = use_path.split(" as ").next().unwrap_or_default();
+        let mut dir = root.clone();
+        for segment in use_path.split("::").map(str::trim) {
+            if !is_rust_ident(segment) || segment == "self" {
+                break;
+            }
+            references.push(RustReference {
+                candidates: module_candidates(&dir, segment),
+                required: false,
+            });
+            dir = join_path(&dir, segment);
+        }
+    }
+    references
+}
+
+/// Drop a leading `pub`, `pub(crate)`, or `pub(in path)` from a line.
+fn strip_visibility(line: &str) -> &str {
+    let Some(rest) = line.strip_prefix("pub") else {
+        return line;
+    };
+    let rest = match rest.strip_prefix('(') {
+        Some(inner) => match inner.split_once(')') {
+            Some((_, after)) => after,
+            None => return line,
+        },
+        None if rest.starts_with(char::is_whitespace) => rest,
+        None => return line,
+    };
+    rest.trim_start()
+}
+
+fn is_rust_ident(s: &str) -> bool {
+    !s.is_empty()
+        && !s.starts_with(|c: char| c.is_ascii_digit())
+        && s.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// The directory `mod` declarations in the file at `path` resolve against:
+/// the file's own directory for crate roots and `mod.rs`, otherwise a
+/// directory named after the file.
+fn module_dir(path: &str) -> String {
+    let (dir, file) = path.rsplit_once('/').unwrap_or(("", path));
+    match file {
+        "lib.rs" | "main.rs" | "mod.rs" => dir.to_string(),
+        _ => join_path(dir, file.trim_end_matches(".rs")),
+    }
+}
+
+/// The `src/` directory `crate::` paths in the file at `path` resolve against.
+fn crate_root(path: &str) -> Option<String> {
+    let segments: Vec<&str> = path.split('/').collect();
+    let src = segments[..segments.len() - 1]
+        .iter()
+        .rposition(|s| *s == "src")?;
+    Some(segments[..=src].join("/"))
+}
+
+fn module_candidates(dir: &str, name: &str) -> Vec<String> {
+    let base = join_path(dir, name);
+    vec![format!("{}.rs", base), format!("{}/mod.rs", base)]
+}
+
+fn join_path(dir: &str, name: &str) -> String {
+    if dir.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", dir, name)
+    }
 }
 
 #[cfg(test)]
@@ -437,6 +681,10 @@ mod tests {
             comments: None,
             amendment: None,
             kind: None,
+            no_commit: false,
+            renamed_from: None,
+            mode_change: None,
+            line_endings_normalized: None,
         }
     }
 
@@ -582,6 +830,41 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_deferred_only_conflicts_with_approved_dependents() {
+        // Deferring a leaf, or an artifact only pending/deferred work needs, is fine.
+        let artifacts = vec![
+            make_artifact("fs://workspace/a.rs", ArtifactDisposition::Approved, vec![]),
+            make_artifact("fs://workspace/b.rs", ArtifactDisposition::Deferred, vec![]),
+            make_artifact(
+                "fs://workspace/c.rs",
+                ArtifactDisposition::Deferred,
+                vec![("fs://workspace/b.rs", DependencyKind::DependsOn)],
+            ),
+        ];
+        let result = SupervisorAgent::new(&artifacts).validate(&artifacts);
+        assert!(result.valid);
+        assert!(!result.has_warnings());
+
+        // An approved artifact that needs the deferred one is a conflict.
+        let artifacts = vec![
+            make_artifact(
+                "fs://workspace/a.rs",
+                ArtifactDisposition::Approved,
+                vec![("fs://workspace/b.rs", DependencyKind::DependsOn)],
+            ),
+            make_artifact("fs://workspace/b.rs", ArtifactDisposition::Deferred, vec![]),
+        ];
+        let result = SupervisorAgent::new(&artifacts).validate(&artifacts);
+        assert_eq!(
+            result.warnings,
+            vec![ValidationWarning::DeferredBlockingApproval {
+                artifact: "fs://workspace/b.rs".to_string(),
+                blocking: vec!["fs://workspace/a.rs".to_string()],
+            }]
+        );
+    }
+
     #[test]
     fn test_depended_by_relationship() {
         let artifacts = vec![
@@ -807,4 +1090,111 @@ mod tests {
         assert!(result.is_well_described());
         assert!(result.notes.is_empty());
     }
+
+    struct MapDiffs(HashMap<String, String>);
+
+    impl DiffProvider for MapDiffs {
+        fn get_diff(&self, diff_ref: &str) -> Result<String, crate::error::ChangeSetError> {
+            self.0
+                .get(diff_ref)
+                .cloned()
+                .ok_or_else(|| crate::error::ChangeSetError::InvalidData(diff_ref.to_string()))
+        }
+    }
+
+    fn rust_artifact(
+        path: &str,
+        change_type: ChangeType,
+        disposition: ArtifactDisposition,
+    ) -> Artifact {
+        let mut artifact = make_artifact(&format!("fs://workspace/{}", path), disposition, vec![]);
+        artifact.change_type = change_type;
+        artifact.diff_ref = path.to_string();
+        artifact
+    }
+
+    #[test]
+    fn test_dangling_reference_to_rejected_or_absent_module() {
+        let artifacts = vec![
+            rust_artifact(
+                "src/lib.rs",
+                ChangeType::Modify,
+                ArtifactDisposition::Approved,
+            ),
+            rust_artifact(
+                "src/parser.rs",
+                ChangeType::Add,
+                ArtifactDisposition::Rejected,
+            ),
+            rust_artifact(
+                "src/lexer.rs",
+                ChangeType::Add,
+                ArtifactDisposition::Approved,
+            ),
+            rust_artifact(
+                "src/cli/run.rs",
+                ChangeType::Modify,
+                ArtifactDisposition::Approved,
+            ),
+        ];
+        let diffs = MapDiffs(HashMap::from([
+            (
+                "src/lib.rs".to_string(),
+                "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ -1 +1,4 @@\n mod cli;\n\
+                 +pub mod parser;\n+mod lexer;\n+mod missing;\n+mod inline {}\n"
+                    .to_string(),
+            ),
+            (
+                "src/cli/run.rs".to_string(),
+                "+use crate::parser::Parser;\n+use crate::{lexer::Token, util};\n\
+                 +use crate::Config;\n"
+                    .to_string(),
+            ),
+            (
+                "src/lexer.rs".to_string(),
+                "+pub struct Token;\n".to_string(),
+            ),
+        ]));
+        let in_source = |path: &str| path == "src/cli.rs";
+
+        let supervisor = SupervisorAgent::new(&artifacts);
+        let warnings = supervisor.check_references(&artifacts, &diffs, in_source);
+
+        assert_eq!(
+            warnings,
+            vec![
+                ValidationWarning::DanglingReference {
+                    artifact: "fs://workspace/src/lib.rs".to_string(),
+                    missing: vec!["src/parser.rs".to_string(), "src/missing.rs".to_string()],
+                },
+                ValidationWarning::DanglingReference {
+                    artifact: "fs://workspace/src/cli/run.rs".to_string(),
+                    missing: vec!["src/parser.rs".to_string()],
+                },
+            ]
+        );
+
+        // Approving the new module resolves both references; `use crate::Config`
+        // and `util` may be items in lib.rs and are never flagged.
+        let mut artifacts = artifacts;
+        artifacts[1].disposition = ArtifactDisposition::Approved;
+        let warnings = supervisor.check_references(&artifacts, &diffs, |p| {
+            in_source(p) || p == "src/missing.rs"
+        });
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_module_paths_follow_rust_layout() {
+        assert_eq!(module_dir("src/lib.rs"), "src");
+        assert_eq!(module_dir("src/net/mod.rs"), "src/net");
+        assert_eq!(module_dir("src/net.rs"), "src/net");
+        assert_eq!(
+            crate_root("crates/foo/src/a/b.rs").as_deref(),
+            Some("crates/foo/src")
+        );
+        assert_eq!(crate_root("build.rs"), None);
+        assert_eq!(strip_visibility("pub(crate) mod x;"), "mod x;");
+        assert_eq!(strip_visibility("public_fn();"), "public_fn();");
+    }
 }
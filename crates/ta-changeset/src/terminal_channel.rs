@@ -3,27 +3,43 @@
 // The default ReviewChannel implementation for v0.4.1.1. Renders interaction
 // requests to stdout with formatting, collects responses from stdin.
 // Supports mock I/O for testing.
+//
+// With a response timeout, each read runs on a helper thread so the prompt
+// can give up and return the configured default decision. A read still
+// outstanding after a timeout is reused by the next prompt; a line that
+// arrives before that prompt was shown answered the earlier one and is dropped.
 
 use std::io::{BufRead, BufReader, Read, Write};
-use std::sync::Mutex;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 use crate::interaction::{
     ChannelCapabilities, Decision, InteractionKind, InteractionRequest, InteractionResponse,
     Notification, NotificationLevel,
 };
 use crate::review_channel::{ReviewChannel, ReviewChannelError};
-use crate::session_channel::{HumanInput, SessionChannel, SessionChannelError, SessionEvent};
+use crate::session_channel::{
+    HumanInput, InteractiveConfig, SessionChannel, SessionChannelError, SessionEvent,
+};
 
 /// A ReviewChannel that uses stdin/stdout for human interaction.
 ///
 /// Renders interaction requests as formatted text, prompts for input,
 /// and parses responses into InteractionResponse values.
 pub struct TerminalChannel {
-    reader: Mutex<BufReader<Box<dyn Read + Send>>>,
+    reader: Arc<Mutex<BufReader<Box<dyn Read + Send>>>>,
     writer: Mutex<Box<dyn Write + Send>>,
     channel_id: String,
+    /// How long to wait for a human, and what to answer when nobody does.
+    timeout: Option<(Duration, Decision)>,
+    /// A read started by an earlier prompt that timed out.
+    pending_read: Mutex<Option<Receiver<TimedLine>>>,
 }
 
+/// A line read from the terminal (`None` at EOF) and when it arrived.
+type TimedLine = (Instant, std::io::Result<Option<String>>);
+
 impl TerminalChannel {
     /// Create a TerminalChannel from raw reader/writer.
     /// Use `TerminalChannel::stdio()` for real terminal, or pass mock I/O for tests.
@@ -33,9 +49,79 @@ impl TerminalChannel {
         channel_id: impl Into<String>,
     ) -> Self {
         Self {
-            reader: Mutex::new(BufReader::new(reader)),
+            reader: Arc::new(Mutex::new(BufReader::new(reader))),
             writer: Mutex::new(writer),
             channel_id: channel_id.into(),
+            timeout: None,
+            pending_read: Mutex::new(None),
+        }
+    }
+
+    /// Answer with `default` when no response arrives within `timeout`, so an
+    /// unattended run can't block on a prompt forever.
+    pub fn with_timeout(mut self, timeout: Duration, default: Decision) -> Self {
+        self.timeout = Some((timeout, default));
+        self
+    }
+
+    /// Read one response line without a timeout. `None` at EOF.
+    fn read_line_blocking(&self) -> Result<Option<String>, ReviewChannelError> {
+        let mut line = String::new();
+        let mut reader = self
+            .reader
+            .lock()
+            .map_err(|e| ReviewChannelError::Other(format!("reader lock poisoned: {}", e)))?;
+        let bytes = reader.read_line(&mut line)?;
+        Ok((bytes > 0).then_some(line))
+    }
+
+    /// Read one response line typed after `shown_at`, giving up after
+    /// `timeout`. `Ok(None)` means the wait timed out; EOF is `ChannelClosed`.
+    fn read_line_until(
+        &self,
+        shown_at: Instant,
+        timeout: Duration,
+    ) -> Result<Option<String>, ReviewChannelError> {
+        let deadline = shown_at + timeout;
+        let mut pending = self
+            .pending_read
+            .lock()
+            .map_err(|e| ReviewChannelError::Other(format!("read lock poisoned: {}", e)))?;
+        loop {
+            let rx = pending.get_or_insert_with(|| {
+                let (tx, rx) = mpsc::channel();
+                let reader = Arc::clone(&self.reader);
+                std::thread::spawn(move || {
+                    let mut line = String::new();
+                    let result = match reader.lock() {
+                        Ok(mut reader) => reader
+                            .read_line(&mut line)
+                            .map(|bytes| (bytes > 0).then_some(line)),
+                        Err(_) => Err(std::io::Error::other("reader lock poisoned")),
+                    };
+                    let _ = tx.send((Instant::now(), result));
+                });
+                rx
+            });
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match rx.recv_timeout(remaining) {
+                Ok((arrived, result)) => {
+                    *pending = None;
+                    if arrived < shown_at {
+                        // A late answer to a prompt that already timed out.
+                        continue;
+                    }
+                    return match result? {
+                        Some(line) => Ok(Some(line)),
+                        None => Err(ReviewChannelError::ChannelClosed),
+                    };
+                }
+                Err(RecvTimeoutError::Timeout) => return Ok(None),
+                Err(RecvTimeoutError::Disconnected) => {
+                    *pending = None;
+                    return Err(ReviewChannelError::ChannelClosed);
+                }
+            }
         }
     }
 
@@ -48,6 +134,21 @@ impl TerminalChannel {
         )
     }
 
+    /// A stdio channel honoring the timeout settings in a channel config
+    /// (`response_timeout_secs`, `on_timeout`; see [`InteractiveConfig`]).
+    pub fn stdio_from_config(config: &serde_json::Value) -> Result<Self, ReviewChannelError> {
+        let channel = Self::stdio();
+        if config.is_null() {
+            return Ok(channel);
+        }
+        let interactive: InteractiveConfig = serde_json::from_value(config.clone())
+            .map_err(|e| ReviewChannelError::Other(format!("invalid terminal config: {}", e)))?;
+        Ok(match interactive.response_timeout() {
+            Some((timeout, default)) => channel.with_timeout(timeout, default),
+            None => channel,
+        })
+    }
+
     /// Render an interaction request as formatted text.
     fn render_request(&self, request: &InteractionRequest) -> String {
         let mut out = String::new();
@@ -123,6 +224,17 @@ impl TerminalChannel {
                     out.push_str(&format!("  Expected response: {}\n", hint));
                 }
             }
+            InteractionKind::Choice { options } => {
+                out.push_str("  CHOICE REQUIRED\n");
+                out.push_str(&"-".repeat(60));
+                out.push('\n');
+                if let Some(q) = request.context.get("question").and_then(|v| v.as_str()) {
+                    out.push_str(&format!("  {}\n", q));
+                }
+                for (i, option) in options.iter().enumerate() {
+                    out.push_str(&format!("  {}) {}\n", i + 1, option));
+                }
+            }
             InteractionKind::Custom(name) => {
                 out.push_str(&format!("  INTERACTION: {}\n", name.to_uppercase()));
                 out.push_str(&"-".repeat(60));
@@ -132,7 +244,13 @@ impl TerminalChannel {
 
         out.push_str(&"-".repeat(60));
         out.push('\n');
-        out.push_str("  [a]pprove  [r]eject  [d]iscuss  [s]kip\n");
+        match &request.kind {
+            InteractionKind::Choice { options } => out.push_str(&format!(
+                "  [1-{}] choose  [d]iscuss  [s]kip\n",
+                options.len()
+            )),
+            _ => out.push_str("  [a]pprove  [r]eject  [d]iscuss  [s]kip\n"),
+        }
         out.push_str(&"=".repeat(60));
         out.push_str("\n> ");
         out
@@ -174,6 +292,24 @@ impl TerminalChannel {
         }
     }
 
+    /// Parse a response to a Choice interaction: a 1-based option number,
+    /// or discuss/skip.
+    fn parse_choice(input: &str, option_count: usize) -> Result<Decision, ReviewChannelError> {
+        let trimmed = input.trim().to_lowercase();
+        match trimmed.as_str() {
+            "d" | "discuss" => Ok(Decision::Discuss),
+            "s" | "skip" => Ok(Decision::SkipForNow),
+            "" => Err(ReviewChannelError::InvalidResponse("empty response".into())),
+            _ => match trimmed.parse::<usize>() {
+                Ok(n) if (1..=option_count).contains(&n) => Ok(Decision::Choice { index: n - 1 }),
+                _ => Err(ReviewChannelError::InvalidResponse(format!(
+                    "expected an option number between 1 and {}, got '{}'",
+                    option_count, trimmed
+                ))),
+            },
+        }
+    }
+
     /// Render a notification as formatted text.
     fn render_notification(notification: &Notification) -> String {
         let prefix = match notification.level {
@@ -204,19 +340,45 @@ impl ReviewChannel for TerminalChannel {
         }
 
         // Read the response from input.
-        let mut line = String::new();
-        {
-            let mut reader = self
-                .reader
-                .lock()
-                .map_err(|e| ReviewChannelError::Other(format!("reader lock poisoned: {}", e)))?;
-            let bytes = reader.read_line(&mut line)?;
-            if bytes == 0 {
-                return Err(ReviewChannelError::ChannelClosed);
-            }
-        }
+        let line = match &self.timeout {
+            None => self
+                .read_line_blocking()?
+                .ok_or(ReviewChannelError::ChannelClosed)?,
+            Some((timeout, default)) => match self.read_line_until(Instant::now(), *timeout)? {
+                Some(line) => line,
+                None => {
+                    tracing::warn!(
+                        channel = %self.channel_id,
+                        interaction_id = %request.interaction_id,
+                        timeout_secs = timeout.as_secs(),
+                        decision = %default,
+                        "No response to interaction before timeout; using default decision"
+                    );
+                    if let Ok(mut writer) = self.writer.lock() {
+                        let _ = writeln!(
+                            writer,
+                            "\n[timeout] No response within {}s — {}.",
+                            timeout.as_secs(),
+                            default
+                        );
+                        let _ = writer.flush();
+                    }
+                    return Ok(
+                        InteractionResponse::new(request.interaction_id, default.clone())
+                            .with_reasoning(format!(
+                                "no response within {}s; default decision applied",
+                                timeout.as_secs()
+                            ))
+                            .with_responder(&self.channel_id),
+                    );
+                }
+            },
+        };
 
-        let decision = Self::parse_decision(&line)?;
+        let decision = match &request.kind {
+            InteractionKind::Choice { options } => Self::parse_choice(&line, options.len())?,
+            _ => Self::parse_decision(&line)?,
+        };
 
         Ok(InteractionResponse::new(request.interaction_id, decision)
             .with_responder(&self.channel_id))
@@ -238,6 +400,11 @@ impl ReviewChannel for TerminalChannel {
             supports_async: false,
             supports_rich_media: false,
             supports_threads: false,
+            // Rounded up so a sub-second timeout never reads as "no wait".
+            response_timeout_secs: self
+                .timeout
+                .as_ref()
+                .map(|(t, _)| t.as_secs() + u64::from(t.subsec_nanos() > 0)),
         }
     }
 
@@ -247,6 +414,7 @@ impl ReviewChannel for TerminalChannel {
 }
 
 /// A no-op ReviewChannel that auto-approves all interactions.
+/// Choice interactions get their first option.
 /// Useful for non-interactive/batch mode and testing.
 pub struct AutoApproveChannel {
     channel_id: String,
@@ -330,10 +498,17 @@ impl ReviewChannel for AutoApproveChannel {
         &self,
         request: &InteractionRequest,
     ) -> Result<InteractionResponse, ReviewChannelError> {
-        Ok(
-            InteractionResponse::new(request.interaction_id, Decision::Approve)
-                .with_responder(&self.channel_id),
-        )
+        let decision = match &request.kind {
+            InteractionKind::Choice { options } if options.is_empty() => {
+                return Err(ReviewChannelError::InvalidResponse(
+                    "choice interaction has no options".into(),
+                ));
+            }
+            InteractionKind::Choice { .. } => Decision::Choice { index: 0 },
+            _ => Decision::Approve,
+        };
+        Ok(InteractionResponse::new(request.interaction_id, decision)
+            .with_responder(&self.channel_id))
     }
 
     fn notify(&self, _notification: &Notification) -> Result<(), ReviewChannelError> {
@@ -423,6 +598,52 @@ mod tests {
         assert_eq!(resp.decision, Decision::SkipForNow);
     }
 
+    #[test]
+    fn choice_renders_numbered_menu_and_returns_index() {
+        let (channel, output) = mock_channel("2\n");
+        let req = InteractionRequest::choice(
+            "Which approach should I take?",
+            vec!["Refactor in place".into(), "Add a new module".into()],
+        );
+        let resp = channel.request_interaction(&req).unwrap();
+        assert_eq!(resp.decision, Decision::Choice { index: 1 });
+
+        let rendered = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+        assert!(rendered.contains("CHOICE REQUIRED"));
+        assert!(rendered.contains("Which approach should I take?"));
+        assert!(rendered.contains("  1) Refactor in place\n"));
+        assert!(rendered.contains("  2) Add a new module\n"));
+        assert!(rendered.contains("[1-2] choose"));
+    }
+
+    #[test]
+    fn choice_rejects_out_of_range_and_approve() {
+        let options = vec!["one".to_string(), "two".to_string()];
+        for input in ["3\n", "0\n", "a\n"] {
+            let (channel, _output) = mock_channel(input);
+            let req = InteractionRequest::choice("Pick", options.clone());
+            assert!(matches!(
+                channel.request_interaction(&req),
+                Err(ReviewChannelError::InvalidResponse(_))
+            ));
+        }
+        let (channel, _output) = mock_channel("s\n");
+        let req = InteractionRequest::choice("Pick", options);
+        let resp = channel.request_interaction(&req).unwrap();
+        assert_eq!(resp.decision, Decision::SkipForNow);
+    }
+
+    #[test]
+    fn auto_approve_picks_first_choice() {
+        let channel = AutoApproveChannel::new();
+        let req = InteractionRequest::choice("Pick", vec!["first".into(), "second".into()]);
+        let resp = channel.request_interaction(&req).unwrap();
+        assert_eq!(resp.decision, Decision::Choice { index: 0 });
+
+        let empty = InteractionRequest::choice("Pick", vec![]);
+        assert!(channel.request_interaction(&empty).is_err());
+    }
+
     #[test]
     fn yes_is_approve() {
         let (channel, _output) = mock_channel("yes\n");
@@ -502,6 +723,60 @@ mod tests {
         assert!(!caps.supports_async);
         assert!(!caps.supports_rich_media);
         assert!(!caps.supports_threads);
+        assert!(caps.response_timeout_secs.is_none());
+    }
+
+    /// A reader that never produces input, like an unattended terminal.
+    struct SilentReader;
+    impl Read for SilentReader {
+        fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+            std::thread::sleep(Duration::from_secs(3600));
+            Ok(0)
+        }
+    }
+
+    #[test]
+    fn timeout_returns_default_decision_and_is_advertised() {
+        let output = std::sync::Arc::new(Mutex::new(Vec::new()));
+        struct SharedWriter(std::sync::Arc<Mutex<Vec<u8>>>);
+        impl Write for SharedWriter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+        let deny = Decision::Reject {
+            reason: "no response".into(),
+        };
+        let channel = TerminalChannel::new(
+            Box::new(SilentReader),
+            Box::new(SharedWriter(output.clone())),
+            "test:silent",
+        )
+        .with_timeout(Duration::from_millis(50), deny.clone());
+        assert_eq!(channel.capabilities().response_timeout_secs, Some(1));
+
+        let req = InteractionRequest::draft_review(Uuid::new_v4(), "Unattended", 1);
+        let resp = channel.request_interaction(&req).unwrap();
+        assert_eq!(resp.decision, deny);
+        assert!(resp.reasoning.unwrap().contains("default decision"));
+        let rendered = String::from_utf8(output.lock().unwrap().clone()).unwrap();
+        assert!(rendered.contains("[timeout]"));
+
+        // The outstanding read is reused rather than stacking threads.
+        channel.request_interaction(&req).unwrap();
+        assert!(channel.pending_read.lock().unwrap().is_some());
+    }
+
+    #[test]
+    fn response_within_timeout_is_used() {
+        let (channel, _) = mock_channel("a\n");
+        let channel = channel.with_timeout(Duration::from_secs(5), Decision::SkipForNow);
+        let req = InteractionRequest::draft_review(Uuid::new_v4(), "Attended", 1);
+        let resp = channel.request_interaction(&req).unwrap();
+        assert_eq!(resp.decision, Decision::Approve);
     }
 
     #[test]
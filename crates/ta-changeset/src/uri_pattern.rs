@@ -12,8 +12,7 @@
 
 use glob::{MatchOptions, Pattern};
 
-/// The default URI scheme + authority prefix for bare patterns.
-const FS_PREFIX: &str = "fs://workspace/";
+use crate::resource_uri::{self, ResourceUri};
 
 /// Match a pattern against a resource URI with scheme-awareness.
 ///
@@ -25,28 +24,20 @@ const FS_PREFIX: &str = "fs://workspace/";
 /// 4. Invalid glob patterns never match (fail-closed).
 pub fn matches_uri(pattern: &str, uri: &str) -> bool {
     if pattern.contains("://") {
-        // Explicit scheme — extract and compare schemes before globbing.
-        let pattern_scheme = scheme_of(pattern);
-        let uri_scheme = scheme_of(uri);
-        if pattern_scheme != uri_scheme {
-            return false;
+        // Explicit scheme — parse and compare schemes before globbing.
+        match (ResourceUri::parse(pattern), ResourceUri::parse(uri)) {
+            (Ok(p), Ok(u)) if p.scheme() == u.scheme() => glob_match(pattern, uri),
+            _ => false,
         }
-        glob_match(pattern, uri)
     } else {
-        // Bare pattern — only match fs:// URIs.
-        if !uri.starts_with(FS_PREFIX) {
-            return false;
+        // Bare pattern — only match workspace files.
+        match resource_uri::workspace_path(uri) {
+            Some(path) => glob_match(pattern, &path),
+            None => false,
         }
-        let full_pattern = format!("{}{}", FS_PREFIX, pattern);
-        glob_match(&full_pattern, uri)
     }
 }
 
-/// Extract the scheme portion of a URI (everything before `://`).
-fn scheme_of(uri: &str) -> &str {
-    uri.split("://").next().unwrap_or("")
-}
-
 /// Glob-match a pattern against a target string. Fail-closed on invalid patterns.
 fn glob_match(pattern: &str, target: &str) -> bool {
     let opts = MatchOptions {
@@ -65,7 +56,7 @@ pub fn resolve_pattern(pattern: &str) -> String {
     if pattern.contains("://") {
         pattern.to_string()
     } else {
-        format!("{}{}", FS_PREFIX, pattern)
+        ResourceUri::workspace_file(pattern).to_string()
     }
 }
 
@@ -96,6 +87,8 @@ mod tests {
         assert!(!matches_uri("src/**", "gmail://inbox/src/draft"));
         assert!(!matches_uri("src/**", "drive://docs/src/readme"));
         assert!(!matches_uri("*.rs", "db://tables/schema.rs"));
+        // Only the workspace authority counts as a bare-path target.
+        assert!(!matches_uri("src/**", "fs://home/src/main.rs"));
     }
 
     #[test]
@@ -148,6 +148,7 @@ impl ReviewChannel for WebhookChannel {
             supports_async: true,
             supports_rich_media: true,
             supports_threads: false,
+            response_timeout_secs: None,
         }
     }
 
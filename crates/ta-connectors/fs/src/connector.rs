@@ -7,6 +7,7 @@
 //
 // Flow:
 //   1. Agent calls `write_patch(path, content)` → staged in temp dir, ChangeSet created
+//      (rewriting a file with the content it already has is a no-op)
 //   2. Agent calls `build_pr_package(...)` → bundles all staged changes
 //   3. Human reviews and approves
 //   4. Agent calls `apply(target_dir)` → copies staged files to real filesystem
@@ -24,6 +25,34 @@ use ta_workspace::{ChangeStore, StagingWorkspace};
 
 use crate::error::FsConnectorError;
 
+/// Result of [`FsConnector::write_patch`].
+#[derive(Debug, Clone)]
+pub enum WritePatchOutcome {
+    /// The content was staged and recorded as a new changeset.
+    Staged(ChangeSet),
+    /// The file was already staged with exactly this content. Nothing was
+    /// written and no changeset was created.
+    Unchanged { target_uri: String },
+}
+
+impl WritePatchOutcome {
+    /// The `fs://workspace/...` URI the write targeted.
+    pub fn target_uri(&self) -> &str {
+        match self {
+            WritePatchOutcome::Staged(changeset) => &changeset.target_uri,
+            WritePatchOutcome::Unchanged { target_uri } => target_uri,
+        }
+    }
+
+    /// The new changeset, or `None` if the write was a no-op.
+    pub fn changeset(self) -> Option<ChangeSet> {
+        match self {
+            WritePatchOutcome::Staged(changeset) => Some(changeset),
+            WritePatchOutcome::Unchanged { .. } => None,
+        }
+    }
+}
+
 /// Filesystem connector — bridges MCP tool calls to staging + changeset model.
 ///
 /// Generic over `S: ChangeStore` so we can use any storage backend
@@ -123,12 +152,23 @@ impl<S: ChangeStore> FsConnector<S> {
     /// track the change. Nothing touches the real filesystem until `apply()`.
     ///
     /// Returns the ChangeSet so the caller can inspect it or include it
-    /// in a PR package.
+    /// in a PR package. Writing the content a file is already staged with
+    /// returns [`WritePatchOutcome::Unchanged`] instead: repeated writes
+    /// don't add changesets or audit events.
     pub fn write_patch(
         &mut self,
         relative_path: &str,
         content: &[u8],
-    ) -> Result<ChangeSet, FsConnectorError> {
+    ) -> Result<WritePatchOutcome, FsConnectorError> {
+        let target_uri = format!("fs://workspace/{}", relative_path);
+        if self
+            .staging
+            .read_file(relative_path)
+            .is_ok_and(|staged| staged == content)
+        {
+            return Ok(WritePatchOutcome::Unchanged { target_uri });
+        }
+
         // Write to staging directory.
         self.staging.write_file(relative_path, content)?;
 
@@ -156,8 +196,6 @@ impl<S: ChangeStore> FsConnector<S> {
             }
         };
 
-        let target_uri = format!("fs://workspace/{}", relative_path);
-
         // Create the changeset.
         let changeset = ChangeSet::new(target_uri.clone(), ChangeKind::FsPatch, diff_content)
             .with_commit_intent(CommitIntent::RequestCommit);
@@ -167,7 +205,7 @@ impl<S: ChangeStore> FsConnector<S> {
 
         self.log_event(AuditAction::ToolCall, &target_uri)?;
 
-        Ok(changeset)
+        Ok(WritePatchOutcome::Staged(changeset))
     }
 
     /// List all files currently staged.
@@ -212,10 +250,16 @@ impl<S: ChangeStore> FsConnector<S> {
             .iter()
             .map(|cs| {
                 let change_type = match &cs.diff_content {
-                    DiffContent::CreateFile { .. } => ChangeType::Add,
+                    DiffContent::CreateFile { .. } | DiffContent::CreateFileBlob { .. } => {
+                        ChangeType::Add
+                    }
                     DiffContent::DeleteFile => ChangeType::Delete,
-                    DiffContent::UnifiedDiff { .. } => ChangeType::Modify,
-                    DiffContent::BinarySummary { .. } => ChangeType::Modify,
+                    DiffContent::UnifiedDiff { .. } | DiffContent::UnifiedDiffBlob { .. } => {
+                        ChangeType::Modify
+                    }
+                    DiffContent::BinarySummary { .. } | DiffContent::ModeChange { .. } => {
+                        ChangeType::Modify
+                    }
                 };
                 Artifact {
                     resource_uri: cs.target_uri.clone(),
@@ -229,6 +273,15 @@ impl<S: ChangeStore> FsConnector<S> {
                     comments: None,
                     amendment: None,
                     kind: None,
+                    no_commit: cs.commit_intent == CommitIntent::NoCommit,
+                    renamed_from: None,
+                    mode_change: match cs.diff_content {
+                        DiffContent::ModeChange { old_mode, new_mode } => {
+                            Some(ModeChange { old_mode, new_mode })
+                        }
+                        _ => None,
+                    },
+                    line_endings_normalized: None,
                 }
             })
             .collect();
@@ -308,8 +361,11 @@ impl<S: ChangeStore> FsConnector<S> {
             display_id: None,
             tag: None,
             vcs_status: None,
+            submit_outcome: None,
+            content_hash: None,
             parent_draft_id: None,
             pending_approvals: vec![],
+            review_assignments: vec![],
             supervisor_review: None,
             ignored_artifacts: vec![],
             baseline_artifacts: vec![],
@@ -319,6 +375,11 @@ impl<S: ChangeStore> FsConnector<S> {
             draft_seq: 0,
             plan_phase: None,
             plan_md_base: None,
+            split_from: None,
+            split_into: Vec::new(),
+            merged_from: Vec::new(),
+            noop_dropped: Vec::new(),
+            staged_hashes: Default::default(),
         };
 
         Ok(package)
@@ -332,7 +393,19 @@ impl<S: ChangeStore> FsConnector<S> {
     ///
     /// Returns a list of files that were applied.
     pub fn apply(&mut self, target_dir: &Path) -> Result<Vec<String>, FsConnectorError> {
+        self.apply_with_progress(target_dir, |_, _, _| {})
+    }
+
+    /// Like [`apply`](Self::apply), but calls `progress(path, current, total)`
+    /// after each file is written. `total` is the number of staged files,
+    /// computed before any file is copied; `current` counts from 1.
+    pub fn apply_with_progress(
+        &mut self,
+        target_dir: &Path,
+        mut progress: impl FnMut(&str, usize, usize),
+    ) -> Result<Vec<String>, FsConnectorError> {
         let staged_files = self.staging.list_files()?;
+        let total = staged_files.len();
         let mut applied = Vec::new();
 
         for relative_path in &staged_files {
@@ -353,6 +426,7 @@ impl<S: ChangeStore> FsConnector<S> {
             })?;
 
             applied.push(relative_path.clone());
+            progress(relative_path, applied.len(), total);
         }
 
         self.log_event(
@@ -413,6 +487,8 @@ mod tests {
 
         let cs = connector
             .write_patch("hello.txt", b"Hello, world!")
+            .unwrap()
+            .changeset()
             .unwrap();
 
         assert_eq!(cs.target_uri, "fs://workspace/hello.txt");
@@ -420,6 +496,22 @@ mod tests {
         assert_eq!(cs.commit_intent, CommitIntent::RequestCommit);
     }
 
+    #[test]
+    fn write_patch_with_identical_content_is_a_no_op() {
+        let (mut connector, _, _) = setup();
+
+        connector.write_patch("hello.txt", b"Hello!").unwrap();
+        let again = connector.write_patch("hello.txt", b"Hello!").unwrap();
+        assert!(
+            matches!(&again, WritePatchOutcome::Unchanged { target_uri } if target_uri == "fs://workspace/hello.txt")
+        );
+        assert_eq!(connector.list_changesets().unwrap().len(), 1);
+
+        let changed = connector.write_patch("hello.txt", b"Hello again!").unwrap();
+        assert!(changed.changeset().is_some());
+        assert_eq!(connector.list_changesets().unwrap().len(), 2);
+    }
+
     #[test]
     fn write_patch_stages_file() {
         let (mut connector, _, _) = setup();
@@ -434,7 +526,11 @@ mod tests {
     fn write_patch_new_file_produces_create_diff() {
         let (mut connector, _, _) = setup();
 
-        let cs = connector.write_patch("new.txt", b"new content").unwrap();
+        let cs = connector
+            .write_patch("new.txt", b"new content")
+            .unwrap()
+            .changeset()
+            .unwrap();
 
         match &cs.diff_content {
             DiffContent::CreateFile { content } => {
@@ -457,6 +553,8 @@ mod tests {
         // Now write a modified version.
         let cs = connector
             .write_patch("file.txt", b"modified line\n")
+            .unwrap()
+            .changeset()
             .unwrap();
 
         match &cs.diff_content {
@@ -558,6 +656,28 @@ mod tests {
         assert_eq!(content2, b"Nested content");
     }
 
+    #[test]
+    fn apply_with_progress_reports_each_file_against_total() {
+        let (mut connector, _, _) = setup();
+        connector.write_patch("a.txt", b"a").unwrap();
+        connector.write_patch("b/c.txt", b"c").unwrap();
+
+        let target = tempdir().unwrap();
+        let mut events = Vec::new();
+        let applied = connector
+            .apply_with_progress(target.path(), |path, current, total| {
+                events.push((path.to_string(), current, total))
+            })
+            .unwrap();
+
+        assert_eq!(events.len(), 2);
+        assert_eq!(events[0].1, 1);
+        assert_eq!(events[1].1, 2);
+        assert!(events.iter().all(|(_, _, total)| *total == 2));
+        let paths: Vec<String> = events.into_iter().map(|(p, _, _)| p).collect();
+        assert_eq!(paths, applied);
+    }
+
     #[test]
     fn read_source_snapshots_original() {
         let (mut connector, _, _) = setup();
@@ -575,6 +695,8 @@ mod tests {
         // Now write a modified version.
         let cs = connector
             .write_patch("existing.txt", b"modified content")
+            .unwrap()
+            .changeset()
             .unwrap();
 
         // The diff should show the change from original to modified.
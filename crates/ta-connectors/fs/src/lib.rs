@@ -9,6 +9,7 @@
 //! ## Flow
 //!
 //! 1. Agent calls [`FsConnector::write_patch`] → file staged, ChangeSet created
+//!    (a write that doesn't change the staged content is a no-op)
 //! 2. Agent calls [`FsConnector::build_pr_package`] → bundles all changes
 //! 3. Human reviews and approves the PR package
 //! 4. Agent calls [`FsConnector::apply`] → copies staged files to real filesystem
@@ -16,5 +17,5 @@
 pub mod connector;
 pub mod error;
 
-pub use connector::FsConnector;
+pub use connector::{FsConnector, WritePatchOutcome};
 pub use error::FsConnectorError;
@@ -108,6 +108,7 @@ fn full_vertical_slice_agent_to_apply() {
         ],
         issued_at: Utc::now(),
         expires_at: Utc::now() + Duration::hours(1),
+        includes: vec![],
     };
 
     policy_engine.load_manifest(manifest);
@@ -155,6 +156,8 @@ fn full_vertical_slice_agent_to_apply() {
     let modified_content = b"[server]\nport = 9090\nhost = \"0.0.0.0\"\n";
     let changeset_1 = connector
         .write_patch("config.toml", modified_content)
+        .unwrap()
+        .changeset()
         .unwrap();
 
     // Verify changeset has correct metadata.
@@ -183,6 +186,8 @@ fn full_vertical_slice_agent_to_apply() {
     let new_file_content = b"# Deployment Notes\n\nUpdated port to 9090 for external access.\n";
     let changeset_2 = connector
         .write_patch("DEPLOY.md", new_file_content)
+        .unwrap()
+        .changeset()
         .unwrap();
 
     assert_eq!(changeset_2.target_uri, "fs://workspace/DEPLOY.md");
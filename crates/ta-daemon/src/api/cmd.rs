@@ -352,7 +352,8 @@ pub async fn execute_command(
                             let goal_id = *detected_goal_id.lock().await;
                             let Some(goal_id) = goal_id else { continue };
 
-                            let goal_dir = working_dir3.join(".ta/goals");
+                            let goal_dir =
+                                ta_mcp_gateway::GatewayConfig::for_project(&working_dir3).goals_dir;
                             let store = ta_goal::store::GoalRunStore::new(&goal_dir);
                             let Ok(store) = store else { continue };
                             let Ok(Some(goal)) = store.get(goal_id) else {
@@ -391,7 +392,9 @@ pub async fn execute_command(
                                 }
                                 "pr_ready" => {
                                     // Emit ReviewRequested so channel plugins show draft-ready.
-                                    let pr_dir = working_dir3.join(".ta/pr_packages");
+                                    let pr_dir =
+                                        ta_mcp_gateway::GatewayConfig::for_project(&working_dir3)
+                                            .pr_packages_dir;
                                     if let Some(d) = latest_draft_for_goal(&pr_dir, goal_id) {
                                         // Log draft detected (item 3).
                                         tracing::info!(
@@ -479,7 +482,10 @@ pub async fn execute_command(
                             let goal_id = *running_log_goal_id.lock().await;
                             if let Some(gid) = goal_id {
                                 // Read current state from store for the log.
-                                let goal_dir = running_log_working_dir.join(".ta/goals");
+                                let goal_dir = ta_mcp_gateway::GatewayConfig::for_project(
+                                    &running_log_working_dir,
+                                )
+                                .goals_dir;
                                 let current_state = ta_goal::store::GoalRunStore::new(&goal_dir)
                                     .ok()
                                     .and_then(|s| s.get(gid).ok().flatten())
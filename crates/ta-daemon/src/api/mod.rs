@@ -73,6 +73,8 @@ pub struct AppState {
 impl AppState {
     pub fn new(project_root: PathBuf, daemon_config: DaemonConfig) -> Self {
         let ta_dir = project_root.join(".ta");
+        // Goals and drafts follow the active profile, like the CLI's.
+        let gateway_config = ta_mcp_gateway::GatewayConfig::for_project(&project_root);
         let shell_config = ShellConfig::load(&project_root);
         let max_sessions = daemon_config.agent.max_sessions;
         let registry = ProjectRegistry::single_project(project_root.clone());
@@ -83,10 +85,10 @@ impl AppState {
         ));
 
         Self {
-            pr_packages_dir: ta_dir.join("pr_packages"),
+            pr_packages_dir: gateway_config.pr_packages_dir,
             memory_dir: ta_dir.join("memory"),
             events_dir: ta_dir.join("events"),
-            goals_dir: ta_dir.join("goals"),
+            goals_dir: gateway_config.goals_dir,
             token_store: TokenStore::new(&project_root),
             shell_config,
             agent_sessions: agent::AgentSessionManager::new(max_sessions),
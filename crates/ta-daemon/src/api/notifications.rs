@@ -111,9 +111,9 @@ pub async fn get_notifications(State(state): State<Arc<AppState>>) -> impl IntoR
 
         if let Ok(all_goals) = store.list() {
             for goal in &all_goals {
-                // Stuck: running but not updated in > threshold.
+                // Stuck: running with no heartbeat or update in > threshold.
                 if matches!(goal.state, GoalRunState::Running | GoalRunState::Configured) {
-                    let stale_hours = (now - goal.updated_at).num_hours();
+                    let stale_hours = (now - goal.last_activity()).num_hours();
                     if stale_hours >= stuck_threshold_hours {
                         let id = format!("goal_stuck:{}", &goal.goal_run_id.to_string()[..8]);
                         let notif = Notification::new(
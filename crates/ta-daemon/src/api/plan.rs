@@ -254,7 +254,7 @@ pub async fn get_plan_phases(State(state): State<Arc<AppState>>) -> impl IntoRes
     let mut phases = parse_plan_phases(&content);
 
     // Annotate phases that have an active goal.
-    let goals_dir = project_root.join(".ta").join("goals");
+    let goals_dir = ta_mcp_gateway::GatewayConfig::for_project(&project_root).goals_dir;
     let active = active_phases(&goals_dir);
     for ph in &mut phases {
         ph.running = active.contains(&ph.id)
@@ -17,6 +17,7 @@ use axum::Json;
 use serde::{Deserialize, Serialize};
 use sha2::Digest;
 use std::net::SocketAddr;
+use subtle::ConstantTimeEq;
 
 use ta_events::schema::{EventEnvelope, SessionEvent};
 use ta_events::store::{EventStore, FsEventStore};
@@ -220,7 +221,10 @@ fn verify_github_signature(secret: &[u8], body: &[u8], signature_header: &str) -
     let computed_hex = hex_encode(&computed);
 
     // Constant-time comparison.
-    constant_time_eq(computed_hex.as_bytes(), expected_hex.as_bytes())
+    computed_hex
+        .as_bytes()
+        .ct_eq(expected_hex.as_bytes())
+        .into()
 }
 
 /// Compute HMAC-SHA256(key, message) using only sha2.
@@ -263,18 +267,6 @@ fn hex_encode(bytes: &[u8]) -> String {
     bytes.iter().map(|b| format!("{:02x}", b)).collect()
 }
 
-/// Constant-time byte slice comparison (timing-safe).
-fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
-    if a.len() != b.len() {
-        return false;
-    }
-    let mut diff: u8 = 0;
-    for (x, y) in a.iter().zip(b.iter()) {
-        diff |= x ^ y;
-    }
-    diff == 0
-}
-
 // ── Generic VCS webhook ─────────────────────────────────────────────────────
 
 /// Payload for the generic VCS webhook endpoint.
@@ -31,10 +31,19 @@ impl ChannelDispatcher {
     ///
     /// Registers adapters for each configured channel (Slack, Discord, Email)
     /// and any external channel plugins from `[[channels.external]]`.
+    /// In offline mode (`TA_OFFLINE`) only local json-stdio plugins are registered.
     pub fn from_config(config: &ChannelsConfig) -> Self {
+        Self::build(config, ta_submit::offline_from_env())
+    }
+
+    fn build(config: &ChannelsConfig, offline: bool) -> Self {
         let mut dispatcher = Self::new(config.default_channels.clone());
 
-        if let Some(ref slack_cfg) = config.slack {
+        if offline {
+            tracing::info!("Offline mode — Slack, Email, and HTTP channel plugins disabled");
+        }
+
+        if let Some(slack_cfg) = config.slack.as_ref().filter(|_| !offline) {
             let adapter = ta_connector_slack::SlackAdapter::new(ta_connector_slack::SlackConfig {
                 bot_token: slack_cfg.bot_token.clone(),
                 channel_id: slack_cfg.channel_id.clone(),
@@ -54,7 +63,7 @@ impl ChannelDispatcher {
             );
         }
 
-        if let Some(ref email_cfg) = config.email {
+        if let Some(email_cfg) = config.email.as_ref().filter(|_| !offline) {
             let adapter = ta_connector_email::EmailAdapter::new(ta_connector_email::EmailConfig {
                 send_endpoint: email_cfg.send_endpoint.clone(),
                 api_key: email_cfg.api_key.clone(),
@@ -67,6 +76,9 @@ impl ChannelDispatcher {
 
         // Register external channel plugins from [[channels.external]].
         for entry in &config.external {
+            if offline && entry.protocol != "json-stdio" {
+                continue;
+            }
             match Self::build_external_adapter(entry) {
                 Ok(adapter) => {
                     dispatcher.register(Arc::new(adapter));
@@ -517,6 +529,19 @@ mod tests {
         assert_eq!(dispatcher.adapter_count(), 0);
     }
 
+    #[test]
+    fn offline_registers_no_network_channels() {
+        let config = ChannelsConfig {
+            slack: Some(crate::config::SlackChannelConfig {
+                bot_token: "xoxb-test".into(),
+                channel_id: "C123".into(),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(ChannelDispatcher::build(&config, false).adapter_count(), 1);
+        assert_eq!(ChannelDispatcher::build(&config, true).adapter_count(), 0);
+    }
+
     #[test]
     fn build_question_helper() {
         let gid = Uuid::new_v4();
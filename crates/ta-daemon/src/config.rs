@@ -689,6 +689,11 @@ impl Default for PowerConfig {
 /// tcp_addr = "127.0.0.1:7800"
 /// auth_token = "secret-bearer-token"
 ///
+/// # HTTP + SSE at /mcp (browser UIs, remote agents; auth_token required
+/// # unless http_addr is a loopback address):
+/// mode = "http"
+/// http_addr = "127.0.0.1:7800"
+///
 /// # Optional TLS for TCP transport:
 /// [transport.tls]
 /// cert_path = "certs/server.pem"
@@ -708,6 +713,9 @@ pub enum TransportMode {
     /// TCP socket. The daemon listens on a configurable address/port.
     /// Supports optional TLS and bearer token authentication.
     Tcp,
+    /// Streamable HTTP + SSE at `/mcp`. Bearer token checked on every
+    /// request; required for non-loopback addresses.
+    Http,
 }
 
 /// TLS certificate configuration for TCP transport (v0.13.2).
@@ -741,6 +749,11 @@ pub struct TransportConfig {
     #[serde(default = "default_tcp_addr")]
     pub tcp_addr: String,
 
+    /// HTTP listen address for `mode = "http"`.
+    /// Default: `127.0.0.1:7800`
+    #[serde(default = "default_http_addr")]
+    pub http_addr: String,
+
     /// TLS certificate configuration for `mode = "tcp"`.
     /// When set, the TCP listener uses TLS (encrypted transport).
     /// When absent, TCP is plaintext (suitable for localhost/VPN-protected deployments).
@@ -764,6 +777,10 @@ fn default_unix_socket_path() -> String {
     ".ta/mcp.sock".to_string()
 }
 
+fn default_http_addr() -> String {
+    ta_mcp_gateway::http::DEFAULT_HTTP_ADDR.to_string()
+}
+
 fn default_tcp_addr() -> String {
     "127.0.0.1:7800".to_string()
 }
@@ -774,6 +791,7 @@ impl Default for TransportConfig {
             mode: TransportMode::Stdio,
             unix_socket_path: default_unix_socket_path(),
             tcp_addr: default_tcp_addr(),
+            http_addr: default_http_addr(),
             tls: None,
             auth_token: None,
         }
@@ -84,6 +84,16 @@ struct Cli {
     #[arg(long)]
     foreground: bool,
 
+    /// Serve MCP over HTTP + SSE on this address instead of the configured
+    /// transport (default 127.0.0.1:7800). Uses `[transport].auth_token`.
+    #[arg(
+        long,
+        value_name = "ADDR",
+        num_args = 0..=1,
+        default_missing_value = ta_mcp_gateway::http::DEFAULT_HTTP_ADDR
+    )]
+    http: Option<String>,
+
     /// Path to an office.yaml for multi-project mode.
     /// Can also be set via TA_OFFICE_CONFIG env var.
     #[arg(long)]
@@ -147,7 +157,11 @@ async fn main() -> Result<()> {
     }
 
     // Load daemon configuration.
-    let daemon_config = config::DaemonConfig::load(&project_root);
+    let mut daemon_config = config::DaemonConfig::load(&project_root);
+    if let Some(addr) = &cli.http {
+        daemon_config.transport.mode = config::TransportMode::Http;
+        daemon_config.transport.http_addr = addr.clone();
+    }
 
     // Plugin version enforcement with auto-setup (v0.11.4).
     // If a project.toml exists, check that all required plugins are installed
@@ -239,7 +253,14 @@ async fn main() -> Result<()> {
     // Start Discord listener manager if configured (v0.12.1).
     // Runs in both API and MCP modes so Discord is available regardless of how
     // the daemon is started.
-    if daemon_config.channels.discord_listener.enabled {
+    // Offline mode (`ta --offline` / `[submit] offline`) keeps it stopped.
+    let offline =
+        ta_submit::WorkflowConfig::load_or_default(&project_root.join(".ta").join("workflow.toml"))
+            .submit
+            .is_offline();
+    if daemon_config.channels.discord_listener.enabled && offline {
+        tracing::info!("Offline mode — Discord listener auto-start skipped");
+    } else if daemon_config.channels.discord_listener.enabled {
         tracing::info!(
             "Discord listener auto-start enabled — spawning ta-channel-discord --listen"
         );
@@ -266,6 +287,30 @@ async fn main() -> Result<()> {
             }
         }
 
+        // Fail goals that have made no progress within their timeout.
+        {
+            let gateway_config = GatewayConfig::for_project(&project_root);
+            let workflow = ta_submit::WorkflowConfig::load_or_default(
+                &project_root.join(".ta").join("workflow.toml"),
+            );
+            let mut dispatcher = ta_goal::EventDispatcher::new();
+            dispatcher.add_sink(
+                Box::new(ta_goal::LogSink::new(&gateway_config.events_log)),
+                ta_changeset::interaction::NotificationLevel::Debug,
+            );
+            match ta_goal::GoalRunStore::new(&gateway_config.goals_dir).and_then(|store| {
+                ta_goal::sweep_timed_out_goals(&store, workflow.gc.goal_timeout(), &dispatcher)
+            }) {
+                Ok(failed) if !failed.is_empty() => tracing::info!(
+                    count = failed.len(),
+                    "Startup timeout sweep: {} goal(s) failed as timed out",
+                    failed.len()
+                ),
+                Ok(_) => {}
+                Err(e) => tracing::warn!(error = %e, "Startup timeout sweep failed"),
+            }
+        }
+
         // Startup GC pass (v0.15.6.2): remove staging for failed/applied goals that
         // have exceeded their retention window. Keeps disk usage bounded on restart.
         {
@@ -13,6 +13,10 @@
 //     Supports optional TLS (encrypted transport) and bearer token authentication.
 //     Enables remote agent execution and cluster deployments.
 //
+//   - HTTP: streamable HTTP + SSE at `/mcp` (ta_mcp_gateway::http). For web
+//     UIs and remote agents; non-loopback binds require `auth_token`, sent
+//     as an `Authorization: Bearer` header on every request.
+//
 // All non-stdio transports support bearer token authentication:
 //   The client sends `Bearer <token>\n` as the first line before any MCP traffic.
 //   The server verifies the token and closes the connection on mismatch.
@@ -59,6 +63,12 @@ pub async fn serve(
             )
             .await
         }
+        TransportMode::Http => {
+            let addr = ta_mcp_gateway::http::resolve_http_addr(&config.http_addr)?;
+            ta_mcp_gateway::http::serve_http(server, addr, config.auth_token.clone())
+                .await
+                .context("MCP HTTP serve error")
+        }
     }
 }
 
@@ -341,6 +351,20 @@ mod tests {
         assert_eq!(result, PathBuf::from("/project/.ta/mcp.sock"));
     }
 
+    #[tokio::test]
+    async fn http_mode_refuses_public_bind_without_token() {
+        let dir = tempfile::tempdir().unwrap();
+        let server =
+            TaGatewayServer::new(ta_mcp_gateway::GatewayConfig::for_project(dir.path())).unwrap();
+        let config = TransportConfig {
+            mode: TransportMode::Http,
+            http_addr: "0.0.0.0:0".to_string(),
+            ..Default::default()
+        };
+        let err = serve(server, &config, dir.path()).await.unwrap_err();
+        assert!(format!("{:#}", err).contains("without a bearer token"));
+    }
+
     #[tokio::test]
     async fn authenticate_connection_no_auth_passthrough() {
         let input = b"MCP data\n";
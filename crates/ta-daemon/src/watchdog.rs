@@ -6,6 +6,7 @@
 //   3. Emits health.check events when issues are found
 //   4. Detects sleep/wake transitions and suppresses false heartbeat alerts (v0.13.1.1)
 //   5. Checks API connectivity after wake and emits connection lost/restored events (v0.13.1.1)
+//   6. Copies `.ta/heartbeats/<goal-id>` touches onto `GoalRun::last_heartbeat`
 //
 // Lightweight: no disk I/O unless issues are detected.
 
@@ -218,7 +219,7 @@ fn watchdog_cycle(
     state: &mut WatchdogState,
     power_manager: Option<&crate::power_manager::PowerManager>,
 ) {
-    let goals_dir = project_root.join(".ta").join("goals");
+    let goals_dir = ta_mcp_gateway::GatewayConfig::for_project(project_root).goals_dir;
     let events_dir = project_root.join(".ta").join("events");
 
     let store = match GoalRunStore::new(&goals_dir) {
@@ -607,9 +608,10 @@ fn check_running_goal(
     };
 
     if is_process_alive(pid) {
+        let last_activity = sync_file_heartbeat(goal, store, project_root);
         // Process is alive. Check for stale condition only if heartbeats are expected.
         if goal.heartbeat_required {
-            let age_secs = (*now - goal.updated_at).num_seconds().unsigned_abs();
+            let age_secs = (*now - last_activity).num_seconds().unsigned_abs();
             if age_secs > config.stale_question_threshold_secs {
                 tracing::warn!(
                     goal_id = %goal.goal_run_id,
@@ -724,6 +726,39 @@ fn check_running_goal(
     }
 }
 
+/// Record a touch of `.ta/heartbeats/<goal-id>` as a goal heartbeat.
+///
+/// Agents that can't call the `ta_heartbeat` tool can touch the file instead;
+/// the watchdog carries its mtime onto the goal each cycle so the timeout
+/// sweep and `ta goal status` see it. Returns the goal's latest activity.
+fn sync_file_heartbeat(
+    goal: &GoalRun,
+    store: &GoalRunStore,
+    project_root: &Path,
+) -> chrono::DateTime<Utc> {
+    let hb_path = project_root
+        .join(".ta")
+        .join("heartbeats")
+        .join(goal.goal_run_id.to_string());
+    let touched: Option<chrono::DateTime<Utc>> = std::fs::metadata(&hb_path)
+        .ok()
+        .and_then(|m| m.modified().ok())
+        .map(Into::into);
+    match touched {
+        Some(at) if goal.last_heartbeat.is_none_or(|prev| at > prev) => {
+            if let Err(e) = store.record_heartbeat(goal.goal_run_id, at) {
+                tracing::warn!(
+                    goal_id = %goal.goal_run_id,
+                    "Watchdog: failed to record heartbeat: {}",
+                    e
+                );
+            }
+            at.max(goal.last_activity())
+        }
+        _ => goal.last_activity(),
+    }
+}
+
 /// Check a goal in `Finalizing` state (v0.13.14 / v0.15.7.1).
 ///
 /// `Finalizing` means the agent exited cleanly and draft creation is in progress
@@ -1165,7 +1200,7 @@ pub fn process_health_label(goal: &GoalRun) -> &'static str {
 ///
 /// Returns the number of goals recovered (transitioned out of zombie Running state).
 pub fn startup_recovery_scan(project_root: &Path) -> usize {
-    let goals_dir = project_root.join(".ta").join("goals");
+    let goals_dir = ta_mcp_gateway::GatewayConfig::for_project(project_root).goals_dir;
     let store = match GoalRunStore::new(&goals_dir) {
         Ok(s) => s,
         Err(e) => {
@@ -1299,7 +1334,7 @@ pub fn startup_gc_pass(
     failed_staging_retention_hours: u32,
     applied_retention_days: u32,
 ) -> (u32, u64) {
-    let goals_dir = project_root.join(".ta").join("goals");
+    let goals_dir = ta_mcp_gateway::GatewayConfig::for_project(project_root).goals_dir;
     let store = match GoalRunStore::new(&goals_dir) {
         Ok(s) => s,
         Err(_) => return (0, 0),
@@ -1833,6 +1868,44 @@ mod tests {
         assert_eq!(updated.state, ta_goal::GoalRunState::Running);
     }
 
+    #[test]
+    fn watchdog_records_heartbeat_file_touch_on_running_goal() {
+        let dir = tempfile::tempdir().unwrap();
+        let project = dir.path();
+        let goals_dir = project.join(".ta/goals");
+        std::fs::create_dir_all(&goals_dir).unwrap();
+        std::fs::create_dir_all(project.join(".ta/events")).unwrap();
+        std::fs::create_dir_all(project.join(".ta/heartbeats")).unwrap();
+
+        let store = GoalRunStore::new(&goals_dir).unwrap();
+        let mut goal = GoalRun::new(
+            "Busy",
+            "obj",
+            "agent",
+            PathBuf::from("/tmp/a"),
+            PathBuf::from("/tmp/b"),
+        );
+        goal.state = ta_goal::GoalRunState::Running;
+        goal.agent_pid = Some(std::process::id());
+        goal.updated_at = Utc::now() - chrono::Duration::seconds(7200);
+        store.save(&goal).unwrap();
+        std::fs::write(
+            project
+                .join(".ta/heartbeats")
+                .join(goal.goal_run_id.to_string()),
+            b"",
+        )
+        .unwrap();
+
+        let mut state = WatchdogState::default();
+        watchdog_cycle(project, &WatchdogConfig::default(), &mut state, None);
+
+        let updated = store.get(goal.goal_run_id).unwrap().unwrap();
+        let heartbeat = updated.last_heartbeat.expect("heartbeat recorded");
+        assert!((Utc::now() - heartbeat).num_seconds() < 60);
+        assert_eq!(updated.updated_at, goal.updated_at);
+    }
+
     #[test]
     fn wake_grace_in_effect_suppresses_nothing_directly() {
         // Verify the in_wake_grace flag is computed correctly from state.
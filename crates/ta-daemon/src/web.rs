@@ -236,6 +236,7 @@ async fn approve_draft(
     let status = DraftStatus::Approved {
         approved_by: "web-ui".into(),
         approved_at: Utc::now(),
+        approval_expires_at: None,
     };
     match update_draft_status(&state.pr_packages_dir, uuid, status) {
         Ok(true) => Json(ActionResponse {
@@ -590,9 +591,9 @@ fn write_pid_file(path: &std::path::Path, server: &crate::config::ServerConfig)
 /// is restarted mid-run. Each recovered goal gets a lightweight poll task that
 /// emits SSE events as state transitions occur (or as the watchdog updates state).
 fn start_goal_recovery_tasks(app_state: &std::sync::Arc<crate::api::AppState>) {
-    let goal_dir = app_state.project_root.join(".ta/goals");
+    let goal_dir = app_state.goals_dir.clone();
     let events_dir = app_state.events_dir.clone();
-    let project_root = app_state.project_root.clone();
+    let pr_dir = app_state.pr_packages_dir.clone();
 
     let store = match ta_goal::store::GoalRunStore::new(&goal_dir) {
         Ok(s) => s,
@@ -631,8 +632,8 @@ fn start_goal_recovery_tasks(app_state: &std::sync::Arc<crate::api::AppState>) {
         let goal_id = goal.goal_run_id;
         let goal_title = goal.title.clone();
         let events_dir = events_dir.clone();
-        let goal_dir = project_root.join(".ta/goals");
-        let pr_dir = project_root.join(".ta/pr_packages");
+        let goal_dir = goal_dir.clone();
+        let pr_dir = pr_dir.clone();
 
         tracing::info!(
             goal_id = %goal_id,
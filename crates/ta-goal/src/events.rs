@@ -19,6 +19,7 @@ use std::path::{Path, PathBuf};
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use ta_changeset::interaction::NotificationLevel;
 use uuid::Uuid;
 
 use crate::error::GoalError;
@@ -309,6 +310,26 @@ impl TaEvent {
         }
     }
 
+    /// Severity of this event, used by [`EventDispatcher`] to filter sinks.
+    ///
+    /// Failures are `Error`; events that need a human or end work early are
+    /// `Warning`; chatty intra-session progress is `Debug`; everything else
+    /// is `Info`.
+    pub fn level(&self) -> NotificationLevel {
+        match self {
+            TaEvent::GoalFailed { .. } | TaEvent::WorkflowFailed { .. } => NotificationLevel::Error,
+            TaEvent::PrDenied { .. }
+            | TaEvent::SessionAborted { .. }
+            | TaEvent::WorkflowAwaitingHuman { .. } => NotificationLevel::Warning,
+            TaEvent::SessionStateChanged { .. }
+            | TaEvent::SessionMessage { .. }
+            | TaEvent::SessionIteration { .. }
+            | TaEvent::StageStarted { .. }
+            | TaEvent::StageCompleted { .. } => NotificationLevel::Debug,
+            _ => NotificationLevel::Info,
+        }
+    }
+
     /// Helper to create a GoalCreated event.
     pub fn goal_created(goal_run_id: Uuid, title: &str, agent_id: &str) -> Self {
         TaEvent::GoalCreated {
@@ -594,10 +615,11 @@ impl NotificationSink for LogSink {
 
 /// Dispatches events to multiple sinks.
 ///
-/// Errors from individual sinks are logged (via tracing) but don't
+/// Each sink has a minimum [`NotificationLevel`]; events below it are not
+/// forwarded. Errors from individual sinks are logged (via tracing) but don't
 /// prevent other sinks from receiving the event.
 pub struct EventDispatcher {
-    sinks: Vec<Box<dyn NotificationSink>>,
+    sinks: Vec<(Box<dyn NotificationSink>, NotificationLevel)>,
 }
 
 impl EventDispatcher {
@@ -606,14 +628,21 @@ impl EventDispatcher {
         Self { sinks: Vec::new() }
     }
 
-    /// Add a notification sink.
-    pub fn add_sink(&mut self, sink: Box<dyn NotificationSink>) {
-        self.sinks.push(sink);
+    /// Add a notification sink that receives events at `min_level` or above.
+    ///
+    /// Use `NotificationLevel::Debug` for sinks that should see everything
+    /// (e.g. the event log).
+    pub fn add_sink(&mut self, sink: Box<dyn NotificationSink>, min_level: NotificationLevel) {
+        self.sinks.push((sink, min_level));
     }
 
-    /// Dispatch an event to all sinks.
+    /// Dispatch an event to every sink whose threshold it meets.
     pub fn dispatch(&self, event: &TaEvent) {
-        for sink in &self.sinks {
+        let level = event.level();
+        for (sink, min_level) in &self.sinks {
+            if level < *min_level {
+                continue;
+            }
             if let Err(e) = sink.send(event) {
                 tracing::warn!("notification sink error: {}", e);
             }
@@ -666,8 +695,8 @@ mod tests {
         let path2 = dir.path().join("sink2.jsonl");
 
         let mut dispatcher = EventDispatcher::new();
-        dispatcher.add_sink(Box::new(LogSink::new(&path1)));
-        dispatcher.add_sink(Box::new(LogSink::new(&path2)));
+        dispatcher.add_sink(Box::new(LogSink::new(&path1)), NotificationLevel::Debug);
+        dispatcher.add_sink(Box::new(LogSink::new(&path2)), NotificationLevel::Debug);
 
         let event = TaEvent::goal_created(Uuid::new_v4(), "Test", "agent-1");
         dispatcher.dispatch(&event);
@@ -677,6 +706,37 @@ mod tests {
         assert!(fs::read_to_string(&path2).unwrap().contains("goal_created"));
     }
 
+    #[test]
+    fn dispatcher_filters_sinks_below_threshold() {
+        let dir = tempdir().unwrap();
+        let log_path = dir.path().join("events.jsonl");
+        let webhook_path = dir.path().join("webhook.jsonl");
+
+        // The log sink sees everything; the webhook-style sink only Warning+.
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.add_sink(Box::new(LogSink::new(&log_path)), NotificationLevel::Debug);
+        dispatcher.add_sink(
+            Box::new(LogSink::new(&webhook_path)),
+            NotificationLevel::Warning,
+        );
+
+        let gid = Uuid::new_v4();
+        let created = TaEvent::goal_created(gid, "Test", "agent-1");
+        assert_eq!(created.level(), NotificationLevel::Info);
+        dispatcher.dispatch(&created);
+
+        assert!(fs::read_to_string(&log_path)
+            .unwrap()
+            .contains("goal_created"));
+        assert!(!webhook_path.exists());
+
+        dispatcher.dispatch(&TaEvent::goal_failed(gid, "boom", Some(1)));
+        assert!(fs::read_to_string(&webhook_path)
+            .unwrap()
+            .contains("goal_failed"));
+        assert_eq!(fs::read_to_string(&log_path).unwrap().lines().count(), 2);
+    }
+
     #[test]
     fn event_type_names() {
         let id = Uuid::new_v4();
@@ -0,0 +1,149 @@
+// file_lock.rs — Advisory locking and atomic writes for JSON record files.
+//
+// Several `ta` processes (the MCP daemon, the CLI, background draft builds)
+// read and write the same goal and draft-package JSON files. Without
+// coordination a reader can observe a half-written file, or two writers can
+// interleave. Every record file gets a hidden sidecar lock file
+// (`.<name>.lock`) in the same directory:
+//
+// - Writers take an exclusive lock, write to `.<name>.tmp`, then rename the
+//   temp file over the target. A reader never sees a partial file.
+// - Readers take a shared lock. They may briefly block while a writer holds
+//   the exclusive lock.
+//
+// The lock lives on a sidecar rather than the record itself because the
+// rename replaces the record's inode, which would silently drop a lock held
+// on the old file.
+
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use fs2::FileExt;
+
+/// RAII guard for an advisory lock on a record file's sidecar.
+///
+/// The lock is released when the guard is dropped.
+#[derive(Debug)]
+pub struct FileLockGuard {
+    file: File,
+}
+
+impl FileLockGuard {
+    /// Block until an exclusive (writer) lock on `path` is held.
+    pub fn exclusive(path: &Path) -> io::Result<Self> {
+        let file = open_lock_file(path)?;
+        file.lock_exclusive()?;
+        Ok(Self { file })
+    }
+
+    /// Block until a shared (reader) lock on `path` is held.
+    pub fn shared(path: &Path) -> io::Result<Self> {
+        let file = open_lock_file(path)?;
+        file.lock_shared()?;
+        Ok(Self { file })
+    }
+}
+
+impl Drop for FileLockGuard {
+    fn drop(&mut self) {
+        let _ = self.file.unlock();
+    }
+}
+
+/// Path of the sidecar lock file for `path`: `<dir>/.<name>.lock`.
+pub fn lock_path(path: &Path) -> PathBuf {
+    sidecar_path(path, "lock")
+}
+
+/// Write `contents` to `path` atomically without taking a lock.
+///
+/// The caller must already hold an exclusive [`FileLockGuard`] for `path`.
+pub fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let tmp = sidecar_path(path, "tmp");
+    {
+        let mut f = File::create(&tmp)?;
+        f.write_all(contents)?;
+        f.sync_all()?;
+    }
+    fs::rename(&tmp, path)
+}
+
+/// Take an exclusive lock on `path`, then write `contents` atomically.
+pub fn write_atomic_locked(path: &Path, contents: &[u8]) -> io::Result<()> {
+    let _guard = FileLockGuard::exclusive(path)?;
+    write_atomic(path, contents)
+}
+
+/// Take a shared lock on `path`, then read it to a string.
+///
+/// Blocks while another process holds the exclusive lock for a write.
+pub fn read_locked(path: &Path) -> io::Result<String> {
+    let _guard = FileLockGuard::shared(path)?;
+    fs::read_to_string(path)
+}
+
+fn open_lock_file(path: &Path) -> io::Result<File> {
+    if let Some(parent) = path.parent() {
+        if !parent.as_os_str().is_empty() {
+            fs::create_dir_all(parent)?;
+        }
+    }
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .open(lock_path(path))
+}
+
+fn sidecar_path(path: &Path, suffix: &str) -> PathBuf {
+    let parent = path.parent().unwrap_or_else(|| Path::new("."));
+    parent.join(format!(
+        ".{}.{}",
+        path.file_name().unwrap_or_default().to_string_lossy(),
+        suffix
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Arc;
+    use std::thread;
+    use tempfile::tempdir;
+
+    #[test]
+    fn write_atomic_locked_leaves_no_temp_file() {
+        let dir = tempdir().unwrap();
+        let path = dir.path().join("record.json");
+        write_atomic_locked(&path, b"{\"a\":1}").unwrap();
+
+        assert_eq!(read_locked(&path).unwrap(), "{\"a\":1}");
+        assert!(!dir.path().join(".record.json.tmp").exists());
+        assert!(lock_path(&path).exists());
+    }
+
+    #[test]
+    fn concurrent_writers_never_produce_partial_json() {
+        let dir = tempdir().unwrap();
+        let path = Arc::new(dir.path().join("record.json"));
+
+        let handles: Vec<_> = (0..8)
+            .map(|i| {
+                let path = Arc::clone(&path);
+                thread::spawn(move || {
+                    let body = serde_json::json!({ "writer": i, "pad": "x".repeat(64 * 1024) });
+                    for _ in 0..10 {
+                        write_atomic_locked(&path, body.to_string().as_bytes()).unwrap();
+                        let read = read_locked(&path).unwrap();
+                        serde_json::from_str::<serde_json::Value>(&read).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+    }
+}
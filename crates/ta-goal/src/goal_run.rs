@@ -185,6 +185,18 @@ impl GoalRunState {
     }
 }
 
+/// One source root of a multi-root goal.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GoalSourceRoot {
+    /// Directory name under the staging workspace and `fs://<alias>/` URI authority.
+    pub alias: String,
+    /// The root's source directory.
+    pub path: PathBuf,
+    /// Source file snapshot of this root at goal start (for conflict detection).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<serde_json::Value>,
+}
+
 /// A GoalRun — one unit of agent work from start to completion.
 ///
 /// This is the top-level execution unit introduced by the Plan Revision doc.
@@ -208,6 +220,11 @@ pub struct GoalRun {
     /// The agent working on this goal.
     pub agent_id: String,
 
+    /// Custom launch command set by `ta run --agent-command` (e.g. `"my-agent --flag"`).
+    /// Follow-ups of this goal relaunch the same command.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub agent_command: Option<String>,
+
     /// Current lifecycle state.
     pub state: GoalRunState,
 
@@ -224,6 +241,11 @@ pub struct GoalRun {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub source_dir: Option<PathBuf>,
 
+    /// Git commit the source was at when the goal started (for git-backed
+    /// sources). `ta draft build --base-ref` diffs staging against it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub base_ref: Option<String>,
+
     /// Optional plan phase this goal is working on (e.g., "4b").
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub plan_phase: Option<String>,
@@ -232,6 +254,12 @@ pub struct GoalRun {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub parent_goal_id: Option<Uuid>,
 
+    /// Goal this one was cloned from by `ta goal clone` (a fresh re-run with the
+    /// same title, objective, source, and phase). Unlike `parent_goal_id`, a
+    /// clone does not supersede the original's draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cloned_from: Option<Uuid>,
+
     /// Source file snapshot taken at goal start (for conflict detection).
     /// Serialized as embedded JSON — allows concurrent session conflict detection.
     #[serde(default, skip_serializing_if = "Option::is_none")]
@@ -295,6 +323,12 @@ pub struct GoalRun {
     #[serde(default, skip_serializing_if = "std::ops::Not::not")]
     pub heartbeat_required: bool,
 
+    /// When the agent (via `ta_heartbeat`) or the daemon last reported this
+    /// goal alive. Unlike `updated_at`, it moves without a state transition,
+    /// so stale and timeout checks use [`GoalRun::last_activity`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_heartbeat: Option<DateTime<Utc>>,
+
     /// PR URL created by `ta draft apply` (v0.11.3).
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub pr_url: Option<String>,
@@ -334,6 +368,19 @@ pub struct GoalRun {
     #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub memory_entries_created: Vec<Uuid>,
 
+    /// Analysis-only goal started with `ta run --readonly`.
+    ///
+    /// The agent runs under a read/list-only capability manifest and its output
+    /// is a report; `ta draft build` packages that report instead of file changes.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub readonly: bool,
+
+    /// Source roots staged side by side under `workspace_path/<alias>/`
+    /// (`[workspace.roots]`). Empty for single-root goals, whose snapshot is
+    /// `source_snapshot`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_roots: Vec<GoalSourceRoot>,
+
     /// When this goal run was created.
     pub created_at: DateTime<Utc>,
 
@@ -354,6 +401,42 @@ pub struct GoalRun {
     /// Populated from stream-json `system` init event.
     #[serde(default, skip_serializing_if = "String::is_empty")]
     pub agent_model: String,
+
+    /// Seconds without an `updated_at` change after which an active goal is
+    /// considered stuck and failed by [`crate::sweep_timed_out_goals`].
+    /// `None` falls back to `[gc] goal_timeout_secs` in workflow.toml.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeout_secs: Option<u64>,
+}
+
+/// Stable, machine-readable projection of a [`GoalRun`] for `--json` output.
+///
+/// `GoalRun` itself carries internal bookkeeping (PIDs, snapshots, token
+/// counters) that changes between releases. Tooling should consume this
+/// record instead: fields are only ever added, never renamed or removed.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct GoalRunRecord {
+    pub id: Uuid,
+    pub shortref: String,
+    pub tag: String,
+    pub title: String,
+    /// Lifecycle state name (e.g. "running", "pr_ready").
+    pub state: String,
+    /// Failure reason when `state` is "failed".
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+    pub agent_id: String,
+    pub source_dir: Option<PathBuf>,
+    pub plan_phase: Option<String>,
+    pub parent_goal_id: Option<Uuid>,
+    pub is_macro: bool,
+    /// Latest draft built for this goal, if any.
+    pub draft_id: Option<Uuid>,
+    pub draft_status: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_heartbeat: Option<DateTime<Utc>>,
 }
 
 /// Generate a slug from a title: lowercase, hyphens, max 30 chars.
@@ -409,13 +492,16 @@ impl GoalRun {
             title,
             objective: objective.into(),
             agent_id: agent_id.into(),
+            agent_command: None,
             state: GoalRunState::Created,
             manifest_id: Uuid::new_v4(),
             workspace_path,
             store_path,
             source_dir: None,
+            base_ref: None,
             plan_phase: None,
             parent_goal_id: None,
+            cloned_from: None,
             source_snapshot: None,
             is_macro: false,
             parent_macro_id: None,
@@ -428,17 +514,21 @@ impl GoalRun {
             project_name: None,
             agent_pid: None,
             heartbeat_required: false,
+            last_heartbeat: None,
             pr_url: None,
             pr_package_id: None,
             progress_note: None,
             vcs_isolation: None,
             initiated_by: None,
             memory_entries_created: Vec::new(),
+            readonly: false,
+            source_roots: Vec::new(),
             created_at: now,
             updated_at: now,
             input_tokens: 0,
             output_tokens: 0,
             agent_model: String::new(),
+            timeout_secs: None,
         }
     }
 
@@ -473,6 +563,45 @@ impl GoalRun {
         }
     }
 
+    /// Latest sign of life: the last heartbeat or state change, whichever is newer.
+    pub fn last_activity(&self) -> DateTime<Utc> {
+        match self.last_heartbeat {
+            Some(heartbeat) if heartbeat > self.updated_at => heartbeat,
+            _ => self.updated_at,
+        }
+    }
+
+    /// Project this goal onto the stable [`GoalRunRecord`] shape. The caller
+    /// supplies the latest draft (id, status), since drafts live in a
+    /// separate store; `pr_package_id` is used when none is given.
+    pub fn to_record(&self, draft: Option<(Uuid, String)>) -> GoalRunRecord {
+        let (draft_id, draft_status) = match draft {
+            Some((id, status)) => (Some(id), Some(status)),
+            None => (self.pr_package_id, None),
+        };
+        GoalRunRecord {
+            id: self.goal_run_id,
+            shortref: self.shortref(),
+            tag: self.display_tag(),
+            title: self.title.clone(),
+            state: self.state.to_string(),
+            failure_reason: match &self.state {
+                GoalRunState::Failed { reason } => Some(reason.clone()),
+                _ => None,
+            },
+            agent_id: self.agent_id.clone(),
+            source_dir: self.source_dir.clone(),
+            plan_phase: self.plan_phase.clone(),
+            parent_goal_id: self.parent_goal_id,
+            is_macro: self.is_macro,
+            draft_id,
+            draft_status,
+            created_at: self.created_at,
+            updated_at: self.updated_at,
+            last_heartbeat: self.last_heartbeat,
+        }
+    }
+
     /// Transition to a new state. Returns an error if the transition is invalid.
     pub fn transition(&mut self, new_state: GoalRunState) -> Result<(), GoalError> {
         if !self.state.can_transition_to(&new_state) {
@@ -486,6 +615,23 @@ impl GoalRun {
         self.updated_at = Utc::now();
         Ok(())
     }
+
+    /// Move a failed goal back to `PrReady` because its draft was reopened.
+    ///
+    /// This is deliberately not part of [`GoalRunState::can_transition_to`]:
+    /// only `ta draft reopen` may revive a failed goal.
+    pub fn reopen_for_review(&mut self) -> Result<(), GoalError> {
+        if !matches!(self.state, GoalRunState::Failed { .. }) {
+            return Err(GoalError::InvalidTransition {
+                goal_run_id: self.goal_run_id,
+                from: self.state.to_string(),
+                to: GoalRunState::PrReady.to_string(),
+            });
+        }
+        self.state = GoalRunState::PrReady;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -545,6 +691,24 @@ mod tests {
         assert!(matches!(gr.state, GoalRunState::Failed { .. }));
     }
 
+    #[test]
+    fn failed_goal_only_returns_to_pr_ready_via_reopen() {
+        let mut gr = test_goal_run();
+        gr.transition(GoalRunState::Failed {
+            reason: "draft denied".to_string(),
+        })
+        .unwrap();
+        let result = gr.transition(GoalRunState::PrReady);
+        assert!(matches!(result, Err(GoalError::InvalidTransition { .. })));
+
+        gr.reopen_for_review().unwrap();
+        assert_eq!(gr.state, GoalRunState::PrReady);
+
+        // Reopening a goal that has not failed is rejected.
+        let result = gr.reopen_for_review();
+        assert!(matches!(result, Err(GoalError::InvalidTransition { .. })));
+    }
+
     #[test]
     fn under_review_can_go_back_to_running() {
         let mut gr = test_goal_run();
@@ -951,4 +1115,38 @@ mod tests {
         let restored: GoalRun = serde_json::from_str(&json).unwrap();
         assert_eq!(restored.tag, Some("my-goal-01".to_string()));
     }
+
+    #[test]
+    fn to_record_projects_stable_fields() {
+        let mut gr = test_goal_run();
+        gr.plan_phase = Some("v0.4.1".to_string());
+        gr.source_dir = Some(PathBuf::from("/tmp/project"));
+        gr.state = GoalRunState::Failed {
+            reason: "agent crashed".to_string(),
+        };
+        let draft_id = Uuid::new_v4();
+
+        let record = gr.to_record(Some((draft_id, "pending_review".to_string())));
+        assert_eq!(record.id, gr.goal_run_id);
+        assert_eq!(record.state, "failed");
+        assert_eq!(record.failure_reason.as_deref(), Some("agent crashed"));
+        assert_eq!(record.plan_phase.as_deref(), Some("v0.4.1"));
+        assert_eq!(record.draft_id, Some(draft_id));
+
+        let json = serde_json::to_value(&record).unwrap();
+        assert!(json.get("agent_pid").is_none());
+        assert!(json.get("source_snapshot").is_none());
+        assert_eq!(json["draft_status"], "pending_review");
+    }
+
+    #[test]
+    fn to_record_falls_back_to_pr_package_id() {
+        let mut gr = test_goal_run();
+        let pkg = Uuid::new_v4();
+        gr.pr_package_id = Some(pkg);
+        let record = gr.to_record(None);
+        assert_eq!(record.draft_id, Some(pkg));
+        assert!(record.draft_status.is_none());
+        assert!(record.failure_reason.is_none());
+    }
 }
@@ -15,12 +15,14 @@
 //! - [`TaEvent`] — events emitted at key lifecycle points
 //! - [`EventDispatcher`] — dispatches events to notification sinks
 //! - [`NotificationSink`] — trait for receiving events (log, webhook, etc.)
+//! - [`sweep_timed_out_goals`] — fails active goals idle past their timeout
 
 pub mod analysis;
 pub mod commit_context;
 pub mod conversation;
 pub mod error;
 pub mod events;
+pub mod file_lock;
 pub mod goal_run;
 pub mod history;
 pub mod human_review;
@@ -32,6 +34,7 @@ pub mod reviewer;
 pub mod security;
 pub mod social_audit;
 pub mod store;
+pub mod timeout;
 pub mod token_cost;
 pub mod velocity;
 
@@ -43,7 +46,7 @@ pub use commit_context::CommitContext;
 pub use conversation::{ConversationStore, ConversationTurn, TurnRole};
 pub use error::GoalError;
 pub use events::{EventDispatcher, LogSink, NotificationSink, TaEvent};
-pub use goal_run::{slugify_title, GoalRun, GoalRunState};
+pub use goal_run::{slugify_title, GoalRun, GoalRunRecord, GoalRunState, GoalSourceRoot};
 pub use history::{GoalHistoryEntry, GoalHistoryLedger, HistoryFilter};
 pub use human_review::{
     extract_human_review_items, HumanReviewRecord, HumanReviewStatus, HumanReviewStore,
@@ -62,6 +65,7 @@ pub use security::{
 };
 pub use social_audit::{DraftSocialRecord, SocialAuditLog, SocialPostRecordState};
 pub use store::GoalRunStore;
+pub use timeout::{is_timed_out, sweep_timed_out_goals, TIMED_OUT_REASON};
 pub use token_cost::{compute_cost, rate_for_model, ModelRate};
 pub use velocity::{
     aggregate_by_contributor, detect_phase_conflicts, filter_by_phase_prefix,
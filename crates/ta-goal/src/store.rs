@@ -4,14 +4,21 @@
 // This keeps goals isolated and makes the store easy to inspect manually.
 //
 // The store supports CRUD operations plus filtering by state.
+//
+// Writes are atomic (temp file + rename) under an exclusive advisory lock and
+// reads take a shared lock (see `file_lock`), so a daemon and a CLI touching
+// the same goal never see a half-written file. Readers may briefly block while
+// a write is in progress.
 
 use std::cmp::Reverse;
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use chrono::{DateTime, Utc};
 use uuid::Uuid;
 
 use crate::error::GoalError;
+use crate::file_lock::{self, FileLockGuard};
 use crate::goal_run::{slugify_title, GoalRun, GoalRunState};
 
 /// Persistent store for GoalRun records.
@@ -35,23 +42,24 @@ impl GoalRunStore {
     }
 
     /// Save a GoalRun to disk (creates or overwrites).
+    ///
+    /// The file is replaced atomically while holding an exclusive lock.
     pub fn save(&self, goal_run: &GoalRun) -> Result<(), GoalError> {
         let path = self.goal_file(goal_run.goal_run_id);
-        let json = serde_json::to_string_pretty(goal_run)?;
-        fs::write(&path, json).map_err(|source| GoalError::IoError {
-            path: path.display().to_string(),
-            source,
-        })?;
-        Ok(())
+        let _guard = self.lock_exclusive(&path)?;
+        self.save_unlocked(goal_run)
     }
 
     /// Get a specific GoalRun by ID.
+    ///
+    /// Takes a shared lock, so this may briefly block while a writer holds
+    /// the goal's exclusive lock.
     pub fn get(&self, goal_run_id: Uuid) -> Result<Option<GoalRun>, GoalError> {
         let path = self.goal_file(goal_run_id);
         if !path.exists() {
             return Ok(None);
         }
-        let json = fs::read_to_string(&path).map_err(|source| GoalError::IoError {
+        let json = file_lock::read_locked(&path).map_err(|source| GoalError::IoError {
             path: path.display().to_string(),
             source,
         })?;
@@ -106,11 +114,25 @@ impl GoalRunStore {
         goal_run_id: Uuid,
         new_state: GoalRunState,
     ) -> Result<GoalRun, GoalError> {
+        // Hold the exclusive lock across read-modify-write so a concurrent
+        // transition cannot be lost.
+        let _guard = self.lock_exclusive(&self.goal_file(goal_run_id))?;
         let mut goal_run = self
-            .get(goal_run_id)?
+            .get_unlocked(goal_run_id)?
             .ok_or(GoalError::NotFound(goal_run_id))?;
         goal_run.transition(new_state)?;
-        self.save(&goal_run)?;
+        self.save_unlocked(&goal_run)?;
+        Ok(goal_run)
+    }
+
+    /// Move a failed GoalRun back to `PrReady` after its draft is reopened.
+    pub fn reopen_for_review(&self, goal_run_id: Uuid) -> Result<GoalRun, GoalError> {
+        let _guard = self.lock_exclusive(&self.goal_file(goal_run_id))?;
+        let mut goal_run = self
+            .get_unlocked(goal_run_id)?
+            .ok_or(GoalError::NotFound(goal_run_id))?;
+        goal_run.reopen_for_review()?;
+        self.save_unlocked(&goal_run)?;
         Ok(goal_run)
     }
 
@@ -193,24 +215,89 @@ impl GoalRunStore {
 
     /// Update the progress_note for a goal without changing state (v0.13.17).
     pub fn update_progress_note(&self, goal_run_id: Uuid, note: &str) -> Result<(), GoalError> {
-        if let Some(mut goal) = self.get(goal_run_id)? {
+        let _guard = self.lock_exclusive(&self.goal_file(goal_run_id))?;
+        if let Some(mut goal) = self.get_unlocked(goal_run_id)? {
             goal.progress_note = Some(note.to_string());
-            self.save(&goal)?;
+            self.save_unlocked(&goal)?;
         }
         Ok(())
     }
 
+    /// Record that the goal was alive at `at`. Leaves `updated_at` alone,
+    /// since no state changed, and never moves `last_heartbeat` backwards.
+    /// Returns `false` if the goal doesn't exist.
+    pub fn record_heartbeat(
+        &self,
+        goal_run_id: Uuid,
+        at: DateTime<Utc>,
+    ) -> Result<bool, GoalError> {
+        let _guard = self.lock_exclusive(&self.goal_file(goal_run_id))?;
+        match self.get_unlocked(goal_run_id)? {
+            Some(mut goal) => {
+                goal.last_heartbeat = Some(goal.last_heartbeat.map_or(at, |prev| prev.max(at)));
+                self.save_unlocked(&goal)?;
+                Ok(true)
+            }
+            None => Ok(false),
+        }
+    }
+
     /// Delete a GoalRun from the store.
     pub fn delete(&self, goal_run_id: Uuid) -> Result<bool, GoalError> {
         let path = self.goal_file(goal_run_id);
         if !path.exists() {
             return Ok(false);
         }
-        fs::remove_file(&path).map_err(|source| GoalError::IoError {
+        {
+            let _guard = self.lock_exclusive(&path)?;
+            fs::remove_file(&path).map_err(|source| GoalError::IoError {
+                path: path.display().to_string(),
+                source,
+            })?;
+        }
+        // The lock sidecar would otherwise outlive the goal forever.
+        let lock = crate::file_lock::lock_path(&path);
+        match fs::remove_file(&lock) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(source) => {
+                return Err(GoalError::IoError {
+                    path: lock.display().to_string(),
+                    source,
+                })
+            }
+        }
+        Ok(true)
+    }
+
+    /// Write a GoalRun atomically. Caller must hold the exclusive lock.
+    fn save_unlocked(&self, goal_run: &GoalRun) -> Result<(), GoalError> {
+        let path = self.goal_file(goal_run.goal_run_id);
+        let json = serde_json::to_string_pretty(goal_run)?;
+        file_lock::write_atomic(&path, json.as_bytes()).map_err(|source| GoalError::IoError {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+
+    /// Read a GoalRun without locking. Caller must hold the exclusive lock.
+    fn get_unlocked(&self, goal_run_id: Uuid) -> Result<Option<GoalRun>, GoalError> {
+        let path = self.goal_file(goal_run_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let json = fs::read_to_string(&path).map_err(|source| GoalError::IoError {
             path: path.display().to_string(),
             source,
         })?;
-        Ok(true)
+        Ok(Some(serde_json::from_str(&json)?))
+    }
+
+    fn lock_exclusive(&self, path: &Path) -> Result<FileLockGuard, GoalError> {
+        FileLockGuard::exclusive(path).map_err(|source| GoalError::IoError {
+            path: file_lock::lock_path(path).display().to_string(),
+            source,
+        })
     }
 
     /// Path to the JSON file for a given GoalRun.
@@ -336,6 +423,37 @@ mod tests {
         assert!(matches!(result, Err(GoalError::NotFound(_))));
     }
 
+    #[test]
+    fn concurrent_saves_leave_readable_goal() {
+        let dir = tempdir().unwrap();
+        let store = std::sync::Arc::new(GoalRunStore::new(dir.path().join("goals")).unwrap());
+
+        let gr = make_goal_run("Concurrent");
+        let id = gr.goal_run_id;
+        store.save(&gr).unwrap();
+
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let store = std::sync::Arc::clone(&store);
+                std::thread::spawn(move || {
+                    for j in 0..10 {
+                        store
+                            .update_progress_note(id, &format!("writer {} step {}", i, j))
+                            .unwrap();
+                        assert!(store.get(id).unwrap().is_some());
+                    }
+                })
+            })
+            .collect();
+        for h in handles {
+            h.join().unwrap();
+        }
+
+        // Lock and temp sidecars must not show up as goals.
+        assert_eq!(store.list().unwrap().len(), 1);
+        assert!(store.get(id).unwrap().unwrap().progress_note.is_some());
+    }
+
     #[test]
     fn delete_goal_run() {
         let dir = tempdir().unwrap();
@@ -349,6 +467,25 @@ mod tests {
         assert!(store.get(id).unwrap().is_none());
     }
 
+    #[test]
+    fn delete_removes_lock_sidecar() {
+        let dir = tempdir().unwrap();
+        let store = GoalRunStore::new(dir.path().join("goals")).unwrap();
+
+        let gr = make_goal_run("Locked");
+        let id = gr.goal_run_id;
+        store.save(&gr).unwrap();
+        let lock = crate::file_lock::lock_path(&store.goal_file(id));
+        assert!(lock.exists());
+
+        assert!(store.delete(id).unwrap());
+        assert!(!lock.exists());
+        assert_eq!(
+            std::fs::read_dir(dir.path().join("goals")).unwrap().count(),
+            0
+        );
+    }
+
     #[test]
     fn store_survives_reopen() {
         let dir = tempdir().unwrap();
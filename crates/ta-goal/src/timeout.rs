@@ -0,0 +1,214 @@
+// timeout.rs — Auto-fail goals that have stopped making progress.
+//
+// An agent that hangs or a CLI that is killed mid-run can leave a goal in
+// `Running` forever. Each goal may carry a `timeout_secs` (set by
+// `ta goal start --timeout` or the `[gc] goal_timeout_secs` default); the
+// sweep here fails any active goal whose last activity (heartbeat or state
+// change, see `GoalRun::last_activity`) is older than that.
+// Both the CLI startup check and the daemon call it.
+
+use chrono::{DateTime, Utc};
+use uuid::Uuid;
+
+use crate::error::GoalError;
+use crate::events::{EventDispatcher, TaEvent};
+use crate::goal_run::{GoalRun, GoalRunState};
+use crate::store::GoalRunStore;
+
+/// Failure reason recorded on goals failed by the sweep.
+pub const TIMED_OUT_REASON: &str = "timed out";
+
+/// Fail every active goal that has been idle longer than its timeout.
+///
+/// The effective timeout is the goal's own `timeout_secs`, falling back to
+/// `default_timeout_secs`. Goals with neither are never timed out. Only
+/// `Created`, `Configured`, and `Running` goals are considered — goals waiting
+/// on a human or a draft build have their own lifecycle handling.
+///
+/// Emits a `GoalFailed` event for each goal failed and returns their IDs.
+/// A goal whose record cannot be updated is logged and skipped; only a
+/// failure to list the store is returned as an error.
+pub fn sweep_timed_out_goals(
+    store: &GoalRunStore,
+    default_timeout_secs: Option<u64>,
+    dispatcher: &EventDispatcher,
+) -> Result<Vec<Uuid>, GoalError> {
+    sweep_timed_out_goals_at(store, default_timeout_secs, dispatcher, Utc::now())
+}
+
+fn sweep_timed_out_goals_at(
+    store: &GoalRunStore,
+    default_timeout_secs: Option<u64>,
+    dispatcher: &EventDispatcher,
+    now: DateTime<Utc>,
+) -> Result<Vec<Uuid>, GoalError> {
+    let mut failed = Vec::new();
+    for goal in store.list()? {
+        if !is_timed_out(&goal, default_timeout_secs, now) {
+            continue;
+        }
+        // One unwritable goal must not stop the sweep for the rest.
+        if let Err(e) = store.transition(
+            goal.goal_run_id,
+            GoalRunState::Failed {
+                reason: TIMED_OUT_REASON.to_string(),
+            },
+        ) {
+            tracing::warn!("could not time out goal {}: {}", goal.goal_run_id, e);
+            continue;
+        }
+        dispatcher.dispatch(&TaEvent::goal_failed(
+            goal.goal_run_id,
+            TIMED_OUT_REASON,
+            None,
+        ));
+        failed.push(goal.goal_run_id);
+    }
+    Ok(failed)
+}
+
+/// Whether `goal` is active and has been idle longer than its timeout at `now`.
+///
+/// A recent heartbeat keeps a long-running goal alive even when its state
+/// hasn't changed since it started.
+pub fn is_timed_out(goal: &GoalRun, default_timeout_secs: Option<u64>, now: DateTime<Utc>) -> bool {
+    if !matches!(
+        goal.state,
+        GoalRunState::Created | GoalRunState::Configured | GoalRunState::Running
+    ) {
+        return false;
+    }
+    let Some(timeout) = goal.timeout_secs.or(default_timeout_secs) else {
+        return false;
+    };
+    if timeout == 0 {
+        return false;
+    }
+    let idle = now
+        .signed_duration_since(goal.last_activity())
+        .num_seconds();
+    idle > timeout as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::events::LogSink;
+    use chrono::Duration;
+    use std::path::PathBuf;
+    use ta_changeset::interaction::NotificationLevel;
+    use tempfile::tempdir;
+
+    fn running_goal(idle_secs: i64, timeout_secs: Option<u64>) -> GoalRun {
+        let mut goal = GoalRun::new(
+            "stuck",
+            "objective",
+            "test-agent",
+            PathBuf::from("/tmp/staging"),
+            PathBuf::from("/tmp/store"),
+        );
+        goal.transition(GoalRunState::Configured).unwrap();
+        goal.transition(GoalRunState::Running).unwrap();
+        goal.updated_at = Utc::now() - Duration::seconds(idle_secs);
+        goal.timeout_secs = timeout_secs;
+        goal
+    }
+
+    #[test]
+    fn sweep_fails_only_goals_past_their_timeout() {
+        let dir = tempdir().unwrap();
+        let store = GoalRunStore::new(dir.path().join("goals")).unwrap();
+        let events = dir.path().join("events.jsonl");
+        let mut dispatcher = EventDispatcher::new();
+        dispatcher.add_sink(Box::new(LogSink::new(&events)), NotificationLevel::Debug);
+
+        let stuck = running_goal(600, Some(60));
+        let fresh = running_goal(10, Some(60));
+        let no_timeout = running_goal(600, None);
+        for g in [&stuck, &fresh, &no_timeout] {
+            store.save(g).unwrap();
+        }
+
+        let failed = sweep_timed_out_goals(&store, None, &dispatcher).unwrap();
+        assert_eq!(failed, vec![stuck.goal_run_id]);
+
+        let reloaded = store.get(stuck.goal_run_id).unwrap().unwrap();
+        assert!(matches!(
+            reloaded.state,
+            GoalRunState::Failed { ref reason } if reason == TIMED_OUT_REASON
+        ));
+        assert!(matches!(
+            store.get(fresh.goal_run_id).unwrap().unwrap().state,
+            GoalRunState::Running
+        ));
+        let log = std::fs::read_to_string(&events).unwrap();
+        assert!(log.contains("goal_failed"));
+
+        // The workflow default applies to goals without their own timeout.
+        let failed = sweep_timed_out_goals(&store, Some(300), &dispatcher).unwrap();
+        assert_eq!(failed, vec![no_timeout.goal_run_id]);
+    }
+
+    #[test]
+    fn sweep_continues_past_a_goal_it_cannot_update() {
+        let dir = tempdir().unwrap();
+        let goals_dir = dir.path().join("goals");
+        let store = GoalRunStore::new(&goals_dir).unwrap();
+        let dispatcher = EventDispatcher::new();
+
+        let blocked = running_goal(600, Some(60));
+        let stuck = running_goal(600, Some(60));
+        store.save(&blocked).unwrap();
+        store.save(&stuck).unwrap();
+        // A directory where the lock file belongs makes the transition fail.
+        let lock =
+            crate::file_lock::lock_path(&goals_dir.join(format!("{}.json", blocked.goal_run_id)));
+        let _ = std::fs::remove_file(&lock);
+        std::fs::create_dir(&lock).unwrap();
+
+        let failed = sweep_timed_out_goals(&store, None, &dispatcher).unwrap();
+        assert_eq!(failed, vec![stuck.goal_run_id]);
+        assert!(store
+            .list()
+            .unwrap()
+            .iter()
+            .any(|g| g.goal_run_id == blocked.goal_run_id
+                && matches!(g.state, GoalRunState::Running)));
+    }
+
+    #[test]
+    fn inactive_goals_and_zero_timeouts_are_ignored() {
+        let now = Utc::now();
+        let mut goal = running_goal(600, Some(0));
+        assert!(!is_timed_out(&goal, Some(60), now));
+
+        goal.timeout_secs = Some(60);
+        goal.transition(GoalRunState::PrReady).unwrap();
+        assert!(!is_timed_out(&goal, None, now));
+    }
+
+    #[test]
+    fn recent_heartbeat_keeps_long_running_goal_alive() {
+        let dir = tempdir().unwrap();
+        let store = GoalRunStore::new(dir.path().join("goals")).unwrap();
+        let dispatcher = EventDispatcher::new();
+
+        let working = running_goal(600, Some(60));
+        let hung = running_goal(600, Some(60));
+        store.save(&working).unwrap();
+        store.save(&hung).unwrap();
+        assert!(store
+            .record_heartbeat(working.goal_run_id, Utc::now())
+            .unwrap());
+        assert!(!store
+            .record_heartbeat(uuid::Uuid::new_v4(), Utc::now())
+            .unwrap());
+
+        let reloaded = store.get(working.goal_run_id).unwrap().unwrap();
+        assert_eq!(reloaded.updated_at, working.updated_at);
+        assert!(reloaded.last_heartbeat.is_some());
+
+        let failed = sweep_timed_out_goals(&store, None, &dispatcher).unwrap();
+        assert_eq!(failed, vec![hung.goal_run_id]);
+    }
+}
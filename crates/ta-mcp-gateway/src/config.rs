@@ -3,12 +3,20 @@
 // GatewayConfig determines where the gateway stores its state: staging
 // workspaces, change stores, goal records, audit logs, and event logs.
 // The `for_project()` constructor generates sensible defaults under a
-// `.ta/` directory in the project root.
+// `.ta/` directory in the project root. Profiles (`for_project_profile()`)
+// namespace the per-run state under `.ta/profiles/<name>/` so isolated TA
+// contexts can share one repository.
 
 use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
+use ta_audit::{AuditError, AuditLog, Redactor};
 use ta_changeset::review_channel::ReviewChannelConfig;
+use ta_workspace::{BlobStore, JsonFileStore, WorkspaceError};
+
+/// Environment variable naming the active profile. `ta --profile <name>`
+/// sets it so spawned `ta` processes and the daemon use the same profile.
+pub const PROFILE_ENV: &str = "TA_PROFILE";
 
 /// Configuration for the MCP gateway server.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -53,12 +61,59 @@ pub struct GatewayConfig {
     /// Set via the `TA_IS_STAGING` environment variable.
     #[serde(default)]
     pub is_staging: bool,
+
+    /// Active profile, if any. Goals, drafts, staging, change stores, and
+    /// the audit and event logs live under `.ta/profiles/<name>/`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
 }
 
 impl GatewayConfig {
     /// Create a config with standard `.ta/` layout for a project.
+    ///
+    /// Honors the profile named by `TA_PROFILE`, if set.
     pub fn for_project(project_root: impl AsRef<Path>) -> Self {
-        let root = project_root.as_ref().to_path_buf();
+        match std::env::var(PROFILE_ENV) {
+            Ok(profile) if !profile.is_empty() => Self::for_project_profile(project_root, &profile),
+            _ => Self::unprofiled(project_root.as_ref()),
+        }
+    }
+
+    /// Create a config whose per-run state lives under
+    /// `.ta/profiles/<profile>/` instead of `.ta/`.
+    ///
+    /// Project-wide files (`.ta/workflow.toml`, the blob store, backups)
+    /// stay shared; goals, drafts, staging workspaces, change stores,
+    /// interactive sessions, and the audit and event logs are per profile.
+    pub fn for_project_profile(project_root: impl AsRef<Path>, profile: &str) -> Self {
+        let mut config = Self::unprofiled(project_root.as_ref());
+        let dir = config
+            .workspace_root
+            .join(".ta")
+            .join("profiles")
+            .join(profile);
+        config.staging_dir = dir.join("staging");
+        config.store_dir = dir.join("store");
+        config.goals_dir = dir.join("goals");
+        config.audit_log = dir.join("audit.jsonl");
+        config.events_log = dir.join("events.jsonl");
+        config.pr_packages_dir = dir.join("pr_packages");
+        config.interactive_sessions_dir = dir.join("interactive_sessions");
+        config.profile = Some(profile.to_string());
+        config
+    }
+
+    /// Whether `name` is usable as a profile: non-empty ASCII letters,
+    /// digits, `-`, and `_`, so it is always a single path component.
+    pub fn is_valid_profile_name(name: &str) -> bool {
+        !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    }
+
+    fn unprofiled(root: &Path) -> Self {
+        let root = root.to_path_buf();
         let ta_dir = root.join(".ta");
         Self {
             workspace_root: root,
@@ -72,6 +127,108 @@ impl GatewayConfig {
             review_channel: ReviewChannelConfig::default(),
             web_ui_port: None,
             is_staging: std::env::var("TA_IS_STAGING").is_ok(),
+            profile: None,
         }
     }
+
+    /// Content-addressed blob store for staged file contents (`.ta/blobs`).
+    ///
+    /// Shared by every goal's change store so identical content is kept once.
+    pub fn blobs_dir(&self) -> PathBuf {
+        self.workspace_root.join(".ta").join("blobs")
+    }
+
+    /// Pre-apply backups taken by `ta draft apply --backup` (`.ta/backups`).
+    pub fn backups_dir(&self) -> PathBuf {
+        self.workspace_root.join(".ta").join("backups")
+    }
+
+    /// Progress file for `ta run --plan-all` (`.ta/plan-all.json`).
+    ///
+    /// Per run state, so it lives under the active profile when one is set.
+    pub fn plan_all_state_path(&self) -> PathBuf {
+        let ta_dir = self.workspace_root.join(".ta");
+        match &self.profile {
+            Some(profile) => ta_dir.join("profiles").join(profile).join("plan-all.json"),
+            None => ta_dir.join("plan-all.json"),
+        }
+    }
+
+    /// Hand-written or installed capability manifests, one per agent
+    /// (`.ta/manifests/<agent>.yaml`). Project-wide, like policies.
+    pub fn installed_manifests_dir(&self) -> PathBuf {
+        self.workspace_root.join(".ta").join("manifests")
+    }
+
+    /// Capability manifests as issued at goal start, one file per manifest
+    /// ID (`<goals_dir>/manifests`).
+    pub fn issued_manifests_dir(&self) -> PathBuf {
+        self.goals_dir.join("manifests")
+    }
+
+    /// Open a goal's change store with the project blob store attached.
+    pub fn open_change_store(
+        &self,
+        store_path: impl AsRef<Path>,
+    ) -> Result<JsonFileStore, WorkspaceError> {
+        Ok(JsonFileStore::new(store_path)?.with_blob_store(BlobStore::new(self.blobs_dir())))
+    }
+
+    /// Open the audit log, redacting events with the project's
+    /// `.ta/redact.toml` wherever the profile puts the log.
+    pub fn open_audit_log(&self) -> Result<AuditLog, AuditError> {
+        Ok(AuditLog::open(&self.audit_log)?
+            .with_redactor(Redactor::for_project(&self.workspace_root)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn profile_namespaces_run_state() {
+        let config = GatewayConfig::for_project_profile("/repo", "experimental");
+        let profile_dir = Path::new("/repo/.ta/profiles/experimental");
+        assert_eq!(config.profile.as_deref(), Some("experimental"));
+        assert_eq!(config.goals_dir, profile_dir.join("goals"));
+        assert_eq!(config.pr_packages_dir, profile_dir.join("pr_packages"));
+        assert_eq!(config.staging_dir, profile_dir.join("staging"));
+        assert_eq!(config.audit_log, profile_dir.join("audit.jsonl"));
+        assert_eq!(
+            config.plan_all_state_path(),
+            profile_dir.join("plan-all.json")
+        );
+        // Project-wide state stays shared.
+        assert_eq!(config.workspace_root, Path::new("/repo"));
+        assert_eq!(config.blobs_dir(), Path::new("/repo/.ta/blobs"));
+    }
+
+    #[test]
+    fn profile_audit_log_redacts_with_project_patterns() {
+        let project = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(project.path().join(".ta")).unwrap();
+        std::fs::write(
+            project.path().join(".ta/redact.toml"),
+            "patterns = ['corp-[0-9a-f]{8}']\n",
+        )
+        .unwrap();
+        let config = GatewayConfig::for_project_profile(project.path(), "experimental");
+        std::fs::create_dir_all(config.audit_log.parent().unwrap()).unwrap();
+
+        let mut event = ta_audit::AuditEvent::new("agent-1", ta_audit::AuditAction::ToolCall);
+        event.metadata = serde_json::json!({ "ticket": "corp-deadbeef" });
+        config.open_audit_log().unwrap().append(&mut event).unwrap();
+
+        let raw = std::fs::read_to_string(&config.audit_log).unwrap();
+        assert!(!raw.contains("corp-deadbeef"), "{}", raw);
+    }
+
+    #[test]
+    fn profile_names_are_single_path_components() {
+        assert!(GatewayConfig::is_valid_profile_name("exp-2_b"));
+        assert!(!GatewayConfig::is_valid_profile_name(""));
+        assert!(!GatewayConfig::is_valid_profile_name("../main"));
+        assert!(!GatewayConfig::is_valid_profile_name("a/b"));
+    }
 }
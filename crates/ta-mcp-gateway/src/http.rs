@@ -0,0 +1,274 @@
+//! http.rs — Streamable HTTP + SSE transport for the MCP gateway.
+//!
+//! Serves the same [`TaGatewayServer`] that normally speaks MCP over stdio
+//! behind rmcp's streamable HTTP service, mounted at [`MCP_HTTP_PATH`].
+//! Each HTTP session gets a clone of the server, so all sessions share one
+//! gateway state.
+//!
+//! Loopback binds may run without a token. Any other bind address requires a
+//! bearer token, checked on every request (`Authorization: Bearer <token>`).
+//! Without a token, requests must name a loopback `Host` and carry no
+//! cross-origin `Origin`, so a web page cannot reach the gateway through
+//! DNS rebinding.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::sync::Arc;
+
+use axum::extract::{Request, State};
+use axum::http::{header, StatusCode};
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
+use rmcp::transport::streamable_http_server::session::local::LocalSessionManager;
+use rmcp::transport::{StreamableHttpServerConfig, StreamableHttpService};
+use subtle::ConstantTimeEq;
+
+use crate::error::GatewayError;
+use crate::server::TaGatewayServer;
+
+/// Default address for `ta serve --http` when no address is given.
+pub const DEFAULT_HTTP_ADDR: &str = "127.0.0.1:7800";
+
+/// Path the MCP endpoint is mounted at.
+pub const MCP_HTTP_PATH: &str = "/mcp";
+
+/// Parse an `--http` address. A bare port binds to localhost.
+pub fn resolve_http_addr(addr: &str) -> Result<SocketAddr, GatewayError> {
+    if let Ok(port) = addr.parse::<u16>() {
+        return Ok(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), port));
+    }
+    addr.parse::<SocketAddr>()
+        .map_err(|e| GatewayError::Other(format!("invalid HTTP address '{}': {}", addr, e)))
+}
+
+/// Refuse to expose the gateway beyond loopback without a bearer token.
+pub fn check_http_bind(addr: &SocketAddr, auth_token: Option<&str>) -> Result<(), GatewayError> {
+    let has_token = auth_token.is_some_and(|t| !t.is_empty());
+    if !addr.ip().is_loopback() && !has_token {
+        return Err(GatewayError::Other(format!(
+            "refusing to serve MCP over HTTP on non-loopback address {} without a bearer token",
+            addr
+        )));
+    }
+    Ok(())
+}
+
+/// Build the HTTP router: the MCP service at [`MCP_HTTP_PATH`], wrapped in
+/// bearer-token authentication when `auth_token` is set, or in a loopback
+/// `Host`/`Origin` check when it is not.
+pub fn router(server: TaGatewayServer, auth_token: Option<String>) -> axum::Router {
+    let service = StreamableHttpService::new(
+        move || Ok(server.clone()),
+        Arc::new(LocalSessionManager::default()),
+        StreamableHttpServerConfig::default(),
+    );
+    let router = axum::Router::new().nest_service(MCP_HTTP_PATH, service);
+    match auth_token.filter(|t| !t.is_empty()) {
+        Some(token) => router.layer(middleware::from_fn_with_state(
+            Arc::new(token),
+            require_bearer,
+        )),
+        None => router.layer(middleware::from_fn(require_loopback_origin)),
+    }
+}
+
+/// Serve the gateway over HTTP + SSE until Ctrl-C.
+pub async fn serve_http(
+    server: TaGatewayServer,
+    addr: SocketAddr,
+    auth_token: Option<String>,
+) -> Result<(), GatewayError> {
+    check_http_bind(&addr, auth_token.as_deref())?;
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    tracing::info!(
+        addr = %listener.local_addr()?,
+        path = MCP_HTTP_PATH,
+        auth = auth_token.is_some(),
+        "MCP transport: HTTP + SSE"
+    );
+    axum::serve(listener, router(server, auth_token))
+        .with_graceful_shutdown(async {
+            let _ = tokio::signal::ctrl_c().await;
+        })
+        .await?;
+    Ok(())
+}
+
+async fn require_bearer(
+    State(token): State<Arc<String>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let presented = request
+        .headers()
+        .get(header::AUTHORIZATION)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.strip_prefix("Bearer "));
+    if presented.is_some_and(|p| bool::from(p.as_bytes().ct_eq(token.as_bytes()))) {
+        next.run(request).await
+    } else {
+        (
+            StatusCode::UNAUTHORIZED,
+            [(header::WWW_AUTHENTICATE, "Bearer")],
+            "missing or invalid bearer token",
+        )
+            .into_response()
+    }
+}
+
+async fn require_loopback_origin(request: Request, next: Next) -> Response {
+    let headers = request.headers();
+    let host_ok = headers
+        .get(header::HOST)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(is_loopback_host);
+    let origin_ok = match headers.get(header::ORIGIN) {
+        None => true,
+        Some(v) => v.to_str().ok().is_some_and(is_loopback_origin),
+    };
+    if host_ok && origin_ok {
+        next.run(request).await
+    } else {
+        (
+            StatusCode::FORBIDDEN,
+            "only loopback Host and Origin are accepted without a bearer token",
+        )
+            .into_response()
+    }
+}
+
+/// True when a `Host` header value (`name[:port]`) names this machine.
+fn is_loopback_host(host: &str) -> bool {
+    let name = match host.strip_prefix('[') {
+        Some(rest) => rest.split(']').next().unwrap_or_default(),
+        None => host.rsplit_once(':').map_or(host, |(name, _)| name),
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+/// True when an `Origin` header value is an http(s) origin on this machine.
+fn is_loopback_origin(origin: &str) -> bool {
+    origin
+        .strip_prefix("http://")
+        .or_else(|| origin.strip_prefix("https://"))
+        .is_some_and(|rest| is_loopback_host(rest.split('/').next().unwrap_or_default()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bare_port_binds_localhost() {
+        let addr = resolve_http_addr("8123").unwrap();
+        assert_eq!(addr, "127.0.0.1:8123".parse().unwrap());
+        assert_eq!(
+            resolve_http_addr("0.0.0.0:9000").unwrap(),
+            "0.0.0.0:9000".parse().unwrap()
+        );
+        assert!(resolve_http_addr("not-an-addr").is_err());
+    }
+
+    #[test]
+    fn non_loopback_bind_requires_token() {
+        let local = resolve_http_addr(DEFAULT_HTTP_ADDR).unwrap();
+        assert!(check_http_bind(&local, None).is_ok());
+
+        let public: SocketAddr = "0.0.0.0:7800".parse().unwrap();
+        assert!(check_http_bind(&public, None).is_err());
+        assert!(check_http_bind(&public, Some("")).is_err());
+        assert!(check_http_bind(&public, Some("s3cret")).is_ok());
+    }
+
+    #[test]
+    fn loopback_host_and_origin_detection() {
+        assert!(is_loopback_host("localhost"));
+        assert!(is_loopback_host("LOCALHOST:7800"));
+        assert!(is_loopback_host("127.0.0.1:7800"));
+        assert!(is_loopback_host("[::1]:7800"));
+        assert!(!is_loopback_host("evil.example:7800"));
+        assert!(!is_loopback_host("localhost.evil.example"));
+
+        assert!(is_loopback_origin("http://localhost:3000"));
+        assert!(is_loopback_origin("https://127.0.0.1"));
+        assert!(!is_loopback_origin("http://evil.example"));
+        assert!(!is_loopback_origin("null"));
+    }
+
+    #[tokio::test]
+    async fn tokenless_loopback_rejects_foreign_host_and_origin() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let server = TaGatewayServer::new(crate::GatewayConfig::for_project(dir.path())).unwrap();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = router(server, None);
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let status = |headers: &'static str| async move {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+            let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+            let req = format!(
+                "GET {} HTTP/1.1\r\n{}Connection: close\r\n\r\n",
+                MCP_HTTP_PATH, headers
+            );
+            stream.write_all(req.as_bytes()).await.unwrap();
+            let mut buf = vec![0u8; 64];
+            let n = stream.read(&mut buf).await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        };
+
+        // DNS rebinding: the browser sends the attacker's host name.
+        assert!(status("Host: evil.example:7800\r\n")
+            .await
+            .starts_with("HTTP/1.1 403"));
+        // Cross-origin request from a web page.
+        assert!(status("Host: localhost\r\nOrigin: http://evil.example\r\n")
+            .await
+            .starts_with("HTTP/1.1 403"));
+        // Local clients reach the MCP service.
+        assert!(!status("Host: localhost\r\n")
+            .await
+            .starts_with("HTTP/1.1 403"));
+        assert!(
+            !status("Host: 127.0.0.1\r\nOrigin: http://localhost:3000\r\n")
+                .await
+                .starts_with("HTTP/1.1 403")
+        );
+    }
+
+    #[tokio::test]
+    async fn bearer_token_is_enforced() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let server = TaGatewayServer::new(crate::GatewayConfig::for_project(dir.path())).unwrap();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let app = router(server, Some("s3cret".to_string()));
+        tokio::spawn(async move { axum::serve(listener, app).await });
+
+        let status = |auth: Option<&'static str>| async move {
+            use tokio::io::{AsyncReadExt, AsyncWriteExt};
+            let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+            let auth = auth
+                .map(|a| format!("Authorization: {}\r\n", a))
+                .unwrap_or_default();
+            let req = format!(
+                "GET {} HTTP/1.1\r\nHost: localhost\r\n{}Connection: close\r\n\r\n",
+                MCP_HTTP_PATH, auth
+            );
+            stream.write_all(req.as_bytes()).await.unwrap();
+            let mut buf = vec![0u8; 64];
+            let n = stream.read(&mut buf).await.unwrap();
+            String::from_utf8_lossy(&buf[..n]).to_string()
+        };
+
+        assert!(status(None).await.starts_with("HTTP/1.1 401"));
+        assert!(status(Some("Bearer wrong"))
+            .await
+            .starts_with("HTTP/1.1 401"));
+        // Authenticated requests reach the MCP service (which rejects a bare
+        // GET without a session, but not with 401).
+        assert!(!status(Some("Bearer s3cret"))
+            .await
+            .starts_with("HTTP/1.1 401"));
+    }
+}
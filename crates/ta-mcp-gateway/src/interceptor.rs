@@ -31,9 +31,11 @@ impl ToolCallInterceptor {
             "ta_goal_status",
             "ta_goal_list",
             "ta_pr_status",
-            "ta_draft",   // draft management is TA-internal, not external
-            "ta_plan",    // plan reading is read-only
-            "ta_context", // memory operations are TA-internal
+            "ta_draft", // draft management is TA-internal, not external
+            "ta_draft_build",
+            "ta_plan",      // plan reading is read-only
+            "ta_context",   // memory operations are TA-internal
+            "ta_heartbeat", // liveness ping, no external effect
         ];
         Self {
             passthrough_tools: passthrough.iter().map(|s| s.to_string()).collect(),
@@ -9,7 +9,8 @@
 //!
 //! ## Architecture
 //!
-//! The gateway server runs as a stdio MCP server. Each tool call:
+//! The gateway server runs as a stdio MCP server, or over HTTP + SSE via
+//! [`http::serve_http`] for remote agents and browser clients. Each tool call:
 //! 1. Checks the policy engine (default deny)
 //! 2. Routes to the appropriate connector (filesystem, etc.)
 //! 3. Records an audit event
@@ -20,16 +21,19 @@
 //! - `ta_goal_start` — create a GoalRun, issue manifest, allocate workspace
 //! - `ta_goal_status` — get current state of a GoalRun
 //! - `ta_goal_list` — list GoalRuns
+//! - `ta_heartbeat` — report that the agent on a goal is still alive
 //! - `ta_fs_read` — read file from source directory
 //! - `ta_fs_write` — write file to staging
 //! - `ta_fs_list` — list staged files
 //! - `ta_fs_diff` — show diff for a staged file
 //! - `ta_pr_build` — bundle staged changes into PR package
+//! - `ta_draft_build` — build a draft from a macro goal's overlay workspace
 //! - `ta_pr_status` — check PR package status
 //! - `ta_event_subscribe` — query/watch events for orchestration (v0.9.4)
 
 pub mod config;
 pub mod error;
+pub mod http;
 pub mod interceptor;
 pub mod server;
 pub mod tools;
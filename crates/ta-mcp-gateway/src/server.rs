@@ -20,9 +20,9 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
-use ta_audit::{AttestationBackend, AuditLog, SoftwareAttestationBackend};
+use ta_audit::{AttestationBackend, AuditLog, RotationPolicy, SoftwareAttestationBackend};
 use ta_changeset::channel_registry;
-use ta_changeset::interaction::{InteractionRequest, Notification};
+use ta_changeset::interaction::{InteractionRequest, Notification, NotificationLevel};
 use ta_changeset::multi_channel::MultiChannelStrategy;
 use ta_changeset::pr_package::PRPackage;
 use ta_changeset::review_channel::{ReviewChannel, ReviewChannelError};
@@ -30,7 +30,8 @@ use ta_connector_fs::FsConnector;
 use ta_goal::{EventDispatcher, GoalRun, GoalRunState, GoalRunStore, LogSink, TaEvent};
 use ta_memory::FsMemoryStore;
 use ta_policy::{
-    AlignmentProfile, CompilerOptions, PolicyCompiler, PolicyDecision, PolicyEngine, PolicyRequest,
+    AlignmentProfile, CompilerOptions, ManifestStore, PolicyCompiler, PolicyDecision, PolicyEngine,
+    PolicyRequest,
 };
 use ta_workspace::{JsonFileStore, StagingWorkspace};
 
@@ -176,6 +177,16 @@ pub struct DraftToolParams {
     pub require_review: Option<bool>,
 }
 
+/// Parameters for `ta_draft_build`.
+#[derive(Debug, Deserialize, JsonSchema)]
+pub struct DraftBuildParams {
+    /// The UUID of the goal run whose staging workspace should be packaged.
+    pub goal_run_id: String,
+    /// Summary of what changed and why.
+    #[serde(default)]
+    pub summary: Option<String>,
+}
+
 /// Parameters for `ta_goal` (inner-loop agent tool).
 #[derive(Debug, Deserialize, JsonSchema)]
 pub struct GoalToolParams {
@@ -356,7 +367,10 @@ impl ProjectState {
 
         let goal_store = GoalRunStore::new(&goals_dir)?;
         let mut event_dispatcher = EventDispatcher::new();
-        event_dispatcher.add_sink(Box::new(LogSink::new(&events_log)));
+        event_dispatcher.add_sink(
+            Box::new(LogSink::new(&events_log)),
+            NotificationLevel::Debug,
+        );
         let memory_store = FsMemoryStore::new(memory_dir);
 
         Ok(Self {
@@ -400,6 +414,9 @@ pub struct GatewayState {
     pub projects: HashMap<String, ProjectState>,
     /// v0.10.18: Currently active project name for this session.
     pub active_project: Option<String>,
+    /// Record every policy decision with its evaluation trace
+    /// (`[audit] policy_trace` in workflow.toml).
+    pub policy_trace: bool,
 }
 
 /// Caller mode determines what operations the MCP gateway allows.
@@ -490,7 +507,10 @@ impl GatewayState {
 
         // Optionally attach Ed25519 attestation backend when enabled in workflow.toml.
         let audit_log = {
-            let log = AuditLog::open(&config.audit_log)?;
+            let log = config.open_audit_log()?.with_rotation(RotationPolicy {
+                max_bytes: wf.audit.max_bytes,
+                keep_segments: wf.audit.keep_segments,
+            });
             if wf.audit.attestation {
                 let keys_dir = if wf.audit.keys_dir.starts_with('/') {
                     std::path::PathBuf::from(&wf.audit.keys_dir)
@@ -517,7 +537,10 @@ impl GatewayState {
         };
 
         let mut event_dispatcher = EventDispatcher::new();
-        event_dispatcher.add_sink(Box::new(LogSink::new(&config.events_log)));
+        event_dispatcher.add_sink(
+            Box::new(LogSink::new(&config.events_log)),
+            NotificationLevel::Debug,
+        );
         let memory_store = FsMemoryStore::new(config.workspace_root.join(".ta").join("memory"));
 
         let auto_capture_config = ta_memory::auto_capture::load_config(&workflow_toml);
@@ -525,6 +548,7 @@ impl GatewayState {
         // v0.10.0: Load channel routing from .ta/config.yaml and build review
         // channel(s) via ChannelRegistry instead of hardcoding AutoApproveChannel.
         let review_channel = Self::build_review_channel(&config);
+        let policy_trace = wf.audit.policy_trace;
 
         Ok(Self {
             config,
@@ -545,6 +569,7 @@ impl GatewayState {
             active_agents: HashMap::new(),
             projects: HashMap::new(),
             active_project: None,
+            policy_trace,
         })
     }
 
@@ -643,15 +668,36 @@ impl GatewayState {
         let mut goal_run = GoalRun::new(title, objective, agent_id, staging_path, store_path);
         goal_run.goal_run_id = goal_run_id;
 
-        let profile = AlignmentProfile::default_developer();
+        // The agent's installed manifest, if any, replaces the default profile.
         let options = CompilerOptions::default();
-        let manifest =
-            PolicyCompiler::compile_with_id(goal_run.manifest_id, agent_id, &profile, &options)
-                .map_err(|e| GatewayError::Other(format!("policy compilation failed: {}", e)))?;
+        let installed = PolicyCompiler::compile_installed_manifest(
+            &self.config.installed_manifests_dir(),
+            agent_id,
+            &options,
+        )
+        .map_err(|e| GatewayError::Other(format!("policy compilation failed: {}", e)))?;
+        let manifest = match installed {
+            Some(mut manifest) => {
+                manifest.manifest_id = goal_run.manifest_id;
+                manifest
+            }
+            None => PolicyCompiler::compile_with_id(
+                goal_run.manifest_id,
+                agent_id,
+                &AlignmentProfile::default_developer(),
+                &options,
+            )
+            .map_err(|e| GatewayError::Other(format!("policy compilation failed: {}", e)))?,
+        };
+        ManifestStore::new(self.config.issued_manifests_dir())
+            .save(&manifest)
+            .map_err(|e| GatewayError::Other(format!("cannot record manifest: {}", e)))?;
         self.policy_engine.load_manifest(manifest);
 
         let staging = StagingWorkspace::new(goal_run_id.to_string(), &self.config.staging_dir)?;
-        let store = JsonFileStore::new(self.config.store_dir.join(goal_run_id.to_string()))?;
+        let store = self
+            .config
+            .open_change_store(self.config.store_dir.join(goal_run_id.to_string()))?;
         let connector = FsConnector::new(goal_run_id.to_string(), staging, store, agent_id);
         self.connectors.insert(goal_run_id, connector);
 
@@ -688,10 +734,15 @@ impl GatewayState {
         let manifest =
             PolicyCompiler::compile_with_id(goal_run.manifest_id, agent_id, profile, &options)
                 .map_err(|e| GatewayError::Other(format!("policy compilation failed: {}", e)))?;
+        ManifestStore::new(self.config.issued_manifests_dir())
+            .save(&manifest)
+            .map_err(|e| GatewayError::Other(format!("cannot record manifest: {}", e)))?;
         self.policy_engine.load_manifest(manifest);
 
         let staging = StagingWorkspace::new(goal_run_id.to_string(), &self.config.staging_dir)?;
-        let store = JsonFileStore::new(self.config.store_dir.join(goal_run_id.to_string()))?;
+        let store = self
+            .config
+            .open_change_store(self.config.store_dir.join(goal_run_id.to_string()))?;
         let connector = FsConnector::new(goal_run_id.to_string(), staging, store, agent_id);
         self.connectors.insert(goal_run_id, connector);
 
@@ -706,11 +757,15 @@ impl GatewayState {
     }
 
     /// Check policy for a filesystem operation.
+    ///
+    /// Non-allow decisions are always audited. With `policy_trace` enabled,
+    /// every decision is audited together with its full evaluation trace.
     pub fn check_policy(
-        &self,
+        &mut self,
         agent_id: &str,
         verb: &str,
         path: &str,
+        goal_run_id: Option<Uuid>,
     ) -> Result<PolicyDecision, GatewayError> {
         let request = PolicyRequest {
             agent_id: agent_id.to_string(),
@@ -718,10 +773,95 @@ impl GatewayState {
             verb: verb.to_string(),
             target_uri: format!("fs://workspace/{}", path),
         };
-        Ok(self.policy_engine.evaluate(&request))
+        // Read-only goals are held to the manifest they were issued, whichever
+        // process started them.
+        if let Some(goal_engine) = goal_run_id.and_then(|id| self.readonly_goal_engine(id)) {
+            let trace = goal_engine.evaluate_with_trace(&request);
+            if trace.decision != PolicyDecision::Allow {
+                let decision = trace.decision.clone();
+                let trace = self.policy_trace.then_some(&trace);
+                self.audit_policy_decision(&request, &decision, goal_run_id, trace);
+                return Ok(decision);
+            }
+        }
+        if self.policy_trace {
+            let trace = self.policy_engine.evaluate_with_trace(&request);
+            self.audit_policy_decision(&request, &trace.decision, goal_run_id, Some(&trace));
+            return Ok(trace.decision);
+        }
+        let decision = self.policy_engine.evaluate(&request);
+        if decision != PolicyDecision::Allow {
+            self.audit_policy_decision(&request, &decision, goal_run_id, None);
+        }
+        Ok(decision)
+    }
+
+    /// A policy engine holding the manifest issued to `goal_run_id`, if that
+    /// goal is read-only. Falls back to compiling the read-only profile when
+    /// no manifest was recorded; if even that fails the engine is empty and
+    /// denies everything.
+    fn readonly_goal_engine(&self, goal_run_id: Uuid) -> Option<PolicyEngine> {
+        let goal = self.goal_store.get(goal_run_id).ok().flatten()?;
+        if !goal.readonly {
+            return None;
+        }
+        let manifest =
+            match ManifestStore::new(self.config.issued_manifests_dir()).load(goal.manifest_id) {
+                Ok(Some(manifest)) => Some(manifest),
+                _ => PolicyCompiler::compile_with_id(
+                    goal.manifest_id,
+                    &goal.agent_id,
+                    &AlignmentProfile::read_only(),
+                    &CompilerOptions::default(),
+                )
+                .ok(),
+            };
+        let mut engine = PolicyEngine::new();
+        if let Some(manifest) = manifest {
+            engine.load_manifest(manifest);
+        }
+        Some(engine)
+    }
+
+    /// Record a policy decision in the audit log, with its reason code in the
+    /// metadata so denials can be aggregated by category. When a trace is
+    /// given it is stored under `metadata.trace` for `ta policy trace`.
+    fn audit_policy_decision(
+        &mut self,
+        request: &PolicyRequest,
+        decision: &PolicyDecision,
+        goal_run_id: Option<Uuid>,
+        trace: Option<&ta_policy::EvaluationTrace>,
+    ) {
+        let mut metadata = serde_json::json!({
+            "tool": request.tool,
+            "verb": request.verb,
+            "decision": decision,
+        });
+        if let Some(trace) = trace {
+            metadata["trace"] = serde_json::to_value(trace).unwrap_or_default();
+        }
+        let mut event =
+            ta_audit::AuditEvent::new(&request.agent_id, ta_audit::AuditAction::PolicyDecision)
+                .with_caller_mode(self.caller_mode.as_str())
+                .with_target(&request.target_uri)
+                .with_metadata(metadata);
+        if let Some(id) = goal_run_id {
+            event = event.with_goal_run_id(id);
+        }
+        if let Err(e) = self.audit_log.append(&mut event) {
+            tracing::warn!(
+                verb = %request.verb,
+                error = %e,
+                "failed to write policy-decision audit entry"
+            );
+        }
     }
 
     /// Save a PR package to both in-memory cache and disk.
+    ///
+    /// The on-disk write is atomic and takes the same advisory lock as the
+    /// CLI's `save_package`, so `ta draft list` never reads a partial file.
     pub fn save_pr_package(&mut self, pkg: PRPackage) -> Result<(), GatewayError> {
         let package_id = pkg.package_id;
         std::fs::create_dir_all(&self.config.pr_packages_dir)?;
@@ -731,7 +871,7 @@ impl GatewayState {
             .join(format!("{}.json", package_id));
         let json =
             serde_json::to_string_pretty(&pkg).map_err(|e| GatewayError::Other(e.to_string()))?;
-        std::fs::write(&path, json)?;
+        ta_goal::file_lock::write_atomic_locked(&path, json.as_bytes())?;
         self.pr_packages.insert(package_id, pkg);
         Ok(())
     }
@@ -856,6 +996,10 @@ impl GatewayState {
 // ── MCP Server ───────────────────────────────────────────────────
 
 /// The MCP gateway server. Holds shared state and the tool router.
+///
+/// Clones share the same state, so one gateway can back several
+/// concurrent sessions (e.g. the HTTP transport).
+#[derive(Clone)]
 pub struct TaGatewayServer {
     state: Arc<Mutex<GatewayState>>,
     tool_router: ToolRouter<Self>,
@@ -923,6 +1067,16 @@ impl TaGatewayServer {
         tools::goal::handle_goal_list(&self.state, params)
     }
 
+    #[tool(
+        description = "Report that you are still working on a goal. Call it every few minutes during long tasks so TA can tell a slow goal from a hung one; goals with no heartbeat or state change past their timeout are failed."
+    )]
+    fn ta_heartbeat(
+        &self,
+        Parameters(params): Parameters<GoalIdParams>,
+    ) -> Result<CallToolResult, McpError> {
+        tools::goal::handle_heartbeat(&self.state, &params.goal_run_id)
+    }
+
     // ── Filesystem tools ─────────────────────────────────────
 
     #[tool(
@@ -1012,6 +1166,17 @@ impl TaGatewayServer {
         tools::draft::handle_draft(&self.state, params)
     }
 
+    #[tool(
+        description = "Build a draft from the files you changed in your staging workspace (the same overlay diff as `ta draft build`). Only available to macro goal sessions. Runs the project's summary enforcement; the draft still needs human approval before it can be applied. Returns the draft_id."
+    )]
+    fn ta_draft_build(
+        &self,
+        Parameters(params): Parameters<DraftBuildParams>,
+    ) -> Result<CallToolResult, McpError> {
+        self.audit("ta_draft_build", None, params.goal_run_id.parse().ok());
+        tools::draft::handle_overlay_draft_build(&self.state, params)
+    }
+
     #[tool(
         description = "Manage sub-goals within a macro goal session. Actions: start (create a sub-goal), status (check sub-goal progress). Set launch:true to spawn the implementation agent in the background."
     )]
@@ -1475,7 +1640,7 @@ mod tests {
         // 26 tools: goal_start, goal_status, goal_list,
         //           fs_read, fs_write, fs_list, fs_diff,
         //           pr_build, pr_status,
-        //           ta_draft, ta_goal_inner, ta_plan, ta_plan_status (v0.14.3.2),
+        //           ta_draft, ta_draft_build, ta_goal_inner, ta_plan, ta_plan_status (v0.14.3.2),
         //           ta_context, ta_agent_status (v0.9.6), ta_event_subscribe (v0.9.4),
         //           ta_workflow (v0.9.8.2), ta_ask_human (v0.9.9.1),
         //           ta_external_action (v0.13.4),
@@ -1483,9 +1648,10 @@ mod tests {
         //           ue5_mrq_submit, ue5_mrq_status (v0.14.14),
         //           ue5_sequencer_query, ue5_lighting_preset_list (v0.14.15.1)
         //           unity_build_trigger, unity_scene_query, unity_test_run,
-        //           unity_addressables_build, unity_render_capture (v0.15.3)
+        //           unity_addressables_build, unity_render_capture (v0.15.3),
+        //           ta_heartbeat
         let names: Vec<String> = tools.iter().map(|t| t.name.to_string()).collect();
-        assert_eq!(tools.len(), 35, "expected 35 tools, got: {:?}", names);
+        assert_eq!(tools.len(), 37, "expected 37 tools, got: {:?}", names);
     }
 
     #[test]
@@ -1518,7 +1684,7 @@ mod tests {
     #[test]
     fn start_goal_issues_manifest() {
         let (server, _dir) = test_server();
-        let _goal_id = start_goal(&server);
+        let goal_id = start_goal(&server);
 
         let state = server.state.lock().unwrap();
         let decision = state.policy_engine.evaluate(&PolicyRequest {
@@ -1528,6 +1694,97 @@ mod tests {
             target_uri: "fs://workspace/src/main.rs".to_string(),
         });
         assert_eq!(decision, PolicyDecision::Allow);
+
+        // The issued manifest is recorded under the goal's manifest ID.
+        let goal = state.goal_store.get(goal_id).unwrap().unwrap();
+        let recorded = ManifestStore::new(state.config.issued_manifests_dir())
+            .load(goal.manifest_id)
+            .unwrap()
+            .expect("issued manifest recorded");
+        assert_eq!(recorded.agent_id, "test-agent");
+        assert!(!recorded.grants.is_empty());
+    }
+
+    #[test]
+    fn start_goal_issues_installed_manifest_with_includes() {
+        let (server, dir) = test_server();
+        let manifests = dir.path().join(".ta/manifests");
+        std::fs::create_dir_all(&manifests).unwrap();
+        std::fs::write(
+            manifests.join("base.yaml"),
+            "grants:\n- { tool: fs, verb: read, resource_pattern: 'fs://workspace/**' }\n",
+        )
+        .unwrap();
+        std::fs::write(
+            manifests.join("test-agent.yaml"),
+            "includes: [base]\n\
+             grants:\n- { tool: fs, verb: write_patch, resource_pattern: 'fs://workspace/docs/**' }\n",
+        )
+        .unwrap();
+        let goal_id = start_goal(&server);
+
+        let state = server.state.lock().unwrap();
+        let decide = |verb: &str, path: &str| {
+            state.policy_engine.evaluate(&PolicyRequest {
+                agent_id: "test-agent".to_string(),
+                tool: "fs".to_string(),
+                verb: verb.to_string(),
+                target_uri: format!("fs://workspace/{}", path),
+            })
+        };
+        assert_eq!(decide("read", "src/main.rs"), PolicyDecision::Allow);
+        assert_eq!(
+            decide("write_patch", "docs/guide.md"),
+            PolicyDecision::Allow
+        );
+        assert_ne!(decide("write_patch", "src/main.rs"), PolicyDecision::Allow);
+
+        let goal = state.goal_store.get(goal_id).unwrap().unwrap();
+        let recorded = ManifestStore::new(state.config.issued_manifests_dir())
+            .load(goal.manifest_id)
+            .unwrap()
+            .unwrap();
+        assert_eq!(recorded.agent_id, "test-agent");
+        assert!(!recorded.includes.is_empty());
+    }
+
+    #[test]
+    fn readonly_goal_is_held_to_its_issued_manifest() {
+        let (server, _dir) = test_server();
+        let goal_id = start_goal(&server);
+        let mut state = server.state.lock().unwrap();
+
+        // Re-issue the goal read-only, as `ta run --readonly` does.
+        let mut goal = state.goal_store.get(goal_id).unwrap().unwrap();
+        goal.readonly = true;
+        state.goal_store.save(&goal).unwrap();
+        let readonly = PolicyCompiler::compile_with_id(
+            goal.manifest_id,
+            &goal.agent_id,
+            &AlignmentProfile::read_only(),
+            &CompilerOptions::default(),
+        )
+        .unwrap();
+        ManifestStore::new(state.config.issued_manifests_dir())
+            .save(&readonly)
+            .unwrap();
+
+        // The agent's default developer manifest would allow the write.
+        let write = state
+            .check_policy("test-agent", "write_patch", "src/lib.rs", Some(goal_id))
+            .unwrap();
+        assert_ne!(write, PolicyDecision::Allow);
+        let read = state
+            .check_policy("test-agent", "read", "src/lib.rs", Some(goal_id))
+            .unwrap();
+        assert_eq!(read, PolicyDecision::Allow);
+
+        // A read-only goal with no recorded manifest still gets read-only grants.
+        std::fs::remove_dir_all(state.config.issued_manifests_dir()).unwrap();
+        let write = state
+            .check_policy("test-agent", "write_patch", "src/lib.rs", Some(goal_id))
+            .unwrap();
+        assert_ne!(write, PolicyDecision::Allow);
     }
 
     #[test]
@@ -1539,6 +1796,52 @@ mod tests {
         assert!(state.connectors.contains_key(&goal_id));
     }
 
+    #[test]
+    fn heartbeat_records_liveness_without_touching_updated_at() {
+        let (server, _dir) = test_server();
+        let goal_id = start_goal(&server);
+        let before = {
+            let state = server.state.lock().unwrap();
+            state.goal_store.get(goal_id).unwrap().unwrap()
+        };
+        assert!(before.last_heartbeat.is_none());
+
+        tools::goal::handle_heartbeat(&server.state, &goal_id.to_string()).unwrap();
+
+        let state = server.state.lock().unwrap();
+        let after = state.goal_store.get(goal_id).unwrap().unwrap();
+        assert!(after.last_heartbeat.is_some());
+        assert_eq!(after.updated_at, before.updated_at);
+        drop(state);
+        assert!(tools::goal::handle_heartbeat(&server.state, &Uuid::new_v4().to_string()).is_err());
+    }
+
+    #[test]
+    fn draft_build_is_limited_to_running_macro_goals() {
+        let (server, _dir) = test_server();
+        let goal_id = start_goal(&server);
+        let params = |id: Uuid| DraftBuildParams {
+            goal_run_id: id.to_string(),
+            summary: None,
+        };
+
+        // Ordinary goals are built by a human, never by the agent itself.
+        let err =
+            tools::draft::handle_overlay_draft_build(&server.state, params(goal_id)).unwrap_err();
+        assert!(err.message.contains("only available to macro goal"));
+
+        {
+            let state = server.state.lock().unwrap();
+            let mut goal = state.goal_store.get(goal_id).unwrap().unwrap();
+            goal.is_macro = true;
+            goal.transition(GoalRunState::PrReady).unwrap();
+            state.goal_store.save(&goal).unwrap();
+        }
+        let err =
+            tools::draft::handle_overlay_draft_build(&server.state, params(goal_id)).unwrap_err();
+        assert!(err.message.contains("not running"));
+    }
+
     #[test]
     fn fs_write_stages_file() {
         let (server, _dir) = test_server();
@@ -1549,7 +1852,7 @@ mod tests {
         let cs = connector
             .write_patch("hello.txt", b"Hello from TA!")
             .unwrap();
-        assert_eq!(cs.target_uri, "fs://workspace/hello.txt");
+        assert_eq!(cs.target_uri(), "fs://workspace/hello.txt");
 
         let content = connector.read_staged("hello.txt").unwrap();
         assert_eq!(content, b"Hello from TA!");
@@ -1938,4 +2241,58 @@ mod tests {
         assert_eq!(last.goal_run_id, Some(goal_id));
         assert_eq!(last.target_uri.as_deref(), Some("fs://workspace/foo.rs"));
     }
+
+    #[test]
+    fn denied_policy_check_is_audited_with_reason_code() {
+        let (server, _dir) = test_server();
+        let mut state = server.state.lock().unwrap();
+        let decision = state
+            .check_policy("unknown", "read", "src/lib.rs", None)
+            .unwrap();
+        assert_eq!(
+            decision.reason_code(),
+            Some(ta_policy::PolicyReasonCode::NoManifest)
+        );
+
+        let events = ta_audit::AuditLog::read_all(state.audit_log.path()).unwrap();
+        let last = events.last().unwrap();
+        assert_eq!(last.action, ta_audit::AuditAction::PolicyDecision);
+        assert_eq!(last.metadata["decision"]["decision"], "deny");
+        assert_eq!(last.metadata["decision"]["reason_code"], "no_manifest");
+        assert!(last.metadata["decision"]["reason"]
+            .as_str()
+            .unwrap()
+            .contains("no capability manifest"));
+    }
+
+    #[test]
+    fn policy_trace_mode_audits_every_decision_with_trace() {
+        let (server, _dir) = test_server();
+        let goal_id = start_goal(&server);
+        let mut state = server.state.lock().unwrap();
+        state.policy_trace = true;
+
+        let allowed = state
+            .check_policy("test-agent", "read", "src/lib.rs", Some(goal_id))
+            .unwrap();
+        assert_eq!(allowed, PolicyDecision::Allow);
+        let denied = state
+            .check_policy("test-agent", "read", "../etc/passwd", Some(goal_id))
+            .unwrap();
+        assert_eq!(
+            denied.reason_code(),
+            Some(ta_policy::PolicyReasonCode::PathTraversal)
+        );
+
+        let events: Vec<_> = ta_audit::AuditLog::read_all(state.audit_log.path())
+            .unwrap()
+            .into_iter()
+            .filter(|e| e.action == ta_audit::AuditAction::PolicyDecision)
+            .collect();
+        assert_eq!(events.len(), 2);
+        assert!(events.iter().all(|e| e.goal_run_id == Some(goal_id)));
+        assert_eq!(events[0].metadata["decision"]["decision"], "allow");
+        assert!(events[0].metadata["trace"]["matching_grant"].is_string());
+        assert_eq!(events[1].metadata["trace"]["reason_code"], "path_traversal");
+    }
 }
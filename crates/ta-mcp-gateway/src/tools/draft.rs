@@ -11,7 +11,7 @@ use ta_goal::{GoalRunState, TaEvent};
 use ta_memory::{AutoCapture, DraftRejectEvent};
 use ta_policy::auto_approve::{self, DraftInfo};
 
-use crate::server::{DraftToolParams, GatewayState, GoalIdParams, PrBuildParams};
+use crate::server::{DraftBuildParams, DraftToolParams, GatewayState, GoalIdParams, PrBuildParams};
 use crate::validation::parse_uuid;
 
 pub fn handle_pr_build(
@@ -295,6 +295,130 @@ fn handle_draft_build(
         })?]))
 }
 
+/// `ta_draft_build`: package the goal's overlay workspace into a draft.
+///
+/// The overlay diff, change_summary.json enrichment, and summary enforcement
+/// all live in the CLI's `ta draft build`, so this runs it as a subprocess (as
+/// `ta_goal_start` does with `ta run`) rather than duplicating that logic.
+/// Only macro goals and their sub-goals may build their own drafts; the result
+/// is an ordinary draft that still needs human approval before apply.
+pub fn handle_overlay_draft_build(
+    state: &Arc<Mutex<GatewayState>>,
+    params: DraftBuildParams,
+) -> Result<CallToolResult, McpError> {
+    let goal_run_id = parse_uuid(&params.goal_run_id)?;
+    let (goal, workspace_root) = {
+        let state = state
+            .lock()
+            .map_err(|e| McpError::internal_error(format!("lock poisoned: {}", e), None))?;
+        let goal = state
+            .goal_store
+            .get(goal_run_id)
+            .map_err(|e| McpError::internal_error(e.to_string(), None))?
+            .ok_or_else(|| {
+                McpError::invalid_params(format!("goal not found: {}", goal_run_id), None)
+            })?;
+        (goal, state.config.workspace_root.clone())
+    };
+
+    if !goal.is_macro && goal.parent_macro_id.is_none() {
+        return Err(McpError::invalid_params(
+            format!(
+                "ta_draft_build is only available to macro goal sessions; goal {} must be built by a human with `ta draft build`",
+                goal_run_id
+            ),
+            None,
+        ));
+    }
+    if goal.state != GoalRunState::Running {
+        return Err(McpError::invalid_params(
+            format!(
+                "goal {} is {}, not running — nothing to build",
+                goal_run_id, goal.state
+            ),
+            None,
+        ));
+    }
+
+    // Run the build without holding the state lock: it can take a while on
+    // large workspaces and other tool calls should not block on it.
+    let summary = params
+        .summary
+        .as_deref()
+        .unwrap_or("Changes from macro goal sub-task");
+    let output = std::process::Command::new("ta")
+        .arg("draft")
+        .arg("build")
+        .arg(goal_run_id.to_string())
+        .arg("--summary")
+        .arg(summary)
+        .current_dir(&workspace_root)
+        .stdin(std::process::Stdio::null())
+        .output()
+        .map_err(|e| {
+            McpError::internal_error(format!("failed to run ta draft build: {}", e), None)
+        })?;
+    if !output.status.success() {
+        // Summary enforcement and empty-diff errors come back to the agent verbatim
+        // so it can fix them (e.g. write change_summary.json) and retry.
+        return Err(McpError::internal_error(
+            format!(
+                "ta draft build failed: {}",
+                String::from_utf8_lossy(&output.stderr).trim()
+            ),
+            None,
+        ));
+    }
+
+    let mut state = state
+        .lock()
+        .map_err(|e| McpError::internal_error(format!("lock poisoned: {}", e), None))?;
+    let package_id = state
+        .goal_store
+        .get(goal_run_id)
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?
+        .and_then(|g| g.pr_package_id)
+        .ok_or_else(|| {
+            McpError::internal_error(
+                format!(
+                    "ta draft build did not record a draft for goal {}",
+                    goal_run_id
+                ),
+                None,
+            )
+        })?;
+
+    // Cache the package so ta_draft status/submit see it without a restart.
+    let path = state
+        .config
+        .pr_packages_dir
+        .join(format!("{}.json", package_id));
+    if let Some(pkg) = std::fs::read_to_string(&path)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+    {
+        state.pr_packages.insert(package_id, pkg);
+    }
+
+    // Record which draft the agent built, alongside the tool-call entry.
+    state.audit_tool_call(
+        "ta_draft_build",
+        Some(&format!("ta://draft/{}", package_id)),
+        Some(goal_run_id),
+    );
+
+    let response = serde_json::json!({
+        "draft_id": package_id.to_string(),
+        "goal_run_id": goal_run_id.to_string(),
+        "status": "built",
+        "message": "Draft built from the staging workspace. A human must approve it before it can be applied; call ta_draft with action: 'submit' to request review.",
+    });
+    Ok(CallToolResult::success(vec![Content::json(response)
+        .map_err(|e| {
+            McpError::internal_error(e.to_string(), None)
+        })?]))
+}
+
 fn handle_draft_submit(
     state: &mut GatewayState,
     params: &DraftToolParams,
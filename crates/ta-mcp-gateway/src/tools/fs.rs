@@ -4,6 +4,7 @@ use std::sync::{Arc, Mutex};
 
 use rmcp::model::*;
 use rmcp::ErrorData as McpError;
+use ta_connector_fs::WritePatchOutcome;
 
 use crate::server::{FsDiffParams, FsListParams, FsReadParams, FsWriteParams, GatewayState};
 use crate::validation::{enforce_policy, parse_uuid};
@@ -21,7 +22,7 @@ pub fn handle_fs_read(
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
     let decision = state
-        .check_policy(&agent_id, "read", &params.path)
+        .check_policy(&agent_id, "read", &params.path, Some(goal_run_id))
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
     enforce_policy(&decision)?;
 
@@ -72,7 +73,7 @@ pub fn handle_fs_write(
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
     let decision = state
-        .check_policy(&agent_id, "write_patch", &params.path)
+        .check_policy(&agent_id, "write_patch", &params.path, Some(goal_run_id))
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
     enforce_policy(&decision)?;
 
@@ -83,15 +84,22 @@ pub fn handle_fs_write(
         )
     })?;
 
-    let changeset = connector
+    let outcome = connector
         .write_patch(&params.path, params.content.as_bytes())
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
-    let response = serde_json::json!({
-        "changeset_id": changeset.changeset_id.to_string(),
-        "target_uri": changeset.target_uri,
-        "status": "staged",
-    });
+    let response = match outcome {
+        WritePatchOutcome::Staged(changeset) => serde_json::json!({
+            "changeset_id": changeset.changeset_id.to_string(),
+            "target_uri": changeset.target_uri,
+            "status": "staged",
+        }),
+        WritePatchOutcome::Unchanged { target_uri } => serde_json::json!({
+            "changeset_id": null,
+            "target_uri": target_uri,
+            "status": "unchanged",
+        }),
+    };
     Ok(CallToolResult::success(vec![Content::json(response)
         .map_err(|e| {
             McpError::internal_error(e.to_string(), None)
@@ -129,7 +137,7 @@ pub fn handle_fs_diff(
     state: &Arc<Mutex<GatewayState>>,
     params: FsDiffParams,
 ) -> Result<CallToolResult, McpError> {
-    let state = state
+    let mut state = state
         .lock()
         .map_err(|e| McpError::internal_error(format!("lock poisoned: {}", e), None))?;
     let goal_run_id = parse_uuid(&params.goal_run_id)?;
@@ -139,7 +147,7 @@ pub fn handle_fs_diff(
         .agent_for_goal(goal_run_id)
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
     let decision = state
-        .check_policy(&agent_id, "read", &params.path)
+        .check_policy(&agent_id, "read", &params.path, Some(goal_run_id))
         .map_err(|e| McpError::internal_error(e.to_string(), None))?;
     enforce_policy(&decision)?;
 
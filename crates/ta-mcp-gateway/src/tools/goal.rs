@@ -157,6 +157,7 @@ pub fn handle_goal_status(
         "agent_id": goal.agent_id,
         "created_at": goal.created_at.to_rfc3339(),
         "updated_at": goal.updated_at.to_rfc3339(),
+        "last_heartbeat": goal.last_heartbeat.map(|t| t.to_rfc3339()),
         "pr_package_id": goal.pr_package_id.map(|id| id.to_string()),
     });
     Ok(CallToolResult::success(vec![Content::json(response)
@@ -165,6 +166,35 @@ pub fn handle_goal_status(
         })?]))
 }
 
+/// Record that the agent working on a goal is alive. Long goals can sit in
+/// `Running` for a while; heartbeats keep the timeout sweep and watchdog from
+/// mistaking them for hung ones.
+pub fn handle_heartbeat(
+    state: &Arc<Mutex<GatewayState>>,
+    goal_run_id_str: &str,
+) -> Result<CallToolResult, McpError> {
+    let state = state
+        .lock()
+        .map_err(|e| McpError::internal_error(format!("lock poisoned: {}", e), None))?;
+    let goal_run_id = parse_uuid(goal_run_id_str)?;
+    validate_goal_exists(&state.goal_store, goal_run_id)?;
+    state
+        .goal_store
+        .record_heartbeat(goal_run_id, chrono::Utc::now())
+        .map_err(|e| McpError::internal_error(e.to_string(), None))?;
+    let goal = validate_goal_exists(&state.goal_store, goal_run_id)?;
+
+    let response = serde_json::json!({
+        "goal_run_id": goal.goal_run_id.to_string(),
+        "state": goal.state.to_string(),
+        "last_heartbeat": goal.last_heartbeat.map(|t| t.to_rfc3339()),
+    });
+    Ok(CallToolResult::success(vec![Content::json(response)
+        .map_err(|e| {
+            McpError::internal_error(e.to_string(), None)
+        })?]))
+}
+
 pub fn handle_goal_list(
     state: &Arc<Mutex<GatewayState>>,
     params: GoalListParams,
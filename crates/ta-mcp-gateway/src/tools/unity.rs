@@ -306,6 +306,7 @@ mod tests {
             ],
             issued_at: Utc::now(),
             expires_at: Utc::now() + Duration::hours(1),
+            includes: vec![],
         };
         state.lock().unwrap().policy_engine.load_manifest(manifest);
 
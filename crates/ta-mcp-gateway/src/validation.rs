@@ -30,11 +30,14 @@ pub fn validate_goal_exists(
 pub fn enforce_policy(decision: &PolicyDecision) -> Result<(), McpError> {
     match decision {
         PolicyDecision::Allow => Ok(()),
-        PolicyDecision::Deny { reason } => Err(McpError::invalid_request(
-            format!("Policy denied: {}", reason),
+        PolicyDecision::Deny {
+            reason_code,
+            reason,
+        } => Err(McpError::invalid_request(
+            format!("Policy denied ({}): {}", reason_code, reason),
             None,
         )),
-        PolicyDecision::RequireApproval { reason } => {
+        PolicyDecision::RequireApproval { reason, .. } => {
             // For now, RequireApproval is treated as allowed since the
             // CLI approval flow will handle the actual gating.
             tracing::info!("action requires approval: {}", reason);
@@ -49,6 +52,7 @@ pub fn enforce_policy(decision: &PolicyDecision) -> Result<(), McpError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use ta_policy::PolicyReasonCode;
 
     #[test]
     fn enforce_policy_allow_passes() {
@@ -59,9 +63,10 @@ mod tests {
     #[test]
     fn enforce_policy_deny_returns_error() {
         // §7: Deny decision MUST return an error — prevents policy bypass.
-        let result = enforce_policy(&PolicyDecision::Deny {
-            reason: "agent lacks read grant for this path".to_string(),
-        });
+        let result = enforce_policy(&PolicyDecision::deny(
+            PolicyReasonCode::NoMatchingGrant,
+            "agent lacks read grant for this path",
+        ));
         assert!(result.is_err(), "Deny decision must produce an MCP error");
         let err = result.unwrap_err();
         assert!(
@@ -74,9 +79,10 @@ mod tests {
     #[test]
     fn enforce_policy_require_approval_passes() {
         // §7: RequireApproval is gated at the CLI review flow, not here.
-        let result = enforce_policy(&PolicyDecision::RequireApproval {
-            reason: "apply verb requires explicit approval".to_string(),
-        });
+        let result = enforce_policy(&PolicyDecision::require_approval(
+            PolicyReasonCode::SideEffectGated,
+            "apply verb requires explicit approval",
+        ));
         assert!(result.is_ok());
     }
 
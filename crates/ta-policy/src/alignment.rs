@@ -23,6 +23,9 @@ use serde::{Deserialize, Serialize};
 ///   coordination:
 ///     allowed_collaborators: ["codex", "claude-flow"]
 ///     shared_resources: ["src/**", "tests/**"]
+///     handoff:
+///       to: "codex"
+///       role: "reviewer"
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct AlignmentProfile {
@@ -82,6 +85,69 @@ pub struct CoordinationConfig {
     /// Resource patterns (glob) that this agent shares with collaborators.
     #[serde(default)]
     pub shared_resources: Vec<String>,
+
+    /// Agent that picks the goal up once this agent's draft is built.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub handoff: Option<Handoff>,
+}
+
+/// A handoff to the next agent: `ta run` starts a follow-up goal for `to`
+/// after this agent's draft is built, so the next agent sees the draft and
+/// the parent goal's context.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Handoff {
+    /// Agent ID to hand the goal to.
+    pub to: String,
+
+    /// Role of the next agent (e.g., "reviewer"), used in the follow-up goal's title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub role: Option<String>,
+
+    /// Objective for the follow-up goal. Defaults to continuing from the
+    /// previous agent's draft.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub objective: Option<String>,
+}
+
+/// Why a declared handoff was not carried out.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum HandoffError {
+    /// The target is not in `allowed_collaborators`.
+    #[error("handoff target '{to}' is not an allowed collaborator")]
+    NotCollaborator { to: String },
+
+    /// The target already worked on this chain of goals.
+    #[error("handoff to '{to}' would loop: {} already ran in this chain", chain.join(" -> "))]
+    Cycle { to: String, chain: Vec<String> },
+}
+
+impl CoordinationConfig {
+    /// The handoff to run once the current agent's draft is built.
+    ///
+    /// `chain` lists the agents that already ran in this chain of handoffs,
+    /// ending with the current one. Handing back to any of them is refused,
+    /// so an `implementer -> reviewer -> implementer` pair of profiles stops
+    /// after one round instead of looping. A non-empty
+    /// `allowed_collaborators` list must name the target.
+    pub fn next_handoff(&self, chain: &[String]) -> Result<Option<&Handoff>, HandoffError> {
+        let Some(handoff) = &self.handoff else {
+            return Ok(None);
+        };
+        if !self.allowed_collaborators.is_empty()
+            && !self.allowed_collaborators.contains(&handoff.to)
+        {
+            return Err(HandoffError::NotCollaborator {
+                to: handoff.to.clone(),
+            });
+        }
+        if chain.contains(&handoff.to) {
+            return Err(HandoffError::Cycle {
+                to: handoff.to.clone(),
+                chain: chain.to_vec(),
+            });
+        }
+        Ok(Some(handoff))
+    }
 }
 
 /// An agent setup proposal — the output of the Intent-to-Policy Planner.
@@ -167,6 +233,28 @@ impl AlignmentProfile {
             coordination: CoordinationConfig::default(),
         }
     }
+
+    /// Create a read-only alignment profile for analysis agents.
+    ///
+    /// Grants only fs read/list on the workspace; writes, applies, network,
+    /// and credential access are forbidden.
+    pub fn read_only() -> Self {
+        Self {
+            principal: "project-owner".to_string(),
+            autonomy_envelope: AutonomyEnvelope {
+                bounded_actions: vec!["fs_read".to_string(), "fs_list".to_string()],
+                escalation_triggers: vec![],
+                forbidden_actions: vec![
+                    "fs_write_patch".to_string(),
+                    "fs_apply".to_string(),
+                    "network_external".to_string(),
+                    "credential_access".to_string(),
+                ],
+            },
+            constitution: "readonly-v1".to_string(),
+            coordination: CoordinationConfig::default(),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -250,6 +338,35 @@ autonomy_envelope:
             .contains(&"network_external".to_string()));
     }
 
+    #[test]
+    fn read_only_profile_denies_writes() {
+        use crate::compiler::{CompilerOptions, PolicyCompiler};
+        use crate::engine::{PolicyDecision, PolicyEngine, PolicyRequest};
+
+        let manifest = PolicyCompiler::compile(
+            "analyst",
+            &AlignmentProfile::read_only(),
+            &CompilerOptions::default(),
+        )
+        .unwrap();
+        let verbs: Vec<&str> = manifest.grants.iter().map(|g| g.verb.as_str()).collect();
+        assert_eq!(verbs, vec!["read", "list"]);
+
+        let mut engine = PolicyEngine::new();
+        engine.load_manifest(manifest);
+        let request = |verb: &str| PolicyRequest {
+            agent_id: "analyst".to_string(),
+            tool: "fs".to_string(),
+            verb: verb.to_string(),
+            target_uri: "fs://workspace/src/main.rs".to_string(),
+        };
+        assert_eq!(engine.evaluate(&request("read")), PolicyDecision::Allow);
+        assert!(matches!(
+            engine.evaluate(&request("write_patch")),
+            PolicyDecision::Deny { .. }
+        ));
+    }
+
     #[test]
     fn agent_setup_proposal_serialization() {
         let proposal = AgentSetupProposal {
@@ -293,6 +410,7 @@ autonomy_envelope:
                 coordination: CoordinationConfig {
                     allowed_collaborators: vec!["codex".to_string()],
                     shared_resources: vec!["src/**".to_string()],
+                    handoff: None,
                 },
             },
             resource_scope: vec!["fs://workspace/src/**".to_string()],
@@ -309,5 +427,39 @@ autonomy_envelope:
         let config = CoordinationConfig::default();
         assert!(config.allowed_collaborators.is_empty());
         assert!(config.shared_resources.is_empty());
+        assert!(config.handoff.is_none());
+    }
+
+    #[test]
+    fn next_handoff_respects_collaborators_and_refuses_loops() {
+        let yaml = r#"
+allowed_collaborators: ["reviewer"]
+handoff:
+  to: "reviewer"
+  role: "code reviewer"
+"#;
+        let config: CoordinationConfig = serde_yaml::from_str(yaml).unwrap();
+        let chain = vec!["implementer".to_string()];
+        let handoff = config.next_handoff(&chain).unwrap().unwrap();
+        assert_eq!(handoff.to, "reviewer");
+        assert_eq!(handoff.role.as_deref(), Some("code reviewer"));
+        assert!(handoff.objective.is_none());
+
+        let looped = vec!["reviewer".to_string(), "implementer".to_string()];
+        assert!(matches!(
+            config.next_handoff(&looped),
+            Err(HandoffError::Cycle { .. })
+        ));
+
+        let mut restricted = config.clone();
+        restricted.allowed_collaborators = vec!["codex".to_string()];
+        assert_eq!(
+            restricted.next_handoff(&chain),
+            Err(HandoffError::NotCollaborator {
+                to: "reviewer".to_string()
+            })
+        );
+
+        assert_eq!(CoordinationConfig::default().next_handoff(&chain), Ok(None));
     }
 }
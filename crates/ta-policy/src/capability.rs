@@ -7,10 +7,16 @@
 //
 // Manifests are time-bounded to limit blast radius of compromised agents.
 
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use uuid::Uuid;
 
+use crate::error::PolicyError;
+
 /// A single permission grant within a capability manifest.
 ///
 /// Grants are scoped by three dimensions:
@@ -46,6 +52,10 @@ pub struct CapabilityManifest {
     pub issued_at: DateTime<Utc>,
     /// When this manifest expires (hard cutoff).
     pub expires_at: DateTime<Utc>,
+    /// Shared bases this manifest was composed from (file paths or named
+    /// profiles), in resolution order. Empty for compiled manifests.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub includes: Vec<String>,
 }
 
 impl CapabilityManifest {
@@ -55,6 +65,140 @@ impl CapabilityManifest {
     }
 }
 
+// ── Issued manifest store ──
+
+/// Keeps every manifest exactly as it was issued, one JSON file per
+/// manifest ID, so a goal's grants can be audited after it finishes.
+pub struct ManifestStore {
+    dir: PathBuf,
+}
+
+impl ManifestStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Record an issued manifest.
+    pub fn save(&self, manifest: &CapabilityManifest) -> Result<(), PolicyError> {
+        fs::create_dir_all(&self.dir).map_err(|source| PolicyError::IoError {
+            path: self.dir.display().to_string(),
+            source,
+        })?;
+        let path = self.path_for(manifest.manifest_id);
+        let json = serde_json::to_string_pretty(manifest)
+            .map_err(|e| PolicyError::ConfigError(format!("cannot serialize manifest: {}", e)))?;
+        fs::write(&path, json).map_err(|source| PolicyError::IoError {
+            path: path.display().to_string(),
+            source,
+        })
+    }
+
+    /// Load the manifest issued under `manifest_id`. Returns None if none was
+    /// recorded.
+    pub fn load(&self, manifest_id: Uuid) -> Result<Option<CapabilityManifest>, PolicyError> {
+        let path = self.path_for(manifest_id);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let json = fs::read_to_string(&path).map_err(|source| PolicyError::IoError {
+            path: path.display().to_string(),
+            source,
+        })?;
+        let manifest = serde_json::from_str(&json).map_err(|e| {
+            PolicyError::ConfigError(format!("invalid manifest {}: {}", path.display(), e))
+        })?;
+        Ok(Some(manifest))
+    }
+
+    fn path_for(&self, manifest_id: Uuid) -> PathBuf {
+        self.dir.join(format!("{}.json", manifest_id))
+    }
+}
+
+/// How one `(tool, verb)` capability differs between two grant sets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GrantChange {
+    /// The capability is new; `patterns` are the resources it now covers.
+    Added {
+        tool: String,
+        verb: String,
+        patterns: Vec<String>,
+    },
+    /// The capability is no longer granted.
+    Removed {
+        tool: String,
+        verb: String,
+        patterns: Vec<String>,
+    },
+    /// The capability is granted on both sides, over different resources.
+    Changed {
+        tool: String,
+        verb: String,
+        old: Vec<String>,
+        new: Vec<String>,
+    },
+}
+
+/// Compare two grant sets by `(tool, verb)`, sorted by tool then verb.
+/// Grants that cover the same resource patterns on both sides are omitted.
+pub fn diff_grants(old: &[CapabilityGrant], new: &[CapabilityGrant]) -> Vec<GrantChange> {
+    fn by_capability(grants: &[CapabilityGrant]) -> BTreeMap<(String, String), Vec<String>> {
+        let mut map: BTreeMap<(String, String), Vec<String>> = BTreeMap::new();
+        for g in grants {
+            let patterns = map.entry((g.tool.clone(), g.verb.clone())).or_default();
+            if !patterns.contains(&g.resource_pattern) {
+                patterns.push(g.resource_pattern.clone());
+            }
+        }
+        for patterns in map.values_mut() {
+            patterns.sort();
+        }
+        map
+    }
+
+    let old = by_capability(old);
+    let mut new = by_capability(new);
+    let mut changes = Vec::new();
+    for ((tool, verb), old_patterns) in old {
+        match new.remove(&(tool.clone(), verb.clone())) {
+            None => changes.push(GrantChange::Removed {
+                tool,
+                verb,
+                patterns: old_patterns,
+            }),
+            Some(new_patterns) if new_patterns != old_patterns => {
+                changes.push(GrantChange::Changed {
+                    tool,
+                    verb,
+                    old: old_patterns,
+                    new: new_patterns,
+                })
+            }
+            Some(_) => {}
+        }
+    }
+    changes.extend(
+        new.into_iter()
+            .map(|((tool, verb), patterns)| GrantChange::Added {
+                tool,
+                verb,
+                patterns,
+            }),
+    );
+    changes.sort_by(|a, b| a.key().cmp(&b.key()));
+    changes
+}
+
+impl GrantChange {
+    fn key(&self) -> (&str, &str) {
+        match self {
+            GrantChange::Added { tool, verb, .. }
+            | GrantChange::Removed { tool, verb, .. }
+            | GrantChange::Changed { tool, verb, .. } => (tool, verb),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -68,6 +212,7 @@ mod tests {
             grants: vec![],
             issued_at: Utc::now(),
             expires_at: Utc::now() + Duration::hours(1),
+            includes: vec![],
         };
         assert!(!manifest.is_expired());
     }
@@ -80,6 +225,7 @@ mod tests {
             grants: vec![],
             issued_at: Utc::now() - Duration::hours(2),
             expires_at: Utc::now() - Duration::hours(1),
+            includes: vec![],
         };
         assert!(manifest.is_expired());
     }
@@ -95,4 +241,70 @@ mod tests {
         let restored: CapabilityGrant = serde_json::from_str(&json).unwrap();
         assert_eq!(grant, restored);
     }
+
+    #[test]
+    fn diff_grants_reports_added_removed_and_changed() {
+        let grant = |tool: &str, verb: &str, pattern: &str| CapabilityGrant {
+            tool: tool.to_string(),
+            verb: verb.to_string(),
+            resource_pattern: pattern.to_string(),
+        };
+        let old = vec![
+            grant("fs", "read", "fs://workspace/**"),
+            grant("fs", "write_patch", "fs://workspace/src/**"),
+            grant("web", "fetch", "https://docs.rs/**"),
+        ];
+        let new = vec![
+            grant("fs", "read", "fs://workspace/**"),
+            grant("fs", "write_patch", "fs://workspace/**"),
+            grant("exec", "cargo test", "fs://workspace/**"),
+        ];
+        assert_eq!(
+            diff_grants(&old, &new),
+            vec![
+                GrantChange::Added {
+                    tool: "exec".to_string(),
+                    verb: "cargo test".to_string(),
+                    patterns: vec!["fs://workspace/**".to_string()],
+                },
+                GrantChange::Changed {
+                    tool: "fs".to_string(),
+                    verb: "write_patch".to_string(),
+                    old: vec!["fs://workspace/src/**".to_string()],
+                    new: vec!["fs://workspace/**".to_string()],
+                },
+                GrantChange::Removed {
+                    tool: "web".to_string(),
+                    verb: "fetch".to_string(),
+                    patterns: vec!["https://docs.rs/**".to_string()],
+                },
+            ]
+        );
+        assert!(diff_grants(&new, &new).is_empty());
+    }
+
+    #[test]
+    fn manifest_store_round_trips_by_id() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = ManifestStore::new(dir.path().join("manifests"));
+        let manifest = CapabilityManifest {
+            manifest_id: Uuid::new_v4(),
+            agent_id: "test-agent".to_string(),
+            grants: vec![CapabilityGrant {
+                tool: "fs".to_string(),
+                verb: "read".to_string(),
+                resource_pattern: "fs://workspace/**".to_string(),
+            }],
+            issued_at: Utc::now(),
+            expires_at: Utc::now() + Duration::hours(8),
+            includes: vec![],
+        };
+        assert!(store.load(manifest.manifest_id).unwrap().is_none());
+
+        store.save(&manifest).unwrap();
+        let loaded = store.load(manifest.manifest_id).unwrap().unwrap();
+        assert_eq!(loaded.agent_id, "test-agent");
+        assert_eq!(loaded.grants, manifest.grants);
+        assert_eq!(loaded.expires_at, manifest.expires_at);
+    }
 }
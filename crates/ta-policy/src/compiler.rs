@@ -11,8 +11,15 @@
 //
 // The key invariant: if an action is in `forbidden_actions`, it NEVER
 // appears in the manifest — this is enforced, not promised.
+//
+// Hand-written manifest files (`.ta/manifests/<name>.yaml`) are compiled too:
+// their `includes` are resolved recursively and the grants flattened into a
+// single time-bounded manifest.
+
+use std::path::{Path, PathBuf};
 
 use chrono::{Duration, Utc};
+use serde::Deserialize;
 use uuid::Uuid;
 
 use crate::alignment::AlignmentProfile;
@@ -25,6 +32,10 @@ pub enum CompilerError {
     ForbiddenOverlap { action: String, message: String },
     /// A bounded_action has an unrecognized format.
     InvalidAction { action: String, message: String },
+    /// A manifest file or one of its includes could not be read or parsed.
+    InvalidInclude { include: String, message: String },
+    /// Manifest includes form a cycle; `chain` ends with the repeated file.
+    IncludeCycle { chain: Vec<String> },
 }
 
 impl std::fmt::Display for CompilerError {
@@ -36,6 +47,12 @@ impl std::fmt::Display for CompilerError {
             CompilerError::InvalidAction { action, message } => {
                 write!(f, "invalid action '{}': {}", action, message)
             }
+            CompilerError::InvalidInclude { include, message } => {
+                write!(f, "invalid manifest include '{}': {}", include, message)
+            }
+            CompilerError::IncludeCycle { chain } => {
+                write!(f, "manifest include cycle: {}", chain.join(" -> "))
+            }
         }
     }
 }
@@ -100,6 +117,7 @@ impl PolicyCompiler {
             grants,
             issued_at: now,
             expires_at: now + Duration::hours(options.validity_hours),
+            includes: vec![],
         })
     }
 
@@ -115,6 +133,111 @@ impl PolicyCompiler {
         Ok(manifest)
     }
 
+    /// Compile the manifest installed for `agent_id` at
+    /// `<manifests_dir>/<agent_id>.yaml`, with its includes resolved.
+    /// Returns None when nothing is installed for the agent, or when the
+    /// agent ID cannot name a file in that directory.
+    pub fn compile_installed_manifest(
+        manifests_dir: &Path,
+        agent_id: &str,
+        options: &CompilerOptions,
+    ) -> Result<Option<CapabilityManifest>, CompilerError> {
+        let is_file_name = !agent_id.is_empty()
+            && !agent_id.starts_with('.')
+            && agent_id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+        let path = manifests_dir.join(format!("{}.yaml", agent_id));
+        if !is_file_name || !path.exists() {
+            return Ok(None);
+        }
+        let mut manifest = Self::compile_manifest_file(&path, manifests_dir, options)?;
+        manifest.agent_id = agent_id.to_string();
+        Ok(Some(manifest))
+    }
+
+    /// Compile a manifest file, resolving its `includes` recursively.
+    ///
+    /// An include containing a path separator or ending in `.yaml`, `.yml`,
+    /// or `.json` is a file path relative to the including file; anything
+    /// else is a named profile at `<profiles_dir>/<name>.yaml`. Grants are
+    /// merged in order — includes first, then the file's own grants — and a
+    /// later grant for a `(tool, verb)` pair replaces every earlier grant for
+    /// that pair, so a child can narrow or widen what a base allows.
+    pub fn compile_manifest_file(
+        path: &Path,
+        profiles_dir: &Path,
+        options: &CompilerOptions,
+    ) -> Result<CapabilityManifest, CompilerError> {
+        let mut stack = Vec::new();
+        let mut includes = Vec::new();
+        let (agent_id, grants) =
+            Self::resolve_manifest_file(path, profiles_dir, &mut stack, &mut includes)?;
+        let agent_id = agent_id.unwrap_or_else(|| {
+            path.file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        });
+
+        let now = Utc::now();
+        Ok(CapabilityManifest {
+            manifest_id: Uuid::new_v4(),
+            agent_id,
+            grants,
+            issued_at: now,
+            expires_at: now + Duration::hours(options.validity_hours),
+            includes,
+        })
+    }
+
+    /// Load one manifest file and its includes depth-first. `stack` holds the
+    /// files currently being resolved (for cycle detection); `includes`
+    /// collects every resolved include in order.
+    fn resolve_manifest_file(
+        path: &Path,
+        profiles_dir: &Path,
+        stack: &mut Vec<PathBuf>,
+        includes: &mut Vec<String>,
+    ) -> Result<(Option<String>, Vec<CapabilityGrant>), CompilerError> {
+        let invalid = |message: String| CompilerError::InvalidInclude {
+            include: path.display().to_string(),
+            message,
+        };
+        let canonical = path.canonicalize().map_err(|e| invalid(e.to_string()))?;
+        if stack.contains(&canonical) {
+            let mut chain: Vec<String> = stack.iter().map(|p| p.display().to_string()).collect();
+            chain.push(canonical.display().to_string());
+            return Err(CompilerError::IncludeCycle { chain });
+        }
+        let content = std::fs::read_to_string(&canonical).map_err(|e| invalid(e.to_string()))?;
+        let source: ManifestSource =
+            serde_yaml::from_str(&content).map_err(|e| invalid(e.to_string()))?;
+
+        stack.push(canonical.clone());
+        let base_dir = canonical.parent().unwrap_or(Path::new("."));
+        let mut grants = Vec::new();
+        for include in &source.includes {
+            let is_path = include.contains('/')
+                || include.contains('\\')
+                || [".yaml", ".yml", ".json"]
+                    .iter()
+                    .any(|ext| include.ends_with(ext));
+            let include_path = if is_path {
+                base_dir.join(include)
+            } else {
+                profiles_dir.join(format!("{}.yaml", include))
+            };
+            let (_, included) =
+                Self::resolve_manifest_file(&include_path, profiles_dir, stack, includes)?;
+            merge_grants(&mut grants, included);
+            includes.push(include.clone());
+        }
+        stack.pop();
+
+        merge_grants(&mut grants, source.grants);
+        Ok((source.agent_id, grants))
+    }
+
     /// Validate that no bounded_action overlaps with forbidden_actions.
     fn validate_no_overlaps(profile: &AlignmentProfile) -> Result<(), CompilerError> {
         for bounded in &profile.autonomy_envelope.bounded_actions {
@@ -176,6 +299,29 @@ impl PolicyCompiler {
     }
 }
 
+/// The on-disk form of a manifest file: IDs and timestamps are assigned at
+/// compile time, so only the agent, includes, and grants are read.
+#[derive(Debug, Deserialize)]
+struct ManifestSource {
+    #[serde(default)]
+    agent_id: Option<String>,
+    #[serde(default)]
+    includes: Vec<String>,
+    #[serde(default)]
+    grants: Vec<CapabilityGrant>,
+}
+
+/// Merge `overlay` into `grants`: overlay grants replace all existing grants
+/// with the same `(tool, verb)`; exact duplicates are kept once.
+fn merge_grants(grants: &mut Vec<CapabilityGrant>, overlay: Vec<CapabilityGrant>) {
+    grants.retain(|g| !overlay.iter().any(|o| o.tool == g.tool && o.verb == g.verb));
+    for grant in overlay {
+        if !grants.contains(&grant) {
+            grants.push(grant);
+        }
+    }
+}
+
 /// A parsed action — intermediate representation.
 #[derive(Debug, Clone)]
 struct ParsedAction {
@@ -416,4 +562,81 @@ mod tests {
         assert_eq!(manifest.grants[0].tool, "fs");
         assert_eq!(manifest.grants[0].verb, "write_patch");
     }
+
+    #[test]
+    fn compile_manifest_file_merges_includes() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let profiles = dir.path().join("manifests");
+        std::fs::create_dir_all(&profiles).unwrap();
+        std::fs::write(
+            profiles.join("base.yaml"),
+            "grants:\n\
+             - { tool: fs, verb: read, resource_pattern: 'fs://workspace/**' }\n\
+             - { tool: fs, verb: write_patch, resource_pattern: 'fs://workspace/**' }\n",
+        )
+        .unwrap();
+        std::fs::write(
+            dir.path().join("web.yaml"),
+            "grants:\n- { tool: web, verb: fetch, resource_pattern: 'https://docs.rs/**' }\n",
+        )
+        .unwrap();
+        let agent = profiles.join("coder.yaml");
+        std::fs::write(
+            &agent,
+            "includes: [base, ../web.yaml]\n\
+             grants:\n\
+             - { tool: fs, verb: write_patch, resource_pattern: 'fs://workspace/src/**' }\n",
+        )
+        .unwrap();
+
+        let manifest =
+            PolicyCompiler::compile_manifest_file(&agent, &profiles, &CompilerOptions::default())
+                .unwrap();
+        assert_eq!(manifest.agent_id, "coder");
+        assert_eq!(manifest.includes, vec!["base", "../web.yaml"]);
+        let grants: Vec<_> = manifest
+            .grants
+            .iter()
+            .map(|g| format!("{}_{} {}", g.tool, g.verb, g.resource_pattern))
+            .collect();
+        // The child's write_patch grant replaces the base's wider one.
+        assert_eq!(
+            grants,
+            vec![
+                "fs_read fs://workspace/**",
+                "web_fetch https://docs.rs/**",
+                "fs_write_patch fs://workspace/src/**",
+            ]
+        );
+    }
+
+    #[test]
+    fn compile_manifest_file_detects_include_cycle() {
+        let dir = tempfile::TempDir::new().unwrap();
+        std::fs::write(dir.path().join("a.yaml"), "includes: [b]\n").unwrap();
+        std::fs::write(dir.path().join("b.yaml"), "includes: [a]\n").unwrap();
+        let err = PolicyCompiler::compile_manifest_file(
+            &dir.path().join("a.yaml"),
+            dir.path(),
+            &CompilerOptions::default(),
+        )
+        .unwrap_err();
+        match err {
+            CompilerError::IncludeCycle { chain } => {
+                assert_eq!(chain.len(), 3);
+                assert!(chain[2].ends_with("a.yaml"));
+            }
+            other => panic!("expected IncludeCycle, got {:?}", other),
+        }
+
+        std::fs::write(dir.path().join("c.yaml"), "includes: [missing]\n").unwrap();
+        assert!(matches!(
+            PolicyCompiler::compile_manifest_file(
+                &dir.path().join("c.yaml"),
+                dir.path(),
+                &CompilerOptions::default(),
+            ),
+            Err(CompilerError::InvalidInclude { .. })
+        ));
+    }
 }
@@ -43,9 +43,104 @@ pub enum PolicyDecision {
     /// The action is allowed — proceed.
     Allow,
     /// The action is denied — do not proceed.
-    Deny { reason: String },
+    Deny {
+        /// Machine-readable category, for aggregating denials.
+        #[serde(default)]
+        reason_code: PolicyReasonCode,
+        /// Human-readable explanation.
+        reason: String,
+    },
     /// The action requires explicit human approval before proceeding.
-    RequireApproval { reason: String },
+    RequireApproval {
+        #[serde(default)]
+        reason_code: PolicyReasonCode,
+        reason: String,
+    },
+}
+
+impl PolicyDecision {
+    pub fn deny(reason_code: PolicyReasonCode, reason: impl Into<String>) -> Self {
+        Self::Deny {
+            reason_code,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn require_approval(reason_code: PolicyReasonCode, reason: impl Into<String>) -> Self {
+        Self::RequireApproval {
+            reason_code,
+            reason: reason.into(),
+        }
+    }
+
+    /// Structured reason for a Deny or RequireApproval; `None` for Allow.
+    pub fn reason_code(&self) -> Option<PolicyReasonCode> {
+        match self {
+            Self::Allow => None,
+            Self::Deny { reason_code, .. } | Self::RequireApproval { reason_code, .. } => {
+                Some(*reason_code)
+            }
+        }
+    }
+
+    /// Free-text reason for a Deny or RequireApproval; `None` for Allow.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Allow => None,
+            Self::Deny { reason, .. } | Self::RequireApproval { reason, .. } => Some(reason),
+        }
+    }
+}
+
+/// Why a request was denied or escalated for approval.
+///
+/// Carried next to the free-text reason so audit consumers (dashboards,
+/// `ta audit`) can group decisions without parsing English.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum PolicyReasonCode {
+    /// The agent has no capability manifest loaded.
+    NoManifest,
+    /// No grant matches the tool, verb, and target.
+    NoMatchingGrant,
+    /// The target URI tries to escape the workspace with `..`.
+    PathTraversal,
+    /// The verb or scheme has irreversible side effects and needs approval.
+    SideEffectGated,
+    /// The agent's capability manifest has expired.
+    Expired,
+    /// An action-count limit for the session was reached.
+    RateLimited,
+    /// The action is on the agent's forbidden list.
+    Forbidden,
+    /// The agent's drift score is over the escalation threshold.
+    DriftDetected,
+    /// The goal's token budget is spent.
+    BudgetExceeded,
+    /// Supervised mode gates every state-changing action.
+    Supervised,
+    /// Decision recorded before reason codes existed.
+    #[default]
+    Unspecified,
+}
+
+impl std::fmt::Display for PolicyReasonCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            Self::NoManifest => "no_manifest",
+            Self::NoMatchingGrant => "no_matching_grant",
+            Self::PathTraversal => "path_traversal",
+            Self::SideEffectGated => "side_effect_gated",
+            Self::Expired => "expired",
+            Self::RateLimited => "rate_limited",
+            Self::Forbidden => "forbidden",
+            Self::DriftDetected => "drift_detected",
+            Self::BudgetExceeded => "budget_exceeded",
+            Self::Supervised => "supervised",
+            Self::Unspecified => "unspecified",
+        };
+        write!(f, "{}", s)
+    }
 }
 
 /// A step in the policy evaluation chain (v0.3.3).
@@ -76,11 +171,14 @@ pub struct EvaluationTrace {
     pub grants_checked: Vec<String>,
     /// Which grant matched (if any).
     pub matching_grant: Option<String>,
+    /// Structured reason for a non-Allow decision.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason_code: Option<PolicyReasonCode>,
 }
 
 /// Verbs that always require human approval, regardless of grants.
 /// These represent irreversible side effects.
-const APPROVAL_REQUIRED_VERBS: &[&str] = &["apply", "commit", "send", "post"];
+pub const APPROVAL_REQUIRED_VERBS: &[&str] = &["apply", "commit", "send", "post"];
 
 /// The policy engine — evaluates requests against capability manifests.
 ///
@@ -112,6 +210,7 @@ impl PolicyEngine {
         // This is a security check — agents must not escape their workspace.
         if contains_path_traversal(&request.target_uri) {
             return PolicyDecision::Deny {
+                reason_code: PolicyReasonCode::PathTraversal,
                 reason: format!(
                     "path traversal detected in target URI: '{}'",
                     request.target_uri
@@ -124,6 +223,7 @@ impl PolicyEngine {
             Some(m) => m,
             None => {
                 return PolicyDecision::Deny {
+                    reason_code: PolicyReasonCode::NoManifest,
                     reason: format!("no capability manifest for agent '{}'", request.agent_id),
                 }
             }
@@ -132,6 +232,7 @@ impl PolicyEngine {
         // Step 3: Check if the manifest has expired.
         if manifest.is_expired() {
             return PolicyDecision::Deny {
+                reason_code: PolicyReasonCode::Expired,
                 reason: format!(
                     "capability manifest for agent '{}' has expired",
                     request.agent_id
@@ -144,10 +245,12 @@ impl PolicyEngine {
             // Still need to verify the agent has a matching grant.
             if has_matching_grant(manifest, request) {
                 return PolicyDecision::RequireApproval {
+                    reason_code: PolicyReasonCode::SideEffectGated,
                     reason: format!("verb '{}' requires explicit approval", request.verb),
                 };
             } else {
                 return PolicyDecision::Deny {
+                    reason_code: PolicyReasonCode::NoMatchingGrant,
                     reason: format!(
                         "no grant for {}.{} on '{}'",
                         request.tool, request.verb, request.target_uri
@@ -161,6 +264,7 @@ impl PolicyEngine {
             PolicyDecision::Allow
         } else {
             PolicyDecision::Deny {
+                reason_code: PolicyReasonCode::NoMatchingGrant,
                 reason: format!(
                     "no grant for {}.{} on '{}'",
                     request.tool, request.verb, request.target_uri
@@ -186,6 +290,7 @@ impl PolicyEngine {
             });
             return EvaluationTrace {
                 decision: PolicyDecision::Deny {
+                    reason_code: PolicyReasonCode::PathTraversal,
                     reason: format!(
                         "path traversal detected in target URI: '{}'",
                         request.target_uri
@@ -194,6 +299,7 @@ impl PolicyEngine {
                 steps,
                 grants_checked,
                 matching_grant,
+                reason_code: Some(PolicyReasonCode::PathTraversal),
             };
         }
         steps.push(EvaluationStep {
@@ -213,11 +319,13 @@ impl PolicyEngine {
                 });
                 return EvaluationTrace {
                     decision: PolicyDecision::Deny {
+                        reason_code: PolicyReasonCode::NoManifest,
                         reason: format!("no capability manifest for agent '{}'", request.agent_id),
                     },
                     steps,
                     grants_checked,
                     matching_grant,
+                    reason_code: Some(PolicyReasonCode::NoManifest),
                 };
             }
         };
@@ -240,6 +348,7 @@ impl PolicyEngine {
             });
             return EvaluationTrace {
                 decision: PolicyDecision::Deny {
+                    reason_code: PolicyReasonCode::Expired,
                     reason: format!(
                         "capability manifest for agent '{}' has expired",
                         request.agent_id
@@ -248,6 +357,7 @@ impl PolicyEngine {
                 steps,
                 grants_checked,
                 matching_grant,
+                reason_code: Some(PolicyReasonCode::Expired),
             };
         }
         steps.push(EvaluationStep {
@@ -284,11 +394,13 @@ impl PolicyEngine {
                 });
                 return EvaluationTrace {
                     decision: PolicyDecision::RequireApproval {
+                        reason_code: PolicyReasonCode::SideEffectGated,
                         reason: format!("verb '{}' requires explicit approval", request.verb),
                     },
                     steps,
                     grants_checked,
                     matching_grant,
+                    reason_code: Some(PolicyReasonCode::SideEffectGated),
                 };
             } else {
                 steps.push(EvaluationStep {
@@ -301,6 +413,7 @@ impl PolicyEngine {
                 });
                 return EvaluationTrace {
                     decision: PolicyDecision::Deny {
+                        reason_code: PolicyReasonCode::NoMatchingGrant,
                         reason: format!(
                             "no grant for {}.{} on '{}'",
                             request.tool, request.verb, request.target_uri
@@ -309,6 +422,7 @@ impl PolicyEngine {
                     steps,
                     grants_checked,
                     matching_grant,
+                    reason_code: Some(PolicyReasonCode::NoMatchingGrant),
                 };
             }
         }
@@ -325,6 +439,7 @@ impl PolicyEngine {
                 steps,
                 grants_checked,
                 matching_grant,
+                reason_code: None,
             }
         } else {
             steps.push(EvaluationStep {
@@ -337,6 +452,7 @@ impl PolicyEngine {
             });
             EvaluationTrace {
                 decision: PolicyDecision::Deny {
+                    reason_code: PolicyReasonCode::NoMatchingGrant,
                     reason: format!(
                         "no grant for {}.{} on '{}'",
                         request.tool, request.verb, request.target_uri
@@ -345,6 +461,7 @@ impl PolicyEngine {
                 steps,
                 grants_checked,
                 matching_grant,
+                reason_code: Some(PolicyReasonCode::NoMatchingGrant),
             }
         }
     }
@@ -390,6 +507,7 @@ impl PolicyEngine {
         if let Some(scheme_policy) = scheme.and_then(|s| document.schemes.get(s)) {
             if scheme_policy.approval_required.contains(&request.verb) {
                 return PolicyDecision::RequireApproval {
+                    reason_code: PolicyReasonCode::SideEffectGated,
                     reason: format!(
                         "scheme '{}' requires approval for verb '{}'",
                         scheme.unwrap_or("unknown"),
@@ -402,6 +520,7 @@ impl PolicyEngine {
             if let Some(max) = scheme_policy.max_actions_per_session {
                 if context.action_count >= max {
                     return PolicyDecision::Deny {
+                        reason_code: PolicyReasonCode::RateLimited,
                         reason: format!(
                             "action count limit ({}) exceeded for scheme '{}'",
                             max,
@@ -419,6 +538,7 @@ impl PolicyEngine {
                 .contains(&request.verb)
             {
                 return PolicyDecision::RequireApproval {
+                    reason_code: PolicyReasonCode::SideEffectGated,
                     reason: format!(
                         "agent '{}' requires approval for verb '{}'",
                         request.agent_id, request.verb
@@ -429,6 +549,7 @@ impl PolicyEngine {
             let action_key = format!("{}_{}", request.tool, request.verb);
             if agent_override.forbidden_actions.contains(&action_key) {
                 return PolicyDecision::Deny {
+                    reason_code: PolicyReasonCode::Forbidden,
                     reason: format!(
                         "action '{}' is forbidden for agent '{}'",
                         action_key, request.agent_id
@@ -440,6 +561,7 @@ impl PolicyEngine {
         // Step 4: Check escalation triggers.
         if context.is_drifting(document.escalation.drift_threshold) {
             return PolicyDecision::RequireApproval {
+                reason_code: PolicyReasonCode::DriftDetected,
                 reason: format!(
                     "drift score ({:.2}) exceeds threshold ({:.2})",
                     context.drift_score.unwrap_or(0.0),
@@ -451,6 +573,7 @@ impl PolicyEngine {
         if let Some(limit) = document.escalation.action_count_limit {
             if context.action_count >= limit {
                 return PolicyDecision::RequireApproval {
+                    reason_code: PolicyReasonCode::RateLimited,
                     reason: format!(
                         "action count ({}) reached escalation limit ({})",
                         context.action_count, limit
@@ -463,6 +586,7 @@ impl PolicyEngine {
         if let Some(ref budget) = document.budget {
             if context.is_over_budget(budget.max_tokens_per_goal) {
                 return PolicyDecision::Deny {
+                    reason_code: PolicyReasonCode::BudgetExceeded,
                     reason: format!(
                         "budget exceeded: {} tokens spent (limit: {})",
                         context.budget_spent,
@@ -481,6 +605,7 @@ impl PolicyEngine {
             let read_verbs = ["read", "list", "diff", "status", "search"];
             if !read_verbs.contains(&request.verb.as_str()) {
                 return PolicyDecision::RequireApproval {
+                    reason_code: PolicyReasonCode::Supervised,
                     reason: "supervised mode: all state-changing actions require approval"
                         .to_string(),
                 };
@@ -547,6 +672,7 @@ mod tests {
             grants,
             issued_at: Utc::now(),
             expires_at: Utc::now() + Duration::hours(1),
+            includes: vec![],
         }
     }
 
@@ -610,7 +736,7 @@ mod tests {
         });
 
         match decision {
-            PolicyDecision::Deny { reason } => {
+            PolicyDecision::Deny { reason, .. } => {
                 assert!(reason.contains("no capability manifest"));
             }
             other => panic!("expected Deny, got {:?}", other),
@@ -634,7 +760,7 @@ mod tests {
         });
 
         match decision {
-            PolicyDecision::Deny { reason } => {
+            PolicyDecision::Deny { reason, .. } => {
                 assert!(reason.contains("expired"));
             }
             other => panic!("expected Deny, got {:?}", other),
@@ -699,7 +825,7 @@ mod tests {
         });
 
         match decision {
-            PolicyDecision::Deny { reason } => {
+            PolicyDecision::Deny { reason, .. } => {
                 assert!(reason.contains("path traversal"));
             }
             other => panic!("expected Deny, got {:?}", other),
@@ -871,11 +997,16 @@ mod tests {
         let json = serde_json::to_string(&allow).unwrap();
         assert!(json.contains("\"allow\""));
 
-        let deny = PolicyDecision::Deny {
-            reason: "test".to_string(),
-        };
+        let deny = PolicyDecision::deny(PolicyReasonCode::NoMatchingGrant, "test");
         let json = serde_json::to_string(&deny).unwrap();
         assert!(json.contains("\"deny\""));
+        assert!(json.contains("\"reason_code\":\"no_matching_grant\""));
+
+        // Decisions logged before reason codes existed still deserialize.
+        let legacy: PolicyDecision =
+            serde_json::from_str(r#"{"decision":"deny","reason":"old"}"#).unwrap();
+        assert_eq!(legacy.reason_code(), Some(PolicyReasonCode::Unspecified));
+        assert_eq!(legacy.reason(), Some("old"));
     }
 
     // ── v0.3.3 Evaluation Trace tests ──
@@ -917,7 +1048,9 @@ mod tests {
         });
 
         match &trace.decision {
-            PolicyDecision::Deny { reason } => assert!(reason.contains("no capability manifest")),
+            PolicyDecision::Deny { reason, .. } => {
+                assert!(reason.contains("no capability manifest"))
+            }
             other => panic!("expected Deny, got {:?}", other),
         }
         assert_eq!(trace.steps.len(), 2); // path_traversal + manifest_lookup
@@ -940,11 +1073,12 @@ mod tests {
         });
 
         match &trace.decision {
-            PolicyDecision::Deny { reason } => assert!(reason.contains("path traversal")),
+            PolicyDecision::Deny { reason, .. } => assert!(reason.contains("path traversal")),
             other => panic!("expected Deny, got {:?}", other),
         }
         assert_eq!(trace.steps.len(), 1);
         assert!(trace.steps[0].terminal);
+        assert_eq!(trace.reason_code, Some(PolicyReasonCode::PathTraversal));
     }
 
     #[test]
@@ -1050,7 +1184,7 @@ mod tests {
 
         let decision = engine.evaluate_with_document(&request, &doc, &ctx);
         match decision {
-            PolicyDecision::RequireApproval { reason } => {
+            PolicyDecision::RequireApproval { reason, .. } => {
                 assert!(reason.contains("write_patch"));
             }
             other => panic!("expected RequireApproval, got {:?}", other),
@@ -1081,7 +1215,7 @@ mod tests {
 
         let decision = engine.evaluate_with_document(&request, &doc, &ctx);
         match decision {
-            PolicyDecision::RequireApproval { reason } => {
+            PolicyDecision::RequireApproval { reason, .. } => {
                 assert!(reason.contains("supervised"));
             }
             other => panic!("expected RequireApproval, got {:?}", other),
@@ -1144,7 +1278,7 @@ mod tests {
 
         let decision = engine.evaluate_with_document(&request, &doc, &ctx);
         match decision {
-            PolicyDecision::Deny { reason } => {
+            PolicyDecision::Deny { reason, .. } => {
                 assert!(reason.contains("budget"));
             }
             other => panic!("expected Deny, got {:?}", other),
@@ -1177,7 +1311,7 @@ mod tests {
 
         let decision = engine.evaluate_with_document(&request, &doc, &ctx);
         match decision {
-            PolicyDecision::RequireApproval { reason } => {
+            PolicyDecision::RequireApproval { reason, .. } => {
                 assert!(reason.contains("drift"));
             }
             other => panic!("expected RequireApproval, got {:?}", other),
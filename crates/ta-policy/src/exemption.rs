@@ -7,6 +7,11 @@
 // - Glob patterns (e.g., `*.lock`, `**/*.toml`)
 // - Comments (lines starting with `#`)
 // - Blank lines (ignored)
+// - Negation (`!pattern`) to un-exempt files an earlier pattern exempted
+//
+// Rules are evaluated in order and the last matching rule wins, as in
+// `.gitignore`: `docs/**` followed by `!docs/api.md` exempts every doc but
+// api.md. A leading `\!` matches a literal `!`.
 //
 // Default patterns are provided when no file exists, matching the
 // previously hardcoded list (lockfiles, config manifests, docs).
@@ -19,10 +24,29 @@ use glob::Pattern;
 /// they get auto-summaries and don't require agent-provided descriptions.
 #[derive(Debug, Clone)]
 pub struct ExemptionPatterns {
-    patterns: Vec<Pattern>,
+    rules: Vec<ExemptionRule>,
     raw_patterns: Vec<String>,
 }
 
+/// One compiled line of an exemption file.
+#[derive(Debug, Clone)]
+struct ExemptionRule {
+    pattern: Pattern,
+    /// `!pattern` — a match removes the exemption instead of granting it.
+    negated: bool,
+}
+
+impl ExemptionRule {
+    fn matches(&self, path: &str) -> bool {
+        self.pattern.matches(path)
+            || path
+                .rsplit('/')
+                .next()
+                .map(|filename| self.pattern.matches(filename))
+                .unwrap_or(false)
+    }
+}
+
 impl ExemptionPatterns {
     /// Load patterns from a file. Each non-empty, non-comment line is a glob pattern.
     pub fn from_file(path: &std::path::Path) -> Result<Self, std::io::Error> {
@@ -32,7 +56,7 @@ impl ExemptionPatterns {
 
     /// Parse patterns from a string (the file contents).
     pub fn parse_content(content: &str) -> Self {
-        let mut patterns = Vec::new();
+        let mut rules = Vec::new();
         let mut raw_patterns = Vec::new();
 
         for line in content.lines() {
@@ -42,13 +66,18 @@ impl ExemptionPatterns {
                 continue;
             }
             raw_patterns.push(trimmed.to_string());
-            if let Ok(pattern) = Pattern::new(trimmed) {
-                patterns.push(pattern);
+            let (glob, negated) = match trimmed.strip_prefix('!') {
+                Some(rest) => (rest.trim_start(), true),
+                None if trimmed.starts_with("\\!") => (&trimmed[1..], false),
+                None => (trimmed, false),
+            };
+            if let Ok(pattern) = Pattern::new(glob) {
+                rules.push(ExemptionRule { pattern, negated });
             }
         }
 
         Self {
-            patterns,
+            rules,
             raw_patterns,
         }
     }
@@ -70,17 +99,15 @@ impl ExemptionPatterns {
     /// Check if a URI is exempt from summary enforcement.
     ///
     /// The URI is expected to be in `fs://workspace/...` format.
-    /// The path portion (after `fs://workspace/`) is matched against patterns.
+    /// The path portion (after `fs://workspace/`) is matched against patterns;
+    /// the last matching rule decides, so a later `!pattern` un-exempts.
     pub fn is_exempt(&self, uri: &str) -> bool {
         let path = uri.strip_prefix("fs://workspace/").unwrap_or(uri);
-        self.patterns.iter().any(|p| {
-            p.matches(path)
-                || path
-                    .rsplit('/')
-                    .next()
-                    .map(|filename| p.matches(filename))
-                    .unwrap_or(false)
-        })
+        self.rules
+            .iter()
+            .rev()
+            .find(|rule| rule.matches(path))
+            .is_some_and(|rule| !rule.negated)
     }
 
     /// Return the raw pattern strings (for display/debugging).
@@ -224,6 +251,36 @@ mod tests {
         assert!(!patterns.is_exempt("fs://workspace/src/main.rs"));
     }
 
+    #[test]
+    fn negation_unexempts_file_matched_by_broad_doc_pattern() {
+        let content = "docs/**\n*.md\n!docs/api/schema.md\n";
+        let patterns = ExemptionPatterns::parse_content(content);
+        assert!(patterns.is_exempt("fs://workspace/docs/guide.md"));
+        assert!(patterns.is_exempt("fs://workspace/README.md"));
+        assert!(!patterns.is_exempt("fs://workspace/docs/api/schema.md"));
+    }
+
+    #[test]
+    fn last_match_wins_so_later_rule_force_exempts_source_file() {
+        let content = "src/generated/**\n!src/generated/schema.rs\nsrc/generated/schema.rs\n";
+        let patterns = ExemptionPatterns::parse_content(content);
+        assert!(patterns.is_exempt("fs://workspace/src/generated/schema.rs"));
+        assert!(patterns.is_exempt("fs://workspace/src/generated/other.rs"));
+        assert!(!patterns.is_exempt("fs://workspace/src/main.rs"));
+
+        // Source file force-exempted, then un-exempted by a later negation.
+        let patterns = ExemptionPatterns::parse_content("src/lib.rs\n!src/*.rs\n");
+        assert!(!patterns.is_exempt("fs://workspace/src/lib.rs"));
+    }
+
+    #[test]
+    fn negation_without_earlier_match_exempts_nothing() {
+        let patterns = ExemptionPatterns::parse_content("!*.md\n\\!weird.txt\n");
+        assert!(!patterns.is_exempt("fs://workspace/README.md"));
+        assert!(patterns.is_exempt("fs://workspace/!weird.txt"));
+        assert_eq!(patterns.raw_patterns(), ["!*.md", "\\!weird.txt"]);
+    }
+
     #[test]
     fn raw_patterns_accessible() {
         let patterns = ExemptionPatterns::parse_content("*.lock\n*.toml\n");
@@ -35,10 +35,12 @@ pub mod error;
 pub mod exemption;
 
 pub use alignment::{
-    AgentSetupProposal, AlignmentProfile, AutonomyEnvelope, CoordinationConfig, Milestone,
-    ProposedAgent,
+    AgentSetupProposal, AlignmentProfile, AutonomyEnvelope, CoordinationConfig, Handoff,
+    HandoffError, Milestone, ProposedAgent,
+};
+pub use capability::{
+    diff_grants, CapabilityGrant, CapabilityManifest, GrantChange, ManifestStore,
 };
-pub use capability::{CapabilityGrant, CapabilityManifest};
 pub use cascade::{CliOverrides, PolicyCascade};
 pub use compiler::{CompilerError, CompilerOptions, PolicyCompiler};
 pub use constitution::{
@@ -63,6 +65,8 @@ pub use document::{
     BudgetConfig, EscalationConfig, PolicyDefaults, PolicyDocument, PolicyEnforcement,
     SchemePolicy, SecurityLevel,
 };
-pub use engine::{EvaluationStep, EvaluationTrace, PolicyDecision, PolicyEngine, PolicyRequest};
+pub use engine::{
+    EvaluationStep, EvaluationTrace, PolicyDecision, PolicyEngine, PolicyReasonCode, PolicyRequest,
+};
 pub use error::PolicyError;
 pub use exemption::ExemptionPatterns;
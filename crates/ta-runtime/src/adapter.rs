@@ -104,6 +104,10 @@ pub struct SpawnRequest {
     /// Environment variables to set for the agent.
     pub env: HashMap<String, String>,
 
+    /// Start from an empty environment holding only `env`, instead of
+    /// inheriting TA's environment.
+    pub clear_env: bool,
+
     /// Working directory for the agent.
     pub working_dir: PathBuf,
 
@@ -271,6 +275,7 @@ mod tests {
             working_dir: PathBuf::from("/tmp"),
             stdin_mode: StdinMode::Inherited,
             stdout_mode: StdoutMode::Inherited,
+            clear_env: false,
         };
         assert_eq!(req.command, "claude");
         assert_eq!(req.args.len(), 2);
@@ -135,6 +135,9 @@ impl RuntimeAdapter for BareProcessRuntime {
         let mut cmd = build_command(&request.command, &request.args);
         cmd.current_dir(&request.working_dir);
 
+        if request.clear_env {
+            cmd.env_clear();
+        }
         for (key, value) in &request.env {
             cmd.env(key, value);
         }
@@ -250,6 +253,7 @@ mod tests {
             working_dir: std::env::temp_dir(),
             stdin_mode: StdinMode::Null,
             stdout_mode: StdoutMode::Inherited,
+            clear_env: false,
         };
         let mut handle = rt.spawn(req).expect("spawn should succeed");
         let status = handle.wait().expect("wait should succeed");
@@ -270,6 +274,7 @@ mod tests {
             working_dir: std::env::temp_dir(),
             stdin_mode: StdinMode::Null,
             stdout_mode: StdoutMode::Inherited,
+            clear_env: false,
         };
         let mut handle = rt.spawn(req).expect("spawn should succeed");
         let status = handle.wait().expect("wait should succeed");
@@ -288,6 +293,7 @@ mod tests {
             working_dir: std::env::temp_dir(),
             stdin_mode: StdinMode::Null,
             stdout_mode: StdoutMode::Piped,
+            clear_env: false,
         };
         let mut handle = rt.spawn(req).expect("spawn should succeed");
         let mut output = String::new();
@@ -298,6 +304,37 @@ mod tests {
         assert_eq!(output.trim(), "hello_piped");
     }
 
+    #[test]
+    fn spawn_with_clear_env_passes_only_request_env() {
+        use std::io::Read;
+
+        let rt = BareProcessRuntime::new();
+        let mut env = HashMap::new();
+        env.insert("ONLY_VAR".into(), "1".into());
+        env.insert("PATH".into(), std::env::var("PATH").unwrap_or_default());
+        let req = SpawnRequest {
+            command: "env".into(),
+            args: vec![],
+            env,
+            working_dir: std::env::temp_dir(),
+            stdin_mode: StdinMode::Null,
+            stdout_mode: StdoutMode::Piped,
+            clear_env: true,
+        };
+        let mut handle = rt.spawn(req).expect("spawn should succeed");
+        let mut output = String::new();
+        if let Some(mut stdout) = handle.take_stdout() {
+            stdout.read_to_string(&mut output).expect("read stdout");
+        }
+        handle.wait().expect("wait should succeed");
+        let mut keys: Vec<&str> = output
+            .lines()
+            .filter_map(|l| l.split_once('=').map(|(k, _)| k))
+            .collect();
+        keys.sort();
+        assert_eq!(keys, vec!["ONLY_VAR", "PATH"]);
+    }
+
     #[test]
     fn transport_info_is_stdio() {
         let rt = BareProcessRuntime::new();
@@ -308,6 +345,7 @@ mod tests {
             working_dir: std::env::temp_dir(),
             stdin_mode: StdinMode::Null,
             stdout_mode: StdoutMode::Inherited,
+            clear_env: false,
         };
         let mut handle = rt.spawn(req).expect("spawn should succeed");
         assert_eq!(handle.transport_info(), TransportInfo::Stdio);
@@ -325,6 +363,7 @@ mod tests {
             working_dir: std::env::temp_dir(),
             stdin_mode: StdinMode::Null,
             stdout_mode: StdoutMode::Inherited,
+            clear_env: false,
         };
         let mut handle = rt.spawn(req).expect("spawn should succeed");
         // Wait for the child, then check status.
@@ -362,6 +401,7 @@ mod tests {
             working_dir: std::env::temp_dir(),
             stdin_mode: StdinMode::Null,
             stdout_mode: StdoutMode::Inherited,
+            clear_env: false,
         };
         let mut handle = rt.spawn(req).expect("spawn should succeed");
         let creds = vec![ScopedCredential::new("K", "v")];
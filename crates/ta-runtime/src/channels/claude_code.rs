@@ -149,6 +149,10 @@ impl AgentContextChannel for ClaudeCodeChannel {
         self.append_to_context_file(persona_section)
     }
 
+    fn inject_reference_files(&self, reference_section: &str) -> anyhow::Result<()> {
+        self.append_to_context_file(reference_section)
+    }
+
     fn inject_work_plan(&self, plan_section: &str) -> anyhow::Result<()> {
         self.append_to_context_file(plan_section)
     }
@@ -252,6 +256,17 @@ mod tests {
         assert!(!dir.path().join("CLAUDE.md").exists());
     }
 
+    #[test]
+    fn inject_reference_files_appends_to_claude_md() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(dir.path().join("CLAUDE.md"), "# Base\n").unwrap();
+        let ch = make_channel(&dir);
+        ch.inject_reference_files("\n## Reference Files\n\n- `.ta/context/api.md`\n")
+            .unwrap();
+        let content = std::fs::read_to_string(dir.path().join("CLAUDE.md")).unwrap();
+        assert!(content.contains("## Reference Files"));
+    }
+
     #[test]
     fn inject_work_plan_appends_to_claude_md() {
         let dir = TempDir::new().unwrap();
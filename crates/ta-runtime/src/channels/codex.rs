@@ -148,6 +148,10 @@ impl AgentContextChannel for CodexChannel {
         self.append_to_context_file(persona_section)
     }
 
+    fn inject_reference_files(&self, reference_section: &str) -> anyhow::Result<()> {
+        self.append_to_context_file(reference_section)
+    }
+
     fn inject_work_plan(&self, plan_section: &str) -> anyhow::Result<()> {
         self.append_to_context_file(plan_section)
     }
@@ -136,6 +136,12 @@ pub trait AgentContextChannel: Send + Sync {
         Ok(())
     }
 
+    /// Append a section pointing at user-supplied reference files
+    /// (`ta run --context-file`) at goal start.
+    fn inject_reference_files(&self, _reference_section: &str) -> anyhow::Result<()> {
+        Ok(())
+    }
+
     /// Append a work-plan section to the context file at goal start.
     fn inject_work_plan(&self, _plan_section: &str) -> anyhow::Result<()> {
         Ok(())
@@ -91,6 +91,9 @@ struct SpawnParams {
     command: String,
     args: Vec<String>,
     env: HashMap<String, String>,
+    /// Plugins must not pass their own environment through when set.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    clear_env: bool,
     working_dir: String,
     stdin_mode: String,  // "null" | "inherited" | "piped"
     stdout_mode: String, // "inherited" | "piped"
@@ -305,6 +308,7 @@ impl RuntimeAdapter for ExternalRuntimeAdapter {
             command: request.command,
             args: request.args,
             env: request.env,
+            clear_env: request.clear_env,
             working_dir: request.working_dir.to_string_lossy().into_owned(),
             stdin_mode: stdin_mode.to_string(),
             stdout_mode: stdout_mode.to_string(),
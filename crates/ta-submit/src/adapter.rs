@@ -414,6 +414,20 @@ pub trait SourceAdapter: Send + Sync {
     fn head_rev_id(&self, _repo_root: &Path) -> Option<String> {
         None
     }
+
+    /// Post reviewer comments onto an open review (PR, Swarm review, etc.).
+    ///
+    /// Git: posts each comment as a GitHub PR review comment via `gh api`,
+    /// anchored to a line when `line` is set or to the whole file otherwise.
+    /// Returns the number of comments posted.
+    /// Default: error — the adapter has no review-comment API.
+    fn post_review_comments(&self, review_id: &str, comments: &[ReviewComment]) -> Result<usize> {
+        let _ = (review_id, comments);
+        Err(SubmitError::ReviewError(format!(
+            "the '{}' adapter does not support posting review comments",
+            self.name()
+        )))
+    }
 }
 
 /// Result of merging a review (PR, shelved CL, etc.) into the target branch.
@@ -439,6 +453,18 @@ pub struct ReviewStatus {
     pub checks_passing: Option<bool>,
 }
 
+/// A reviewer comment to post onto a VCS review (`ta draft comment-export`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReviewComment {
+    /// Repository-relative file path the comment is attached to.
+    pub path: String,
+    /// Comment body (markdown).
+    pub body: String,
+    /// 1-based line in the new version of the file. `None` = file-level comment.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line: Option<u32>,
+}
+
 /// Commit summary for log_since results.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CommitSummary {
@@ -446,6 +472,36 @@ pub struct CommitSummary {
     pub subject: String,
 }
 
+/// Append `Key: value` trailer lines to a commit message.
+///
+/// Git only recognizes trailers in the message's final paragraph, so when the
+/// message already ends with a trailer block the new lines join it instead of
+/// starting a separate paragraph.
+pub fn append_trailers(message: &str, trailers: &[String]) -> String {
+    let message = message.trim_end();
+    if trailers.is_empty() {
+        return message.to_string();
+    }
+    let last_paragraph = message.rsplit("\n\n").next().unwrap_or("");
+    let separator = if !last_paragraph.is_empty() && last_paragraph.lines().all(is_trailer_line) {
+        "\n"
+    } else {
+        "\n\n"
+    };
+    format!("{}{}{}", message, separator, trailers.join("\n"))
+}
+
+fn is_trailer_line(line: &str) -> bool {
+    match line.split_once(": ") {
+        Some((key, value)) => {
+            !key.is_empty()
+                && !value.trim().is_empty()
+                && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        }
+        None => false,
+    }
+}
+
 /// Backward-compatible alias: `SubmitAdapter` is the old name for `SourceAdapter`.
 ///
 /// Deprecated in v0.11.1. Use `SourceAdapter` instead.
@@ -486,6 +542,18 @@ mod tests {
         assert!(adapter.verify_not_on_protected_target().is_ok());
     }
 
+    #[test]
+    fn default_post_review_comments_is_unsupported() {
+        let adapter = MockAdapter;
+        let comments = vec![ReviewComment {
+            path: "src/main.rs".to_string(),
+            body: "needs error handling".to_string(),
+            line: None,
+        }];
+        let err = adapter.post_review_comments("42", &comments).unwrap_err();
+        assert!(err.to_string().contains("'mock' adapter"));
+    }
+
     #[test]
     fn sync_result_is_clean_when_no_conflicts() {
         let result = SyncResult {
@@ -528,6 +596,24 @@ mod tests {
         assert_eq!(restored.new_commits, 5);
     }
 
+    #[test]
+    fn append_trailers_joins_existing_trailer_block() {
+        let trailers = vec!["Goal-ID: g1".to_string(), "PR-ID: p1".to_string()];
+        assert_eq!(
+            append_trailers("Subject\n\nBody text.\n", &trailers),
+            "Subject\n\nBody text.\n\nGoal-ID: g1\nPR-ID: p1"
+        );
+        assert_eq!(
+            append_trailers("Subject\n\nBody.\n\nTA-Goal: g1\n", &trailers),
+            "Subject\n\nBody.\n\nTA-Goal: g1\nGoal-ID: g1\nPR-ID: p1"
+        );
+        // Keys containing spaces are prose, not trailers.
+        assert_eq!(
+            append_trailers("Subject\n\nSee also: the docs", &trailers),
+            "Subject\n\nSee also: the docs\n\nGoal-ID: g1\nPR-ID: p1"
+        );
+    }
+
     #[test]
     fn commit_diff_default_returns_none() {
         let adapter = MockAdapter;
@@ -4,6 +4,18 @@ use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+/// Environment variable that forces offline mode (set by `ta --offline`).
+pub const OFFLINE_ENV: &str = "TA_OFFLINE";
+
+/// Whether offline mode was requested through [`OFFLINE_ENV`].
+///
+/// Any non-empty value other than `0` or `false` turns offline mode on.
+pub fn offline_from_env() -> bool {
+    std::env::var(OFFLINE_ENV)
+        .map(|v| !v.is_empty() && v != "0" && !v.eq_ignore_ascii_case("false"))
+        .unwrap_or(false)
+}
+
 /// Top-level workflow configuration from .ta/workflow.toml
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct WorkflowConfig {
@@ -51,6 +63,15 @@ pub struct WorkflowConfig {
     #[serde(default)]
     pub staging: StagingConfig,
 
+    /// Overlay workspace copy/diff behavior.
+    ///
+    /// ```toml
+    /// [workspace]
+    /// parallel = true   # copy and diff files on all cores (default)
+    /// ```
+    #[serde(default)]
+    pub workspace: WorkspaceConfig,
+
     /// Constitution / compliance checker configuration (v0.12.0)
     #[serde(default)]
     pub constitution: ConstitutionConfig,
@@ -246,6 +267,14 @@ pub struct ApplyConfig {
     /// Special key `"default"` sets the fallback for files not matched by any pattern.
     #[serde(default, skip_serializing_if = "std::collections::HashMap::is_empty")]
     pub conflict_policy: std::collections::HashMap<String, String>,
+
+    /// Command run for the "merge tool" choice of `ta draft apply --interactive`.
+    ///
+    /// `{staged}` is replaced with the agent's staged file and `{source}` with
+    /// the current source file; the staged file's content after the tool exits
+    /// is what gets applied. Example: `"code --wait --diff {staged} {source}"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub merge_tool: Option<String>,
 }
 
 impl ApplyConfig {
@@ -499,13 +528,18 @@ impl Default for SandboxConfig {
     }
 }
 
-/// Audit log attestation configuration (v0.14.1).
+/// Audit log attestation and rotation configuration (v0.14.1).
 ///
 /// ```toml
 /// [audit]
 /// attestation = true
 /// # keys_dir defaults to .ta/keys/ (relative to workspace root)
 /// keys_dir = ".ta/keys"
+/// # Rotate audit.jsonl to audit-<timestamp>.jsonl past 10 MiB; keep 20 segments.
+/// max_bytes = 10485760
+/// keep_segments = 20
+/// # Record every policy decision (including allows) with its evaluation trace.
+/// policy_trace = true
 /// ```
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AuditConfig {
@@ -518,6 +552,22 @@ pub struct AuditConfig {
     /// Defaults to `.ta/keys` (relative to workspace root).
     #[serde(default = "default_keys_dir")]
     pub keys_dir: String,
+
+    /// Rotate the active audit log once it reaches this size in bytes.
+    /// 0 (default) disables rotation.
+    #[serde(default)]
+    pub max_bytes: u64,
+
+    /// Number of rotated segments to keep; older segments are deleted.
+    /// 0 (default) keeps every segment.
+    #[serde(default)]
+    pub keep_segments: usize,
+
+    /// Record every gateway policy decision as an audit event, including
+    /// allows, with the full `EvaluationTrace`. When false (default) only
+    /// denials and approval gates are logged. Read back with `ta policy trace`.
+    #[serde(default)]
+    pub policy_trace: bool,
 }
 
 fn default_keys_dir() -> String {
@@ -529,6 +579,9 @@ impl Default for AuditConfig {
         Self {
             attestation: false,
             keys_dir: default_keys_dir(),
+            max_bytes: 0,
+            keep_segments: 0,
+            policy_trace: false,
         }
     }
 }
@@ -1135,7 +1188,7 @@ impl Default for WorkflowSection {
 /// Submit adapter configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubmitConfig {
-    /// Adapter type: "git", "svn", "perforce", or "none"
+    /// Adapter type: "git", "hg", "svn", "perforce", or "none"
     #[serde(default = "default_adapter")]
     pub adapter: String,
 
@@ -1168,23 +1221,39 @@ pub struct SubmitConfig {
     /// SVN-specific configuration
     #[serde(default)]
     pub svn: SvnConfig,
+
+    /// Mercurial-specific configuration
+    #[serde(default)]
+    pub hg: HgConfig,
+
+    /// Air-gapped mode: always use the "none" adapter, never run VCS commands
+    /// or network operations, and apply drafts as a plain filesystem copy.
+    /// Also enabled by `ta --offline` / `TA_OFFLINE=1`.
+    #[serde(default)]
+    pub offline: bool,
 }
 
 impl SubmitConfig {
+    /// Whether offline mode is on, from `[submit] offline` or `TA_OFFLINE`.
+    pub fn is_offline(&self) -> bool {
+        self.offline || offline_from_env()
+    }
+
     /// Whether the full submit workflow should run by default.
     ///
     /// Resolution order:
-    /// 1. `auto_submit` if explicitly set
-    /// 2. `true` when adapter is not "none" (default behavior)
+    /// 1. `false` in offline mode
+    /// 2. `auto_submit` if explicitly set
+    /// 3. `true` when adapter is not "none" (default behavior)
     pub fn effective_auto_submit(&self) -> bool {
-        self.auto_submit.unwrap_or(self.adapter != "none")
+        !self.is_offline() && self.auto_submit.unwrap_or(self.adapter != "none")
     }
 
     /// Whether review should be opened after submit.
     ///
-    /// Resolution: explicit `auto_review` > default (true when adapter != "none").
+    /// Resolution: offline mode > explicit `auto_review` > default (true when adapter != "none").
     pub fn effective_auto_review(&self) -> bool {
-        self.auto_review.unwrap_or(self.adapter != "none")
+        !self.is_offline() && self.auto_review.unwrap_or(self.adapter != "none")
     }
 }
 
@@ -1198,6 +1267,8 @@ impl Default for SubmitConfig {
             git: GitConfig::default(),
             perforce: PerforceConfig::default(),
             svn: SvnConfig::default(),
+            hg: HgConfig::default(),
+            offline: false,
         }
     }
 }
@@ -1224,6 +1295,37 @@ pub struct SvnConfig {
     pub repo_url: Option<String>,
 }
 
+/// Mercurial adapter configuration (`[submit.hg]`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HgConfig {
+    /// Bookmark naming prefix (e.g., "ta/")
+    #[serde(default = "default_branch_prefix")]
+    pub bookmark_prefix: String,
+
+    /// Path alias to push to and pull from. Default: "default".
+    #[serde(default = "default_hg_remote")]
+    pub remote: String,
+
+    /// Bookmarks that agents must never commit to directly (§15).
+    /// Defaults to ["main", "master", "@"] when empty.
+    #[serde(default)]
+    pub protected_bookmarks: Vec<String>,
+}
+
+impl Default for HgConfig {
+    fn default() -> Self {
+        Self {
+            bookmark_prefix: default_branch_prefix(),
+            remote: default_hg_remote(),
+            protected_bookmarks: vec![],
+        }
+    }
+}
+
+fn default_hg_remote() -> String {
+    "default".to_string()
+}
+
 /// Source-level configuration section (`[source]` in workflow.toml).
 ///
 /// Groups adapter-agnostic sync settings. Provider-specific options
@@ -1357,6 +1459,11 @@ pub struct DiffConfig {
 
     /// Optional path override for diff-handlers.toml (defaults to .ta/diff-handlers.toml)
     pub handlers_file: Option<PathBuf>,
+
+    /// Line diff algorithm for draft diffs, used by `ta draft build` and
+    /// `ta draft view --diff-context`. Default: myers.
+    #[serde(default)]
+    pub algorithm: DiffAlgorithm,
 }
 
 impl Default for DiffConfig {
@@ -1364,10 +1471,27 @@ impl Default for DiffConfig {
         Self {
             open_external: default_open_external(),
             handlers_file: None,
+            algorithm: DiffAlgorithm::default(),
         }
     }
 }
 
+/// Line diff algorithm (`[diff] algorithm`).
+///
+/// - **Myers**: minimal diff, git's default.
+/// - **Patience**: anchors on lines unique to both versions; moved blocks
+///   read as whole-block moves.
+/// - **Histogram**: patience extended to anchor on the least frequent lines
+///   when none are unique (`git diff --histogram`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffAlgorithm {
+    #[default]
+    Myers,
+    Patience,
+    Histogram,
+}
+
 fn default_open_external() -> bool {
     true
 }
@@ -1487,6 +1611,19 @@ pub struct GcConfig {
     /// Emit a one-line warning on `ta` startup if stale drafts exist. Default: true.
     #[serde(default = "default_health_check")]
     pub health_check: bool,
+
+    /// Seconds an active goal may go without progress before the startup
+    /// sweep marks it `Failed { reason: "timed out" }`. Applies to goals that
+    /// were not started with `--timeout`. Default: 0 (disabled).
+    #[serde(default)]
+    pub goal_timeout_secs: u64,
+}
+
+impl GcConfig {
+    /// The default goal timeout, or `None` when disabled.
+    pub fn goal_timeout(&self) -> Option<u64> {
+        (self.goal_timeout_secs > 0).then_some(self.goal_timeout_secs)
+    }
 }
 
 impl Default for GcConfig {
@@ -1495,6 +1632,7 @@ impl Default for GcConfig {
             stale_threshold_days: default_stale_threshold_days(),
             stale_hint_days: default_stale_hint_days(),
             health_check: default_health_check(),
+            goal_timeout_secs: 0,
         }
     }
 }
@@ -1805,6 +1943,83 @@ impl StagingStrategy {
     }
 }
 
+/// Overlay workspace copy/diff behavior.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkspaceConfig {
+    /// Copy files at goal start and diff them at draft build in parallel.
+    /// Turn off on slow network filesystems where concurrent I/O hurts.
+    /// Default: true.
+    #[serde(default = "default_workspace_parallel")]
+    pub parallel: bool,
+    /// Minimum content similarity (0.0–1.0) for a deleted file and a created
+    /// file to be reported as one rename in the draft. 0 disables rename
+    /// detection. Default: 0.5.
+    #[serde(default = "default_rename_similarity")]
+    pub rename_similarity: f64,
+    /// Drop modifications whose effective diff is empty (the
+    /// whitespace-only edits enabled below) at draft build. Created and
+    /// deleted files are always kept. Default: true.
+    #[serde(default = "default_drop_noop")]
+    pub drop_noop: bool,
+    /// Count CRLF ⇄ LF conversions as no-ops. Default: false.
+    #[serde(default)]
+    pub noop_ignore_line_endings: bool,
+    /// Count trailing-whitespace-only edits as no-ops. Default: false.
+    #[serde(default)]
+    pub noop_ignore_trailing_whitespace: bool,
+    /// Normalize staged text files' line endings before diffing, so files
+    /// that only differ by CRLF/LF don't show up as rewritten. Applied files
+    /// carry the normalized endings. `.gitattributes` `eol`/`-text` take
+    /// precedence. Default: unset (no normalization).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub line_endings: Option<LineEndings>,
+    /// Stage several source roots side by side instead of the project alone,
+    /// as `alias = "path"` (relative to the project). Each root is copied to
+    /// `<staging>/<alias>/` and its artifacts are `fs://<alias>/<path>`.
+    /// Default: empty (single-root staging).
+    #[serde(default, skip_serializing_if = "std::collections::BTreeMap::is_empty")]
+    pub roots: std::collections::BTreeMap<String, PathBuf>,
+}
+
+/// Line-ending normalization for staged files (`[workspace] line_endings`).
+///
+/// - **Lf** / **Crlf**: convert every staged text file.
+/// - **Preserve**: match each file's existing source line endings; new
+///   files are left as staged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEndings {
+    Lf,
+    Crlf,
+    Preserve,
+}
+
+impl Default for WorkspaceConfig {
+    fn default() -> Self {
+        Self {
+            parallel: default_workspace_parallel(),
+            rename_similarity: default_rename_similarity(),
+            drop_noop: default_drop_noop(),
+            noop_ignore_line_endings: false,
+            noop_ignore_trailing_whitespace: false,
+            line_endings: None,
+            roots: Default::default(),
+        }
+    }
+}
+
+fn default_drop_noop() -> bool {
+    true
+}
+
+fn default_workspace_parallel() -> bool {
+    true
+}
+
+fn default_rename_similarity() -> f64 {
+    0.5
+}
+
 /// Staging directory management (v0.11.3, extended v0.13.13).
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StagingConfig {
@@ -2011,6 +2226,22 @@ mod tests {
         assert_eq!(config.build.adapter, "auto");
     }
 
+    #[test]
+    fn parse_toml_with_workspace_roots() {
+        let toml = r#"
+[workspace.roots]
+api = "packages/api"
+web = "../web"
+"#;
+        let config: WorkflowConfig = toml::from_str(toml).unwrap();
+        assert_eq!(
+            config.workspace.roots.get("web"),
+            Some(&PathBuf::from("../web"))
+        );
+        assert_eq!(config.workspace.roots.len(), 2);
+        assert!(WorkflowConfig::default().workspace.roots.is_empty());
+    }
+
     #[test]
     fn parse_toml_with_build_section() {
         let toml = r#"
@@ -2475,6 +2706,17 @@ auto_merge = true
         assert!(config.submit.git.auto_merge);
     }
 
+    #[test]
+    fn diff_algorithm_defaults_to_myers_and_parses() {
+        assert_eq!(
+            WorkflowConfig::default().diff.algorithm,
+            DiffAlgorithm::Myers
+        );
+        let config: WorkflowConfig = toml::from_str("[diff]\nalgorithm = \"patience\"\n").unwrap();
+        assert_eq!(config.diff.algorithm, DiffAlgorithm::Patience);
+        assert!(toml::from_str::<WorkflowConfig>("[diff]\nalgorithm = \"minimal\"\n").is_err());
+    }
+
     #[test]
     fn sandbox_config_defaults() {
         let config = SandboxConfig::default();
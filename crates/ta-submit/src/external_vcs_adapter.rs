@@ -265,6 +265,7 @@ impl SourceAdapter for ExternalVcsAdapter {
             .changes
             .artifacts
             .iter()
+            .filter(|a| !a.no_commit)
             .map(|a| {
                 a.resource_uri
                     .trim_start_matches("fs://workspace/")
@@ -6,8 +6,8 @@ use ta_changeset::DraftPackage;
 use ta_goal::CommitContext;
 
 use crate::adapter::{
-    CommitResult, CommitSummary, MergeResult, PushResult, Result, ReviewResult, ReviewStatus,
-    SavedVcsState, SourceAdapter, SubmitError, SyncResult,
+    append_trailers, CommitResult, CommitSummary, MergeResult, PushResult, Result, ReviewComment,
+    ReviewResult, ReviewStatus, SavedVcsState, SourceAdapter, SubmitError, SyncResult,
 };
 use crate::config::SubmitConfig;
 use crate::config::SyncConfig;
@@ -112,63 +112,16 @@ impl GitAdapter {
 
     /// Generate a safe git branch name from the goal title.
     ///
-    /// Sanitization steps (item 28):
-    /// 1. Lowercase and map all non-alphanumeric chars to `-`
-    /// 2. Collapse consecutive dashes into a single `-`
-    /// 3. Trim leading and trailing dashes (fixes titles like `` `ta sync` ``)
-    /// 4. Truncate to 50 chars and trim any trailing dashes from truncation
-    ///
-    /// All characters are passed directly to git as command arguments, not
-    /// through shell interpolation, so no shell-escaping is needed.
+    /// The title is sanitized by [`branch_slug`]. All characters are passed
+    /// directly to git as command arguments, not through shell interpolation,
+    /// so no shell-escaping is needed.
     fn branch_name(&self, ctx: &CommitContext, config: &SubmitConfig) -> String {
         let prefix = &config.git.branch_prefix;
 
-        // Step 1: lowercase + replace non-alphanumeric/dash with dash.
-        let raw: String = ctx
-            .title
-            .to_lowercase()
-            .chars()
-            .map(|c| {
-                if c.is_alphanumeric() || c == '-' {
-                    c
-                } else {
-                    '-'
-                }
-            })
-            .collect();
-
-        // Step 2: collapse consecutive dashes.
-        let mut collapsed = String::with_capacity(raw.len());
-        let mut prev_dash = false;
-        for c in raw.chars() {
-            if c == '-' {
-                if !prev_dash {
-                    collapsed.push(c);
-                }
-                prev_dash = true;
-            } else {
-                collapsed.push(c);
-                prev_dash = false;
-            }
-        }
-
-        // Step 3: trim leading/trailing dashes.
-        let trimmed = collapsed.trim_matches('-');
-
-        // Fallback if trimming produced an empty string (e.g. title was "!!!").
-        let slug = if trimmed.is_empty() { "goal" } else { trimmed };
-
-        // Step 4: truncate to 50 chars, then trim any trailing dash from truncation.
-        let truncated = if slug.len() > 50 {
-            slug[..50].trim_end_matches('-')
-        } else {
-            slug
-        };
-
         // v0.14.7.3: Prefix branch with goal shortref for traceability.
         // e.g. ta/2159d87e-v0-14-7-1-shell-ux-fixes
         let shortref = ctx.shortref();
-        format!("{}{}-{}", prefix, shortref, truncated)
+        format!("{}{}-{}", prefix, shortref, branch_slug(&ctx.title))
     }
 
     /// Auto-detect whether this is a git repository.
@@ -344,6 +297,57 @@ impl GitAdapter {
     }
 }
 
+/// Sanitize a goal title into a branch/bookmark-safe slug (item 28).
+///
+/// 1. Lowercase and map all non-alphanumeric chars to `-`
+/// 2. Collapse consecutive dashes into a single `-`
+/// 3. Trim leading and trailing dashes (fixes titles like `` `ta sync` ``)
+/// 4. Truncate to 50 chars and trim any trailing dashes from truncation
+pub(crate) fn branch_slug(title: &str) -> String {
+    // Step 1: lowercase + replace non-alphanumeric/dash with dash.
+    let raw: String = title
+        .to_lowercase()
+        .chars()
+        .map(|c| {
+            if c.is_alphanumeric() || c == '-' {
+                c
+            } else {
+                '-'
+            }
+        })
+        .collect();
+
+    // Step 2: collapse consecutive dashes.
+    let mut collapsed = String::with_capacity(raw.len());
+    let mut prev_dash = false;
+    for c in raw.chars() {
+        if c == '-' {
+            if !prev_dash {
+                collapsed.push(c);
+            }
+            prev_dash = true;
+        } else {
+            collapsed.push(c);
+            prev_dash = false;
+        }
+    }
+
+    // Step 3: trim leading/trailing dashes.
+    let trimmed = collapsed.trim_matches('-');
+
+    // Fallback if trimming produced an empty string (e.g. title was "!!!").
+    let slug = if trimmed.is_empty() { "goal" } else { trimmed };
+
+    // Step 4: truncate to 50 chars, then trim any trailing dash from truncation.
+    let truncated = if slug.len() > 50 {
+        slug[..50].trim_end_matches('-')
+    } else {
+        slug
+    };
+
+    truncated.to_string()
+}
+
 impl SourceAdapter for GitAdapter {
     fn prepare(&self, ctx: &CommitContext, config: &SubmitConfig) -> Result<()> {
         let branch_name = self.branch_name(ctx, config);
@@ -409,16 +413,22 @@ impl SourceAdapter for GitAdapter {
         // filesystem paths are staged.
         // Deduplicate: a follow-up draft or combined parent+child diff can
         // produce the same path more than once in the artifact list.
+        // Apply-only artifacts (`CommitIntent::NoCommit`) are on disk but are
+        // never staged.
+        let has_fs_artifacts = pr
+            .changes
+            .artifacts
+            .iter()
+            .any(|a| a.resource_uri.starts_with("fs://workspace/"));
         let mut seen = std::collections::HashSet::new();
         let artifact_paths: Vec<String> = pr
             .changes
             .artifacts
             .iter()
-            .filter_map(|a| {
-                a.resource_uri
-                    .strip_prefix("fs://workspace/")
-                    .map(|p| p.to_string())
-            })
+            .filter(|a| !a.no_commit)
+            // A rename also stages the removal of its old path.
+            .flat_map(|a| std::iter::once(&a.resource_uri).chain(a.renamed_from.as_ref()))
+            .filter_map(|uri| uri.strip_prefix("fs://workspace/").map(|p| p.to_string()))
             .filter(|p| seen.insert(p.clone()))
             .collect();
 
@@ -501,6 +511,17 @@ impl SourceAdapter for GitAdapter {
         };
 
         if artifact_paths.is_empty() {
+            if has_fs_artifacts {
+                // Every file artifact is apply-only — `git add .` would pick
+                // them up, so there is nothing to commit.
+                return Ok(CommitResult {
+                    commit_id: String::new(),
+                    message: "All artifacts are apply-only (no commit) — nothing was committed."
+                        .to_string(),
+                    metadata: std::collections::HashMap::new(),
+                    ignored_artifacts,
+                });
+            }
             // Fall back to `git add .` when there are no fs:// artifacts
             // (e.g. all artifacts are external URIs like mailto://).
             self.git_cmd(&["add", "."])?;
@@ -515,20 +536,17 @@ impl SourceAdapter for GitAdapter {
         }
 
         // Append metadata trailers to the caller-provided message.
-        let phase_line = ctx
-            .plan_phase
-            .as_ref()
-            .map(|p| format!("\nPhase: {}", p))
-            .unwrap_or_default();
-        let co_author_line = if self.config.co_author.is_empty() {
-            String::new()
-        } else {
-            format!("\n\nCo-Authored-By: {}", self.config.co_author)
-        };
-        let commit_msg = format!(
-            "{}\n\nGoal-ID: {}\nPR-ID: {}{}{}",
-            message, ctx.goal_run_id, pr.package_id, phase_line, co_author_line
-        );
+        let mut trailers = vec![
+            format!("Goal-ID: {}", ctx.goal_run_id),
+            format!("PR-ID: {}", pr.package_id),
+        ];
+        if let Some(phase) = &ctx.plan_phase {
+            trailers.push(format!("Phase: {}", phase));
+        }
+        if !self.config.co_author.is_empty() {
+            trailers.push(format!("Co-Authored-By: {}", self.config.co_author));
+        }
+        let commit_msg = append_trailers(message, &trailers);
 
         // Commit
         self.git_cmd(&["commit", "-m", &commit_msg])?;
@@ -1344,6 +1362,92 @@ impl SourceAdapter for GitAdapter {
             None
         }
     }
+
+    fn post_review_comments(&self, review_id: &str, comments: &[ReviewComment]) -> Result<usize> {
+        if comments.is_empty() {
+            return Ok(0);
+        }
+        if !self.has_gh_cli() {
+            return Err(SubmitError::ReviewError(
+                "gh CLI not found — install GitHub CLI to post review comments to the PR."
+                    .to_string(),
+            ));
+        }
+
+        // Review comments must be anchored to a commit — use the PR's head.
+        let view = Command::new("gh")
+            .args(["pr", "view", review_id, "--json", "number,headRefOid"])
+            .current_dir(&self.work_dir)
+            .output()?;
+        if !view.status.success() {
+            return Err(SubmitError::ReviewError(format!(
+                "gh pr view failed for PR {}: {}",
+                review_id,
+                String::from_utf8_lossy(&view.stderr).trim()
+            )));
+        }
+        let json: serde_json::Value = serde_json::from_slice(&view.stdout).map_err(|e| {
+            SubmitError::ReviewError(format!("Failed to parse gh pr view output: {}", e))
+        })?;
+        let number = json.get("number").and_then(|v| v.as_u64()).ok_or_else(|| {
+            SubmitError::ReviewError(format!("gh pr view returned no number for {}", review_id))
+        })?;
+        let head = json
+            .get("headRefOid")
+            .and_then(|v| v.as_str())
+            .ok_or_else(|| {
+                SubmitError::ReviewError(format!(
+                    "gh pr view returned no head commit for PR #{}",
+                    number
+                ))
+            })?
+            .to_string();
+
+        let endpoint = format!("repos/{{owner}}/{{repo}}/pulls/{}/comments", number);
+        let mut posted = 0;
+        for comment in comments {
+            let mut args = vec![
+                "api".to_string(),
+                "-X".to_string(),
+                "POST".to_string(),
+                endpoint.clone(),
+                "-f".to_string(),
+                format!("body={}", comment.body),
+                "-f".to_string(),
+                format!("path={}", comment.path),
+                "-f".to_string(),
+                format!("commit_id={}", head),
+            ];
+            match comment.line {
+                Some(line) => args.extend([
+                    "-F".to_string(),
+                    format!("line={}", line),
+                    "-f".to_string(),
+                    "side=RIGHT".to_string(),
+                ]),
+                None => args.extend(["-f".to_string(), "subject_type=file".to_string()]),
+            }
+
+            let output = Command::new("gh")
+                .args(&args)
+                .current_dir(&self.work_dir)
+                .output()?;
+            if !output.status.success() {
+                return Err(SubmitError::ReviewError(format!(
+                    "posting comment on {} to PR #{} failed after {} of {} comment(s) were posted: {}",
+                    comment.path,
+                    number,
+                    posted,
+                    comments.len(),
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )));
+            }
+            posted += 1;
+        }
+
+        tracing::info!(pr = number, posted, "GitAdapter: posted review comments");
+        Ok(posted)
+    }
 }
 
 impl GitAdapter {
@@ -0,0 +1,558 @@
+//! Mercurial adapter for bookmark-based workflows.
+//!
+//! Mirrors the Git workflow with Mercurial primitives:
+//! - `prepare()` creates (or activates) a bookmark named like the Git feature branch
+//! - `commit()` runs `hg addremove` on the draft's artifact paths, then `hg commit`
+//! - `push()` runs `hg push -B <bookmark>` to the configured path alias
+//!
+//! Mercurial hosting varies (Heptapod, Phabricator, self-hosted hgweb), so
+//! `open_review()` does not create a review. It returns the changeset hash with
+//! a note instead.
+
+use std::path::Path;
+use std::process::{Command, Output};
+use ta_changeset::DraftPackage;
+use ta_goal::CommitContext;
+
+use crate::adapter::{
+    append_trailers, CommitResult, CommitSummary, PushResult, Result, ReviewResult, SavedVcsState,
+    SourceAdapter, SubmitError, SyncResult,
+};
+use crate::config::SubmitConfig;
+use crate::git::branch_slug;
+
+/// Mercurial adapter implementing a bookmark-based workflow.
+pub struct MercurialAdapter {
+    /// Working directory for hg operations
+    work_dir: std::path::PathBuf,
+    /// Submit configuration (co-author, bookmark prefix, remote, etc.)
+    config: SubmitConfig,
+    /// Plan file name relative to workspace root (default: "PLAN.md").
+    plan_file: String,
+}
+
+impl MercurialAdapter {
+    /// Create a new MercurialAdapter for the given working directory
+    pub fn new(work_dir: impl Into<std::path::PathBuf>) -> Self {
+        Self::with_config(work_dir, SubmitConfig::default())
+    }
+
+    /// Create a new MercurialAdapter with explicit configuration
+    pub fn with_config(work_dir: impl Into<std::path::PathBuf>, config: SubmitConfig) -> Self {
+        Self {
+            work_dir: work_dir.into(),
+            config,
+            plan_file: "PLAN.md".to_string(),
+        }
+    }
+
+    /// Run an hg command and return its raw output.
+    ///
+    /// `HGPLAIN` disables user aliases, localization, and other output
+    /// customization so the results can be parsed reliably.
+    fn hg_output(&self, args: &[&str]) -> Result<Output> {
+        Ok(Command::new("hg")
+            .args(args)
+            .current_dir(&self.work_dir)
+            .env("HGPLAIN", "1")
+            .output()?)
+    }
+
+    /// Run an hg command in the working directory
+    fn hg_cmd(&self, args: &[&str]) -> Result<String> {
+        let output = self.hg_output(args)?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(SubmitError::VcsError(format!(
+                "hg {} failed: {}",
+                args.join(" "),
+                stderr
+            )));
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    /// Bookmark name for a goal: `<prefix><shortref>-<slug>`, same shape as
+    /// the Git feature branch.
+    fn bookmark_name(&self, ctx: &CommitContext) -> String {
+        format!(
+            "{}{}-{}",
+            self.config.hg.bookmark_prefix,
+            ctx.shortref(),
+            branch_slug(&ctx.title)
+        )
+    }
+
+    /// The active bookmark, if any.
+    fn active_bookmark(&self) -> Result<Option<String>> {
+        let name = self.hg_cmd(&["log", "-r", ".", "--template", "{activebookmark}"])?;
+        Ok(Some(name).filter(|n| !n.is_empty()))
+    }
+
+    /// Auto-detect whether this is a Mercurial repository.
+    pub fn detect(project_root: &Path) -> bool {
+        project_root.join(".hg").exists()
+    }
+}
+
+impl SourceAdapter for MercurialAdapter {
+    fn prepare(&self, ctx: &CommitContext, _config: &SubmitConfig) -> Result<()> {
+        let bookmark = self.bookmark_name(ctx);
+
+        tracing::info!("MercurialAdapter: activating bookmark {}", bookmark);
+
+        // If the bookmark already exists, switch to it; otherwise create it at
+        // the working directory parent. Uncommitted changes carry over either way.
+        let existing = self.hg_cmd(&["bookmarks", "--template", "{bookmark}\n"])?;
+        if existing.lines().any(|b| b == bookmark) {
+            self.hg_cmd(&["update", &bookmark])?;
+        } else {
+            self.hg_cmd(&["bookmark", &bookmark])?;
+        }
+
+        Ok(())
+    }
+
+    fn commit(
+        &self,
+        ctx: &CommitContext,
+        pr: &DraftPackage,
+        message: &str,
+    ) -> Result<CommitResult> {
+        tracing::info!("MercurialAdapter: committing changes");
+
+        // Commit only the draft's own files (same selection as GitAdapter):
+        // fs:// artifacts, deduplicated, excluding apply-only artifacts.
+        let has_fs_artifacts = pr
+            .changes
+            .artifacts
+            .iter()
+            .any(|a| a.resource_uri.starts_with("fs://workspace/"));
+        let mut seen = std::collections::HashSet::new();
+        let mut paths: Vec<String> = pr
+            .changes
+            .artifacts
+            .iter()
+            .filter(|a| !a.no_commit)
+            // A rename also stages the removal of its old path.
+            .flat_map(|a| std::iter::once(&a.resource_uri).chain(a.renamed_from.as_ref()))
+            .filter_map(|uri| uri.strip_prefix("fs://workspace/").map(|p| p.to_string()))
+            .filter(|p| seen.insert(p.clone()))
+            .collect();
+
+        if paths.is_empty() && has_fs_artifacts {
+            return Ok(CommitResult {
+                commit_id: String::new(),
+                message: "All artifacts are apply-only (no commit) — nothing was committed."
+                    .to_string(),
+                metadata: std::collections::HashMap::new(),
+                ignored_artifacts: vec![],
+            });
+        }
+
+        // The plan file is always included if it exists (may have been updated by apply).
+        if !paths.is_empty()
+            && self.work_dir.join(&self.plan_file).exists()
+            && !paths.contains(&self.plan_file)
+        {
+            paths.push(self.plan_file.clone());
+        }
+
+        // `hg addremove` adds new files and marks missing ones as removed, so
+        // agent-deleted files are recorded without a separate step. With no
+        // fs:// artifacts (e.g. only external URIs), fall back to the whole tree.
+        let mut addremove_args = vec!["addremove"];
+        let mut status_args = vec!["status", "--modified", "--added", "--removed"];
+        let mut commit_paths: Vec<&str> = Vec::new();
+        if !paths.is_empty() {
+            addremove_args.push("--");
+            status_args.push("--");
+            for p in &paths {
+                addremove_args.push(p.as_str());
+                status_args.push(p.as_str());
+                commit_paths.push(p.as_str());
+            }
+        }
+        self.hg_cmd(&addremove_args)?;
+
+        // Check if there are changes to commit
+        let status = self.hg_cmd(&status_args)?;
+        if status.trim().is_empty() {
+            return Err(SubmitError::InvalidState(
+                "No changes to commit".to_string(),
+            ));
+        }
+
+        // Append metadata trailers to the caller-provided message.
+        let mut trailers = vec![
+            format!("Goal-ID: {}", ctx.goal_run_id),
+            format!("PR-ID: {}", pr.package_id),
+        ];
+        if let Some(phase) = &ctx.plan_phase {
+            trailers.push(format!("Phase: {}", phase));
+        }
+        if !self.config.co_author.is_empty() {
+            trailers.push(format!("Co-Authored-By: {}", self.config.co_author));
+        }
+        let commit_msg = append_trailers(message, &trailers);
+
+        let mut commit_args = vec!["commit", "-m", commit_msg.as_str()];
+        if !commit_paths.is_empty() {
+            commit_args.push("--");
+            commit_args.extend(commit_paths);
+        }
+        self.hg_cmd(&commit_args)?;
+
+        let node = self.hg_cmd(&["log", "-r", ".", "--template", "{node}"])?;
+
+        Ok(CommitResult {
+            commit_id: node.clone(),
+            message: format!("Committed as {}", &node[..node.len().min(12)]),
+            metadata: [("full_hash".to_string(), node)].into_iter().collect(),
+            ignored_artifacts: vec![],
+        })
+    }
+
+    fn push(&self, ctx: &CommitContext) -> Result<PushResult> {
+        let bookmark = self.bookmark_name(ctx);
+        let remote = &self.config.hg.remote;
+
+        tracing::info!(
+            "MercurialAdapter: pushing bookmark {} to {}",
+            bookmark,
+            remote
+        );
+
+        // `hg push` exits 1 when there is nothing to push — not a failure.
+        let args = ["push", "-B", bookmark.as_str(), remote.as_str()];
+        let output = self.hg_output(&args)?;
+        if !output.status.success() && output.status.code() != Some(1) {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(SubmitError::VcsError(format!(
+                "hg {} failed: {}",
+                args.join(" "),
+                stderr
+            )));
+        }
+
+        Ok(PushResult {
+            remote_ref: format!("{}#{}", remote, bookmark),
+            message: format!("Pushed bookmark {} to {}", bookmark, remote),
+            metadata: [
+                ("bookmark".to_string(), bookmark),
+                ("remote".to_string(), remote.clone()),
+            ]
+            .into_iter()
+            .collect(),
+        })
+    }
+
+    fn open_review(&self, ctx: &CommitContext, _pr: &DraftPackage) -> Result<ReviewResult> {
+        let node = self
+            .hg_cmd(&["log", "-r", ".", "--template", "{node}"])
+            .unwrap_or_else(|_| "unknown".to_string());
+        let bookmark = self.bookmark_name(ctx);
+        tracing::debug!("MercurialAdapter: open_review() — returning changeset hash");
+        Ok(ReviewResult {
+            review_url: format!("hg://{}", node),
+            review_id: node.clone(),
+            message: format!(
+                "Mercurial hosting varies, so no review was opened automatically. \
+                 Open a review for bookmark '{}' (changeset {}) in your code review tool.",
+                bookmark, node
+            ),
+            metadata: [("bookmark".to_string(), bookmark)].into_iter().collect(),
+        })
+    }
+
+    fn sync_upstream(&self) -> Result<SyncResult> {
+        tracing::info!("MercurialAdapter: running hg pull --update");
+
+        let before = self.head_sha();
+        let remote = self.config.hg.remote.as_str();
+        match self.hg_cmd(&["pull", "--update", remote]) {
+            Ok(output) => {
+                let after = self.head_sha();
+                let new_commits = match (&before, &after) {
+                    (Some(b), Some(a)) if a != b => self
+                        .hg_cmd(&["log", "-r", &format!("{}::. - {}", b, b), "--template", "x"])
+                        .map(|s| s.len() as u32)
+                        .unwrap_or(0),
+                    _ => 0,
+                };
+                Ok(SyncResult {
+                    updated: before != after,
+                    conflicts: vec![],
+                    new_commits,
+                    message: format!("hg pull completed. {}", output.lines().last().unwrap_or("")),
+                    metadata: Default::default(),
+                })
+            }
+            Err(e) => Err(SubmitError::SyncError(format!("hg pull failed: {}", e))),
+        }
+    }
+
+    fn name(&self) -> &str {
+        "hg"
+    }
+
+    fn exclude_patterns(&self) -> Vec<String> {
+        vec![".hg/".to_string()]
+    }
+
+    fn commit_diff(&self) -> Option<String> {
+        match self.hg_cmd(&["diff", "--change", "."]) {
+            Ok(diff) => Some(diff),
+            Err(e) => {
+                tracing::warn!("MercurialAdapter: commit_diff failed ({}); scan skipped", e);
+                None
+            }
+        }
+    }
+
+    fn save_state(&self) -> Result<Option<SavedVcsState>> {
+        // Save the active bookmark, or the working directory parent when none is active.
+        let target = match self.active_bookmark()? {
+            Some(bookmark) => bookmark,
+            None => self.hg_cmd(&["log", "-r", ".", "--template", "{node}"])?,
+        };
+        tracing::debug!(target = %target, "MercurialAdapter: saved state");
+        Ok(Some(SavedVcsState {
+            adapter: "hg".to_string(),
+            data: Box::new(target),
+        }))
+    }
+
+    fn restore_state(&self, state: Option<SavedVcsState>) -> Result<()> {
+        let state = match state {
+            Some(s) => s,
+            None => return Ok(()),
+        };
+
+        if state.adapter != "hg" {
+            return Err(SubmitError::InvalidState(format!(
+                "Cannot restore state from adapter '{}' in MercurialAdapter",
+                state.adapter
+            )));
+        }
+
+        let target = state
+            .data
+            .downcast::<String>()
+            .map_err(|_| SubmitError::InvalidState("Invalid saved state type".to_string()))?;
+
+        if let Err(e) = self.hg_cmd(&["update", &target]) {
+            tracing::warn!(
+                target = %target,
+                error = %e,
+                "MercurialAdapter: could not restore state. Run: hg update {}",
+                target
+            );
+        }
+        Ok(())
+    }
+
+    fn current_branch(&self) -> Result<String> {
+        // Bookmarks play the role of Git branches; fall back to the named branch.
+        match self.active_bookmark()? {
+            Some(bookmark) => Ok(bookmark),
+            None => self.hg_cmd(&["branch"]),
+        }
+    }
+
+    fn revision_id(&self) -> Result<String> {
+        // `hg id -i` prints the short hash with a trailing `+` when dirty.
+        let id = self.hg_cmd(&["id", "-i"])?;
+        Ok(match id.strip_suffix('+') {
+            Some(hash) => format!("{}-dirty", hash),
+            None => id,
+        })
+    }
+
+    fn is_dirty(&self) -> Result<bool> {
+        let status = self.hg_cmd(&["status", "--modified", "--added", "--removed", "--deleted"])?;
+        Ok(!status.trim().is_empty())
+    }
+
+    fn list_tracked_files(&self) -> Result<Vec<std::path::PathBuf>> {
+        let output = self.hg_cmd(&["files"])?;
+        Ok(output
+            .lines()
+            .filter(|l| !l.is_empty())
+            .map(|l| self.work_dir.join(l))
+            .collect())
+    }
+
+    fn head_sha(&self) -> Option<String> {
+        self.hg_cmd(&["log", "-r", ".", "--template", "{node}"])
+            .ok()
+            .map(|s| s.trim().to_string())
+    }
+
+    fn log_since(&self, ref_: &str) -> Result<Vec<CommitSummary>> {
+        let revset = if ref_.is_empty() {
+            "reverse(::.)".to_string()
+        } else {
+            format!("reverse({}::. - {})", ref_, ref_)
+        };
+        let output = self.hg_cmd(&[
+            "log",
+            "-r",
+            &revset,
+            "--no-merges",
+            "--limit",
+            "50",
+            "--template",
+            "{node} {desc|firstline}\n",
+        ])?;
+        Ok(output
+            .lines()
+            .filter_map(|l| {
+                let (sha, subject) = l.split_once(' ')?;
+                Some(CommitSummary {
+                    sha: sha.to_string(),
+                    subject: subject.to_string(),
+                })
+            })
+            .collect())
+    }
+
+    fn checkout_branch(&self, branch: &str) -> Result<()> {
+        if let Err(e) = self.hg_cmd(&["update", branch]) {
+            tracing::warn!(
+                branch = %branch,
+                error = %e,
+                "MercurialAdapter: checkout_branch failed — continuing"
+            );
+        }
+        Ok(()) // Non-fatal, same as GitAdapter.
+    }
+
+    fn create_tag(&self, tag: &str, message: &str) -> Result<()> {
+        // Mercurial tags are commits to .hgtags.
+        self.hg_cmd(&["tag", "-m", message, tag]).map(|_| ())
+    }
+
+    fn tag_exists(&self, tag: &str) -> Result<bool> {
+        let output = self
+            .hg_cmd(&["tags", "--template", "{tag}\n"])
+            .unwrap_or_default();
+        Ok(output.lines().any(|t| t == tag))
+    }
+
+    fn push_tag(&self, tag: &str) -> Result<()> {
+        // The tag lives in the .hgtags commit, which a regular push carries.
+        let _ = tag;
+        let remote = self.config.hg.remote.as_str();
+        let output = self.hg_output(&["push", remote])?;
+        if !output.status.success() && output.status.code() != Some(1) {
+            return Err(SubmitError::VcsError(format!(
+                "hg push {} failed: {}",
+                remote,
+                String::from_utf8_lossy(&output.stderr)
+            )));
+        }
+        Ok(())
+    }
+
+    fn protected_submit_targets(&self) -> Vec<String> {
+        // Configured protected bookmarks, or the well-known defaults.
+        let custom = &self.config.hg.protected_bookmarks;
+        if !custom.is_empty() {
+            return custom.clone();
+        }
+        vec!["main".to_string(), "master".to_string(), "@".to_string()]
+    }
+
+    fn verify_not_on_protected_target(&self) -> Result<()> {
+        let protected = self.protected_submit_targets();
+        match self.active_bookmark()? {
+            Some(bookmark) if protected.contains(&bookmark) => {
+                Err(SubmitError::InvalidState(format!(
+                    "Refusing to commit: still on protected bookmark '{}' after prepare(). \
+                     This would bypass the feature bookmark + review workflow. \
+                     Check that the VCS adapter created a feature bookmark, then \
+                     re-run `ta draft apply --submit`.",
+                    bookmark
+                )))
+            }
+            Some(_) => Ok(()),
+            None => Err(SubmitError::InvalidState(
+                "Refusing to commit: no active bookmark after prepare(). \
+                 Check that the VCS adapter created a feature bookmark, then \
+                 re-run `ta draft apply --submit`."
+                    .to_string(),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_ctx(dir: &Path, title: &str) -> CommitContext {
+        let goal = ta_goal::GoalRun::new(
+            title,
+            "Test",
+            "test-agent",
+            dir.to_path_buf(),
+            dir.join("store"),
+        );
+        CommitContext::from(&goal)
+    }
+
+    #[test]
+    fn test_hg_adapter_name_and_excludes() {
+        let dir = tempfile::tempdir().unwrap();
+        let adapter = MercurialAdapter::new(dir.path());
+        assert_eq!(adapter.name(), "hg");
+        assert_eq!(adapter.exclude_patterns(), vec![".hg/"]);
+    }
+
+    #[test]
+    fn test_hg_adapter_detect() {
+        let dir = tempfile::tempdir().unwrap();
+        assert!(!MercurialAdapter::detect(dir.path()));
+
+        std::fs::create_dir(dir.path().join(".hg")).unwrap();
+        assert!(MercurialAdapter::detect(dir.path()));
+    }
+
+    #[test]
+    fn test_hg_bookmark_name_matches_git_branch_shape() {
+        let dir = tempfile::tempdir().unwrap();
+        let adapter = MercurialAdapter::new(dir.path());
+        let ctx = test_ctx(dir.path(), "Fix `ta sync` output!");
+        let name = adapter.bookmark_name(&ctx);
+        assert_eq!(name, format!("ta/{}-fix-ta-sync-output", ctx.shortref()));
+    }
+
+    #[test]
+    fn test_hg_protected_targets_default_and_configured() {
+        let dir = tempfile::tempdir().unwrap();
+        let adapter = MercurialAdapter::new(dir.path());
+        assert!(adapter
+            .protected_submit_targets()
+            .contains(&"@".to_string()));
+
+        let mut config = SubmitConfig::default();
+        config.hg.protected_bookmarks = vec!["stable".to_string()];
+        let adapter = MercurialAdapter::with_config(dir.path(), config);
+        assert_eq!(adapter.protected_submit_targets(), vec!["stable"]);
+    }
+
+    #[test]
+    fn test_hg_restore_state_rejects_foreign_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let adapter = MercurialAdapter::new(dir.path());
+        let state = SavedVcsState {
+            adapter: "git".to_string(),
+            data: Box::new("main".to_string()),
+        };
+        assert!(adapter.restore_state(Some(state)).is_err());
+        assert!(adapter.restore_state(None).is_ok());
+    }
+}
@@ -3,7 +3,7 @@
 //! This crate provides pluggable adapters for source control operations through
 //! different version control systems and workflows. The core abstraction is the
 //! `SourceAdapter` trait (unified from the former `SubmitAdapter` in v0.11.1),
-//! with built-in implementations for Git and "none" fallback, plus external
+//! with built-in implementations for Git, Mercurial, and "none" fallback, plus external
 //! plugin support for Perforce, SVN, and any community VCS (v0.13.5).
 //!
 //! ## External VCS plugins (v0.13.5)
@@ -20,6 +20,7 @@ pub mod adapter;
 pub mod config;
 pub mod external_vcs_adapter;
 pub mod git;
+pub mod hg;
 pub mod messaging_adapter;
 pub mod messaging_plugin_protocol;
 pub mod none;
@@ -33,22 +34,24 @@ pub mod vcs_plugin_protocol;
 
 // Primary exports (v0.11.1+)
 pub use adapter::{
-    CommitResult, CommitSummary, MergeResult, PushResult, ReviewResult, ReviewStatus,
-    SavedVcsState, SourceAdapter, SyncResult,
+    append_trailers, CommitResult, CommitSummary, MergeResult, PushResult, ReviewComment,
+    ReviewResult, ReviewStatus, SavedVcsState, SourceAdapter, SyncResult,
 };
 
 // Backward-compatible re-export: SubmitAdapter is a type alias for SourceAdapter.
 pub use adapter::SubmitAdapter;
 
 pub use config::{
-    check_disk_space_mb, resolve_plan_path, AgentProfile, ApplyConfig, AssetDiffConfig,
-    BuildConfig, BuildOnFail, CommitConfig, ContextMode, DiffConfig, DraftReviewConfig, GitConfig,
-    PerforceConfig, PlanConfig, SecurityConfig, ShellConfig, StagingConfig, SubmitConfig,
-    SvnConfig, SyncConfig, TaLocalPaths, TaPathConfig, TaProjectPaths, VcsAgentConfig, VcsConfig,
-    VerifyCommand, VerifyConfig, VerifyOnFailure, WorkflowConfig,
+    check_disk_space_mb, offline_from_env, resolve_plan_path, AgentProfile, ApplyConfig,
+    AssetDiffConfig, BuildConfig, BuildOnFail, CommitConfig, ContextMode, DiffConfig,
+    DraftReviewConfig, GitConfig, HgConfig, PerforceConfig, PlanConfig, SecurityConfig,
+    ShellConfig, StagingConfig, SubmitConfig, SvnConfig, SyncConfig, TaLocalPaths, TaPathConfig,
+    TaProjectPaths, VcsAgentConfig, VcsConfig, VerifyCommand, VerifyConfig, VerifyOnFailure,
+    WorkflowConfig, WorkspaceConfig, OFFLINE_ENV,
 };
 pub use external_vcs_adapter::ExternalVcsAdapter;
 pub use git::GitAdapter;
+pub use hg::MercurialAdapter;
 pub use messaging_adapter::{
     discover_messaging_plugins, find_messaging_plugin, DiscoveredMessagingPlugin,
     ExternalMessagingAdapter, MessagingPluginManifest, MessagingPluginSource,
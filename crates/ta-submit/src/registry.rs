@@ -8,7 +8,7 @@
 //!
 //! When an adapter name is given (e.g., `adapter = "perforce"`):
 //!
-//! 1. Check built-in adapters: `git`, `svn`, `perforce`, `none`.
+//! 1. Check built-in adapters: `git`, `hg`, `svn`, `perforce`, `none`.
 //! 2. Check for an installed plugin via `find_vcs_plugin()`:
 //!    - `.ta/plugins/vcs/<name>/plugin.toml`
 //!    - `~/.config/ta/plugins/vcs/<name>/plugin.toml`
@@ -33,6 +33,7 @@ use crate::adapter::SourceAdapter;
 use crate::config::{SubmitConfig, SyncConfig};
 use crate::external_vcs_adapter::ExternalVcsAdapter;
 use crate::git::GitAdapter;
+use crate::hg::MercurialAdapter;
 use crate::none::NoneAdapter;
 use crate::perforce::PerforceAdapter;
 use crate::svn::SvnAdapter;
@@ -49,7 +50,7 @@ pub const TA_VERSION: &str = env!("CARGO_PKG_VERSION");
 
 /// Auto-detect the appropriate VCS adapter for the given project root.
 ///
-/// Detection order: Git → SVN → Perforce → None.
+/// Detection order: Git → Mercurial → SVN → Perforce → None.
 /// First match wins.
 pub fn detect_adapter(project_root: &Path) -> Box<dyn SourceAdapter> {
     detect_adapter_with_config(project_root, &SubmitConfig::default())
@@ -66,6 +67,11 @@ pub fn detect_adapter_with_config(
         return Box::new(GitAdapter::with_config(project_root, config.clone()));
     }
 
+    if MercurialAdapter::detect(project_root) {
+        tracing::info!(adapter = "hg", "Auto-detected Mercurial repository");
+        return Box::new(MercurialAdapter::with_config(project_root, config.clone()));
+    }
+
     if SvnAdapter::detect(project_root) {
         tracing::info!(adapter = "svn", "Auto-detected SVN working copy");
         // Try external plugin first (svn may have been externalized).
@@ -121,6 +127,8 @@ pub fn detect_adapter_with_config(
 /// Select an adapter by name from configuration, with auto-detection fallback.
 ///
 /// Resolution order:
+/// 0. In offline mode (`[submit] offline` or `TA_OFFLINE`), always NoneAdapter —
+///    no VCS detection and no plugin discovery.
 /// 1. If `config.adapter` is explicitly set to a known built-in adapter name, use it.
 /// 2. If `config.adapter` is unknown, check for an external VCS plugin with that name.
 /// 3. If `config.adapter` is "none" (the default), auto-detect from the project root.
@@ -128,11 +136,19 @@ pub fn detect_adapter_with_config(
 ///
 /// §15 enforcement is applied to all loaded adapters.
 pub fn select_adapter(project_root: &Path, config: &SubmitConfig) -> Box<dyn SourceAdapter> {
+    if config.is_offline() {
+        tracing::info!(adapter = "none", "Offline mode — skipping VCS detection");
+        return Box::new(NoneAdapter::new());
+    }
     match config.adapter.as_str() {
         "git" => {
             tracing::info!(adapter = "git", "Using configured Git adapter");
             Box::new(GitAdapter::with_config(project_root, config.clone()))
         }
+        "hg" | "mercurial" => {
+            tracing::info!(adapter = "hg", "Using configured Mercurial adapter");
+            Box::new(MercurialAdapter::with_config(project_root, config.clone()))
+        }
         "svn" => {
             tracing::info!(adapter = "svn", "Using configured SVN adapter");
             // Prefer external plugin when available.
@@ -227,7 +243,7 @@ pub fn select_adapter_with_sync(
     sync_config: &SyncConfig,
 ) -> Box<dyn SourceAdapter> {
     match config.adapter.as_str() {
-        "git" => {
+        "git" if !config.is_offline() => {
             tracing::info!(
                 adapter = "git",
                 "Using configured Git adapter (with sync config)"
@@ -245,7 +261,7 @@ pub fn select_adapter_with_sync(
 
 /// List all known built-in adapter names.
 pub fn known_adapters() -> &'static [&'static str] {
-    &["git", "svn", "perforce", "none"]
+    &["git", "hg", "svn", "perforce", "none"]
 }
 
 // ---------------------------------------------------------------------------
@@ -334,6 +350,16 @@ mod tests {
         assert_eq!(adapter.name(), "svn");
     }
 
+    #[test]
+    fn test_detect_adapter_hg() {
+        let dir = tempdir().unwrap();
+        // Create .hg directory to simulate a Mercurial repository
+        std::fs::create_dir(dir.path().join(".hg")).unwrap();
+
+        let adapter = detect_adapter(dir.path());
+        assert_eq!(adapter.name(), "hg");
+    }
+
     #[test]
     fn test_detect_adapter_perforce() {
         let dir = tempdir().unwrap();
@@ -387,6 +413,19 @@ mod tests {
         assert_eq!(adapter.name(), "svn");
     }
 
+    #[test]
+    fn test_select_adapter_explicit_hg() {
+        let dir = tempdir().unwrap();
+        for name in ["hg", "mercurial"] {
+            let config = SubmitConfig {
+                adapter: name.to_string(),
+                ..Default::default()
+            };
+            let adapter = select_adapter(dir.path(), &config);
+            assert_eq!(adapter.name(), "hg");
+        }
+    }
+
     #[test]
     fn test_select_adapter_explicit_perforce() {
         let dir = tempdir().unwrap();
@@ -411,11 +450,34 @@ mod tests {
         assert_eq!(adapter.name(), "git");
     }
 
+    #[test]
+    fn test_select_adapter_offline_forces_none() {
+        let dir = tempdir().unwrap();
+        clear_git_env(Command::new("git").args(["init"]).current_dir(dir.path()))
+            .output()
+            .unwrap();
+
+        for name in ["git", "svn", "none", "fossil"] {
+            let config = SubmitConfig {
+                adapter: name.to_string(),
+                offline: true,
+                ..Default::default()
+            };
+            assert_eq!(select_adapter(dir.path(), &config).name(), "none");
+            assert_eq!(
+                select_adapter_with_sync(dir.path(), &config, &SyncConfig::default()).name(),
+                "none"
+            );
+            assert!(!config.effective_auto_submit());
+            assert!(!config.effective_auto_review());
+        }
+    }
+
     #[test]
     fn test_select_adapter_unknown_falls_back() {
         let dir = tempdir().unwrap();
         let config = SubmitConfig {
-            adapter: "mercurial".to_string(),
+            adapter: "fossil".to_string(),
             ..Default::default()
         };
         let adapter = select_adapter(dir.path(), &config);
@@ -427,6 +489,7 @@ mod tests {
     fn test_known_adapters() {
         let adapters = known_adapters();
         assert!(adapters.contains(&"git"));
+        assert!(adapters.contains(&"hg"));
         assert!(adapters.contains(&"svn"));
         assert!(adapters.contains(&"perforce"));
         assert!(adapters.contains(&"none"));
@@ -6,6 +6,8 @@
 //! 2. The head branch (derived from goal title + branch_prefix) is passed as `--head`.
 //! 3. Idempotency: when an open PR already exists for the head branch, a second
 //!    `open_review()` call returns the existing PR URL without calling `gh pr create`.
+//! 4. `commit()` leaves apply-only artifacts (`CommitIntent::NoCommit`) out of
+//!    the commit while still committing the rest of the draft.
 //!
 //! Uses a `gh` stub shell script placed at the front of PATH to intercept CLI
 //! calls without requiring a live GitHub account or network access.
@@ -123,6 +125,10 @@ fn make_draft_package() -> DraftPackage {
                 comments: None,
                 amendment: None,
                 kind: None,
+                no_commit: false,
+                renamed_from: None,
+                mode_change: None,
+                line_endings_normalized: None,
             }],
             patch_sets: vec![],
             pending_actions: vec![],
@@ -156,8 +162,11 @@ fn make_draft_package() -> DraftPackage {
         display_id: None,
         tag: None,
         vcs_status: None,
+        submit_outcome: None,
+        content_hash: None,
         parent_draft_id: None,
         pending_approvals: vec![],
+        review_assignments: vec![],
         supervisor_review: None,
         ignored_artifacts: vec![],
         baseline_artifacts: vec![],
@@ -167,6 +176,11 @@ fn make_draft_package() -> DraftPackage {
         draft_seq: 0,
         plan_phase: None,
         plan_md_base: None,
+        split_from: None,
+        split_into: Vec::new(),
+        merged_from: Vec::new(),
+        noop_dropped: Vec::new(),
+        staged_hashes: Default::default(),
     }
 }
 
@@ -376,3 +390,45 @@ fn test_open_review_idempotency_returns_existing_pr() {
         "gh pr create must not be called when an existing open PR is found"
     );
 }
+
+/// Apply-only artifacts stay on disk but are not staged or committed.
+#[test]
+fn test_commit_skips_no_commit_artifacts() {
+    let repo_dir = tempdir().unwrap();
+    init_git_repo(repo_dir.path());
+
+    std::fs::write(repo_dir.path().join("test.txt"), "committed\n").unwrap();
+    std::fs::write(repo_dir.path().join("NOTES.local.md"), "scratch\n").unwrap();
+
+    let mut pkg = make_draft_package();
+    let mut scratch = pkg.changes.artifacts[0].clone();
+    scratch.resource_uri = "fs://workspace/NOTES.local.md".to_string();
+    scratch.no_commit = true;
+    pkg.changes.artifacts.push(scratch);
+
+    let adapter = GitAdapter::new(repo_dir.path());
+    let goal = make_goal("no commit", repo_dir.path());
+    let result = adapter
+        .commit(&CommitContext::from(&goal), &pkg, "Add test file")
+        .expect("commit should succeed");
+    assert!(!result.commit_id.is_empty());
+
+    let committed = std::process::Command::new("git")
+        .args(["show", "--name-only", "--format=", "HEAD"])
+        .current_dir(repo_dir.path())
+        .env_remove("GIT_DIR")
+        .env_remove("GIT_WORK_TREE")
+        .output()
+        .unwrap();
+    let committed = String::from_utf8_lossy(&committed.stdout);
+    assert!(committed.contains("test.txt"), "got: {committed}");
+    assert!(!committed.contains("NOTES.local.md"), "got: {committed}");
+    assert!(repo_dir.path().join("NOTES.local.md").exists());
+
+    // A draft made only of apply-only artifacts commits nothing.
+    pkg.changes.artifacts.remove(0);
+    let result = adapter
+        .commit(&CommitContext::from(&goal), &pkg, "Scratch only")
+        .expect("apply-only commit should not error");
+    assert!(result.commit_id.is_empty());
+}
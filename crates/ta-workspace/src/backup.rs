@@ -0,0 +1,302 @@
+// backup.rs — Pre-apply snapshots of files a draft apply will overwrite.
+//
+// `ta draft apply --backup` copies every target file the draft touches to
+// `.ta/backups/<package-id>/<path>` before writing, and records which paths
+// did not exist yet in `.ta/backups/<package-id>.json`. `ta draft restore`
+// copies the saved files back and removes the files the apply created, so a
+// force-overwrite apply can be undone without relying on VCS. A multi-root
+// goal's paths are `<alias>/<path>` and resolve against the root recorded for
+// that alias instead of the target directory.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+use crate::error::WorkspaceError;
+
+/// What a backup holds: files saved before being overwritten or deleted, and
+/// files that did not exist (and so are removed on restore).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub package_id: String,
+    pub created_at: DateTime<Utc>,
+    /// Directory the draft was applied to; the default restore target.
+    pub target_dir: PathBuf,
+    /// Relative paths whose original content is saved in the backup directory.
+    pub saved: Vec<String>,
+    /// Relative paths that did not exist in the target before the apply.
+    pub created: Vec<String>,
+    /// Source root of each alias of a multi-root goal. Paths whose first
+    /// component is one of these aliases live under that root.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub roots: BTreeMap<String, PathBuf>,
+}
+
+impl BackupManifest {
+    /// Where a recorded path lives: under its alias's root, or `target_dir`.
+    pub fn target_path(&self, target_dir: &Path, path: &str) -> PathBuf {
+        if let Some((alias, rest)) = path.split_once('/') {
+            if let Some(root) = self.roots.get(alias) {
+                return root.join(rest);
+            }
+        }
+        target_dir.join(path)
+    }
+}
+
+/// The backup of one draft package under a backups root (`.ta/backups`).
+#[derive(Debug, Clone)]
+pub struct ApplyBackup {
+    dir: PathBuf,
+    manifest_path: PathBuf,
+    package_id: String,
+    roots: BTreeMap<String, PathBuf>,
+}
+
+impl ApplyBackup {
+    pub fn new(backups_root: impl AsRef<Path>, package_id: &str) -> Self {
+        let root = backups_root.as_ref();
+        Self {
+            dir: root.join(package_id),
+            manifest_path: root.join(format!("{}.json", package_id)),
+            package_id: package_id.to_string(),
+            roots: BTreeMap::new(),
+        }
+    }
+
+    /// Resolve `<alias>/<path>` paths against the `(alias, source_dir)` roots
+    /// of a multi-root goal rather than the target directory.
+    pub fn with_roots(mut self, roots: impl IntoIterator<Item = (String, PathBuf)>) -> Self {
+        self.roots = roots.into_iter().collect();
+        self
+    }
+
+    /// Directory mirroring the saved files' relative paths.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Whether a backup has been taken for this package.
+    pub fn exists(&self) -> bool {
+        self.manifest_path.exists()
+    }
+
+    /// Save the current content of `paths` (relative to `target_dir`).
+    ///
+    /// Paths already recorded by an earlier snapshot of the same package are
+    /// left alone, so re-running a failed apply never replaces the original
+    /// content with partially-applied content.
+    pub fn snapshot<'a>(
+        &self,
+        target_dir: &Path,
+        paths: impl IntoIterator<Item = &'a str>,
+    ) -> Result<BackupManifest, WorkspaceError> {
+        let mut manifest = if self.exists() {
+            self.load_manifest()?
+        } else {
+            BackupManifest {
+                package_id: self.package_id.clone(),
+                created_at: Utc::now(),
+                target_dir: target_dir.to_path_buf(),
+                saved: Vec::new(),
+                created: Vec::new(),
+                roots: self.roots.clone(),
+            }
+        };
+
+        for path in paths {
+            check_relative(path)?;
+            if manifest.saved.iter().any(|p| p == path)
+                || manifest.created.iter().any(|p| p == path)
+            {
+                continue;
+            }
+            let source = manifest.target_path(target_dir, path);
+            if source.is_file() {
+                copy_creating_parents(&source, &self.dir.join(path))?;
+                manifest.saved.push(path.to_string());
+            } else if !source.exists() {
+                manifest.created.push(path.to_string());
+            }
+        }
+
+        if let Some(parent) = self.manifest_path.parent() {
+            fs::create_dir_all(parent).map_err(|source| WorkspaceError::IoError {
+                path: parent.to_path_buf(),
+                source,
+            })?;
+        }
+        let json = serde_json::to_string_pretty(&manifest)?;
+        fs::write(&self.manifest_path, json).map_err(|source| WorkspaceError::IoError {
+            path: self.manifest_path.clone(),
+            source,
+        })?;
+        Ok(manifest)
+    }
+
+    /// Put `target_dir` (and any multi-root roots) back the way it was when
+    /// the backup was taken.
+    pub fn restore(&self, target_dir: &Path) -> Result<BackupManifest, WorkspaceError> {
+        let manifest = self.load_manifest()?;
+        for path in &manifest.saved {
+            check_relative(path)?;
+            copy_creating_parents(
+                &self.dir.join(path),
+                &manifest.target_path(target_dir, path),
+            )?;
+        }
+        for path in &manifest.created {
+            check_relative(path)?;
+            let target = manifest.target_path(target_dir, path);
+            if target.is_file() {
+                fs::remove_file(&target).map_err(|source| WorkspaceError::IoError {
+                    path: target.clone(),
+                    source,
+                })?;
+            }
+        }
+        Ok(manifest)
+    }
+
+    pub fn load_manifest(&self) -> Result<BackupManifest, WorkspaceError> {
+        let json = fs::read_to_string(&self.manifest_path).map_err(|source| {
+            if source.kind() == std::io::ErrorKind::NotFound {
+                WorkspaceError::StoreError(format!(
+                    "no backup found for package {}",
+                    self.package_id
+                ))
+            } else {
+                WorkspaceError::IoError {
+                    path: self.manifest_path.clone(),
+                    source,
+                }
+            }
+        })?;
+        Ok(serde_json::from_str(&json)?)
+    }
+}
+
+/// Reject absolute paths and `..` so a backup can never read or write outside
+/// its target and backup directories.
+fn check_relative(path: &str) -> Result<(), WorkspaceError> {
+    let ok = !path.is_empty()
+        && Path::new(path)
+            .components()
+            .all(|c| matches!(c, Component::Normal(_) | Component::CurDir));
+    if ok {
+        Ok(())
+    } else {
+        Err(WorkspaceError::StoreError(format!(
+            "refusing to back up path outside the target: '{}'",
+            path
+        )))
+    }
+}
+
+fn copy_creating_parents(from: &Path, to: &Path) -> Result<(), WorkspaceError> {
+    if let Some(parent) = to.parent() {
+        fs::create_dir_all(parent).map_err(|source| WorkspaceError::IoError {
+            path: parent.to_path_buf(),
+            source,
+        })?;
+    }
+    fs::copy(from, to).map_err(|source| WorkspaceError::IoError {
+        path: from.to_path_buf(),
+        source,
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn snapshot_then_restore_undoes_overwrite_delete_and_create() {
+        let target = tempdir().unwrap();
+        let backups = tempdir().unwrap();
+        fs::create_dir_all(target.path().join("src")).unwrap();
+        fs::write(target.path().join("src/lib.rs"), "original").unwrap();
+        fs::write(target.path().join("old.txt"), "to be deleted").unwrap();
+
+        let backup = ApplyBackup::new(backups.path(), "pkg-1");
+        let manifest = backup
+            .snapshot(target.path(), ["src/lib.rs", "old.txt", "new.txt"])
+            .unwrap();
+        assert_eq!(manifest.saved, vec!["src/lib.rs", "old.txt"]);
+        assert_eq!(manifest.created, vec!["new.txt"]);
+        assert!(backup.dir().join("src/lib.rs").exists());
+
+        // Simulate the apply.
+        fs::write(target.path().join("src/lib.rs"), "overwritten").unwrap();
+        fs::remove_file(target.path().join("old.txt")).unwrap();
+        fs::write(target.path().join("new.txt"), "created").unwrap();
+
+        // A second snapshot (e.g. a retried apply) keeps the originals.
+        backup.snapshot(target.path(), ["src/lib.rs"]).unwrap();
+
+        backup.restore(target.path()).unwrap();
+        assert_eq!(
+            fs::read_to_string(target.path().join("src/lib.rs")).unwrap(),
+            "original"
+        );
+        assert_eq!(
+            fs::read_to_string(target.path().join("old.txt")).unwrap(),
+            "to be deleted"
+        );
+        assert!(!target.path().join("new.txt").exists());
+    }
+
+    #[test]
+    fn multi_root_paths_resolve_against_their_alias_root() {
+        let target = tempdir().unwrap();
+        let api = tempdir().unwrap();
+        let backups = tempdir().unwrap();
+        fs::write(api.path().join("lib.rs"), "original").unwrap();
+
+        let backup = ApplyBackup::new(backups.path(), "pkg-1")
+            .with_roots([("api".to_string(), api.path().to_path_buf())]);
+        let manifest = backup
+            .snapshot(target.path(), ["api/lib.rs", "api/new.rs"])
+            .unwrap();
+        assert_eq!(manifest.saved, vec!["api/lib.rs"]);
+        assert_eq!(manifest.created, vec!["api/new.rs"]);
+
+        fs::write(api.path().join("lib.rs"), "overwritten").unwrap();
+        fs::write(api.path().join("new.rs"), "created").unwrap();
+
+        // A fresh handle restores from the roots recorded in the manifest.
+        ApplyBackup::new(backups.path(), "pkg-1")
+            .restore(target.path())
+            .unwrap();
+        assert_eq!(
+            fs::read_to_string(api.path().join("lib.rs")).unwrap(),
+            "original"
+        );
+        assert!(!api.path().join("new.rs").exists());
+        assert!(!target.path().join("api").exists());
+    }
+
+    #[test]
+    fn rejects_paths_escaping_the_target() {
+        let target = tempdir().unwrap();
+        let backups = tempdir().unwrap();
+        let backup = ApplyBackup::new(backups.path(), "pkg-1");
+        assert!(backup.snapshot(target.path(), ["../secret"]).is_err());
+        assert!(backup.snapshot(target.path(), ["/etc/passwd"]).is_err());
+    }
+
+    #[test]
+    fn restore_without_backup_is_an_error() {
+        let backups = tempdir().unwrap();
+        let backup = ApplyBackup::new(backups.path(), "missing");
+        assert!(matches!(
+            backup.restore(backups.path()),
+            Err(WorkspaceError::StoreError(_))
+        ));
+    }
+}
@@ -0,0 +1,218 @@
+// blob_store.rs — Content-addressed storage for staged file contents.
+//
+// Agents that stage the same large file repeatedly used to leave a full copy
+// inline in every changeset line. The BlobStore keeps each distinct content
+// once under `.ta/blobs/<sha256>`; changesets persisted by a JsonFileStore
+// with a blob store attached reference the hash instead
+// (`DiffContent::CreateFileBlob`) and are resolved back to
+// `DiffContent::CreateFile` on load, so callers never see the difference.
+// Unified diffs larger than `INLINE_DIFF_LIMIT` (a rewritten multi-megabyte
+// file) are moved out of line the same way, as `DiffContent::UnifiedDiffBlob`.
+
+use std::fs::{self, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use ta_changeset::DiffContent;
+
+use crate::error::WorkspaceError;
+
+/// Unified diffs longer than this many bytes are stored as blobs; smaller
+/// diffs stay inline so the common case needs no extra file reads.
+pub const INLINE_DIFF_LIMIT: usize = 256 * 1024;
+
+/// A directory of immutable blobs named by the SHA-256 of their content.
+#[derive(Debug, Clone)]
+pub struct BlobStore {
+    dir: PathBuf,
+}
+
+impl BlobStore {
+    /// Create a blob store rooted at `dir` (typically `.ta/blobs`).
+    /// The directory is created on first write.
+    pub fn new(dir: impl AsRef<Path>) -> Self {
+        Self {
+            dir: dir.as_ref().to_path_buf(),
+        }
+    }
+
+    /// Path of the blob with the given hash.
+    pub fn path_for(&self, hash: &str) -> PathBuf {
+        self.dir.join(hash)
+    }
+
+    /// Store `content` and return its hash. Content already present is not rewritten.
+    pub fn put(&self, content: &[u8]) -> Result<String, WorkspaceError> {
+        let hash = format!("{:x}", Sha256::digest(content));
+        let path = self.path_for(&hash);
+        if path.exists() {
+            return Ok(hash);
+        }
+
+        fs::create_dir_all(&self.dir).map_err(|source| WorkspaceError::IoError {
+            path: self.dir.clone(),
+            source,
+        })?;
+        // Write to a temp name and rename so a concurrent reader never sees a
+        // partial blob.
+        let tmp = self
+            .dir
+            .join(format!(".{}.{}.tmp", hash, std::process::id()));
+        let io_err = |source| WorkspaceError::IoError {
+            path: tmp.clone(),
+            source,
+        };
+        let mut file = File::create(&tmp).map_err(io_err)?;
+        file.write_all(content).map_err(io_err)?;
+        file.sync_all().map_err(io_err)?;
+        fs::rename(&tmp, &path).map_err(|source| WorkspaceError::IoError {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(hash)
+    }
+
+    /// Read the blob with the given hash.
+    pub fn get(&self, hash: &str) -> Result<Vec<u8>, WorkspaceError> {
+        if hash.len() != 64 || !hash.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(WorkspaceError::StoreError(format!(
+                "invalid blob hash '{}'",
+                hash
+            )));
+        }
+        let path = self.path_for(hash);
+        fs::read(&path).map_err(|source| {
+            if source.kind() == std::io::ErrorKind::NotFound {
+                WorkspaceError::BlobNotFound {
+                    hash: hash.to_string(),
+                }
+            } else {
+                WorkspaceError::IoError { path, source }
+            }
+        })
+    }
+
+    /// Whether `externalize` would move this diff into the store: all
+    /// `CreateFile` content, and unified diffs over `INLINE_DIFF_LIMIT`.
+    pub fn should_externalize(diff: &DiffContent) -> bool {
+        match diff {
+            DiffContent::CreateFile { .. } => true,
+            DiffContent::UnifiedDiff { content } => content.len() > INLINE_DIFF_LIMIT,
+            _ => false,
+        }
+    }
+
+    /// Move inline content into the store, returning a `CreateFileBlob` or
+    /// `UnifiedDiffBlob` reference. Content that `should_externalize` keeps
+    /// inline is returned unchanged.
+    pub fn externalize(&self, diff: &DiffContent) -> Result<DiffContent, WorkspaceError> {
+        match diff {
+            DiffContent::CreateFile { content } => Ok(DiffContent::CreateFileBlob {
+                hash: self.put(content.as_bytes())?,
+                size_bytes: content.len() as u64,
+            }),
+            DiffContent::UnifiedDiff { content } if Self::should_externalize(diff) => {
+                Ok(DiffContent::UnifiedDiffBlob {
+                    hash: self.put(content.as_bytes())?,
+                    size_bytes: content.len() as u64,
+                })
+            }
+            other => Ok(other.clone()),
+        }
+    }
+
+    /// Resolve a blob reference back to inline `CreateFile` or `UnifiedDiff`
+    /// content. Other variants are returned unchanged.
+    pub fn resolve(&self, diff: DiffContent) -> Result<DiffContent, WorkspaceError> {
+        match diff {
+            DiffContent::CreateFileBlob { hash, .. } => {
+                let bytes = self.get(&hash)?;
+                Ok(DiffContent::CreateFile {
+                    content: String::from_utf8_lossy(&bytes).into_owned(),
+                })
+            }
+            DiffContent::UnifiedDiffBlob { hash, .. } => {
+                let bytes = self.get(&hash)?;
+                Ok(DiffContent::UnifiedDiff {
+                    content: String::from_utf8_lossy(&bytes).into_owned(),
+                })
+            }
+            other => Ok(other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn put_is_content_addressed_and_deduplicated() {
+        let dir = tempdir().unwrap();
+        let blobs = BlobStore::new(dir.path().join("blobs"));
+
+        let a = blobs.put(b"same content").unwrap();
+        let b = blobs.put(b"same content").unwrap();
+        let c = blobs.put(b"other content").unwrap();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(fs::read_dir(dir.path().join("blobs")).unwrap().count(), 2);
+        assert_eq!(blobs.get(&a).unwrap(), b"same content");
+    }
+
+    #[test]
+    fn externalize_and_resolve_round_trip() {
+        let dir = tempdir().unwrap();
+        let blobs = BlobStore::new(dir.path());
+        let diff = DiffContent::CreateFile {
+            content: "fn main() {}\n".to_string(),
+        };
+
+        let stored = blobs.externalize(&diff).unwrap();
+        assert!(matches!(
+            stored,
+            DiffContent::CreateFileBlob { size_bytes: 13, .. }
+        ));
+        assert_eq!(blobs.resolve(stored).unwrap(), diff);
+
+        // Non-CreateFile variants pass through untouched.
+        assert_eq!(
+            blobs.externalize(&DiffContent::DeleteFile).unwrap(),
+            DiffContent::DeleteFile
+        );
+    }
+
+    #[test]
+    fn only_large_unified_diffs_are_externalized() {
+        let dir = tempdir().unwrap();
+        let blobs = BlobStore::new(dir.path());
+        let small = DiffContent::UnifiedDiff {
+            content: "@@ -1 +1 @@\n-a\n+b\n".to_string(),
+        };
+        assert_eq!(blobs.externalize(&small).unwrap(), small);
+
+        let large = DiffContent::UnifiedDiff {
+            content: "+line\n".repeat(INLINE_DIFF_LIMIT / 6 + 1),
+        };
+        let stored = blobs.externalize(&large).unwrap();
+        assert!(matches!(stored, DiffContent::UnifiedDiffBlob { .. }));
+        assert_eq!(blobs.resolve(stored).unwrap(), large);
+    }
+
+    #[test]
+    fn get_rejects_non_hash_names() {
+        let dir = tempdir().unwrap();
+        let blobs = BlobStore::new(dir.path());
+        assert!(matches!(
+            blobs.get("../../etc/passwd"),
+            Err(WorkspaceError::StoreError(_))
+        ));
+        assert!(matches!(
+            blobs.get(&"0".repeat(64)),
+            Err(WorkspaceError::BlobNotFound { .. })
+        ));
+    }
+}
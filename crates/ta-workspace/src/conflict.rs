@@ -31,6 +31,10 @@ pub enum ConflictResolution {
     /// Attempt automatic merge using VCS adapter (git merge, etc.).
     /// Falls back to Abort if no VCS adapter is available.
     Merge,
+    /// Ask the user what to do with each conflicting file. Prompting is the
+    /// caller's job (see `OverlayWorkspace::true_conflicts`); the workspace
+    /// itself treats unresolved conflicts like `Abort`.
+    Interactive,
 }
 
 /// Snapshot of a single file's state at a point in time.
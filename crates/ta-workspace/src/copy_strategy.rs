@@ -273,7 +273,13 @@ mod linux {
             )
         };
 
-        Ok(ret == 0)
+        if ret != 0 {
+            return Ok(false);
+        }
+        // The clone shares data but `File::create` gave it default
+        // permissions; carry the source mode (e.g. the executable bit) over.
+        std::fs::set_permissions(dst, src_file.metadata()?.permissions())?;
+        Ok(true)
     }
 }
 
@@ -25,6 +25,10 @@ pub enum WorkspaceError {
     #[error("serialization error: {0}")]
     SerializationError(#[from] serde_json::Error),
 
+    /// A content-addressed blob referenced by a changeset is missing.
+    #[error("blob not found in content store: {hash}")]
+    BlobNotFound { hash: String },
+
     /// The change store operation failed.
     #[error("change store error: {0}")]
     StoreError(String),
@@ -36,4 +40,12 @@ pub enum WorkspaceError {
     /// A Windows Projected File System operation failed (v0.15.8).
     #[error("ProjFS error: {0}")]
     ProjFsError(String),
+
+    /// A multi-root workspace was given an unusable source root alias.
+    #[error("invalid source root '{alias}': {reason}")]
+    InvalidSourceRoot { alias: String, reason: String },
+
+    /// A git command needed to read repository content failed.
+    #[error("git error: {0}")]
+    GitError(String),
 }
@@ -15,10 +15,17 @@
 //!   backends (JSONL → SQLite → S3) without changing callers.
 //! - [`JsonFileStore`] — MVP implementation: one JSONL file per goal,
 //!   append-optimized, survives process restarts.
+//! - [`BlobStore`] — content-addressed storage for new-file contents, so
+//!   repeated stagings of the same file are kept once.
+//! - [`ApplyBackup`] — snapshots of target files taken before a draft apply
+//!   overwrites them, restorable with `ta draft restore`.
 
+pub mod backup;
+pub mod blob_store;
 pub mod conflict;
 pub mod copy_strategy;
 pub mod error;
+pub mod line_endings;
 pub mod merge_tool;
 pub mod overlay;
 pub mod partitioning;
@@ -27,10 +34,15 @@ pub mod staging;
 pub mod store;
 pub mod windows_features;
 
+pub use backup::{ApplyBackup, BackupManifest};
+pub use blob_store::BlobStore;
 pub use conflict::{Conflict, ConflictResolution, FileSnapshot, SourceSnapshot};
 pub use copy_strategy::{CopyStat, CopyStrategy};
 pub use error::WorkspaceError;
-pub use overlay::{ExcludePatterns, OverlayStagingMode, OverlayWorkspace};
+pub use line_endings::{GitAttributes, LineEnding, LineEndingPolicy};
+pub use overlay::{
+    DiffAlgorithm, ExcludePatterns, NoopNormalization, OverlayStagingMode, OverlayWorkspace,
+};
 pub use partitioning::{
     gitignore_block, p4ignore_block, update_gitignore, update_p4ignore, VcsBackend,
     GITIGNORE_MARKER, LOCAL_TA_PATHS, P4IGNORE_MARKER, SHARED_TA_PATHS,
@@ -0,0 +1,289 @@
+// line_endings.rs — Line-ending normalization for staged files.
+//
+// An agent on Windows (or an editor with CRLF defaults) can stage text files
+// whose every line differs from an LF source only by `\r`. Normalizing the
+// staged copy before diffing makes those whole-file diffs disappear, and
+// because apply copies the staged bytes, the normalized content is what lands
+// in the source tree.
+//
+// `.gitattributes` in the source root takes precedence over the configured
+// policy: `eol=lf|crlf` forces an ending for matching paths, and `-text` or
+// `binary` exempts them from normalization.
+
+use std::path::Path;
+
+use glob::{MatchOptions, Pattern};
+pub use ta_changeset::draft_package::LineEnding;
+
+use crate::overlay::is_text_content;
+
+/// How staged text files' line endings are normalized before diffing
+/// (`[workspace] line_endings`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEndingPolicy {
+    /// Convert to LF.
+    Lf,
+    /// Convert to CRLF.
+    Crlf,
+    /// Match the line endings of the file's current source version. New
+    /// files are left as staged.
+    Preserve,
+}
+
+/// What `.gitattributes` says about one path's line endings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EolAttribute {
+    /// No relevant attribute; the configured policy applies.
+    Unspecified,
+    /// `-text` or `binary`: never normalize.
+    NotText,
+    /// `eol=lf` or `eol=crlf`.
+    Eol(LineEnding),
+}
+
+/// The `text`/`eol` rules of a `.gitattributes` file.
+///
+/// Only the file in the source root is read. Patterns follow gitattributes
+/// rules: a pattern without `/` matches the file name at any depth, anything
+/// else matches the path from the root. Later lines override earlier ones.
+#[derive(Debug, Clone, Default)]
+pub struct GitAttributes {
+    rules: Vec<AttributeRule>,
+}
+
+#[derive(Debug, Clone)]
+struct AttributeRule {
+    pattern: Pattern,
+    basename_only: bool,
+    /// `Some(false)` for `-text`/`binary`, `Some(true)` for `text`.
+    text: Option<bool>,
+    eol: Option<LineEnding>,
+}
+
+impl GitAttributes {
+    /// Load `<source_dir>/.gitattributes`; empty when the file is missing.
+    pub fn load(source_dir: &Path) -> Self {
+        std::fs::read_to_string(source_dir.join(".gitattributes"))
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Parse `.gitattributes` content, ignoring attributes other than
+    /// `text`, `eol`, and `binary`.
+    pub fn parse(content: &str) -> Self {
+        let mut rules = Vec::new();
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split_whitespace();
+            let Some(raw_pattern) = fields.next() else {
+                continue;
+            };
+            let mut text = None;
+            let mut eol = None;
+            for attr in fields {
+                match attr {
+                    "text" | "text=auto" => text = Some(true),
+                    "-text" | "binary" => text = Some(false),
+                    "eol=lf" => eol = Some(LineEnding::Lf),
+                    "eol=crlf" => eol = Some(LineEnding::Crlf),
+                    _ => {}
+                }
+            }
+            if text.is_none() && eol.is_none() {
+                continue;
+            }
+            let basename_only = !raw_pattern.trim_end_matches('/').contains('/');
+            let Ok(pattern) = Pattern::new(raw_pattern.trim_start_matches('/')) else {
+                continue;
+            };
+            rules.push(AttributeRule {
+                pattern,
+                basename_only,
+                text,
+                eol,
+            });
+        }
+        Self { rules }
+    }
+
+    /// The line-ending attribute for a workspace-relative path.
+    pub fn eol_for(&self, path: &str) -> EolAttribute {
+        let options = MatchOptions {
+            require_literal_separator: true,
+            ..MatchOptions::default()
+        };
+        let name = path.rsplit('/').next().unwrap_or(path);
+        let mut text = None;
+        let mut eol = None;
+        for rule in &self.rules {
+            let subject = if rule.basename_only { name } else { path };
+            if rule.pattern.matches_with(subject, options) {
+                text = rule.text.or(text);
+                eol = rule.eol.or(eol);
+            }
+        }
+        match (text, eol) {
+            (Some(false), _) => EolAttribute::NotText,
+            (_, Some(ending)) => EolAttribute::Eol(ending),
+            _ => EolAttribute::Unspecified,
+        }
+    }
+}
+
+/// The dominant line ending of `text`, or `None` if it has no line breaks.
+pub fn detect_line_ending(text: &[u8]) -> Option<LineEnding> {
+    let newlines = text.iter().filter(|b| **b == b'\n').count();
+    if newlines == 0 {
+        return None;
+    }
+    let crlf = text.windows(2).filter(|w| w == b"\r\n").count();
+    Some(if crlf * 2 > newlines {
+        LineEnding::Crlf
+    } else {
+        LineEnding::Lf
+    })
+}
+
+/// `text` with every line break converted to `ending`.
+pub fn convert_line_endings(text: &[u8], ending: LineEnding) -> Vec<u8> {
+    let mut out = Vec::with_capacity(text.len());
+    let mut i = 0;
+    while i < text.len() {
+        match text[i] {
+            b'\r' if text.get(i + 1) == Some(&b'\n') => {
+                i += 1;
+                continue;
+            }
+            b'\n' => {
+                if ending == LineEnding::Crlf {
+                    out.push(b'\r');
+                }
+                out.push(b'\n');
+            }
+            byte => out.push(byte),
+        }
+        i += 1;
+    }
+    out
+}
+
+/// Normalize one staged file in place.
+///
+/// `source` is the file's current source content, used by
+/// [`LineEndingPolicy::Preserve`]. Returns the ending the file was converted
+/// to, or `None` if it was left alone (not text, exempted by
+/// `.gitattributes`, no target ending, or already normalized).
+pub fn normalize_staged_file(
+    staged_path: &Path,
+    rel_path: &str,
+    source: Option<&[u8]>,
+    policy: LineEndingPolicy,
+    attributes: &GitAttributes,
+) -> std::io::Result<Option<LineEnding>> {
+    let target = match attributes.eol_for(rel_path) {
+        EolAttribute::NotText => return Ok(None),
+        EolAttribute::Eol(ending) => ending,
+        EolAttribute::Unspecified => match policy {
+            LineEndingPolicy::Lf => LineEnding::Lf,
+            LineEndingPolicy::Crlf => LineEnding::Crlf,
+            LineEndingPolicy::Preserve => match source.and_then(detect_line_ending) {
+                Some(ending) => ending,
+                None => return Ok(None),
+            },
+        },
+    };
+    let staged = std::fs::read(staged_path)?;
+    if !is_text_content(&staged) {
+        return Ok(None);
+    }
+    let converted = convert_line_endings(&staged, target);
+    if converted == staged {
+        return Ok(None);
+    }
+    std::fs::write(staged_path, converted)?;
+    Ok(Some(target))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn converts_between_lf_and_crlf() {
+        assert_eq!(convert_line_endings(b"a\r\nb\n", LineEnding::Lf), b"a\nb\n");
+        assert_eq!(
+            convert_line_endings(b"a\r\nb\n", LineEnding::Crlf),
+            b"a\r\nb\r\n"
+        );
+        assert_eq!(detect_line_ending(b"a\r\nb\r\nc\n"), Some(LineEnding::Crlf));
+        assert_eq!(detect_line_ending(b"no newline"), None);
+    }
+
+    #[test]
+    fn gitattributes_overrides_policy() {
+        let attributes = GitAttributes::parse(
+            "# comment\n*.bat eol=crlf\n*.png binary\nscripts/*.sh text eol=lf\n",
+        );
+        assert_eq!(
+            attributes.eol_for("tools/run.bat"),
+            EolAttribute::Eol(LineEnding::Crlf)
+        );
+        assert_eq!(attributes.eol_for("img/logo.png"), EolAttribute::NotText);
+        assert_eq!(
+            attributes.eol_for("scripts/build.sh"),
+            EolAttribute::Eol(LineEnding::Lf)
+        );
+        assert_eq!(
+            attributes.eol_for("scripts/nested/x.sh"),
+            EolAttribute::Unspecified
+        );
+        assert_eq!(attributes.eol_for("README.md"), EolAttribute::Unspecified);
+    }
+
+    #[test]
+    fn preserve_matches_source_endings() {
+        let dir = TempDir::new().unwrap();
+        let staged = dir.path().join("main.rs");
+        std::fs::write(&staged, "fn main() {\r\n}\r\n").unwrap();
+        let attributes = GitAttributes::default();
+
+        let applied = normalize_staged_file(
+            &staged,
+            "main.rs",
+            Some(b"fn main() {}\n"),
+            LineEndingPolicy::Preserve,
+            &attributes,
+        )
+        .unwrap();
+        assert_eq!(applied, Some(LineEnding::Lf));
+        assert_eq!(std::fs::read(&staged).unwrap(), b"fn main() {\n}\n");
+
+        // Already normalized, and new files have no source to preserve.
+        assert_eq!(
+            normalize_staged_file(
+                &staged,
+                "main.rs",
+                Some(b"x\n"),
+                LineEndingPolicy::Preserve,
+                &attributes
+            )
+            .unwrap(),
+            None
+        );
+        assert_eq!(
+            normalize_staged_file(
+                &staged,
+                "main.rs",
+                None,
+                LineEndingPolicy::Preserve,
+                &attributes
+            )
+            .unwrap(),
+            None
+        );
+    }
+}
@@ -155,6 +155,89 @@ pub fn select_merge_tool(name: &str) -> Box<dyn MergeTool> {
     }
 }
 
+/// One side's edit to the base: replace base lines `start..end` with `lines`.
+/// An empty range is a pure insertion before base line `start`.
+#[derive(Debug, PartialEq, Eq)]
+struct LineEdit<'a> {
+    start: usize,
+    end: usize,
+    lines: Vec<&'a str>,
+}
+
+/// Line-level edits that turn `base` into `side`.
+fn line_edits<'a>(base: &[&str], side: &[&'a str]) -> Vec<LineEdit<'a>> {
+    similar::capture_diff_slices(similar::Algorithm::Myers, base, side)
+        .into_iter()
+        .filter_map(|op| {
+            let (tag, old, new) = op.as_tag_tuple();
+            (tag != similar::DiffTag::Equal).then(|| LineEdit {
+                start: old.start,
+                end: old.end,
+                lines: side[new].to_vec(),
+            })
+        })
+        .collect()
+}
+
+/// Whether two edits touch the same base lines.
+///
+/// Ranges that only meet at a boundary do not overlap, so an upstream
+/// reformat of the line after the agent's edit still merges. Two insertions
+/// at the same point do overlap: their relative order is ambiguous.
+fn edits_overlap(a: &LineEdit, b: &LineEdit) -> bool {
+    if a.start == a.end && b.start == b.end {
+        return a.start == b.start;
+    }
+    if a.start == a.end {
+        return b.start < a.start && a.start < b.end;
+    }
+    if b.start == b.end {
+        return a.start < b.start && b.start < a.end;
+    }
+    a.start < b.end && b.start < a.end
+}
+
+/// Three-way merge at line granularity that succeeds only when `ours` and
+/// `theirs` change disjoint line ranges of `base`.
+///
+/// Returns the merged text and the number of hunks applied, or `None` when
+/// an edit from one side overlaps an edit from the other (identical edits on
+/// both sides are taken once and are not a conflict). Unlike `git merge-file`,
+/// adjacent but non-overlapping hunks merge cleanly, so an upstream
+/// `cargo fmt` next to the agent's change doesn't block the apply.
+pub fn merge_disjoint_lines(base: &str, ours: &str, theirs: &str) -> Option<(String, usize)> {
+    let base_lines: Vec<&str> = base.split_inclusive('\n').collect();
+    let ours_lines: Vec<&str> = ours.split_inclusive('\n').collect();
+    let theirs_lines: Vec<&str> = theirs.split_inclusive('\n').collect();
+
+    let ours_edits = line_edits(&base_lines, &ours_lines);
+    let mut edits = Vec::with_capacity(ours_edits.len());
+    let mut theirs_only = Vec::new();
+    for edit in line_edits(&base_lines, &theirs_lines) {
+        if ours_edits.contains(&edit) {
+            continue;
+        }
+        if ours_edits.iter().any(|o| edits_overlap(o, &edit)) {
+            return None;
+        }
+        theirs_only.push(edit);
+    }
+    edits.extend(ours_edits);
+    edits.extend(theirs_only);
+    // Insertions sort ahead of a replacement starting at the same line.
+    edits.sort_by_key(|e| (e.start, e.end));
+
+    let mut merged = String::with_capacity(ours.len().max(theirs.len()));
+    let mut pos = 0;
+    for edit in &edits {
+        merged.extend(base_lines[pos..edit.start].iter().copied());
+        merged.extend(edit.lines.iter().copied());
+        pos = edit.end;
+    }
+    merged.extend(base_lines[pos..].iter().copied());
+    Some((merged, edits.len()))
+}
+
 /// Unused parameter to satisfy trait object sizing.
 #[allow(dead_code)]
 fn _assert_path_unused(_p: &Path) {}
@@ -199,4 +282,45 @@ mod tests {
         let tool = select_merge_tool("none");
         assert_eq!(tool.name(), "none");
     }
+
+    #[test]
+    fn merge_disjoint_lines_takes_adjacent_edits() {
+        let base = "fn a() {}\nfn b(){}\nfn c() {}\n";
+        // Agent edits line 1; upstream reformats line 2 right next to it.
+        let ours = "fn a() { todo!() }\nfn b(){}\nfn c() {}\n";
+        let theirs = "fn a() {}\nfn b() {}\nfn c() {}\n";
+        let (merged, hunks) = merge_disjoint_lines(base, ours, theirs).unwrap();
+        assert_eq!(merged, "fn a() { todo!() }\nfn b() {}\nfn c() {}\n");
+        assert_eq!(hunks, 2);
+    }
+
+    #[test]
+    fn merge_disjoint_lines_keeps_insertions_and_deletions() {
+        let base = "one\ntwo\nthree\nfour\n";
+        let ours = "zero\none\ntwo\nthree\nfour\n";
+        let theirs = "one\ntwo\nfour\n";
+        let (merged, _) = merge_disjoint_lines(base, ours, theirs).unwrap();
+        assert_eq!(merged, "zero\none\ntwo\nfour\n");
+    }
+
+    #[test]
+    fn merge_disjoint_lines_rejects_overlap() {
+        let base = "one\ntwo\nthree\n";
+        let ours = "one\nTWO\nthree\n";
+        let theirs = "one\n2\nthree\n";
+        assert!(merge_disjoint_lines(base, ours, theirs).is_none());
+        // Both sides inserting at the same point is ambiguous.
+        assert!(
+            merge_disjoint_lines(base, "one\nx\ntwo\nthree\n", "one\ny\ntwo\nthree\n").is_none()
+        );
+    }
+
+    #[test]
+    fn merge_disjoint_lines_identical_edit_is_not_a_conflict() {
+        let base = "one\ntwo\nthree\n";
+        let both = "one\nTWO\nthree\n";
+        let (merged, hunks) = merge_disjoint_lines(base, both, both).unwrap();
+        assert_eq!(merged, both);
+        assert_eq!(hunks, 1);
+    }
 }
@@ -12,11 +12,27 @@
 //
 // The strategy is detected automatically at workspace creation time by probing
 // the staging directory. No configuration is needed.
+//
+// File copies during creation and per-file comparisons in `diff_all` run on
+// the rayon thread pool unless `[workspace] parallel = false`; results are
+// sorted by path afterwards so output order never depends on scheduling.
+//
+// After diffing, deleted/created pairs whose contents are similar enough
+// (`[workspace] rename_similarity`, default 0.5) are folded into a single
+// `OverlayChange::Renamed` so a moved file reviews as one change.
+//
+// File modes are tracked the way git tracks them: a file is either `100644`
+// or `100755` (any execute bit set). A file whose content is unchanged but
+// whose executable bit flipped is reported as `OverlayChange::ModeChanged`
+// and applying it sets or clears the bit on the target. On non-Unix
+// platforms modes are not tracked and this is a no-op.
 
 use std::fs;
 use std::path::{Path, PathBuf};
 use std::time::Instant;
 
+use rayon::prelude::*;
+
 use crate::merge_tool::MergeTool;
 
 use crate::copy_strategy::{copy_file_with_strategy, detect_strategy, CopyStat, CopyStrategy};
@@ -44,6 +60,7 @@ pub enum OverlayStagingMode {
 
 use crate::conflict::{Conflict, ConflictResolution, FileSnapshot, SourceSnapshot};
 use crate::error::WorkspaceError;
+use crate::line_endings::{self, GitAttributes, LineEnding, LineEndingPolicy};
 
 // ── V1 copy-optimization excludes (remove when V2 VFS lands) ──────
 
@@ -151,29 +168,32 @@ impl ExcludePatterns {
     const INFRA_DIRS: &'static [&'static str] = &[".ta", ".claude-flow", ".hive-mind", ".swarm"];
 
     pub fn should_exclude(&self, name: &str) -> bool {
+        self.matching_pattern(name).is_some()
+    }
+
+    /// The pattern that excludes a file/directory name, if any. Always-excluded
+    /// infrastructure directories (`.ta`, ...) are returned as their bare name,
+    /// which is not among [`Self::patterns`].
+    pub fn matching_pattern(&self, name: &str) -> Option<&str> {
         // Infrastructure dirs are always excluded (hardcoded, separate from user patterns).
-        if Self::INFRA_DIRS.contains(&name) {
-            return true;
+        if let Some(infra) = Self::INFRA_DIRS.iter().find(|d| **d == name) {
+            return Some(infra);
         }
-        for pattern in &self.patterns {
-            if let Some(dir_name) = pattern.strip_suffix('/') {
-                // Directory pattern: "target/" matches entry named "target".
-                if name == dir_name {
-                    return true;
-                }
-            } else if let Some(suffix) = pattern.strip_prefix('*') {
-                // Extension pattern: "*.pyc" matches files ending in ".pyc".
-                if name.ends_with(suffix) {
-                    return true;
-                }
-            } else {
-                // Exact name match.
-                if name == pattern {
-                    return true;
+        self.patterns
+            .iter()
+            .find(|pattern| {
+                if let Some(dir_name) = pattern.strip_suffix('/') {
+                    // Directory pattern: "target/" matches entry named "target".
+                    name == dir_name
+                } else if let Some(suffix) = pattern.strip_prefix('*') {
+                    // Extension pattern: "*.pyc" matches files ending in ".pyc".
+                    name.ends_with(suffix)
+                } else {
+                    // Exact name match.
+                    name == pattern.as_str()
                 }
-            }
-        }
-        false
+            })
+            .map(String::as_str)
     }
 
     /// V1 TEMPORARY: Check if a relative path should be skipped.
@@ -194,7 +214,7 @@ impl ExcludePatterns {
 // ── End V1 copy-optimization excludes ─────────────────────────────
 
 /// A change detected by diffing the staging workspace against the source.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum OverlayChange {
     /// A file that existed in source was modified in staging.
     Modified { path: String, diff: String },
@@ -202,6 +222,110 @@ pub enum OverlayChange {
     Created { path: String, content: String },
     /// A file that existed in source was deleted from staging.
     Deleted { path: String },
+    /// A source file was removed and a file with similar content created
+    /// elsewhere. `diff` has `a/<from>` / `b/<to>` headers.
+    Renamed {
+        from: String,
+        to: String,
+        diff: String,
+    },
+    /// Content is unchanged but the executable bit differs. Modes are
+    /// git-style (`0o100644` / `0o100755`).
+    ModeChanged {
+        path: String,
+        old_mode: u32,
+        new_mode: u32,
+    },
+}
+
+impl OverlayChange {
+    /// The staging-side path of the change (the new path for renames).
+    pub fn path(&self) -> &str {
+        match self {
+            OverlayChange::Modified { path, .. }
+            | OverlayChange::Created { path, .. }
+            | OverlayChange::Deleted { path }
+            | OverlayChange::ModeChanged { path, .. } => path,
+            OverlayChange::Renamed { to, .. } => to,
+        }
+    }
+
+    /// The same change with `<alias>/` prepended to its paths, as reported by
+    /// a multi-root workspace.
+    fn with_root_alias(self, alias: &str) -> Self {
+        let prefix = |path: String| format!("{}/{}", alias, path);
+        match self {
+            OverlayChange::Modified { path, diff } => OverlayChange::Modified {
+                path: prefix(path),
+                diff,
+            },
+            OverlayChange::Created { path, content } => OverlayChange::Created {
+                path: prefix(path),
+                content,
+            },
+            OverlayChange::Deleted { path } => OverlayChange::Deleted { path: prefix(path) },
+            OverlayChange::Renamed { from, to, diff } => OverlayChange::Renamed {
+                from: prefix(from),
+                to: prefix(to),
+                diff,
+            },
+            OverlayChange::ModeChanged {
+                path,
+                old_mode,
+                new_mode,
+            } => OverlayChange::ModeChanged {
+                path: prefix(path),
+                old_mode,
+                new_mode,
+            },
+        }
+    }
+}
+
+/// Default minimum similarity for pairing a deleted and a created file as a
+/// rename (`[workspace] rename_similarity`).
+pub const DEFAULT_RENAME_SIMILARITY: f64 = 0.5;
+
+/// Which differences [`OverlayWorkspace::drop_noop_changes`] treats as no
+/// change at all. Byte-identical files and empty creates/deletes are always
+/// no-ops; these flags widen that to whitespace-only edits.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct NoopNormalization {
+    /// Treat CRLF and LF line endings as equal.
+    pub ignore_line_endings: bool,
+    /// Ignore whitespace at the end of lines and blank lines at end of file.
+    pub ignore_trailing_whitespace: bool,
+}
+
+impl NoopNormalization {
+    /// Normalize text so that equal results mean an empty effective diff.
+    fn normalize(&self, text: &str) -> String {
+        let mut lines: Vec<&str> = text.split('\n').collect();
+        for line in &mut lines {
+            if self.ignore_trailing_whitespace {
+                *line = line.trim_end();
+            } else if self.ignore_line_endings {
+                *line = line.strip_suffix('\r').unwrap_or(line);
+            }
+        }
+        let joined = lines.join("\n");
+        if self.ignore_trailing_whitespace {
+            joined.trim_end().to_string()
+        } else {
+            joined
+        }
+    }
+}
+
+/// One source root of a multi-root workspace (see
+/// [`OverlayWorkspace::open_multi`]). Its files are staged under
+/// `<staging_dir>/<alias>/`.
+#[derive(Debug, Clone)]
+struct SourceRoot {
+    alias: String,
+    source_dir: PathBuf,
+    /// Source state at goal start, for conflict detection in this root.
+    snapshot: Option<SourceSnapshot>,
 }
 
 /// An overlay workspace that copies a source project for transparent agent work.
@@ -225,6 +349,18 @@ pub struct OverlayWorkspace {
     source_snapshot: Option<SourceSnapshot>, // v0.2.1: Conflict detection
     /// Statistics from staging creation (strategy, duration, file count).
     copy_stat: Option<CopyStat>,
+    /// Copy and diff files on the rayon thread pool. Default: true.
+    parallel: bool,
+    /// Minimum content similarity (0.0–1.0) for a delete + create pair to be
+    /// reported as a rename. 0 disables rename detection.
+    rename_threshold: f64,
+    /// Line diff algorithm for modified-file diffs (`[diff] algorithm`).
+    diff_algorithm: DiffAlgorithm,
+    /// Source roots of a multi-root workspace; empty for a single root.
+    roots: Vec<SourceRoot>,
+    /// `(from, to)` path prefixes rewritten when applying
+    /// (`ta draft apply --target-subdir`).
+    destination_remaps: Vec<(String, String)>,
     /// Active ProjFS virtualization provider (Windows only, v0.15.8).
     /// Must outlive the workspace root directory. `None` on non-Windows or
     /// when ProjFS mode is not in use. Held for RAII drop — intentionally
@@ -275,6 +411,20 @@ impl OverlayWorkspace {
         staging_root: impl AsRef<Path>,
         excludes: ExcludePatterns,
         mode: OverlayStagingMode,
+    ) -> Result<Self, WorkspaceError> {
+        Self::create_with_options(goal_id, source_dir, staging_root, excludes, mode, true)
+    }
+
+    /// Like [`create_with_strategy`](Self::create_with_strategy), with
+    /// parallel file copying and diffing switched on or off
+    /// (`[workspace] parallel` in workflow.toml).
+    pub fn create_with_options(
+        goal_id: impl Into<String>,
+        source_dir: impl AsRef<Path>,
+        staging_root: impl AsRef<Path>,
+        excludes: ExcludePatterns,
+        mode: OverlayStagingMode,
+        parallel: bool,
     ) -> Result<Self, WorkspaceError> {
         let goal_id = goal_id.into();
         let source_dir = source_dir.as_ref().to_path_buf();
@@ -328,6 +478,7 @@ impl OverlayWorkspace {
                     &excludes,
                     copy_strategy,
                     &mut stat,
+                    parallel,
                 )?;
             }
             OverlayStagingMode::ProjFs => {
@@ -351,6 +502,7 @@ impl OverlayWorkspace {
                                 &excludes,
                                 copy_strategy,
                                 &mut stat,
+                                parallel,
                             )?;
                         }
                     }
@@ -366,6 +518,7 @@ impl OverlayWorkspace {
                         &excludes,
                         copy_strategy,
                         &mut stat,
+                        parallel,
                     )?;
                 }
             }
@@ -377,6 +530,7 @@ impl OverlayWorkspace {
                     &excludes,
                     copy_strategy,
                     &mut stat,
+                    parallel,
                 )?;
             }
         }
@@ -411,6 +565,11 @@ impl OverlayWorkspace {
             excludes,
             source_snapshot: snapshot,
             copy_stat: Some(stat),
+            parallel,
+            rename_threshold: DEFAULT_RENAME_SIMILARITY,
+            diff_algorithm: DiffAlgorithm::default(),
+            roots: Vec::new(),
+            destination_remaps: Vec::new(),
             #[cfg(all(target_os = "windows", feature = "projfs"))]
             projfs_provider,
         })
@@ -430,11 +589,304 @@ impl OverlayWorkspace {
             excludes,
             source_snapshot: None, // Snapshot must be loaded separately if needed.
             copy_stat: None,       // Not available when reopening an existing workspace.
+            parallel: true,
+            rename_threshold: DEFAULT_RENAME_SIMILARITY,
+            diff_algorithm: DiffAlgorithm::default(),
+            roots: Vec::new(),
+            destination_remaps: Vec::new(),
             #[cfg(all(target_os = "windows", feature = "projfs"))]
             projfs_provider: None, // Not available when reopening an existing workspace.
         }
     }
 
+    /// Open a workspace spanning several source roots, e.g. sibling packages
+    /// of a monorepo that don't share a parent the agent should see.
+    ///
+    /// Each `(alias, source_dir)` root is staged under `<staging_dir>/<alias>/`.
+    /// Change paths are reported as `<alias>/<path>` and artifact URIs as
+    /// `fs://<alias>/<path>` (see [`resource_uri`](Self::resource_uri)). The
+    /// apply methods route each artifact back to the root its alias names.
+    /// [`source_dir`](Self::source_dir) returns the first root.
+    ///
+    /// Aliases must be non-empty, unique, and a single path component.
+    pub fn open_multi(
+        goal_id: impl Into<String>,
+        roots: Vec<(String, PathBuf)>,
+        staging_dir: impl AsRef<Path>,
+        excludes: ExcludePatterns,
+    ) -> Result<Self, WorkspaceError> {
+        let invalid = |alias: &str, reason: &str| WorkspaceError::InvalidSourceRoot {
+            alias: alias.to_string(),
+            reason: reason.to_string(),
+        };
+        let Some((_, primary)) = roots.first() else {
+            return Err(invalid("", "at least one source root is required"));
+        };
+        let primary = primary.clone();
+        let mut seen = std::collections::HashSet::new();
+        for (alias, _) in &roots {
+            if alias.is_empty() || alias == "." || alias == ".." || alias.contains(['/', '\\']) {
+                return Err(invalid(alias, "alias must be a single path component"));
+            }
+            if !seen.insert(alias.as_str()) {
+                return Err(invalid(alias, "alias is used by more than one root"));
+            }
+        }
+
+        let mut workspace = Self::open(goal_id, primary, staging_dir, excludes);
+        workspace.roots = roots
+            .into_iter()
+            .map(|(alias, source_dir)| SourceRoot {
+                alias,
+                source_dir,
+                snapshot: None,
+            })
+            .collect();
+        Ok(workspace)
+    }
+
+    /// Create a multi-root workspace: each `(alias, source_dir)` root is copied
+    /// to `<staging_root>/<goal_id>/<alias>/` and snapshotted for conflict
+    /// detection, as [`create_with_options`](Self::create_with_options) does
+    /// for a single root. URIs and apply routing are as for
+    /// [`open_multi`](Self::open_multi).
+    pub fn create_multi(
+        goal_id: impl Into<String>,
+        roots: Vec<(String, PathBuf)>,
+        staging_root: impl AsRef<Path>,
+        excludes: ExcludePatterns,
+        mode: OverlayStagingMode,
+        parallel: bool,
+    ) -> Result<Self, WorkspaceError> {
+        let goal_id = goal_id.into();
+        let staging_dir = staging_root.as_ref().join(&goal_id);
+        let mut workspace = Self::open_multi(goal_id, roots, &staging_dir, excludes)?;
+        workspace.parallel = parallel;
+        for root in &mut workspace.roots {
+            let staged = Self::create_with_options(
+                root.alias.clone(),
+                &root.source_dir,
+                &staging_dir,
+                workspace.excludes.clone(),
+                mode,
+                parallel,
+            )?;
+            root.snapshot = staged.snapshot().cloned();
+        }
+        Ok(workspace)
+    }
+
+    /// Whether this workspace was opened with [`open_multi`](Self::open_multi).
+    pub fn is_multi_root(&self) -> bool {
+        !self.roots.is_empty()
+    }
+
+    /// The `(alias, source_dir)` roots of a multi-root workspace, in order.
+    pub fn roots(&self) -> impl Iterator<Item = (&str, &Path)> {
+        self.roots
+            .iter()
+            .map(|root| (root.alias.as_str(), root.source_dir.as_path()))
+    }
+
+    /// The goal-start snapshot of one root of a multi-root workspace.
+    pub fn root_snapshot(&self, alias: &str) -> Option<&SourceSnapshot> {
+        self.roots
+            .iter()
+            .find(|root| root.alias == alias)
+            .and_then(|root| root.snapshot.as_ref())
+    }
+
+    /// Set the goal-start snapshot of one root of a multi-root workspace.
+    pub fn set_root_snapshot(
+        &mut self,
+        alias: &str,
+        snapshot: SourceSnapshot,
+    ) -> Result<(), WorkspaceError> {
+        let root = self
+            .roots
+            .iter_mut()
+            .find(|root| root.alias == alias)
+            .ok_or_else(|| WorkspaceError::InvalidSourceRoot {
+                alias: alias.to_string(),
+                reason: "no source root with this alias".to_string(),
+            })?;
+        root.snapshot = Some(snapshot);
+        Ok(())
+    }
+
+    /// The artifact URI for a change path: `fs://workspace/<path>`, or
+    /// `fs://<alias>/<path>` in a multi-root workspace.
+    pub fn resource_uri(&self, path: &str) -> String {
+        if self.roots.is_empty() {
+            format!("fs://workspace/{}", path)
+        } else {
+            format!("fs://{}", path)
+        }
+    }
+
+    /// The change path an artifact URI refers to, or `None` if the URI does
+    /// not name a file in this workspace. Inverse of
+    /// [`resource_uri`](Self::resource_uri).
+    pub fn uri_path(&self, uri: &str) -> Option<String> {
+        if self.roots.is_empty() {
+            return uri.strip_prefix("fs://workspace/").map(str::to_string);
+        }
+        let path = uri.strip_prefix("fs://")?;
+        let (alias, _) = path.split_once('/')?;
+        self.roots
+            .iter()
+            .any(|root| root.alias == alias)
+            .then(|| path.to_string())
+    }
+
+    /// Single-root views of each root of a multi-root workspace, sharing this
+    /// workspace's diff settings.
+    fn root_workspaces(&self) -> Vec<(&str, OverlayWorkspace)> {
+        self.roots
+            .iter()
+            .map(|root| {
+                let mut workspace = OverlayWorkspace::open(
+                    self.goal_id.clone(),
+                    &root.source_dir,
+                    self.staging_dir.join(&root.alias),
+                    self.excludes.clone(),
+                );
+                workspace.parallel = self.parallel;
+                workspace.rename_threshold = self.rename_threshold;
+                workspace.diff_algorithm = self.diff_algorithm;
+                workspace.source_snapshot = root.snapshot.clone();
+                (root.alias.as_str(), workspace)
+            })
+            .collect()
+    }
+
+    /// Source-side file for a change path. In a multi-root workspace the
+    /// leading alias selects the root.
+    fn source_path(&self, path: &str) -> PathBuf {
+        if let Some((alias, rest)) = path.split_once('/') {
+            if let Some(root) = self.roots.iter().find(|root| root.alias == alias) {
+                return root.source_dir.join(rest);
+            }
+        }
+        self.source_dir.join(path)
+    }
+
+    /// Where applying a change path writes: `target_dir` (after destination
+    /// remaps), or the root its alias names in a multi-root workspace.
+    pub fn apply_target(&self, target_dir: &Path, path: &str) -> PathBuf {
+        if self.roots.is_empty() {
+            target_dir.join(self.destination_path(path))
+        } else {
+            self.source_path(path)
+        }
+    }
+
+    /// Run a single-root apply against each root of a multi-root workspace.
+    ///
+    /// `fs://<alias>/<path>` URIs are handed to that root as
+    /// `fs://workspace/<path>` and applied to its source directory; the
+    /// applied paths come back alias-prefixed. The roots are applied as one
+    /// unit: every source file the artifacts touch is read up front, and if
+    /// any root fails, the roots already written are put back.
+    fn apply_per_root<F>(
+        &self,
+        artifact_uris: &[String],
+        apply: F,
+    ) -> Result<Vec<(String, &'static str)>, WorkspaceError>
+    where
+        F: Fn(
+            &OverlayWorkspace,
+            &Path,
+            &[String],
+        ) -> Result<Vec<(String, &'static str)>, WorkspaceError>,
+    {
+        let mut staged = Vec::new();
+        let mut backups: Vec<(PathBuf, Option<Vec<u8>>)> = Vec::new();
+        for (alias, root) in self.root_workspaces() {
+            let prefix = format!("fs://{}/", alias);
+            let uris: Vec<String> = artifact_uris
+                .iter()
+                .filter_map(|uri| uri.strip_prefix(&prefix))
+                .map(|path| format!("fs://workspace/{}", path))
+                .collect();
+            if uris.is_empty() {
+                continue;
+            }
+            for change in root.diff_all()? {
+                if !uris.contains(&format!("fs://workspace/{}", change.path())) {
+                    continue;
+                }
+                let mut paths = vec![change.path().to_string()];
+                if let OverlayChange::Renamed { from, .. } = &change {
+                    paths.push(from.clone());
+                }
+                for path in paths {
+                    let file = root.source_dir().join(path);
+                    let previous = if file.exists() {
+                        Some(fs::read(&file).map_err(|source| WorkspaceError::IoError {
+                            path: file.clone(),
+                            source,
+                        })?)
+                    } else {
+                        None
+                    };
+                    backups.push((file, previous));
+                }
+            }
+            staged.push((alias, root, uris));
+        }
+
+        let mut applied = Vec::new();
+        for (alias, root, uris) in &staged {
+            match apply(root, root.source_dir(), uris) {
+                Ok(paths) => applied.extend(
+                    paths
+                        .into_iter()
+                        .map(|(path, kind)| (format!("{}/{}", alias, path), kind)),
+                ),
+                Err(e) => {
+                    restore_files(&backups);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(applied)
+    }
+
+    /// Enable or disable parallel diffing (`[workspace] parallel`).
+    pub fn set_parallel(&mut self, parallel: bool) {
+        self.parallel = parallel;
+    }
+
+    /// Set the minimum similarity for rename detection
+    /// (`[workspace] rename_similarity`). 0 disables it.
+    pub fn set_rename_threshold(&mut self, threshold: f64) {
+        self.rename_threshold = threshold;
+    }
+
+    /// Set the line diff algorithm for modified files (`[diff] algorithm`).
+    pub fn set_diff_algorithm(&mut self, algorithm: DiffAlgorithm) {
+        self.diff_algorithm = algorithm;
+    }
+
+    /// Apply changes under the `from` directory to the `to` directory instead.
+    ///
+    /// Only the destination moves: diffs, conflict detection against the
+    /// source snapshot, and artifact URIs still use the staged path. Changes
+    /// that land elsewhere are not checked for source conflicts. The first
+    /// matching remap wins.
+    pub fn add_destination_remap(&mut self, from: &str, to: &str) {
+        self.destination_remaps.push((
+            from.trim_matches('/').to_string(),
+            to.trim_matches('/').to_string(),
+        ));
+    }
+
+    /// Where an applied change path is written in the target directory.
+    pub fn destination_path(&self, path: &str) -> String {
+        remap_destination(&self.destination_remaps, path)
+    }
+
     /// Set the source snapshot (for conflict detection after restore from disk).
     pub fn set_snapshot(&mut self, snapshot: SourceSnapshot) {
         self.source_snapshot = Some(snapshot);
@@ -476,133 +928,258 @@ impl OverlayWorkspace {
     /// diffing (internal state, not agent work product), plus V1 exclude patterns
     /// (which include VCS metadata dirs contributed by the active adapter).
     pub fn diff_all(&self) -> Result<Vec<OverlayChange>, WorkspaceError> {
-        let mut changes = Vec::new();
+        if !self.roots.is_empty() {
+            let mut changes = Vec::new();
+            for (alias, root) in self.root_workspaces() {
+                changes.extend(
+                    root.diff_all()?
+                        .into_iter()
+                        .map(|change| change.with_root_alias(alias)),
+                );
+            }
+            changes.sort_by(|a, b| a.path().cmp(b.path()));
+            return Ok(changes);
+        }
 
         // Collect all file paths from both directories.
-        let mut staging_files = Vec::new();
-        walk_dir_relative(
-            &self.staging_dir,
-            &self.staging_dir,
-            &mut staging_files,
-            &self.excludes,
-        )?;
-
-        let mut source_files = Vec::new();
-        walk_dir_relative(
-            &self.source_dir,
-            &self.source_dir,
-            &mut source_files,
-            &self.excludes,
-        )?;
+        let walk = |root: &Path| -> Result<Vec<String>, WorkspaceError> {
+            let mut files = Vec::new();
+            walk_dir_relative(root, root, &mut files, &self.excludes)?;
+            Ok(files)
+        };
+        let (staging_files, source_files) = if self.parallel {
+            rayon::join(|| walk(&self.staging_dir), || walk(&self.source_dir))
+        } else {
+            (walk(&self.staging_dir), walk(&self.source_dir))
+        };
+        let (staging_files, source_files) = (staging_files?, source_files?);
 
         // Check each staging file against source.
-        for path in &staging_files {
+        let diff_one = |path: &String| {
             if should_skip_for_diff(path, &self.excludes) {
-                continue;
+                return Ok(None);
             }
+            diff_staged_file(
+                &self.source_dir,
+                &self.staging_dir,
+                path,
+                self.diff_algorithm,
+            )
+        };
+        let staged: Vec<Option<OverlayChange>> = if self.parallel {
+            staging_files
+                .par_iter()
+                .map(diff_one)
+                .collect::<Result<_, _>>()?
+        } else {
+            staging_files
+                .iter()
+                .map(diff_one)
+                .collect::<Result<_, _>>()?
+        };
+        let mut changes: Vec<OverlayChange> = staged.into_iter().flatten().collect();
 
-            let staging_path = self.staging_dir.join(path);
-            let source_path = self.source_dir.join(path);
-
-            if source_path.exists() {
-                // File exists in both — check if modified.
-                let staging_content =
-                    fs::read(&staging_path).map_err(|source| WorkspaceError::IoError {
-                        path: staging_path.clone(),
-                        source,
-                    })?;
-                let source_content =
-                    fs::read(&source_path).map_err(|source| WorkspaceError::IoError {
-                        path: source_path.clone(),
-                        source,
-                    })?;
-
-                if staging_content != source_content {
-                    // Detect binary: if either version has null bytes in first 8KB,
-                    // produce a summary instead of a lossy text diff.
-                    let source_binary = source_content
-                        .get(..8192)
-                        .unwrap_or(&source_content)
-                        .contains(&0);
-                    let staging_binary = staging_content
-                        .get(..8192)
-                        .unwrap_or(&staging_content)
-                        .contains(&0);
-                    let diff = if source_binary || staging_binary {
-                        format!(
-                            "--- a/{}\n+++ b/{}\n[binary file changed: {} -> {} bytes]\n",
-                            path,
-                            path,
-                            source_content.len(),
-                            staging_content.len()
-                        )
-                    } else {
-                        simple_unified_diff(
-                            path,
-                            &String::from_utf8_lossy(&source_content),
-                            &String::from_utf8_lossy(&staging_content),
-                        )
-                    };
-                    changes.push(OverlayChange::Modified {
-                        path: path.clone(),
-                        diff,
-                    });
+        // Check for deleted files (in source but not in staging).
+        let is_deleted = |path: &&String| {
+            !should_skip_for_diff(path, &self.excludes) && !self.staging_dir.join(path).exists()
+        };
+        let deleted: Vec<&String> = if self.parallel {
+            source_files.par_iter().filter(is_deleted).collect()
+        } else {
+            source_files.iter().filter(is_deleted).collect()
+        };
+        changes.extend(
+            deleted
+                .into_iter()
+                .map(|path| OverlayChange::Deleted { path: path.clone() }),
+        );
+
+        if self.rename_threshold > 0.0 {
+            changes = self.detect_renames(changes)?;
+        }
+
+        changes.sort_by(|a, b| a.path().cmp(b.path()));
+
+        Ok(changes)
+    }
+
+    /// Fold deleted/created pairs with similar content into `Renamed` changes.
+    ///
+    /// Candidates are scored with [`content_similarity`]; the best-scoring
+    /// pairs at or above the threshold are matched first, and each file takes
+    /// part in at most one rename.
+    fn detect_renames(
+        &self,
+        changes: Vec<OverlayChange>,
+    ) -> Result<Vec<OverlayChange>, WorkspaceError> {
+        let read = |path: PathBuf| {
+            fs::read(&path).map_err(|source| WorkspaceError::IoError { path, source })
+        };
+        let mut deleted = Vec::new();
+        let mut created = Vec::new();
+        for change in &changes {
+            match change {
+                OverlayChange::Deleted { path } => {
+                    deleted.push((path.clone(), read(self.source_dir.join(path))?))
                 }
-            } else {
-                // File only in staging — created.
-                // Detect binary files: if the first 8KB contains a null byte,
-                // store a placeholder instead of lossy UTF-8 conversion.
-                let raw = fs::read(&staging_path).map_err(|source| WorkspaceError::IoError {
-                    path: staging_path.clone(),
-                    source,
-                })?;
-                let is_binary = raw.get(..8192).unwrap_or(&raw).contains(&0);
-                let content = if is_binary {
-                    format!("[binary file: {} bytes]", raw.len())
-                } else {
-                    String::from_utf8(raw).unwrap_or_else(|e| {
-                        format!("[binary file: {} bytes]", e.into_bytes().len())
-                    })
-                };
-                changes.push(OverlayChange::Created {
-                    path: path.clone(),
-                    content,
-                });
+                OverlayChange::Created { path, .. } => {
+                    created.push((path.clone(), read(self.staging_dir.join(path))?))
+                }
+                _ => {}
             }
         }
+        if deleted.is_empty() || created.is_empty() {
+            return Ok(changes);
+        }
 
-        // Check for deleted files (in source but not in staging).
-        for path in &source_files {
-            if should_skip_for_diff(path, &self.excludes) {
-                continue;
+        let mut candidates = Vec::new();
+        for (d, (_, old)) in deleted.iter().enumerate() {
+            for (c, (_, new)) in created.iter().enumerate() {
+                let score = content_similarity(old, new);
+                if score >= self.rename_threshold {
+                    candidates.push((score, d, c));
+                }
             }
-            let staging_path = self.staging_dir.join(path);
-            if !staging_path.exists() {
-                changes.push(OverlayChange::Deleted { path: path.clone() });
+        }
+        // Highest score first; ties resolved by path order for stable output.
+        candidates.sort_by(|a, b| {
+            b.0.total_cmp(&a.0)
+                .then_with(|| deleted[a.1].0.cmp(&deleted[b.1].0))
+                .then_with(|| created[a.2].0.cmp(&created[b.2].0))
+        });
+
+        let mut used_deleted = vec![false; deleted.len()];
+        let mut used_created = vec![false; created.len()];
+        let mut renames = Vec::new();
+        for (_, d, c) in candidates {
+            if used_deleted[d] || used_created[c] {
+                continue;
             }
+            used_deleted[d] = true;
+            used_created[c] = true;
+            let (from, old) = &deleted[d];
+            let (to, new) = &created[c];
+            renames.push(OverlayChange::Renamed {
+                from: from.clone(),
+                to: to.clone(),
+                diff: renamed_file_diff(from, to, old, new, self.diff_algorithm),
+            });
+        }
+        if renames.is_empty() {
+            return Ok(changes);
         }
 
-        changes.sort_by(|a, b| {
-            let path_a = match a {
-                OverlayChange::Modified { path, .. }
-                | OverlayChange::Created { path, .. }
-                | OverlayChange::Deleted { path } => path,
-            };
-            let path_b = match b {
-                OverlayChange::Modified { path, .. }
-                | OverlayChange::Created { path, .. }
-                | OverlayChange::Deleted { path } => path,
+        let paired_from: std::collections::HashSet<&str> = renames
+            .iter()
+            .filter_map(|r| match r {
+                OverlayChange::Renamed { from, .. } => Some(from.as_str()),
+                _ => None,
+            })
+            .collect();
+        let paired_to: std::collections::HashSet<&str> = renames.iter().map(|r| r.path()).collect();
+        let mut kept: Vec<OverlayChange> = changes
+            .iter()
+            .filter(|change| match change {
+                OverlayChange::Deleted { path } => !paired_from.contains(path.as_str()),
+                OverlayChange::Created { path, .. } => !paired_to.contains(path.as_str()),
+                _ => true,
+            })
+            .cloned()
+            .collect();
+        kept.extend(renames);
+        Ok(kept)
+    }
+
+    /// Drop modifications whose effective diff is empty.
+    ///
+    /// Catches what the byte comparison in [`diff_all`](Self::diff_all)
+    /// misses: edits that (per `normalization`) only touch line endings or
+    /// trailing whitespace. Creates and deletes are always kept — even an
+    /// empty file appearing or disappearing changes the tree — as are
+    /// renames, mode changes, and non-text files. Returns the kept changes
+    /// and the paths that were dropped.
+    pub fn drop_noop_changes(
+        &self,
+        changes: Vec<OverlayChange>,
+        normalization: NoopNormalization,
+    ) -> Result<(Vec<OverlayChange>, Vec<String>), WorkspaceError> {
+        let read = |path: PathBuf| {
+            fs::read(&path).map_err(|source| WorkspaceError::IoError { path, source })
+        };
+        let mut kept = Vec::with_capacity(changes.len());
+        let mut dropped = Vec::new();
+        for change in changes {
+            let noop = match &change {
+                OverlayChange::Modified { path, .. } => {
+                    let source = read(self.source_path(path))?;
+                    let staging = read(self.staging_dir.join(path))?;
+                    let same = match (std::str::from_utf8(&source), std::str::from_utf8(&staging)) {
+                        (Ok(a), Ok(b)) if is_text_content(&source) && is_text_content(&staging) => {
+                            normalization.normalize(a) == normalization.normalize(b)
+                        }
+                        _ => source == staging,
+                    };
+                    same && self.mode_change(path).is_none()
+                }
+                OverlayChange::Created { .. }
+                | OverlayChange::Deleted { .. }
+                | OverlayChange::Renamed { .. }
+                | OverlayChange::ModeChanged { .. } => false,
             };
-            path_a.cmp(path_b)
-        });
+            if noop {
+                dropped.push(change.path().to_string());
+            } else {
+                kept.push(change);
+            }
+        }
+        Ok((kept, dropped))
+    }
 
-        Ok(changes)
+    /// Normalize the line endings of the staged files behind `changes`.
+    ///
+    /// Created, modified, and renamed text files are rewritten in staging
+    /// per `policy`, with `.gitattributes` `eol`/`-text` taking precedence
+    /// (see [`crate::line_endings`]). Returns each rewritten path and the
+    /// ending it now uses; diff again afterwards, since changes that only
+    /// differed by line endings are gone.
+    pub fn normalize_line_endings(
+        &self,
+        changes: &[OverlayChange],
+        policy: LineEndingPolicy,
+        attributes: &GitAttributes,
+    ) -> Result<Vec<(String, LineEnding)>, WorkspaceError> {
+        let mut normalized = Vec::new();
+        for change in changes {
+            let (path, source_path) = match change {
+                OverlayChange::Modified { path, .. } => (path, Some(self.source_path(path))),
+                OverlayChange::Renamed { from, to, .. } => (to, Some(self.source_path(from))),
+                OverlayChange::Created { path, .. } => (path, None),
+                OverlayChange::Deleted { .. } | OverlayChange::ModeChanged { .. } => continue,
+            };
+            let source = source_path.and_then(|p| fs::read(p).ok());
+            let staged = self.staging_dir.join(path);
+            let converted = line_endings::normalize_staged_file(
+                &staged,
+                path,
+                source.as_deref(),
+                policy,
+                attributes,
+            )
+            .map_err(|source| WorkspaceError::IoError {
+                path: staged.clone(),
+                source,
+            })?;
+            if let Some(ending) = converted {
+                normalized.push((path.clone(), ending));
+            }
+        }
+        Ok(normalized)
     }
 
     /// Diff a single file between staging and source.
     pub fn diff_file(&self, relative_path: &str) -> Result<Option<String>, WorkspaceError> {
         let staging_path = self.staging_dir.join(relative_path);
-        let source_path = self.source_dir.join(relative_path);
+        let source_path = self.source_path(relative_path);
 
         if !staging_path.exists() && !source_path.exists() {
             return Ok(None);
@@ -610,22 +1187,34 @@ impl OverlayWorkspace {
 
         if !staging_path.exists() {
             // Deleted.
-            let content =
-                fs::read_to_string(&source_path).map_err(|source| WorkspaceError::IoError {
-                    path: source_path,
-                    source,
-                })?;
-            return Ok(Some(deleted_file_diff(relative_path, &content)));
+            let raw = fs::read(&source_path).map_err(|source| WorkspaceError::IoError {
+                path: source_path,
+                source,
+            })?;
+            return Ok(Some(match std::str::from_utf8(&raw) {
+                Ok(content) if is_text_content(&raw) => deleted_file_diff(relative_path, content),
+                _ => format!(
+                    "--- a/{}\n+++ /dev/null\n[non-text file deleted: {} bytes]\n",
+                    relative_path,
+                    raw.len()
+                ),
+            }));
         }
 
         if !source_path.exists() {
             // Created.
-            let content =
-                fs::read_to_string(&staging_path).map_err(|source| WorkspaceError::IoError {
-                    path: staging_path,
-                    source,
-                })?;
-            return Ok(Some(new_file_diff(relative_path, &content)));
+            let raw = fs::read(&staging_path).map_err(|source| WorkspaceError::IoError {
+                path: staging_path,
+                source,
+            })?;
+            return Ok(Some(match std::str::from_utf8(&raw) {
+                Ok(content) if is_text_content(&raw) => new_file_diff(relative_path, content),
+                _ => format!(
+                    "--- /dev/null\n+++ b/{}\n[non-text file created: {} bytes]\n",
+                    relative_path,
+                    raw.len()
+                ),
+            }));
         }
 
         // Both exist — compare.
@@ -643,10 +1232,11 @@ impl OverlayWorkspace {
             return Ok(None);
         }
 
-        Ok(Some(simple_unified_diff(
+        Ok(Some(modified_file_diff(
             relative_path,
-            &String::from_utf8_lossy(&source_content),
-            &String::from_utf8_lossy(&staging_content),
+            &source_content,
+            &staging_content,
+            self.diff_algorithm,
         )))
     }
 
@@ -659,15 +1249,38 @@ impl OverlayWorkspace {
                 OverlayChange::Modified { path, .. } => (path, "modified"),
                 OverlayChange::Created { path, .. } => (path, "created"),
                 OverlayChange::Deleted { path } => (path, "deleted"),
+                OverlayChange::Renamed { to, .. } => (to, "renamed"),
+                OverlayChange::ModeChanged { path, .. } => (path, "mode changed"),
             })
             .collect())
     }
 
+    /// The git-style `(old, new)` mode of `path` when its executable bit
+    /// differs between source and staging. Always `None` on non-Unix.
+    pub fn mode_change(&self, path: &str) -> Option<(u32, u32)> {
+        let old_mode = file_mode(&self.source_path(path))?;
+        let new_mode = file_mode(&self.staging_dir.join(path))?;
+        (old_mode != new_mode).then_some((old_mode, new_mode))
+    }
+
     /// Detect conflicts between the current source state and the snapshot.
     /// Returns None if no snapshot was captured (conflict detection disabled).
     /// Uses the overlay's ExcludePatterns to filter build artifacts (target/, node_modules/, etc.)
     /// from the "new file" scan, preventing false conflicts from cargo build output.
     pub fn detect_conflicts(&self) -> Result<Option<Vec<Conflict>>, WorkspaceError> {
+        if !self.roots.is_empty() {
+            let mut all = None;
+            for (alias, root) in self.root_workspaces() {
+                if let Some(conflicts) = root.detect_conflicts()? {
+                    all.get_or_insert_with(Vec::new)
+                        .extend(conflicts.into_iter().map(|mut conflict| {
+                            conflict.path = format!("{}/{}", alias, conflict.path);
+                            conflict
+                        }));
+                }
+            }
+            return Ok(all);
+        }
         match &self.source_snapshot {
             Some(snapshot) => Ok(Some(
                 snapshot.detect_conflicts(&self.source_dir, |path| {
@@ -688,10 +1301,21 @@ impl OverlayWorkspace {
 
     /// Apply only the changed files from staging back to a target directory.
     /// Does NOT check for conflicts — use apply_with_conflict_check for safety.
+    ///
+    /// In a multi-root workspace `target_dir` is not used: each root's
+    /// changes go back to that root's source directory.
     pub fn apply_to(
         &self,
         target_dir: &Path,
     ) -> Result<Vec<(String, &'static str)>, WorkspaceError> {
+        if !self.roots.is_empty() {
+            let uris: Vec<String> = self
+                .diff_all()?
+                .iter()
+                .map(|change| self.resource_uri(change.path()))
+                .collect();
+            return self.apply_per_root(&uris, |root, target, _| root.apply_to(target));
+        }
         let changes = self.diff_all()?;
         let mut applied = Vec::new();
 
@@ -699,7 +1323,8 @@ impl OverlayWorkspace {
             match change {
                 OverlayChange::Modified { path, .. } | OverlayChange::Created { path, .. } => {
                     let src = self.staging_dir.join(path);
-                    let dst = target_dir.join(path);
+                    let destination = self.destination_path(path);
+                    let dst = target_dir.join(&destination);
                     if let Some(parent) = dst.parent() {
                         fs::create_dir_all(parent).map_err(|source| WorkspaceError::IoError {
                             path: parent.to_path_buf(),
@@ -713,15 +1338,27 @@ impl OverlayWorkspace {
                     } else {
                         "created"
                     };
-                    applied.push((path.clone(), kind));
+                    applied.push((destination, kind));
                 }
                 OverlayChange::Deleted { path } => {
-                    let dst = target_dir.join(path);
+                    let destination = self.destination_path(path);
+                    let dst = target_dir.join(&destination);
                     if dst.exists() {
                         fs::remove_file(&dst)
                             .map_err(|source| WorkspaceError::IoError { path: dst, source })?;
                     }
-                    applied.push((path.clone(), "deleted"));
+                    applied.push((destination, "deleted"));
+                }
+                OverlayChange::Renamed { from, to, .. } => {
+                    self.apply_rename(target_dir, from, to)?;
+                    applied.push((self.destination_path(to), "renamed"));
+                }
+                OverlayChange::ModeChanged { path, new_mode, .. } => {
+                    let destination = self.destination_path(path);
+                    let dst = target_dir.join(&destination);
+                    set_file_mode(&dst, *new_mode)
+                        .map_err(|source| WorkspaceError::IoError { path: dst, source })?;
+                    applied.push((destination, "mode changed"));
                 }
             }
         }
@@ -729,15 +1366,41 @@ impl OverlayWorkspace {
         Ok(applied)
     }
 
+    /// Copy a renamed file to its new path in `target_dir` and remove the old one.
+    fn apply_rename(&self, target_dir: &Path, from: &str, to: &str) -> Result<(), WorkspaceError> {
+        let src = self.staging_dir.join(to);
+        let dst = target_dir.join(self.destination_path(to));
+        if let Some(parent) = dst.parent() {
+            fs::create_dir_all(parent).map_err(|source| WorkspaceError::IoError {
+                path: parent.to_path_buf(),
+                source,
+            })?;
+        }
+        fs::copy(&src, &dst).map_err(|source| WorkspaceError::IoError { path: dst, source })?;
+        let old = target_dir.join(self.destination_path(from));
+        if old.exists() {
+            fs::remove_file(&old)
+                .map_err(|source| WorkspaceError::IoError { path: old, source })?;
+        }
+        Ok(())
+    }
+
     /// Apply only selected artifacts (by URI) to the target directory.
     ///
     /// Used for selective approval where only a subset of changes should be applied.
-    /// URIs should be in the form "fs://workspace/<path>".
+    /// URIs should be in the form "fs://workspace/<path>", or
+    /// "fs://<alias>/<path>" in a multi-root workspace, where each artifact is
+    /// applied to its own root and `target_dir` is not used.
     pub fn apply_selective(
         &self,
         target_dir: &Path,
         approved_uris: &[String],
     ) -> Result<Vec<(String, &'static str)>, WorkspaceError> {
+        if !self.roots.is_empty() {
+            return self.apply_per_root(approved_uris, |root, target, uris| {
+                root.apply_selective(target, uris)
+            });
+        }
         let changes = self.diff_all()?;
         let mut applied = Vec::new();
 
@@ -749,11 +1412,8 @@ impl OverlayWorkspace {
             .collect();
 
         for change in &changes {
-            let path = match change {
-                OverlayChange::Modified { path, .. } => path,
-                OverlayChange::Created { path, .. } => path,
-                OverlayChange::Deleted { path } => path,
-            };
+            // Renames are approved by their new path.
+            let path = change.path();
 
             // Skip if not in approved set.
             if !approved_paths.contains(path) {
@@ -763,7 +1423,8 @@ impl OverlayWorkspace {
             match change {
                 OverlayChange::Modified { path, .. } | OverlayChange::Created { path, .. } => {
                     let src = self.staging_dir.join(path);
-                    let dst = target_dir.join(path);
+                    let destination = self.destination_path(path);
+                    let dst = target_dir.join(&destination);
                     if let Some(parent) = dst.parent() {
                         fs::create_dir_all(parent).map_err(|source| WorkspaceError::IoError {
                             path: parent.to_path_buf(),
@@ -777,15 +1438,27 @@ impl OverlayWorkspace {
                     } else {
                         "created"
                     };
-                    applied.push((path.clone(), kind));
+                    applied.push((destination, kind));
                 }
                 OverlayChange::Deleted { path } => {
-                    let dst = target_dir.join(path);
+                    let destination = self.destination_path(path);
+                    let dst = target_dir.join(&destination);
                     if dst.exists() {
                         fs::remove_file(&dst)
                             .map_err(|source| WorkspaceError::IoError { path: dst, source })?;
                     }
-                    applied.push((path.clone(), "deleted"));
+                    applied.push((destination, "deleted"));
+                }
+                OverlayChange::Renamed { from, to, .. } => {
+                    self.apply_rename(target_dir, from, to)?;
+                    applied.push((self.destination_path(to), "renamed"));
+                }
+                OverlayChange::ModeChanged { path, new_mode, .. } => {
+                    let destination = self.destination_path(path);
+                    let dst = target_dir.join(&destination);
+                    set_file_mode(&dst, *new_mode)
+                        .map_err(|source| WorkspaceError::IoError { path: dst, source })?;
+                    applied.push((destination, "mode changed"));
                 }
             }
         }
@@ -799,24 +1472,38 @@ impl OverlayWorkspace {
     /// (from the PR package's artifact list). Only these files are applied and only conflicts
     /// overlapping with these files trigger abort/force. This prevents stale staging copies
     /// of unrelated files from overwriting newer source changes.
+    ///
+    /// In a multi-root workspace each `fs://<alias>/<path>` artifact is applied
+    /// to the root its alias names and `target_dir` is not used. Under
+    /// `Abort`, every root is checked before any root is written.
     pub fn apply_with_conflict_check(
         &self,
         target_dir: &Path,
         resolution: ConflictResolution,
         artifact_uris: &[String],
     ) -> Result<Vec<(String, &'static str)>, WorkspaceError> {
-        // Convert URIs to relative paths for comparison.
-        let artifact_paths: std::collections::HashSet<String> = artifact_uris
-            .iter()
-            .filter_map(|uri| uri.strip_prefix("fs://workspace/"))
-            .map(|s| s.to_string())
-            .collect();
-
+        if !self.roots.is_empty() {
+            if matches!(
+                resolution,
+                ConflictResolution::Abort | ConflictResolution::Interactive
+            ) {
+                let conflicts = self.true_conflicts(artifact_uris)?;
+                if !conflicts.is_empty() {
+                    return Err(WorkspaceError::ConflictDetected {
+                        conflicts: conflicts.into_iter().map(|c| c.description).collect(),
+                    });
+                }
+            }
+            return self.apply_per_root(artifact_uris, |root, target, uris| {
+                root.apply_with_conflict_check(target, resolution, uris)
+            });
+        }
         let mut filtered_uris = artifact_uris.to_vec();
 
         // Check for conflicts if snapshot exists.
         if let Some(all_conflicts) = self.detect_conflicts()? {
             if !all_conflicts.is_empty() {
+                let artifact_paths = self.conflict_paths(artifact_uris)?;
                 // Only flag conflicts that overlap with the artifact list.
                 let overlapping: Vec<_> = all_conflicts
                     .iter()
@@ -858,7 +1545,7 @@ impl OverlayWorkspace {
 
                     if !true_conflicts.is_empty() {
                         match resolution {
-                            ConflictResolution::Abort => {
+                            ConflictResolution::Abort | ConflictResolution::Interactive => {
                                 return Err(WorkspaceError::ConflictDetected {
                                     conflicts: true_conflicts,
                                 });
@@ -870,7 +1557,8 @@ impl OverlayWorkspace {
                                 );
                             }
                             ConflictResolution::Merge => {
-                                // v0.14.3.5: Attempt three-way merge via `git merge-file`.
+                                // v0.14.3.5: Attempt three-way merge (disjoint line edits merge
+                                // directly; overlapping ones go through `git merge-file`).
                                 // base = snapshot (goal-start content)
                                 // ours = staging (agent's version)
                                 // theirs = current source (external changes)
@@ -942,11 +1630,70 @@ impl OverlayWorkspace {
         self.apply_selective(target_dir, &filtered_uris)
     }
 
-    /// Classify overlapping conflicts into true conflicts (agent changed the file)
-    /// vs phantom artifacts (agent didn't touch it, only source diverged).
+    /// Conflicts that `apply_with_conflict_check` would refuse to apply for
+    /// `artifact_uris`: the source changed since goal start *and* the agent
+    /// changed the same file. Phantom artifacts are left out, as on apply.
     ///
-    /// Compares the staging file hash to the snapshot hash. If they match,
-    /// the agent never modified the file — it's safe to auto-resolve.
+    /// Returns an empty list when no snapshot was captured.
+    pub fn true_conflicts(
+        &self,
+        artifact_uris: &[String],
+    ) -> Result<Vec<Conflict>, WorkspaceError> {
+        if !self.roots.is_empty() {
+            let mut conflicts = Vec::new();
+            for (alias, root) in self.root_workspaces() {
+                let prefix = format!("fs://{}/", alias);
+                let uris: Vec<String> = artifact_uris
+                    .iter()
+                    .filter_map(|uri| uri.strip_prefix(&prefix))
+                    .map(|path| format!("fs://workspace/{}", path))
+                    .collect();
+                conflicts.extend(root.true_conflicts(&uris)?.into_iter().map(|mut c| {
+                    c.path = format!("{}/{}", alias, c.path);
+                    c
+                }));
+            }
+            return Ok(conflicts);
+        }
+        let conflicts = self.detect_conflicts()?.unwrap_or_default();
+        if conflicts.is_empty() {
+            return Ok(conflicts);
+        }
+        let artifact_paths = self.conflict_paths(artifact_uris)?;
+        Ok(conflicts
+            .into_iter()
+            .filter(|c| artifact_paths.contains(&c.path) && self.agent_changed(c))
+            .collect())
+    }
+
+    /// Source paths an apply of `artifact_uris` writes or removes, for
+    /// intersecting with source-side conflicts.
+    ///
+    /// Includes the old path of every rename (approved by its new path),
+    /// since applying the rename deletes it. Relocated changes don't land on
+    /// their source path, so they are left out.
+    fn conflict_paths(
+        &self,
+        artifact_uris: &[String],
+    ) -> Result<std::collections::HashSet<String>, WorkspaceError> {
+        let mut paths: std::collections::HashSet<String> = artifact_uris
+            .iter()
+            .filter_map(|uri| uri.strip_prefix("fs://workspace/"))
+            .map(str::to_string)
+            .collect();
+        for change in self.diff_all()? {
+            if let OverlayChange::Renamed { from, to, .. } = change {
+                if paths.contains(&to) {
+                    paths.insert(from);
+                }
+            }
+        }
+        paths.retain(|path| self.destination_path(path) == *path);
+        Ok(paths)
+    }
+
+    /// Classify overlapping conflicts into true conflicts (agent changed the file)
+    /// vs phantom artifacts (agent didn't touch it, only source diverged).
     fn classify_overlapping_conflicts(
         &self,
         overlapping: &[&Conflict],
@@ -955,23 +1702,7 @@ impl OverlayWorkspace {
         let mut auto_resolved = Vec::new();
 
         for conflict in overlapping {
-            let staging_path = self.staging_dir.join(&conflict.path);
-            let agent_changed = if staging_path.exists() {
-                // Compare staging file hash to snapshot hash at goal start.
-                match FileSnapshot::capture(&self.staging_dir, &conflict.path) {
-                    Ok(staging_snap) => staging_snap.content_hash != conflict.snapshot.content_hash,
-                    Err(_) => true, // Can't read staging file — assume agent changed it (safe)
-                }
-            } else if conflict.snapshot.content_hash.is_empty() {
-                // File didn't exist at snapshot time and doesn't exist in staging.
-                // This is a new-in-source file the agent never saw. Auto-resolve.
-                false
-            } else {
-                // File existed at snapshot time but agent deleted it. Real change.
-                true
-            };
-
-            if agent_changed {
+            if self.agent_changed(conflict) {
                 true_conflicts.push(conflict.description.clone());
             } else {
                 auto_resolved.push(conflict.path.clone());
@@ -981,6 +1712,28 @@ impl OverlayWorkspace {
         (true_conflicts, auto_resolved)
     }
 
+    /// Whether the agent changed a conflicting file in staging.
+    ///
+    /// Compares the staging file hash to the snapshot hash. If they match,
+    /// the agent never modified the file — it's safe to auto-resolve.
+    fn agent_changed(&self, conflict: &Conflict) -> bool {
+        let staging_path = self.staging_dir.join(&conflict.path);
+        if staging_path.exists() {
+            // Compare staging file hash to snapshot hash at goal start.
+            match FileSnapshot::capture(&self.staging_dir, &conflict.path) {
+                Ok(staging_snap) => staging_snap.content_hash != conflict.snapshot.content_hash,
+                Err(_) => true, // Can't read staging file — assume agent changed it (safe)
+            }
+        } else if conflict.snapshot.content_hash.is_empty() {
+            // File didn't exist at snapshot time and doesn't exist in staging.
+            // This is a new-in-source file the agent never saw. Auto-resolve.
+            false
+        } else {
+            // File existed at snapshot time but agent deleted it. Real change.
+            true
+        }
+    }
+
     /// Clean up the staging directory.
     pub fn cleanup(self) -> Result<(), WorkspaceError> {
         if self.staging_dir.exists() {
@@ -1087,7 +1840,23 @@ pub fn three_way_merge(
         return Err("Binary file — skipping three-way merge".into());
     }
 
-    // Route through MergeTool abstraction (Diff3MergeTool wraps git merge-file).
+    // Only edits that touch overlapping line ranges of the base block the
+    // merge. Disjoint edits (e.g. an upstream `cargo fmt` elsewhere in the
+    // file, even on the neighbouring line) are combined directly.
+    if let (Ok(base), Ok(ours), Ok(theirs)) = (
+        std::str::from_utf8(&base_bytes),
+        std::str::from_utf8(&ours_bytes),
+        std::str::from_utf8(&theirs_bytes),
+    ) {
+        if let Some((merged, hunks)) = crate::merge_tool::merge_disjoint_lines(base, ours, theirs) {
+            return Ok(MergeResult::Clean {
+                content: merged.into_bytes(),
+                hunks,
+            });
+        }
+    }
+
+    // Overlapping edits: route through MergeTool abstraction (Diff3MergeTool wraps git merge-file).
     let tool = crate::merge_tool::Diff3MergeTool;
     let result = tool.merge(&base_bytes, &ours_bytes, &theirs_bytes)?;
 
@@ -1197,6 +1966,35 @@ pub fn fetch_from_git_head(source_dir: &std::path::Path, relative_path: &str) ->
     Some(content)
 }
 
+/// Rewrite `path` with the first `(from, to)` prefix that matches it at a
+/// path-component boundary; unmatched paths are returned unchanged.
+pub fn remap_destination(remaps: &[(String, String)], path: &str) -> String {
+    for (from, to) in remaps {
+        if path == from {
+            return to.clone();
+        }
+        if let Some(rest) = path
+            .strip_prefix(from.as_str())
+            .and_then(|rest| rest.strip_prefix('/'))
+        {
+            return format!("{}/{}", to, rest);
+        }
+    }
+    path.to_string()
+}
+
+/// Put files back as they were before a failed apply: rewrite the ones that
+/// existed and remove the ones that didn't. Best-effort, latest first.
+fn restore_files(backups: &[(PathBuf, Option<Vec<u8>>)]) {
+    for (path, previous) in backups.iter().rev() {
+        let _ = match previous {
+            Some(bytes) => fs::write(path, bytes),
+            None if path.exists() => fs::remove_file(path),
+            None => Ok(()),
+        };
+    }
+}
+
 /// Return the current git HEAD SHA for `project_root`, or `None` if git is
 /// unavailable or `project_root` is not inside a repository.
 pub fn get_git_head_sha(project_root: &std::path::Path) -> Option<String> {
@@ -1214,6 +2012,74 @@ pub fn get_git_head_sha(project_root: &std::path::Path) -> Option<String> {
     }
 }
 
+/// Check out the tree of `git_ref` for the repository containing
+/// `source_dir` into `dest`, without touching the repository's index or
+/// working tree.
+///
+/// Returns the directory inside `dest` that corresponds to `source_dir`
+/// (the repository root when `source_dir` is the root) and the resolved
+/// commit SHA. `dest` is created if missing; callers own its cleanup.
+pub fn materialize_git_ref(
+    source_dir: &Path,
+    git_ref: &str,
+    dest: &Path,
+) -> Result<(PathBuf, String), WorkspaceError> {
+    let git = |args: &[&str], index: Option<&Path>| -> Result<String, WorkspaceError> {
+        let mut cmd = std::process::Command::new("git");
+        cmd.args(args)
+            .current_dir(source_dir)
+            .env_remove("GIT_DIR")
+            .env_remove("GIT_WORK_TREE");
+        match index {
+            Some(index) => cmd.env("GIT_INDEX_FILE", index),
+            None => cmd.env_remove("GIT_INDEX_FILE"),
+        };
+        let out = cmd
+            .output()
+            .map_err(|e| WorkspaceError::GitError(format!("failed to run git: {}", e)))?;
+        if !out.status.success() {
+            return Err(WorkspaceError::GitError(format!(
+                "git {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&out.stderr).trim()
+            )));
+        }
+        Ok(String::from_utf8_lossy(&out.stdout).trim().to_string())
+    };
+
+    let toplevel = PathBuf::from(git(&["rev-parse", "--show-toplevel"], None)?);
+    let sha = git(
+        &["rev-parse", "--verify", &format!("{}^{{commit}}", git_ref)],
+        None,
+    )?;
+
+    fs::create_dir_all(dest).map_err(|source| WorkspaceError::IoError {
+        path: dest.to_path_buf(),
+        source,
+    })?;
+    let tree_dir = dest.join("tree");
+    let index = dest.join("base.index");
+    git(&["read-tree", &sha], Some(&index))?;
+    let prefix = format!("{}/", tree_dir.display());
+    git(
+        &["checkout-index", "-a", "-f", "--prefix", &prefix],
+        Some(&index),
+    )?;
+    let _ = fs::remove_file(&index);
+    // checkout-index creates nothing for an empty tree.
+    fs::create_dir_all(&tree_dir).map_err(|source| WorkspaceError::IoError {
+        path: tree_dir.clone(),
+        source,
+    })?;
+
+    let canonical = |p: &Path| p.canonicalize().unwrap_or_else(|_| p.to_path_buf());
+    let subdir = canonical(source_dir)
+        .strip_prefix(canonical(&toplevel))
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    Ok((tree_dir.join(subdir), sha))
+}
+
 // ── Staging mode resolution ─────────────────────────────────────
 
 /// Resolve the effective staging mode at workspace creation time.
@@ -1282,6 +2148,39 @@ fn is_refs_volume(path: &Path) -> bool {
 
 // ── Directory copy ──────────────────────────────────────────────
 
+/// A file copy deferred until the directory walk has finished, so the copies
+/// themselves can run in parallel.
+struct CopyJob {
+    src: PathBuf,
+    dst: PathBuf,
+    size: u64,
+}
+
+/// Run the planned copies, on the rayon pool when `parallel` is set.
+fn run_copy_jobs(
+    jobs: &[CopyJob],
+    strategy: CopyStrategy,
+    stat: &mut CopyStat,
+    parallel: bool,
+) -> Result<(), WorkspaceError> {
+    let copy = |job: &CopyJob| {
+        copy_file_with_strategy(&job.src, &job.dst, strategy)
+            .map(|_| ())
+            .map_err(|source| WorkspaceError::IoError {
+                path: job.dst.clone(),
+                source,
+            })
+    };
+    if parallel {
+        jobs.par_iter().try_for_each(copy)?;
+    } else {
+        jobs.iter().try_for_each(copy)?;
+    }
+    stat.files_copied += jobs.len();
+    stat.bytes_total += jobs.iter().map(|job| job.size).sum::<u64>();
+    Ok(())
+}
+
 /// Recursively copy a directory using the specified strategy.
 ///
 /// Excludes `.ta/` (always, via [`ExcludePatterns`]) and any other configured
@@ -1292,6 +2191,19 @@ fn copy_dir_recursive(
     excludes: &ExcludePatterns,
     strategy: CopyStrategy,
     stat: &mut CopyStat,
+    parallel: bool,
+) -> Result<(), WorkspaceError> {
+    let mut jobs = Vec::new();
+    plan_dir_copy(src, dst, excludes, &mut jobs)?;
+    run_copy_jobs(&jobs, strategy, stat, parallel)
+}
+
+/// Create the destination directory tree and collect the file copies needed.
+fn plan_dir_copy(
+    src: &Path,
+    dst: &Path,
+    excludes: &ExcludePatterns,
+    jobs: &mut Vec<CopyJob>,
 ) -> Result<(), WorkspaceError> {
     let entries = fs::read_dir(src).map_err(|source| WorkspaceError::IoError {
         path: src.to_path_buf(),
@@ -1318,20 +2230,15 @@ fn copy_dir_recursive(
                 path: dst_path.clone(),
                 source,
             })?;
-            copy_dir_recursive(&src_path, &dst_path, excludes, strategy, stat)?;
+            plan_dir_copy(&src_path, &dst_path, excludes, jobs)?;
         } else {
             // Collect source file size for benchmarking before copying.
-            let file_size = entry.metadata().map(|m| m.len()).unwrap_or(0);
-
-            copy_file_with_strategy(&src_path, &dst_path, strategy).map_err(|source| {
-                WorkspaceError::IoError {
-                    path: dst_path,
-                    source,
-                }
-            })?;
-
-            stat.files_copied += 1;
-            stat.bytes_total += file_size;
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            jobs.push(CopyJob {
+                src: src_path,
+                dst: dst_path,
+                size,
+            });
         }
     }
 
@@ -1348,7 +2255,6 @@ fn copy_dir_recursive(
 /// This gives the agent a view of the full workspace with minimal disk I/O:
 /// only the agent-writable subset is physically copied; large excluded trees
 /// (e.g., `node_modules/`, Unreal `Content/`) appear as read-only symlinks.
-#[allow(clippy::only_used_in_recursion)]
 fn copy_dir_recursive_smart(
     src: &Path,
     dst: &Path,
@@ -1356,6 +2262,23 @@ fn copy_dir_recursive_smart(
     excludes: &ExcludePatterns,
     strategy: CopyStrategy,
     stat: &mut CopyStat,
+    parallel: bool,
+) -> Result<(), WorkspaceError> {
+    let mut jobs = Vec::new();
+    plan_dir_copy_smart(src, dst, source_root, excludes, stat, &mut jobs)?;
+    run_copy_jobs(&jobs, strategy, stat, parallel)
+}
+
+/// Smart-mode counterpart of [`plan_dir_copy`]: symlinks excluded entries
+/// immediately and collects the remaining file copies.
+#[allow(clippy::only_used_in_recursion)]
+fn plan_dir_copy_smart(
+    src: &Path,
+    dst: &Path,
+    source_root: &Path,
+    excludes: &ExcludePatterns,
+    stat: &mut CopyStat,
+    jobs: &mut Vec<CopyJob>,
 ) -> Result<(), WorkspaceError> {
     let entries = fs::read_dir(src).map_err(|source| WorkspaceError::IoError {
         path: src.to_path_buf(),
@@ -1400,7 +2323,7 @@ fn copy_dir_recursive_smart(
                 path: dst_path.clone(),
                 source,
             })?;
-            copy_dir_recursive_smart(&src_path, &dst_path, source_root, excludes, strategy, stat)?;
+            plan_dir_copy_smart(&src_path, &dst_path, source_root, excludes, stat, jobs)?;
         } else {
             // Files: check user excludes (glob patterns like "*.pyc").
             if excludes.should_exclude(&name) {
@@ -1411,15 +2334,12 @@ fn copy_dir_recursive_smart(
                 stat.bytes_symlinked += file_size;
                 continue;
             }
-            let file_size = entry.metadata().map(|m| m.len()).unwrap_or(0);
-            copy_file_with_strategy(&src_path, &dst_path, strategy).map_err(|source| {
-                WorkspaceError::IoError {
-                    path: dst_path,
-                    source,
-                }
-            })?;
-            stat.files_copied += 1;
-            stat.bytes_total += file_size;
+            let size = entry.metadata().map(|m| m.len()).unwrap_or(0);
+            jobs.push(CopyJob {
+                src: src_path,
+                dst: dst_path,
+                size,
+            });
         }
     }
 
@@ -1452,6 +2372,7 @@ fn create_symlink_dir(src: &Path, dst: &Path) -> Result<(), WorkspaceError> {
             &ExcludePatterns::none(),
             CopyStrategy::Full,
             &mut CopyStat::new(CopyStrategy::Full),
+            false,
         )
     }
 }
@@ -1510,6 +2431,146 @@ fn estimate_dir_bytes(path: &Path, depth: u8) -> u64 {
 
 // ── Directory walking ───────────────────────────────────────────
 
+/// Whether file content can be shown as a text diff: no NUL byte in the
+/// first 8KB and valid UTF-8 throughout. Anything else (images, latin-1
+/// configs, ...) is summarized instead of diffed.
+pub fn is_text_content(bytes: &[u8]) -> bool {
+    !bytes.get(..8192).unwrap_or(bytes).contains(&0) && std::str::from_utf8(bytes).is_ok()
+}
+
+/// Unified diff of a modified file, or a size summary when either side is
+/// not text.
+fn modified_file_diff(
+    path: &str,
+    source: &[u8],
+    staging: &[u8],
+    algorithm: DiffAlgorithm,
+) -> String {
+    match (std::str::from_utf8(source), std::str::from_utf8(staging)) {
+        (Ok(old), Ok(new)) if is_text_content(source) && is_text_content(staging) => {
+            unified_diff_with(path, old, new, DEFAULT_DIFF_CONTEXT, algorithm)
+        }
+        _ => {
+            tracing::info!(path, "treating non-text file as binary in overlay diff");
+            format!(
+                "--- a/{}\n+++ b/{}\n[binary file changed: {} -> {} bytes]\n",
+                path,
+                path,
+                source.len(),
+                staging.len()
+            )
+        }
+    }
+}
+
+/// Compare one staged file against its source counterpart.
+///
+/// Returns `Modified` or `Created`, or `None` when the contents are identical.
+fn diff_staged_file(
+    source_dir: &Path,
+    staging_dir: &Path,
+    path: &str,
+    algorithm: DiffAlgorithm,
+) -> Result<Option<OverlayChange>, WorkspaceError> {
+    let staging_path = staging_dir.join(path);
+    let source_path = source_dir.join(path);
+
+    if source_path.exists() {
+        // File exists in both — check if modified.
+        let staging_content =
+            fs::read(&staging_path).map_err(|source| WorkspaceError::IoError {
+                path: staging_path.clone(),
+                source,
+            })?;
+        let source_content = fs::read(&source_path).map_err(|source| WorkspaceError::IoError {
+            path: source_path.clone(),
+            source,
+        })?;
+
+        if staging_content == source_content {
+            // Same bytes — only a flipped executable bit is still a change.
+            return Ok(match (file_mode(&source_path), file_mode(&staging_path)) {
+                (Some(old_mode), Some(new_mode)) if old_mode != new_mode => {
+                    Some(OverlayChange::ModeChanged {
+                        path: path.to_string(),
+                        old_mode,
+                        new_mode,
+                    })
+                }
+                _ => None,
+            });
+        }
+        Ok(Some(OverlayChange::Modified {
+            path: path.to_string(),
+            diff: modified_file_diff(path, &source_content, &staging_content, algorithm),
+        }))
+    } else {
+        // File only in staging — created.
+        // Non-text files (binary or not UTF-8) get a placeholder instead of
+        // a lossy UTF-8 conversion.
+        let raw = fs::read(&staging_path).map_err(|source| WorkspaceError::IoError {
+            path: staging_path.clone(),
+            source,
+        })?;
+        let content = match String::from_utf8(raw) {
+            Ok(text) if !text.as_bytes()[..text.len().min(8192)].contains(&0) => text,
+            other => {
+                let len = other.map_or_else(|e| e.into_bytes().len(), |text| text.len());
+                tracing::info!(path, "treating non-text file as binary in overlay diff");
+                format!("[binary file: {} bytes]", len)
+            }
+        };
+        Ok(Some(OverlayChange::Created {
+            path: path.to_string(),
+            content,
+        }))
+    }
+}
+
+/// Git-style mode of a regular file: `0o100755` when any execute bit is
+/// set, otherwise `0o100644`. `None` on non-Unix platforms or when the file
+/// can't be read.
+pub fn file_mode(path: &Path) -> Option<u32> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(path).ok()?.permissions().mode();
+        Some(if mode & 0o111 != 0 {
+            0o100755
+        } else {
+            0o100644
+        })
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = path;
+        None
+    }
+}
+
+/// Set or clear the execute bits of `path` to match the git-style `mode`,
+/// granting execute to whoever can read the file. No-op on non-Unix.
+pub fn set_file_mode(path: &Path, mode: u32) -> std::io::Result<()> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mut permissions = fs::metadata(path)?.permissions();
+        let current = permissions.mode();
+        let updated = if mode & 0o111 != 0 {
+            current | ((current & 0o444) >> 2)
+        } else {
+            current & !0o111
+        };
+        permissions.set_mode(updated);
+        fs::set_permissions(path, permissions)
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = (path, mode);
+        Ok(())
+    }
+}
+
 /// Walk a directory tree and collect relative file paths.
 ///
 /// Directories that should be excluded (per `excludes`) are pruned before
@@ -1652,6 +2713,128 @@ fn should_skip_for_diff(path: &str, excludes: &ExcludePatterns) -> bool {
 
 // ── Diff utilities ──────────────────────────────────────────────
 
+/// Lines of unchanged context around each hunk in stored overlay diffs.
+pub const DEFAULT_DIFF_CONTEXT: usize = 3;
+
+/// Line diff algorithm for generated diffs (`[diff] algorithm`).
+///
+/// Every algorithm produces a correct diff; they differ in how hunks are
+/// grouped. Patience anchors on lines that occur once in both versions, so
+/// moved blocks and reordered functions read as whole-block moves instead of
+/// interleaved `+`/`-` lines. Histogram extends patience to anchor on the
+/// least frequent lines when none are unique.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DiffAlgorithm {
+    /// Myers minimal diff — git's default.
+    #[default]
+    Myers,
+    /// Patience diff.
+    Patience,
+    /// Histogram diff (`git diff --histogram`), computed with `imara-diff`.
+    Histogram,
+}
+
+/// Generate a hunk-based unified diff with `context` unchanged lines around
+/// each change (`git diff -U<context>`).
+pub fn unified_diff(path: &str, original: &str, modified: &str, context: usize) -> String {
+    unified_diff_with(path, original, modified, context, DiffAlgorithm::Myers)
+}
+
+/// [`unified_diff`] with a chosen line diff algorithm.
+pub fn unified_diff_with(
+    path: &str,
+    original: &str,
+    modified: &str,
+    context: usize,
+    algorithm: DiffAlgorithm,
+) -> String {
+    let algorithm = match algorithm {
+        DiffAlgorithm::Myers => similar::Algorithm::Myers,
+        DiffAlgorithm::Patience => similar::Algorithm::Patience,
+        DiffAlgorithm::Histogram => {
+            return histogram_unified_diff(path, original, modified, context)
+        }
+    };
+    similar::TextDiff::configure()
+        .algorithm(algorithm)
+        .diff_lines(original, modified)
+        .unified_diff()
+        .context_radius(context)
+        .header(&format!("a/{}", path), &format!("b/{}", path))
+        .to_string()
+}
+
+/// Histogram diff from `imara-diff`, rendered through `similar`'s unified
+/// diff hunks so the output (headers, `\ No newline at end of file`) matches
+/// the other algorithms.
+fn histogram_unified_diff(path: &str, original: &str, modified: &str, context: usize) -> String {
+    use similar::DiffOp;
+
+    // Only the line split of this diff is used; its ops are replaced by the
+    // histogram ones, so a zero timeout keeps it from running Myers in full.
+    let lines = similar::TextDiff::configure()
+        .timeout(std::time::Duration::ZERO)
+        .diff_lines(original, modified);
+    let mut input = imara_diff::InternedInput::default();
+    input.update_before(lines.old_slices().iter().copied());
+    input.update_after(lines.new_slices().iter().copied());
+    let mut diff = imara_diff::Diff::compute(imara_diff::Algorithm::Histogram, &input);
+    diff.postprocess_lines(&input);
+
+    let mut ops = Vec::new();
+    let (mut old, mut new) = (0, 0);
+    for hunk in diff.hunks() {
+        let before = hunk.before.start as usize..hunk.before.end as usize;
+        let after = hunk.after.start as usize..hunk.after.end as usize;
+        if before.start > old {
+            ops.push(DiffOp::Equal {
+                old_index: old,
+                new_index: new,
+                len: before.start - old,
+            });
+        }
+        ops.push(match (before.is_empty(), after.is_empty()) {
+            (false, true) => DiffOp::Delete {
+                old_index: before.start,
+                old_len: before.len(),
+                new_index: after.start,
+            },
+            (true, false) => DiffOp::Insert {
+                old_index: before.start,
+                new_index: after.start,
+                new_len: after.len(),
+            },
+            _ => DiffOp::Replace {
+                old_index: before.start,
+                old_len: before.len(),
+                new_index: after.start,
+                new_len: after.len(),
+            },
+        });
+        (old, new) = (before.end, after.end);
+    }
+    let old_len = lines.old_slices().len();
+    if old < old_len {
+        ops.push(DiffOp::Equal {
+            old_index: old,
+            new_index: new,
+            len: old_len - old,
+        });
+    }
+
+    let mut out = String::new();
+    for group in similar::group_diff_ops(ops, context) {
+        if group.is_empty() {
+            continue;
+        }
+        if out.is_empty() {
+            out.push_str(&format!("--- a/{}\n+++ b/{}\n", path, path));
+        }
+        out.push_str(&similar::udiff::UnifiedDiffHunk::new(group, &lines, true).to_string());
+    }
+    out
+}
+
 /// Generate a simple unified diff between two strings.
 pub fn simple_unified_diff(path: &str, original: &str, modified: &str) -> String {
     let mut output = String::new();
@@ -1678,6 +2861,95 @@ pub fn simple_unified_diff(path: &str, original: &str, modified: &str) -> String
     output
 }
 
+/// Lines shorter than this (trimmed) — braces, `end`, blank lines — carry no
+/// identity and are ignored when scoring similarity.
+const MIN_SIGNIFICANT_LINE_LEN: usize = 4;
+
+/// Files with fewer distinct significant lines than this only match when
+/// identical; small boilerplate files would otherwise pair up as renames.
+const MIN_DISTINCT_LINES: usize = 3;
+
+/// Trimmed lines of `text` long enough to count towards similarity.
+fn significant_lines(text: &str) -> Vec<&str> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| line.len() >= MIN_SIGNIFICANT_LINE_LEN)
+        .collect()
+}
+
+/// Similarity of two file contents in `[0.0, 1.0]`, used for rename detection.
+///
+/// Identical non-empty contents score 1.0; empty files score 0.0, since they
+/// carry nothing to match on. Text is compared as a multiset of significant
+/// lines (`2 * shared / (lines_a + lines_b)`), so reordered or lightly edited
+/// files still score high. Files with too few distinct significant lines, and
+/// differing non-text contents, score 0.0.
+pub fn content_similarity(a: &[u8], b: &[u8]) -> f64 {
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    if a == b {
+        return 1.0;
+    }
+    if !is_text_content(a) || !is_text_content(b) {
+        return 0.0;
+    }
+    let (Ok(a), Ok(b)) = (std::str::from_utf8(a), std::str::from_utf8(b)) else {
+        return 0.0;
+    };
+    let (lines_a, lines_b) = (significant_lines(a), significant_lines(b));
+    let distinct = |lines: &[&str]| lines.iter().collect::<std::collections::HashSet<_>>().len();
+    if distinct(&lines_a) < MIN_DISTINCT_LINES || distinct(&lines_b) < MIN_DISTINCT_LINES {
+        return 0.0;
+    }
+    let mut counts: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    let total_a = lines_a.len();
+    for line in lines_a {
+        *counts.entry(line).or_default() += 1;
+    }
+    let mut total_b = 0usize;
+    let mut shared = 0usize;
+    for line in lines_b {
+        total_b += 1;
+        if let Some(n) = counts.get_mut(line) {
+            if *n > 0 {
+                *n -= 1;
+                shared += 1;
+            }
+        }
+    }
+    if total_a + total_b == 0 {
+        return 0.0;
+    }
+    (2 * shared) as f64 / (total_a + total_b) as f64
+}
+
+/// Diff for a renamed file: git-style `rename from`/`rename to` header
+/// followed by the content diff between the old and new paths.
+fn renamed_file_diff(
+    from: &str,
+    to: &str,
+    source: &[u8],
+    staging: &[u8],
+    algorithm: DiffAlgorithm,
+) -> String {
+    let mut output = format!("rename from {}\nrename to {}\n", from, to);
+    if source == staging {
+        return output;
+    }
+    let body = modified_file_diff(to, source, staging, algorithm);
+    // Point the old-side header at the original path.
+    let old_header = format!("--- a/{}\n", to);
+    match body.strip_prefix(&old_header) {
+        Some(rest) => {
+            output.push_str(&format!("--- a/{}\n", from));
+            output.push_str(rest);
+        }
+        None => output.push_str(&body),
+    }
+    output
+}
+
 /// Generate a diff for a newly created file.
 pub fn new_file_diff(path: &str, content: &str) -> String {
     let mut output = String::new();
@@ -1748,7 +3020,256 @@ mod tests {
     fn create_overlay_excludes_ta_directory() {
         let source = create_source_project();
         let staging_root = TempDir::new().unwrap();
-
+
+        let overlay = OverlayWorkspace::create(
+            "goal-1",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+
+        // .ta/ should NOT be copied.
+        assert!(!overlay.staging_dir().join(".ta").exists());
+        assert!(!overlay.staging_dir().join(".ta/config.toml").exists());
+    }
+
+    #[test]
+    fn diff_detects_modified_files() {
+        let source = create_source_project();
+        let staging_root = TempDir::new().unwrap();
+
+        let overlay = OverlayWorkspace::create(
+            "goal-1",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+
+        // Modify a file in staging.
+        fs::write(
+            overlay.staging_dir().join("src/main.rs"),
+            "fn main() {\n    println!(\"hello\");\n}\n",
+        )
+        .unwrap();
+
+        let changes = overlay.diff_all().unwrap();
+        assert_eq!(changes.len(), 1);
+
+        match &changes[0] {
+            OverlayChange::Modified { path, diff } => {
+                assert_eq!(path, "src/main.rs");
+                assert!(diff.contains("-fn main() {}"));
+                assert!(diff.contains("+fn main() {"));
+            }
+            other => panic!("expected Modified, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn diff_detects_new_files() {
+        let source = create_source_project();
+        let staging_root = TempDir::new().unwrap();
+
+        let overlay = OverlayWorkspace::create(
+            "goal-1",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+
+        // Create a new file in staging.
+        fs::write(
+            overlay.staging_dir().join("src/new_module.rs"),
+            "pub fn new_thing() {}\n",
+        )
+        .unwrap();
+
+        let changes = overlay.diff_all().unwrap();
+        assert_eq!(changes.len(), 1);
+
+        match &changes[0] {
+            OverlayChange::Created { path, content } => {
+                assert_eq!(path, "src/new_module.rs");
+                assert!(content.contains("new_thing"));
+            }
+            other => panic!("expected Created, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn diff_detects_deleted_files() {
+        let source = create_source_project();
+        let staging_root = TempDir::new().unwrap();
+
+        let overlay = OverlayWorkspace::create(
+            "goal-1",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+
+        // Delete a file from staging.
+        fs::remove_file(overlay.staging_dir().join("src/lib.rs")).unwrap();
+
+        let changes = overlay.diff_all().unwrap();
+        assert_eq!(changes.len(), 1);
+
+        match &changes[0] {
+            OverlayChange::Deleted { path } => {
+                assert_eq!(path, "src/lib.rs");
+            }
+            other => panic!("expected Deleted, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn diff_pairs_similar_delete_and_create_as_rename() {
+        let source = create_source_project();
+        fs::write(
+            source.path().join("src/util.rs"),
+            "pub fn a() {}\npub fn b() {}\npub fn c() {}\npub fn d() {}\n",
+        )
+        .unwrap();
+        let staging_root = TempDir::new().unwrap();
+        let mut overlay = OverlayWorkspace::create(
+            "goal-1",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+
+        // Move util.rs and edit one of its four lines (similarity 0.75).
+        let staging = overlay.staging_dir().to_path_buf();
+        fs::remove_file(staging.join("src/util.rs")).unwrap();
+        fs::create_dir_all(staging.join("src/helpers")).unwrap();
+        fs::write(
+            staging.join("src/helpers/util.rs"),
+            "pub fn a() {}\npub fn b() {}\npub fn c() {}\npub fn e() {}\n",
+        )
+        .unwrap();
+        // An unrelated move is not paired with it.
+        fs::rename(staging.join("README.md"), staging.join("docs.md")).unwrap();
+
+        let changes = overlay.diff_all().unwrap();
+        assert_eq!(changes.len(), 2, "{:?}", changes);
+        match &changes[0] {
+            OverlayChange::Renamed { from, to, diff } => {
+                assert_eq!(from, "README.md");
+                assert_eq!(to, "docs.md");
+                assert_eq!(diff, "rename from README.md\nrename to docs.md\n");
+            }
+            other => panic!("expected Renamed, got {:?}", other),
+        }
+        match &changes[1] {
+            OverlayChange::Renamed { from, to, diff } => {
+                assert_eq!(from, "src/util.rs");
+                assert_eq!(to, "src/helpers/util.rs");
+                assert!(diff.contains("--- a/src/util.rs\n+++ b/src/helpers/util.rs\n"));
+                assert!(diff.contains("+pub fn e() {}"));
+            }
+            other => panic!("expected Renamed, got {:?}", other),
+        }
+
+        // Above the file's similarity, the edited move is a delete + create again.
+        overlay.set_rename_threshold(0.8);
+        let kinds = overlay.list_changes().unwrap();
+        assert!(kinds.contains(&("docs.md".to_string(), "renamed")));
+        assert!(kinds.contains(&("src/util.rs".to_string(), "deleted")));
+        assert!(kinds.contains(&("src/helpers/util.rs".to_string(), "created")));
+
+        // 0 disables rename detection entirely.
+        overlay.set_rename_threshold(0.0);
+        let changes = overlay.diff_all().unwrap();
+        assert_eq!(changes.len(), 4);
+        assert!(!changes
+            .iter()
+            .any(|c| matches!(c, OverlayChange::Renamed { .. })));
+    }
+
+    #[test]
+    fn drop_noop_changes_trims_only_whitespace_only_edits() {
+        let source = create_source_project();
+        fs::write(source.path().join("empty.txt"), "").unwrap();
+        fs::write(source.path().join("crlf.txt"), "one\ntwo\n").unwrap();
+        fs::write(source.path().join("ws.txt"), "one\ntwo\n").unwrap();
+        let staging_root = TempDir::new().unwrap();
+        let overlay = OverlayWorkspace::create(
+            "goal-1",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+
+        let staging = overlay.staging_dir().to_path_buf();
+        fs::remove_file(staging.join("empty.txt")).unwrap();
+        fs::write(staging.join("new-empty.txt"), "").unwrap();
+        fs::write(staging.join("crlf.txt"), "one\r\ntwo\r\n").unwrap();
+        fs::write(staging.join("ws.txt"), "one  \ntwo\n\n").unwrap();
+        fs::write(staging.join("README.md"), "# Changed\n").unwrap();
+
+        let changes = overlay.diff_all().unwrap();
+        assert_eq!(changes.len(), 5, "{:?}", changes);
+
+        // Default: nothing is a no-op; empty creates and deletes are real.
+        let (kept, dropped) = overlay
+            .drop_noop_changes(changes.clone(), NoopNormalization::default())
+            .unwrap();
+        assert!(dropped.is_empty(), "{:?}", dropped);
+        assert_eq!(kept.len(), 5);
+
+        let (kept, dropped) = overlay
+            .drop_noop_changes(
+                changes,
+                NoopNormalization {
+                    ignore_line_endings: true,
+                    ignore_trailing_whitespace: true,
+                },
+            )
+            .unwrap();
+        assert_eq!(dropped, vec!["crlf.txt", "ws.txt"]);
+        let mut kept: Vec<&str> = kept.iter().map(|c| c.path()).collect();
+        kept.sort();
+        assert_eq!(kept, vec!["README.md", "empty.txt", "new-empty.txt"]);
+    }
+
+    #[test]
+    fn apply_rename_writes_new_path_and_removes_old() {
+        let source = create_source_project();
+        let staging_root = TempDir::new().unwrap();
+        let overlay = OverlayWorkspace::create(
+            "goal-1",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+        let staging = overlay.staging_dir().to_path_buf();
+        fs::rename(staging.join("src/lib.rs"), staging.join("src/hello.rs")).unwrap();
+
+        let applied = overlay
+            .apply_selective(source.path(), &["fs://workspace/src/hello.rs".to_string()])
+            .unwrap();
+        assert_eq!(applied, vec![("src/hello.rs".to_string(), "renamed")]);
+        assert!(!source.path().join("src/lib.rs").exists());
+        assert_eq!(
+            fs::read_to_string(source.path().join("src/hello.rs")).unwrap(),
+            "pub fn hello() {}\n"
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn executable_bit_change_is_diffed_and_applied() {
+        use std::os::unix::fs::PermissionsExt;
+        let source = create_source_project();
+        fs::write(source.path().join("run.sh"), "#!/bin/sh\necho hi\n").unwrap();
+        let staging_root = TempDir::new().unwrap();
         let overlay = OverlayWorkspace::create(
             "goal-1",
             source.path(),
@@ -1756,48 +3277,185 @@ mod tests {
             ExcludePatterns::none(),
         )
         .unwrap();
+        let staged = overlay.staging_dir().join("run.sh");
+        fs::set_permissions(&staged, fs::Permissions::from_mode(0o755)).unwrap();
 
-        // .ta/ should NOT be copied.
-        assert!(!overlay.staging_dir().join(".ta").exists());
-        assert!(!overlay.staging_dir().join(".ta/config.toml").exists());
+        let changes = overlay.diff_all().unwrap();
+        assert_eq!(
+            changes,
+            vec![OverlayChange::ModeChanged {
+                path: "run.sh".to_string(),
+                old_mode: 0o100644,
+                new_mode: 0o100755,
+            }]
+        );
+        assert_eq!(overlay.mode_change("run.sh"), Some((0o100644, 0o100755)));
+        let (kept, dropped) = overlay
+            .drop_noop_changes(changes, NoopNormalization::default())
+            .unwrap();
+        assert_eq!(kept.len(), 1);
+        assert!(dropped.is_empty());
+
+        let applied = overlay
+            .apply_selective(source.path(), &["fs://workspace/run.sh".to_string()])
+            .unwrap();
+        assert_eq!(applied, vec![("run.sh".to_string(), "mode changed")]);
+        let mode = fs::metadata(source.path().join("run.sh"))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o111, 0o111 & (mode & 0o444) >> 2);
+        assert_ne!(mode & 0o100, 0);
+        assert!(overlay.diff_all().unwrap().is_empty());
     }
 
     #[test]
-    fn diff_detects_modified_files() {
-        let source = create_source_project();
+    fn small_boilerplate_files_are_not_paired_as_renames() {
+        let source = TempDir::new().unwrap();
+        fs::create_dir_all(source.path().join("a")).unwrap();
+        fs::write(source.path().join("a/mod.rs"), "pub mod x;\n}\n").unwrap();
         let staging_root = TempDir::new().unwrap();
-
         let overlay = OverlayWorkspace::create(
-            "goal-1",
+            "goal-boilerplate",
             source.path(),
             staging_root.path(),
             ExcludePatterns::none(),
         )
         .unwrap();
-
-        // Modify a file in staging.
-        fs::write(
-            overlay.staging_dir().join("src/main.rs"),
-            "fn main() {\n    println!(\"hello\");\n}\n",
-        )
-        .unwrap();
+        let staging = overlay.staging_dir().to_path_buf();
+        fs::remove_file(staging.join("a/mod.rs")).unwrap();
+        fs::create_dir_all(staging.join("b")).unwrap();
+        fs::write(staging.join("b/mod.rs"), "pub mod y;\n}\n").unwrap();
 
         let changes = overlay.diff_all().unwrap();
-        assert_eq!(changes.len(), 1);
+        assert_eq!(changes.len(), 2, "{:?}", changes);
+        assert!(!changes
+            .iter()
+            .any(|c| matches!(c, OverlayChange::Renamed { .. })));
+    }
 
-        match &changes[0] {
-            OverlayChange::Modified { path, diff } => {
-                assert_eq!(path, "src/main.rs");
-                assert!(diff.contains("-fn main() {}"));
-                assert!(diff.contains("+fn main() {"));
+    #[test]
+    fn content_similarity_scores_shared_lines() {
+        let abcd = b"alpha\nbravo\ncharlie\ndelta\n";
+        assert_eq!(content_similarity(abcd, abcd), 1.0);
+        assert_eq!(
+            content_similarity(abcd, b"delta\ncharlie\nbravo\nalpha\n"),
+            1.0
+        );
+        assert_eq!(
+            content_similarity(abcd, b"alpha\nbravo\nxray\nyankee\n"),
+            0.5
+        );
+        assert_eq!(content_similarity(b"a\n", b"x\n"), 0.0);
+        assert_eq!(content_similarity(b"\0\x01", b"\0\x02"), 0.0);
+        assert_eq!(content_similarity(b"\0\x01", b"\0\x01"), 1.0);
+        assert_eq!(content_similarity(b"", b""), 0.0);
+        // Braces and blank lines don't count towards similarity.
+        assert_eq!(
+            content_similarity(b"alpha\n}\n\nbravo\ncharlie\n", abcd),
+            content_similarity(b"alpha\nbravo\ncharlie\n", abcd)
+        );
+    }
+
+    #[test]
+    fn unified_diff_honors_context_radius() {
+        let original: String = (1..=20).map(|n| format!("line {}\n", n)).collect();
+        let modified = original.replace("line 10\n", "line ten\n");
+
+        let default = unified_diff("f.txt", &original, &modified, DEFAULT_DIFF_CONTEXT);
+        assert!(default.starts_with("--- a/f.txt\n+++ b/f.txt\n"));
+        assert!(default.contains("@@ -7,7 +7,7 @@"), "{}", default);
+        assert!(default.contains("-line 10\n+line ten\n"));
+        assert!(!default.contains(" line 6\n"));
+
+        let narrow = unified_diff("f.txt", &original, &modified, 0);
+        assert!(narrow.contains("@@ -10 +10 @@"), "{}", narrow);
+        assert!(!narrow.contains(" line 9\n"));
+
+        let wide = unified_diff("f.txt", &original, &modified, 10);
+        assert!(wide.contains(" line 1\n") && wide.contains(" line 20\n"));
+    }
+
+    /// Apply a unified diff produced by [`unified_diff_with`] to `original`,
+    /// checking every context and removed line against it.
+    fn apply_unified(original: &str, diff: &str) -> String {
+        let old: Vec<&str> = original.split_inclusive('\n').collect();
+        let mut out = String::new();
+        let mut pos = 0;
+        for line in diff.split_inclusive('\n').skip(2) {
+            if let Some(header) = line.strip_prefix("@@ -") {
+                let start: usize = header.split([',', ' ']).next().unwrap().parse().unwrap();
+                // A zero-length old range starts *after* line `start`.
+                let start = if header.starts_with(&format!("{},0 ", start)) {
+                    start
+                } else {
+                    start - 1
+                };
+                out.extend(old[pos..start].iter().copied());
+                pos = start;
+            } else if let Some(text) = line.strip_prefix(' ') {
+                assert_eq!(old[pos], text, "context mismatch");
+                out.push_str(text);
+                pos += 1;
+            } else if let Some(text) = line.strip_prefix('-') {
+                assert_eq!(old[pos], text, "removed line mismatch");
+                pos += 1;
+            } else if let Some(text) = line.strip_prefix('+') {
+                out.push_str(text);
             }
-            other => panic!("expected Modified, got {:?}", other),
         }
+        out.extend(old[pos..].iter().copied());
+        out
     }
 
     #[test]
-    fn diff_detects_new_files() {
+    fn diff_algorithms_group_hunks_differently_but_apply_cleanly() {
+        // Moving `fn b` above `fn a`: the shared `}` and blank lines let Myers
+        // interleave the two bodies, while patience anchors on the unique
+        // signatures.
+        let original = "fn a() {\n    one();\n    two();\n}\n\nfn b() {\n    three();\n}\n\nfn c() {\n    four();\n}\n";
+        let modified = "fn b() {\n    three();\n}\n\nfn a() {\n    one();\n    two();\n}\n\nfn c() {\n    four();\n}\n";
+
+        let myers = unified_diff_with("f.rs", original, modified, 3, DiffAlgorithm::Myers);
+        let patience = unified_diff_with("f.rs", original, modified, 3, DiffAlgorithm::Patience);
+        let histogram = unified_diff_with("f.rs", original, modified, 3, DiffAlgorithm::Histogram);
+        assert_ne!(myers, patience);
+        assert_ne!(myers, histogram);
+        assert_eq!(apply_unified(original, &myers), modified);
+        assert_eq!(apply_unified(original, &patience), modified);
+        assert_eq!(apply_unified(original, &histogram), modified);
+        assert_eq!(myers, unified_diff("f.rs", original, modified, 3));
+    }
+
+    #[test]
+    fn histogram_diff_matches_unified_format() {
+        // Same hunk shape as Myers for a one-line edit, including the
+        // missing-newline marker and no output for identical input.
+        let original = "a\nb\nc\nd\ne\nf\ng\nh\ni";
+        let modified = "a\nb\nc\nd\nE\nf\ng\nh\nI";
+        for context in [0, 1, 3] {
+            assert_eq!(
+                unified_diff_with(
+                    "f.txt",
+                    original,
+                    modified,
+                    context,
+                    DiffAlgorithm::Histogram
+                ),
+                unified_diff("f.txt", original, modified, context),
+            );
+        }
+        assert_eq!(
+            unified_diff_with("f.txt", original, original, 3, DiffAlgorithm::Histogram),
+            ""
+        );
+    }
+
+    #[test]
+    fn non_utf8_files_are_summarized_not_diffed() {
         let source = create_source_project();
+        // A latin-1 config: "café" with 0xE9, no NUL bytes.
+        fs::write(source.path().join("legacy.cfg"), b"name=caf\xe9\n").unwrap();
         let staging_root = TempDir::new().unwrap();
 
         let overlay = OverlayWorkspace::create(
@@ -1808,50 +3466,146 @@ mod tests {
         )
         .unwrap();
 
-        // Create a new file in staging.
-        fs::write(
-            overlay.staging_dir().join("src/new_module.rs"),
-            "pub fn new_thing() {}\n",
-        )
-        .unwrap();
+        fs::write(overlay.staging_dir().join("legacy.cfg"), b"name=caf\xe9s\n").unwrap();
+        fs::write(overlay.staging_dir().join("new.cfg"), b"x=\xff\n").unwrap();
 
         let changes = overlay.diff_all().unwrap();
-        assert_eq!(changes.len(), 1);
-
+        assert_eq!(changes.len(), 2);
         match &changes[0] {
+            OverlayChange::Modified { path, diff } => {
+                assert_eq!(path, "legacy.cfg");
+                assert!(
+                    diff.contains("[binary file changed: 10 -> 11 bytes]"),
+                    "{}",
+                    diff
+                );
+            }
+            other => panic!("expected Modified, got {:?}", other),
+        }
+        match &changes[1] {
             OverlayChange::Created { path, content } => {
-                assert_eq!(path, "src/new_module.rs");
-                assert!(content.contains("new_thing"));
+                assert_eq!(path, "new.cfg");
+                assert_eq!(content, "[binary file: 4 bytes]");
             }
             other => panic!("expected Created, got {:?}", other),
         }
+
+        // diff_file must not error on the created or deleted non-UTF-8 file.
+        let created = overlay.diff_file("new.cfg").unwrap().unwrap();
+        assert!(created.contains("non-text file created"));
+        fs::remove_file(overlay.staging_dir().join("legacy.cfg")).unwrap();
+        let deleted = overlay.diff_file("legacy.cfg").unwrap().unwrap();
+        assert!(deleted.contains("non-text file deleted"));
+    }
+
+    /// Build a synthetic tree of `dirs * files_per_dir` small source files.
+    fn create_large_source_project(dirs: usize, files_per_dir: usize) -> TempDir {
+        let dir = TempDir::new().unwrap();
+        for d in 0..dirs {
+            let sub = dir.path().join(format!("mod_{:03}", d));
+            fs::create_dir_all(&sub).unwrap();
+            for f in 0..files_per_dir {
+                fs::write(
+                    sub.join(format!("file_{:03}.rs", f)),
+                    format!("// mod {} file {}\npub fn f() -> usize {{ {} }}\n", d, f, f),
+                )
+                .unwrap();
+            }
+        }
+        dir
+    }
+
+    /// Touch every tenth file, delete one, and create one in staging.
+    fn mutate_staging(overlay: &OverlayWorkspace, dirs: usize, files_per_dir: usize) {
+        for d in 0..dirs {
+            for f in (0..files_per_dir).step_by(10) {
+                fs::write(
+                    overlay
+                        .staging_dir()
+                        .join(format!("mod_{:03}/file_{:03}.rs", d, f)),
+                    "pub fn f() -> usize { 0 }\n",
+                )
+                .unwrap();
+            }
+        }
+        fs::remove_file(overlay.staging_dir().join("mod_000/file_001.rs")).unwrap();
+        fs::write(overlay.staging_dir().join("mod_000/new.rs"), "// new\n").unwrap();
     }
 
     #[test]
-    fn diff_detects_deleted_files() {
-        let source = create_source_project();
+    fn parallel_and_sequential_diff_agree() {
+        let source = create_large_source_project(8, 25);
         let staging_root = TempDir::new().unwrap();
-
-        let overlay = OverlayWorkspace::create(
-            "goal-1",
+        let mut overlay = OverlayWorkspace::create_with_options(
+            "goal-par",
             source.path(),
             staging_root.path(),
             ExcludePatterns::none(),
+            OverlayStagingMode::Full,
+            true,
         )
         .unwrap();
+        assert_eq!(overlay.copy_stat().unwrap().files_copied, 200);
+        mutate_staging(&overlay, 8, 25);
+
+        let parallel = overlay.diff_all().unwrap();
+        overlay.set_parallel(false);
+        let sequential = overlay.diff_all().unwrap();
+
+        // 8 dirs × 3 touched files, plus one deleted and one created.
+        assert_eq!(parallel.len(), 8 * 3 + 2);
+        assert_eq!(parallel, sequential);
+        let paths: Vec<String> = parallel.iter().map(|c| c.path().to_string()).collect();
+        let mut sorted = paths.clone();
+        sorted.sort();
+        assert_eq!(paths, sorted, "changes must be ordered by path");
+    }
 
-        // Delete a file from staging.
-        fs::remove_file(overlay.staging_dir().join("src/lib.rs")).unwrap();
-
-        let changes = overlay.diff_all().unwrap();
-        assert_eq!(changes.len(), 1);
-
-        match &changes[0] {
-            OverlayChange::Deleted { path } => {
-                assert_eq!(path, "src/lib.rs");
-            }
-            other => panic!("expected Deleted, got {:?}", other),
+    /// Compare sequential and parallel staging creation + diff on a large tree.
+    ///
+    /// Run with `cargo test -p ta-workspace --release -- --ignored --nocapture
+    /// overlay_parallel_benchmark`. The speedup depends on core count and disk.
+    #[test]
+    #[ignore = "benchmark"]
+    fn overlay_parallel_benchmark() {
+        let (dirs, files_per_dir) = (100, 200);
+        let source = create_large_source_project(dirs, files_per_dir);
+
+        let mut timings = Vec::new();
+        let mut results = Vec::new();
+        for parallel in [false, true] {
+            let staging_root = TempDir::new().unwrap();
+            let start = Instant::now();
+            let overlay = OverlayWorkspace::create_with_options(
+                "goal-bench",
+                source.path(),
+                staging_root.path(),
+                ExcludePatterns::none(),
+                OverlayStagingMode::Full,
+                parallel,
+            )
+            .unwrap();
+            let created = start.elapsed();
+            mutate_staging(&overlay, dirs, files_per_dir);
+            let start = Instant::now();
+            results.push(overlay.diff_all().unwrap());
+            let diffed = start.elapsed();
+            println!(
+                "parallel={:<5} create={:>8.1?} diff={:>8.1?} ({} files)",
+                parallel,
+                created,
+                diffed,
+                dirs * files_per_dir
+            );
+            timings.push(created + diffed);
         }
+
+        assert_eq!(results[0], results[1]);
+        println!(
+            "speedup: {:.2}x on {} thread(s)",
+            timings[0].as_secs_f64() / timings[1].as_secs_f64(),
+            rayon::current_num_threads()
+        );
     }
 
     #[test]
@@ -1886,6 +3640,7 @@ mod tests {
             &ExcludePatterns::none(),
             CopyStrategy::Full,
             &mut stat,
+            false,
         )
         .unwrap();
 
@@ -1942,6 +3697,19 @@ mod tests {
 
     // ── V1 TEMPORARY: ExcludePatterns tests ───────────────────────
 
+    #[test]
+    fn matching_pattern_names_the_excluding_pattern() {
+        let excludes = ExcludePatterns::from_taignore("build/\n*.log\nsecrets.env\n");
+        assert_eq!(excludes.matching_pattern("build"), Some("build/"));
+        assert_eq!(excludes.matching_pattern("debug.log"), Some("*.log"));
+        assert_eq!(
+            excludes.matching_pattern("secrets.env"),
+            Some("secrets.env")
+        );
+        assert_eq!(excludes.matching_pattern(".ta"), Some(".ta"));
+        assert_eq!(excludes.matching_pattern("main.rs"), None);
+    }
+
     #[test]
     fn default_excludes_skip_target_dir() {
         let source = create_source_project();
@@ -2138,6 +3906,82 @@ mod tests {
         );
     }
 
+    #[test]
+    fn true_conflicts_lists_only_agent_changed_overlaps() {
+        let source = TempDir::new().unwrap();
+        fs::write(source.path().join("a.txt"), "original A").unwrap();
+        fs::write(source.path().join("b.txt"), "original B").unwrap();
+
+        let staging_root = TempDir::new().unwrap();
+        let overlay = OverlayWorkspace::create(
+            "goal-true-conflicts",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+
+        fs::write(overlay.staging_dir().join("a.txt"), "agent changed A").unwrap();
+        fs::write(source.path().join("a.txt"), "source changed A").unwrap();
+        fs::write(source.path().join("b.txt"), "source changed B").unwrap();
+
+        let artifact_uris = vec![
+            "fs://workspace/a.txt".to_string(),
+            "fs://workspace/b.txt".to_string(),
+        ];
+        let conflicts = overlay.true_conflicts(&artifact_uris).unwrap();
+        let paths: Vec<&str> = conflicts.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["a.txt"]);
+
+        // Unprompted, Interactive refuses the apply just like Abort.
+        let result = overlay.apply_with_conflict_check(
+            source.path(),
+            ConflictResolution::Interactive,
+            &artifact_uris,
+        );
+        assert!(result.is_err());
+        assert_eq!(
+            fs::read_to_string(source.path().join("a.txt")).unwrap(),
+            "source changed A"
+        );
+    }
+
+    #[test]
+    fn source_edit_to_renamed_file_is_a_conflict() {
+        let source = TempDir::new().unwrap();
+        let body = "fn alpha() {}\nfn bravo() {}\nfn charlie() {}\nfn delta() {}\n";
+        fs::write(source.path().join("old.rs"), body).unwrap();
+
+        let staging_root = TempDir::new().unwrap();
+        let overlay = OverlayWorkspace::create(
+            "goal-rename-conflict",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+        let staging = overlay.staging_dir().to_path_buf();
+        fs::rename(staging.join("old.rs"), staging.join("new.rs")).unwrap();
+        // Someone edits the old path in the source after the goal started.
+        fs::write(
+            source.path().join("old.rs"),
+            format!("{}fn echo() {{}}\n", body),
+        )
+        .unwrap();
+
+        let artifact_uris = vec!["fs://workspace/new.rs".to_string()];
+        let conflicts = overlay.true_conflicts(&artifact_uris).unwrap();
+        let paths: Vec<&str> = conflicts.iter().map(|c| c.path.as_str()).collect();
+        assert_eq!(paths, vec!["old.rs"]);
+
+        // The apply refuses rather than deleting the edited old path.
+        assert!(overlay
+            .apply_with_conflict_check(source.path(), ConflictResolution::Abort, &artifact_uris)
+            .is_err());
+        assert!(source.path().join("old.rs").exists());
+        assert!(!source.path().join("new.rs").exists());
+    }
+
     #[test]
     fn phantom_artifacts_excluded_from_apply() {
         // Setup: source has files A and B.
@@ -2308,14 +4152,7 @@ mod tests {
         // Should see ONLY the main.rs change — .git/ must not appear.
         let git_changes: Vec<_> = changes
             .iter()
-            .filter(|c| {
-                let p = match c {
-                    OverlayChange::Modified { path, .. } => path,
-                    OverlayChange::Created { path, .. } => path,
-                    OverlayChange::Deleted { path } => path,
-                };
-                p.starts_with(".git")
-            })
+            .filter(|c| c.path().starts_with(".git"))
             .collect();
         assert!(
             git_changes.is_empty(),
@@ -2423,14 +4260,7 @@ mod tests {
         let changes = overlay.diff_all().unwrap();
         let node_changes: Vec<_> = changes
             .iter()
-            .filter(|c| {
-                let p = match c {
-                    OverlayChange::Modified { path, .. } => path,
-                    OverlayChange::Created { path, .. } => path,
-                    OverlayChange::Deleted { path } => path,
-                };
-                p.starts_with("node_modules")
-            })
+            .filter(|c| c.path().starts_with("node_modules"))
             .collect();
         assert!(
             node_changes.is_empty(),
@@ -2755,14 +4585,7 @@ mod tests {
         .unwrap();
 
         let changes = overlay.diff_all().unwrap();
-        let paths: Vec<&str> = changes
-            .iter()
-            .map(|c| match c {
-                OverlayChange::Modified { path, .. }
-                | OverlayChange::Created { path, .. }
-                | OverlayChange::Deleted { path } => path.as_str(),
-            })
-            .collect();
+        let paths: Vec<&str> = changes.iter().map(|c| c.path()).collect();
 
         assert!(
             !paths.contains(&".ta-decisions.json"),
@@ -2847,14 +4670,7 @@ mod tests {
 
         // Diff for goal B must also be clean (no decisions file).
         let changes = overlay_b.diff_all().unwrap();
-        let paths: Vec<&str> = changes
-            .iter()
-            .map(|c| match c {
-                OverlayChange::Modified { path, .. }
-                | OverlayChange::Created { path, .. }
-                | OverlayChange::Deleted { path } => path.as_str(),
-            })
-            .collect();
+        let paths: Vec<&str> = changes.iter().map(|c| c.path()).collect();
         assert!(
             !paths.contains(&".ta-decisions.json"),
             "goal B diff must not include .ta-decisions.json from goal A, got: {:?}",
@@ -2979,6 +4795,31 @@ mod tests {
         );
     }
 
+    #[test]
+    fn materialize_git_ref_checks_out_committed_tree() {
+        let repo = TempDir::new().unwrap();
+        init_git_repo_with_file(repo.path(), "README.md", "committed\n");
+        fs::write(repo.path().join("README.md"), "working tree edit\n").unwrap();
+
+        let dest = TempDir::new().unwrap();
+        let (tree, sha) = materialize_git_ref(repo.path(), "HEAD", dest.path()).unwrap();
+        assert_eq!(Some(sha), get_git_head_sha(repo.path()));
+        assert_eq!(
+            fs::read_to_string(tree.join("README.md")).unwrap(),
+            "committed\n"
+        );
+        // The real index and working tree are untouched.
+        assert_eq!(
+            fs::read_to_string(repo.path().join("README.md")).unwrap(),
+            "working tree edit\n"
+        );
+
+        assert!(matches!(
+            materialize_git_ref(repo.path(), "no-such-ref", dest.path()),
+            Err(WorkspaceError::GitError(_))
+        ));
+    }
+
     #[test]
     fn get_git_head_sha_returns_none_outside_repo() {
         let dir = TempDir::new().unwrap();
@@ -2988,4 +4829,234 @@ mod tests {
             "non-git directory should return None for SHA"
         );
     }
+
+    /// Two sibling package roots staged under one multi-root workspace.
+    fn multi_root_fixture() -> (TempDir, TempDir, TempDir, OverlayWorkspace) {
+        let api = TempDir::new().unwrap();
+        let web = TempDir::new().unwrap();
+        let staging = TempDir::new().unwrap();
+        fs::create_dir_all(staging.path().join("api")).unwrap();
+        fs::create_dir_all(staging.path().join("web")).unwrap();
+        for root in [api.path(), &staging.path().join("api")] {
+            fs::write(root.join("lib.rs"), "pub fn api() {}\n").unwrap();
+        }
+        for root in [web.path(), &staging.path().join("web")] {
+            fs::write(root.join("index.ts"), "export {};\n").unwrap();
+        }
+        let overlay = OverlayWorkspace::open_multi(
+            "goal-1",
+            vec![
+                ("api".to_string(), api.path().to_path_buf()),
+                ("web".to_string(), web.path().to_path_buf()),
+            ],
+            staging.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+        (api, web, staging, overlay)
+    }
+
+    #[test]
+    fn multi_root_diff_prefixes_paths_and_apply_routes_to_each_root() {
+        let (api, web, staging, overlay) = multi_root_fixture();
+        fs::write(staging.path().join("api/lib.rs"), "pub fn api_v2() {}\n").unwrap();
+        fs::write(staging.path().join("web/app.ts"), "export const app = 1;\n").unwrap();
+
+        let changes = overlay.diff_all().unwrap();
+        let paths: Vec<&str> = changes.iter().map(|c| c.path()).collect();
+        assert_eq!(paths, vec!["api/lib.rs", "web/app.ts"]);
+        assert_eq!(overlay.resource_uri("web/app.ts"), "fs://web/app.ts");
+        assert_eq!(
+            overlay.uri_path("fs://web/app.ts").as_deref(),
+            Some("web/app.ts")
+        );
+        assert_eq!(overlay.uri_path("fs://workspace/app.ts"), None);
+
+        let uris: Vec<String> = paths.iter().map(|p| overlay.resource_uri(p)).collect();
+        let unused_target = TempDir::new().unwrap();
+        let applied = overlay
+            .apply_with_conflict_check(unused_target.path(), ConflictResolution::Abort, &uris)
+            .unwrap();
+        assert_eq!(applied.len(), 2);
+        assert_eq!(
+            fs::read_to_string(api.path().join("lib.rs")).unwrap(),
+            "pub fn api_v2() {}\n"
+        );
+        assert!(web.path().join("app.ts").exists());
+        assert!(!api.path().join("app.ts").exists());
+        assert!(fs::read_dir(unused_target.path()).unwrap().next().is_none());
+    }
+
+    #[test]
+    fn multi_root_conflict_in_one_root_blocks_every_root() {
+        let (api, web, staging, mut overlay) = multi_root_fixture();
+        for (alias, dir) in [("api", api.path()), ("web", web.path())] {
+            let snapshot = SourceSnapshot::capture(dir, |_| false).unwrap();
+            overlay.set_root_snapshot(alias, snapshot).unwrap();
+        }
+        fs::write(staging.path().join("api/lib.rs"), "pub fn agent() {}\n").unwrap();
+        fs::write(staging.path().join("web/index.ts"), "export const a = 1;\n").unwrap();
+        // The web source changes under the agent's edit.
+        fs::write(web.path().join("index.ts"), "export const b = 2;\n").unwrap();
+
+        let uris = vec![
+            "fs://api/lib.rs".to_string(),
+            "fs://web/index.ts".to_string(),
+        ];
+        let conflicts = overlay.true_conflicts(&uris).unwrap();
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].path, "web/index.ts");
+
+        let err = overlay
+            .apply_with_conflict_check(api.path(), ConflictResolution::Abort, &uris)
+            .unwrap_err();
+        assert!(matches!(err, WorkspaceError::ConflictDetected { .. }));
+        assert_eq!(
+            fs::read_to_string(api.path().join("lib.rs")).unwrap(),
+            "pub fn api() {}\n",
+            "no root is written when any root conflicts"
+        );
+    }
+
+    #[test]
+    fn multi_root_apply_failure_restores_roots_already_applied() {
+        let (api, web, staging, overlay) = multi_root_fixture();
+        fs::write(staging.path().join("api/lib.rs"), "pub fn api_v2() {}\n").unwrap();
+        fs::write(staging.path().join("api/new.rs"), "pub fn new() {}\n").unwrap();
+        fs::write(staging.path().join("web/index.ts"), "export const a = 1;\n").unwrap();
+
+        let uris = vec![
+            "fs://api/lib.rs".to_string(),
+            "fs://api/new.rs".to_string(),
+            "fs://web/index.ts".to_string(),
+        ];
+        let web_dir = web.path().to_path_buf();
+        let err = overlay
+            .apply_per_root(&uris, |root, target, uris| {
+                if root.source_dir() == web_dir {
+                    return Err(WorkspaceError::ConflictDetected {
+                        conflicts: vec!["web failed".to_string()],
+                    });
+                }
+                root.apply_selective(target, uris)
+            })
+            .unwrap_err();
+        assert!(matches!(err, WorkspaceError::ConflictDetected { .. }));
+        assert_eq!(
+            fs::read_to_string(api.path().join("lib.rs")).unwrap(),
+            "pub fn api() {}\n",
+            "the api root is restored when web fails"
+        );
+        assert!(!api.path().join("new.rs").exists());
+        assert_eq!(
+            fs::read_to_string(web.path().join("index.ts")).unwrap(),
+            "export {};\n"
+        );
+    }
+
+    #[test]
+    fn create_multi_stages_each_root_under_its_alias() {
+        let api = TempDir::new().unwrap();
+        let web = TempDir::new().unwrap();
+        fs::write(api.path().join("lib.rs"), "pub fn api() {}\n").unwrap();
+        fs::write(web.path().join("index.ts"), "export {};\n").unwrap();
+        let staging_root = TempDir::new().unwrap();
+
+        let overlay = OverlayWorkspace::create_multi(
+            "goal-1",
+            vec![
+                ("api".to_string(), api.path().to_path_buf()),
+                ("web".to_string(), web.path().to_path_buf()),
+            ],
+            staging_root.path(),
+            ExcludePatterns::none(),
+            OverlayStagingMode::Full,
+            true,
+        )
+        .unwrap();
+        let staging = staging_root.path().join("goal-1");
+        assert_eq!(overlay.staging_dir(), staging);
+        assert_eq!(
+            fs::read_to_string(staging.join("api/lib.rs")).unwrap(),
+            "pub fn api() {}\n"
+        );
+        assert!(staging.join("web/index.ts").exists());
+        assert!(overlay.root_snapshot("api").is_some());
+        assert!(overlay.root_snapshot("web").is_some());
+        assert_eq!(
+            overlay.roots().map(|(alias, _)| alias).collect::<Vec<_>>(),
+            vec!["api", "web"]
+        );
+        assert!(overlay.diff_all().unwrap().is_empty());
+        assert_eq!(
+            overlay.apply_target(Path::new("/unused"), "web/index.ts"),
+            web.path().join("index.ts")
+        );
+    }
+
+    #[test]
+    fn open_multi_rejects_bad_aliases() {
+        let dir = TempDir::new().unwrap();
+        let open = |aliases: &[&str]| {
+            OverlayWorkspace::open_multi(
+                "goal-1",
+                aliases
+                    .iter()
+                    .map(|a| (a.to_string(), dir.path().to_path_buf()))
+                    .collect(),
+                dir.path(),
+                ExcludePatterns::none(),
+            )
+        };
+        assert!(open(&[]).is_err());
+        assert!(open(&["a/b"]).is_err());
+        assert!(open(&[".."]).is_err());
+        assert!(open(&["api", "api"]).is_err());
+        assert!(open(&["api", "web"]).unwrap().is_multi_root());
+    }
+
+    #[test]
+    fn destination_remap_relocates_applied_paths() {
+        let source = create_source_project();
+        let staging_root = TempDir::new().unwrap();
+        let mut overlay = OverlayWorkspace::create(
+            "goal-1",
+            source.path(),
+            staging_root.path(),
+            ExcludePatterns::none(),
+        )
+        .unwrap();
+        overlay.add_destination_remap("src/", "/crates/core/src");
+        fs::write(overlay.staging_dir().join("src/lib.rs"), "pub fn v2() {}\n").unwrap();
+        fs::write(overlay.staging_dir().join("README.md"), "# Moved\n").unwrap();
+
+        // Only whole path components match.
+        assert_eq!(overlay.destination_path("src"), "crates/core/src");
+        assert_eq!(overlay.destination_path("srcs/a.rs"), "srcs/a.rs");
+
+        let target = TempDir::new().unwrap();
+        let mut applied = overlay
+            .apply_with_conflict_check(
+                target.path(),
+                ConflictResolution::Abort,
+                &[
+                    "fs://workspace/src/lib.rs".to_string(),
+                    "fs://workspace/README.md".to_string(),
+                ],
+            )
+            .unwrap();
+        applied.sort();
+        assert_eq!(
+            applied,
+            vec![
+                ("README.md".to_string(), "modified"),
+                ("crates/core/src/lib.rs".to_string(), "modified"),
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(target.path().join("crates/core/src/lib.rs")).unwrap(),
+            "pub fn v2() {}\n"
+        );
+        assert!(!target.path().join("src/lib.rs").exists());
+    }
 }
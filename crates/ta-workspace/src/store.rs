@@ -7,14 +7,21 @@
 //
 // Design: each goal gets its own JSONL file: `<store_dir>/<goal_id>.jsonl`.
 // This keeps goals isolated and makes cleanup simple.
+//
+// With a BlobStore attached, new-file contents (and oversized unified diffs)
+// are written to the blob store and the JSONL line carries only the hash
+// (`CreateFileBlob` / `UnifiedDiffBlob`). Loads resolve
+// the hash back to inline content. `migrate_inline_content` rewrites older
+// files that still inline their content.
 
 use std::fs::{self, File, OpenOptions};
 use std::io::{BufRead, BufReader, BufWriter, Write};
 use std::path::{Path, PathBuf};
 
-use ta_changeset::ChangeSet;
+use ta_changeset::{ChangeSet, DiffContent};
 use uuid::Uuid;
 
+use crate::blob_store::BlobStore;
 use crate::error::WorkspaceError;
 
 /// Trait for persisting and retrieving ChangeSets.
@@ -46,6 +53,7 @@ pub trait ChangeStore {
 /// Fine for MVP volumes; swap to SQLite for heavy use.
 pub struct JsonFileStore {
     store_dir: PathBuf,
+    blobs: Option<BlobStore>,
 }
 
 impl JsonFileStore {
@@ -57,13 +65,111 @@ impl JsonFileStore {
             path: store_dir.clone(),
             source,
         })?;
-        Ok(Self { store_dir })
+        Ok(Self {
+            store_dir,
+            blobs: None,
+        })
+    }
+
+    /// Store new-file contents in `blobs` instead of inline in the JSONL.
+    pub fn with_blob_store(mut self, blobs: BlobStore) -> Self {
+        self.blobs = Some(blobs);
+        self
     }
 
     /// Path to the JSONL file for a given goal.
     fn goal_file(&self, goal_id: &str) -> PathBuf {
         self.store_dir.join(format!("{}.jsonl", goal_id))
     }
+
+    /// Serialize a changeset for persistence, moving file content to the
+    /// blob store when one is attached.
+    fn encode_line(&self, changeset: &ChangeSet) -> Result<String, WorkspaceError> {
+        match &self.blobs {
+            Some(blobs) if BlobStore::should_externalize(&changeset.diff_content) => {
+                let mut stored = changeset.clone();
+                stored.diff_content = blobs.externalize(&changeset.diff_content)?;
+                Ok(serde_json::to_string(&stored)?)
+            }
+            _ => Ok(serde_json::to_string(changeset)?),
+        }
+    }
+
+    /// Parse a persisted line, resolving blob references to inline content.
+    fn decode_line(&self, line: &str) -> Result<ChangeSet, WorkspaceError> {
+        let mut cs: ChangeSet = serde_json::from_str(line)?;
+        if let DiffContent::CreateFileBlob { hash, .. }
+        | DiffContent::UnifiedDiffBlob { hash, .. } = &cs.diff_content
+        {
+            let blobs = self.blobs.as_ref().ok_or_else(|| {
+                WorkspaceError::StoreError(format!(
+                    "changeset {} references blob {} but no blob store is configured",
+                    cs.changeset_id, hash
+                ))
+            })?;
+            cs.diff_content = blobs.resolve(cs.diff_content)?;
+        }
+        Ok(cs)
+    }
+
+    /// Rewrite every goal file in this store so inline `CreateFile` content
+    /// and oversized diffs move to the blob store. Returns the number of changesets migrated.
+    ///
+    /// A no-op without a blob store. Lines already referencing blobs are left
+    /// as they are.
+    pub fn migrate_inline_content(&self) -> Result<usize, WorkspaceError> {
+        let Some(blobs) = &self.blobs else {
+            return Ok(0);
+        };
+        let entries = fs::read_dir(&self.store_dir).map_err(|source| WorkspaceError::IoError {
+            path: self.store_dir.clone(),
+            source,
+        })?;
+
+        let mut migrated = 0;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
+                continue;
+            }
+            let content = fs::read_to_string(&path).map_err(|source| WorkspaceError::IoError {
+                path: path.clone(),
+                source,
+            })?;
+
+            let mut lines = Vec::new();
+            let mut changed = 0;
+            for line in content.lines().filter(|l| !l.trim().is_empty()) {
+                let mut cs: ChangeSet = serde_json::from_str(line)?;
+                if BlobStore::should_externalize(&cs.diff_content) {
+                    cs.diff_content = blobs.externalize(&cs.diff_content)?;
+                    lines.push(serde_json::to_string(&cs)?);
+                    changed += 1;
+                } else {
+                    lines.push(line.to_string());
+                }
+            }
+            if changed == 0 {
+                continue;
+            }
+
+            // Write beside the original and rename so an interrupted
+            // migration never leaves a truncated store file.
+            let tmp = path.with_extension("jsonl.migrating");
+            let mut body = lines.join("\n");
+            body.push('\n');
+            fs::write(&tmp, body).map_err(|source| WorkspaceError::IoError {
+                path: tmp.clone(),
+                source,
+            })?;
+            fs::rename(&tmp, &path).map_err(|source| WorkspaceError::IoError {
+                path: path.clone(),
+                source,
+            })?;
+            migrated += changed;
+        }
+        Ok(migrated)
+    }
 }
 
 impl ChangeStore for JsonFileStore {
@@ -81,7 +187,7 @@ impl ChangeStore for JsonFileStore {
             })?;
 
         let mut writer = BufWriter::new(file);
-        let json = serde_json::to_string(changeset)?;
+        let json = self.encode_line(changeset)?;
         writeln!(writer, "{}", json).map_err(|source| WorkspaceError::IoError {
             path: path.clone(),
             source,
@@ -116,8 +222,7 @@ impl ChangeStore for JsonFileStore {
             if line.trim().is_empty() {
                 continue;
             }
-            let cs: ChangeSet = serde_json::from_str(&line)?;
-            changesets.push(cs);
+            changesets.push(self.decode_line(&line)?);
         }
 
         Ok(changesets)
@@ -153,7 +258,7 @@ impl ChangeStore for JsonFileStore {
         let mut writer = BufWriter::new(file);
 
         for cs in remaining {
-            let json = serde_json::to_string(cs)?;
+            let json = self.encode_line(cs)?;
             writeln!(writer, "{}", json).map_err(|source| WorkspaceError::IoError {
                 path: path.clone(),
                 source,
@@ -171,7 +276,7 @@ impl ChangeStore for JsonFileStore {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use ta_changeset::{ChangeKind, DiffContent};
+    use ta_changeset::ChangeKind;
     use tempfile::tempdir;
 
     fn test_changeset(name: &str) -> ChangeSet {
@@ -300,4 +405,76 @@ mod tests {
             assert_eq!(listed[0].changeset_id, id);
         }
     }
+
+    #[test]
+    fn blob_store_dedups_repeated_file_content() {
+        let dir = tempdir().unwrap();
+        let blobs_dir = dir.path().join("blobs");
+        let mut store = JsonFileStore::new(dir.path().join("store"))
+            .unwrap()
+            .with_blob_store(BlobStore::new(&blobs_dir));
+
+        let big = "x".repeat(64 * 1024);
+        for _ in 0..3 {
+            let cs = ChangeSet::new(
+                "fs://workspace/big.txt".to_string(),
+                ChangeKind::FsPatch,
+                DiffContent::CreateFile {
+                    content: big.clone(),
+                },
+            );
+            store.save("goal-1", &cs).unwrap();
+        }
+
+        // One blob, and the JSONL holds only references.
+        assert_eq!(fs::read_dir(&blobs_dir).unwrap().count(), 1);
+        let jsonl = fs::read_to_string(dir.path().join("store/goal-1.jsonl")).unwrap();
+        assert!(jsonl.len() < big.len());
+        assert!(jsonl.contains("create_file_blob"));
+
+        // Loads resolve back to inline content.
+        for cs in store.list("goal-1").unwrap() {
+            assert_eq!(
+                cs.diff_content,
+                DiffContent::CreateFile {
+                    content: big.clone()
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn migrate_inline_content_moves_existing_files_to_blobs() {
+        let dir = tempdir().unwrap();
+        let store_path = dir.path().join("store");
+        let cs = test_changeset("legacy.txt");
+        let id = cs.changeset_id;
+        {
+            // Written by an older version: content inline.
+            let mut legacy = JsonFileStore::new(&store_path).unwrap();
+            legacy.save("goal-1", &cs).unwrap();
+            legacy
+                .save(
+                    "goal-1",
+                    &ChangeSet::new(
+                        "fs://workspace/gone.txt".to_string(),
+                        ChangeKind::FsPatch,
+                        DiffContent::DeleteFile,
+                    ),
+                )
+                .unwrap();
+        }
+
+        let store = JsonFileStore::new(&store_path)
+            .unwrap()
+            .with_blob_store(BlobStore::new(dir.path().join("blobs")));
+        assert_eq!(store.migrate_inline_content().unwrap(), 1);
+        assert_eq!(store.migrate_inline_content().unwrap(), 0);
+
+        let jsonl = fs::read_to_string(store_path.join("goal-1.jsonl")).unwrap();
+        assert!(!jsonl.contains("content of legacy.txt"));
+        let loaded = store.get("goal-1", id).unwrap().unwrap();
+        assert_eq!(loaded.diff_content, cs.diff_content);
+        assert_eq!(store.list("goal-1").unwrap().len(), 2);
+    }
 }